@@ -415,27 +415,29 @@ mod stats {
             let out = match (self.left.is_literal(), self.right.is_literal()) {
                 (false, true) => {
                     let l = stats.get_stats(fld_l.name())?;
-                    match l.to_min_max() {
-                        None => Ok(true),
+                    let lit_s = self.right.evaluate(&dummy, &state).unwrap();
+                    let read = match l.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.right.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op))
+                            apply_operator_stats_rhs_lit(&min_max_s, &lit_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && (self.op != Operator::Eq || l.may_contain_any(&lit_s)))
                 },
                 (true, false) => {
                     let r = stats.get_stats(fld_r.name())?;
-                    match r.to_min_max() {
-                        None => Ok(true),
+                    let lit_s = self.left.evaluate(&dummy, &state).unwrap();
+                    let read = match r.to_min_max() {
+                        None => true,
                         Some(min_max_s) => {
                             // will be incorrect if not
                             debug_assert_eq!(min_max_s.null_count(), 0);
-                            let lit_s = self.left.evaluate(&dummy, &state).unwrap();
-                            Ok(apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op))
+                            apply_operator_stats_lhs_lit(&lit_s, &min_max_s, self.op)
                         },
-                    }
+                    };
+                    Ok(read && (self.op != Operator::Eq || r.may_contain_any(&lit_s)))
                 },
                 // Default: read the file
                 _ => Ok(true),
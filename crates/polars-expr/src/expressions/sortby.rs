@@ -78,6 +78,7 @@ fn sort_by_groups_single_by(
         multithreaded: false,
         ..Default::default()
     };
+    let group_first = indicator.first();
     let new_idx = match indicator {
         GroupsIndicator::Idx((_, idx)) => {
             // SAFETY: group tuples are always in bounds.
@@ -92,11 +93,10 @@ fn sort_by_groups_single_by(
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
+    // an empty group (e.g. after a filter) keeps its first index
+    let first = new_idx.first().copied().unwrap_or(group_first);
 
-    Ok((*first, new_idx))
+    Ok((first, new_idx))
 }
 
 fn sort_by_groups_no_match_single<'a>(
@@ -134,6 +134,53 @@ fn sort_by_groups_no_match_single<'a>(
     Ok(ac_in)
 }
 
+fn sort_by_groups_no_match_multiple<'a>(
+    mut ac_in: AggregationContext<'a>,
+    mut ac_by: Vec<AggregationContext<'a>>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    expr: &Expr,
+) -> PolarsResult<AggregationContext<'a>> {
+    let s_in = ac_in.aggregated();
+    let mut s_in = s_in.list().unwrap().clone();
+    let s_by = ac_by
+        .iter_mut()
+        .map(|ac| {
+            let s = ac.aggregated();
+            s.list().unwrap().into_iter().collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let options = SortMultipleOptions {
+        descending,
+        nulls_last,
+        // We are already in par iter.
+        multithreaded: false,
+        maintain_order,
+    };
+
+    let dtype = s_in.dtype().clone();
+    let ca: PolarsResult<ListChunked> = POOL.install(|| {
+        s_in.par_iter_indexed()
+            .enumerate()
+            .map(|(i, opt_s)| {
+                let s_sort_by = s_by.iter().map(|s| s[i].clone()).collect::<Option<Vec<_>>>();
+                match (opt_s, s_sort_by) {
+                    (Some(s), Some(s_sort_by)) => {
+                        polars_ensure!(s_sort_by.iter().all(|s_by| s.len() == s_by.len()), ComputeError: "series lengths don't match in 'sort_by' expression");
+                        let idx = s_sort_by[0].arg_sort_multiple(&s_sort_by[1..], &options)?;
+                        Ok(Some(unsafe { s.take_unchecked(&idx) }))
+                    },
+                    _ => Ok(None),
+                }
+            })
+            .collect_ca_with_dtype("", dtype)
+    });
+    let s = ca?.with_name(s_in.name()).into_series();
+    ac_in.with_series(s, true, Some(expr))?;
+    Ok(ac_in)
+}
+
 fn sort_by_groups_multiple_by(
     indicator: GroupsIndicator,
     sort_by_s: &[Series],
@@ -142,6 +189,7 @@ fn sort_by_groups_multiple_by(
     multithreaded: bool,
     maintain_order: bool,
 ) -> PolarsResult<(IdxSize, IdxVec)> {
+    let group_first = indicator.first();
     let new_idx = match indicator {
         GroupsIndicator::Idx((_first, idx)) => {
             // SAFETY: group tuples are always in bounds.
@@ -176,11 +224,10 @@ fn sort_by_groups_multiple_by(
             map_sorted_indices_to_group_slice(&sorted_idx, first)
         },
     };
-    let first = new_idx
-        .first()
-        .ok_or_else(|| polars_err!(ComputeError: "{}", ERR_MSG))?;
+    // an empty group (e.g. after a filter) keeps its first index
+    let first = new_idx.first().copied().unwrap_or(group_first);
 
-    Ok((*first, new_idx))
+    Ok((first, new_idx))
 }
 
 impl PhysicalExpr for SortByExpr {
@@ -319,6 +366,19 @@ impl PhysicalExpr for SortByExpr {
 
             groups?
         } else {
+            // The groups of the lhs of the expressions do not match the series values,
+            // we must take the slower path.
+            if !matches!(ac_in.update_groups, UpdateGroups::No) {
+                return sort_by_groups_no_match_multiple(
+                    ac_in,
+                    ac_sort_by,
+                    descending,
+                    nulls_last,
+                    self.sort_options.maintain_order,
+                    &self.expr,
+                );
+            };
+
             let groups = ac_sort_by[0].groups();
 
             let groups = POOL.install(|| {
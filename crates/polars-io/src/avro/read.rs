@@ -1,4 +1,4 @@
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
@@ -7,6 +7,7 @@ use polars_core::prelude::*;
 
 use crate::prelude::*;
 use crate::shared::{finish_reader, ArrowReader};
+use crate::RowIndex;
 
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
@@ -33,6 +34,7 @@ pub struct AvroReader<R> {
     n_rows: Option<usize>,
     columns: Option<Vec<String>>,
     projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
 }
 
 impl<R: Read + Seek> AvroReader<R> {
@@ -44,12 +46,32 @@ impl<R: Read + Seek> AvroReader<R> {
 
     /// Get arrow schema of the avro File, this is faster than a polars schema.
     pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
+        self.reader.rewind()?;
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
         let schema = read::infer_schema(&metadata.record)?;
         Ok(schema)
     }
 
+    /// Count the rows of the file. This only reads the headers of the data blocks, which are
+    /// neither decompressed nor deserialized.
+    pub fn num_rows(&mut self) -> PolarsResult<usize> {
+        self.reader.rewind()?;
+        avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
+
+        let mut num_rows = 0;
+        // Every block starts with its number of rows and its size in bytes, and ends with the
+        // 16 byte sync marker of the file.
+        while let Some(rows) = read_long(&mut self.reader)?.filter(|rows| *rows > 0) {
+            let Some(bytes) = read_long(&mut self.reader)? else {
+                polars_bail!(ComputeError: "unexpected end of avro file")
+            };
+            num_rows += rows as usize;
+            self.reader.seek(SeekFrom::Current(bytes + 16))?;
+        }
+        Ok(num_rows)
+    }
+
     /// Stop reading when `n` rows are read.
     pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
         self.n_rows = num_rows;
@@ -68,6 +90,29 @@ impl<R: Read + Seek> AvroReader<R> {
         self.columns = columns;
         self
     }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+}
+
+/// Reads a zigzag encoded long. Returns `None` if the reader is at its end.
+fn read_long<R: Read>(reader: &mut R) -> PolarsResult<Option<i64>> {
+    let mut value = 0u64;
+    let mut byte = [0u8; 1];
+    for i in 0..10 {
+        if reader.read(&mut byte)? == 0 {
+            polars_ensure!(i == 0, ComputeError: "unexpected end of avro file");
+            return Ok(None);
+        }
+        value |= u64::from(byte[0] & 0x7F) << (i * 7);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some((value >> 1) as i64 ^ -((value & 1) as i64)));
+        }
+    }
+    polars_bail!(ComputeError: "invalid avro block header")
 }
 
 impl<R> ArrowReader for read::Reader<R>
@@ -90,6 +135,7 @@ where
             n_rows: None,
             columns: None,
             projection: None,
+            row_index: None,
         }
     }
 
@@ -100,6 +146,7 @@ where
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
+        self.reader.rewind()?;
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
         let schema = read::infer_schema(&metadata.record)?;
@@ -108,7 +155,9 @@ where
             self.projection = Some(columns_to_projection(columns, &schema)?);
         }
 
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
+        let (projection, projected_schema) = if let Some(mut projection) = self.projection {
+            // The columns are read in the order of the file.
+            projection.sort_unstable();
             let mut prj = vec![false; schema.fields.len()];
             for &index in projection.iter() {
                 prj[index] = true;
@@ -127,7 +176,7 @@ where
             self.n_rows,
             None,
             &projected_schema,
-            None,
+            self.row_index,
         )
     }
 }
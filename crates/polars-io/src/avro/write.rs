@@ -1,14 +1,60 @@
 use std::io::Write;
 
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 pub use Compression as AvroCompression;
 
 use crate::shared::{schema_to_arrow_checked, SerWriter};
 
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvroWriterOptions {
+    /// Data block compression
+    #[cfg_attr(feature = "serde", serde(with = "serde_compression"))]
+    pub compression: Option<AvroCompression>,
+    /// Name of the Avro record
+    pub name: String,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+}
+
+#[cfg(feature = "serde")]
+mod serde_compression {
+    use serde::{Deserializer, Serializer};
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "Compression")]
+    enum CompressionDef {
+        Deflate,
+        Snappy,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "CompressionDef")] Compression);
+
+    pub(super) fn serialize<S: Serializer>(
+        compression: &Option<Compression>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        compression.map(Wrapper).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Compression>, D::Error> {
+        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|wrapper| wrapper.0))
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -49,6 +95,12 @@ where
         self.name = name;
         self
     }
+
+    /// Write the header of the file and return a writer that writes every batch as
+    /// (a number of) Avro data blocks.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        BatchedWriter::new(self.writer, schema, self.name, self.compression)
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -64,39 +116,84 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(&df.schema(), false, "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let mut writer = BatchedWriter::new(
+            &mut self.writer,
+            &df.schema(),
+            self.name.clone(),
+            self.compression,
+        )?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    record: Record,
+    compression: Option<Compression>,
+    data: Vec<u8>,
+    compressed_block: CompressedBlock,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    fn new(
+        mut writer: W,
+        schema: &Schema,
+        name: String,
+        compression: Option<AvroCompression>,
+    ) -> PolarsResult<Self> {
+        let schema = schema_to_arrow_checked(schema, false, "avro")?;
+        let record = write::to_record(&schema, name)?;
+        avro_schema::write::write_metadata(&mut writer, record.clone(), compression)
+            .map_err(to_compute_err)?;
+
+        Ok(Self {
+            writer,
+            record,
+            compression,
+            data: vec![],
+            compressed_block: CompressedBlock::default(),
+        })
+    }
+
+    /// Write a batch to the avro writer.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         for chunk in df.iter_chunks(false, true) {
+            let num_rows = chunk.len();
+            // A block without rows marks the end of the file.
+            if num_rows == 0 {
+                continue;
+            }
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
+            let mut block = Block::new(num_rows, std::mem::take(&mut self.data));
             write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+            let _was_compressed = avro_schema::write::compress(
+                &mut block,
+                &mut self.compressed_block,
+                self.compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
+            avro_schema::write::write_block(&mut self.writer, &self.compressed_block)
                 .map_err(to_compute_err)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
             // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            self.data = block.data;
+            self.data.clear();
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0
         }
+        Ok(())
+    }
 
+    /// Flush the underlying writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.flush()?;
         Ok(())
     }
 }
@@ -0,0 +1,331 @@
+//! Compression of text based formats (CSV and NDJSON).
+//!
+//! Compressed files are decoded incrementally: [`DecompressedChunks`] yields batches of whole
+//! records so that readers never have to inflate the complete file in memory.
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// magic numbers
+pub(crate) const GZIP: [u8; 2] = [31, 139];
+pub(crate) const ZLIB0: [u8; 2] = [0x78, 0x01];
+pub(crate) const ZLIB1: [u8; 2] = [0x78, 0x9C];
+pub(crate) const ZLIB2: [u8; 2] = [0x78, 0xDA];
+pub(crate) const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// The default number of decompressed bytes in a batch.
+pub const DEFAULT_DECOMPRESSED_CHUNK_SIZE: usize = 1 << 24;
+
+/// Compression codec for writing text based formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CompressionCodec {
+    /// Gzip with an optional level between 0 and 9.
+    Gzip(Option<u32>),
+    /// Zstandard with an optional level between 1 and 22.
+    Zstd(Option<i32>),
+}
+
+impl CompressionCodec {
+    /// The conventional file extension, without the leading dot.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Gzip(_) => "gz",
+            Self::Zstd(_) => "zst",
+        }
+    }
+}
+
+/// check if csv file is compressed
+pub fn is_compressed(bytes: &[u8]) -> bool {
+    bytes.starts_with(&ZLIB0)
+        || bytes.starts_with(&ZLIB1)
+        || bytes.starts_with(&ZLIB2)
+        || bytes.starts_with(&GZIP)
+        || bytes.starts_with(&ZSTD)
+}
+
+/// Checks the magic bytes of `reader` and rewinds it.
+pub fn is_compressed_reader<R: Read + Seek>(reader: &mut R) -> PolarsResult<bool> {
+    let position = reader.stream_position()?;
+    let mut magic = [0u8; 4];
+    let mut read = 0;
+    while read < magic.len() {
+        match reader.read(&mut magic[read..])? {
+            0 => break,
+            n => read += n,
+        }
+    }
+    reader.seek(SeekFrom::Start(position))?;
+    Ok(is_compressed(&magic[..read]))
+}
+
+/// A writer that optionally compresses everything written to it.
+///
+/// [`CompressedWriter::finish`] must be called once all data is written.
+pub enum CompressedWriter<W: Write> {
+    Plain(W),
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn try_new(writer: W, compression: Option<CompressionCodec>) -> PolarsResult<Self> {
+        let Some(compression) = compression else {
+            return Ok(Self::Plain(writer));
+        };
+
+        #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+        {
+            Ok(match compression {
+                CompressionCodec::Gzip(level) => {
+                    let level = level.unwrap_or(6);
+                    polars_ensure!(
+                        level <= 9,
+                        InvalidOperation: "invalid gzip compression level {}; expected a level between 0 and 9", level
+                    );
+                    Self::Gzip(flate2::write::GzEncoder::new(
+                        writer,
+                        flate2::Compression::new(level),
+                    ))
+                },
+                CompressionCodec::Zstd(level) => {
+                    let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+                    polars_ensure!(
+                        (1..=22).contains(&level),
+                        InvalidOperation: "invalid zstd compression level {}; expected a level between 1 and 22", level
+                    );
+                    Self::Zstd(zstd::Encoder::new(writer, level)?)
+                },
+            })
+        }
+        #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+        {
+            polars_bail!(
+                ComputeError: "cannot write {:?} compressed data; \
+                compile with feature 'decompress' or 'decompress-fast'", compression
+            )
+        }
+    }
+
+    /// Writes the trailer of the compressed stream and flushes the underlying writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        match self {
+            Self::Plain(w) => w.flush()?,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.try_finish()?,
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => {
+                w.do_finish()?;
+                w.get_mut().flush()?
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.write(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        match self {
+            Self::Plain(w) => w.write_all(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.write_all(buf),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.write_all(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Gzip(w) => w.flush(),
+            #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+            Self::Zstd(w) => w.flush(),
+        }
+    }
+}
+
+/// Returns a decoder if `reader` starts with the magic bytes of a supported compression.
+pub fn maybe_decoder<'a, R: BufRead + Send + Sync + 'a>(
+    mut reader: R,
+) -> PolarsResult<Option<Box<dyn Read + Send + Sync + 'a>>> {
+    let magic = reader.fill_buf()?;
+    if !is_compressed(magic) {
+        return Ok(None);
+    }
+
+    #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+    {
+        let decoder: Box<dyn Read + Send + Sync + 'a> = if magic.starts_with(&GZIP) {
+            Box::new(flate2::bufread::MultiGzDecoder::new(reader))
+        } else if magic.starts_with(&ZSTD) {
+            Box::new(zstd::Decoder::with_buffer(reader)?)
+        } else {
+            Box::new(flate2::bufread::ZlibDecoder::new(reader))
+        };
+        Ok(Some(decoder))
+    }
+    #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
+    {
+        polars_bail!(
+            ComputeError: "cannot read compressed data; \
+            compile with feature 'decompress' or 'decompress-fast'"
+        )
+    }
+}
+
+/// Splits a decompressed stream into batches of whole records.
+///
+/// Records are terminated by `eol_char`; an `eol_char` between `quote_char`s does not end a
+/// record.
+pub struct DecompressedChunks<'a> {
+    decoder: Box<dyn Read + Send + Sync + 'a>,
+    buf: Vec<u8>,
+    chunk_size: usize,
+    quote_char: Option<u8>,
+    eol_char: u8,
+    eof: bool,
+}
+
+impl<'a> DecompressedChunks<'a> {
+    pub fn new(
+        decoder: Box<dyn Read + Send + Sync + 'a>,
+        chunk_size: usize,
+        quote_char: Option<u8>,
+        eol_char: u8,
+    ) -> Self {
+        Self {
+            decoder,
+            buf: vec![],
+            chunk_size: chunk_size.max(1),
+            quote_char,
+            eol_char,
+            eof: false,
+        }
+    }
+
+    /// Set the minimum number of bytes in a batch.
+    pub fn set_chunk_size(&mut self, chunk_size: usize) {
+        self.chunk_size = chunk_size.max(1);
+    }
+
+    /// Read until the buffer holds at least `size` bytes or the stream is depleted.
+    fn fill(&mut self, size: usize) -> PolarsResult<()> {
+        while !self.eof && self.buf.len() < size {
+            let to_read = (size - self.buf.len()) as u64;
+            if (&mut self.decoder)
+                .take(to_read)
+                .read_to_end(&mut self.buf)?
+                == 0
+            {
+                self.eof = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next batch of records, or `None` once the stream is depleted.
+    ///
+    /// A batch holds at least `chunk_size` bytes unless it is the last one, and is larger if a
+    /// single record does not fit.
+    pub fn next_chunk(&mut self) -> PolarsResult<Option<Vec<u8>>> {
+        let mut size = self.chunk_size;
+        // The buffer starts at a record, so the bytes scanned in a previous iteration need not
+        // be scanned again; only whether they end within quotes is kept.
+        let mut scanned = 0;
+        let mut in_quotes = false;
+        let end = loop {
+            self.fill(size)?;
+            if self.eof {
+                break self.buf.len();
+            }
+            let bytes = &self.buf[scanned..];
+            if let Some(end) =
+                last_record_end(bytes, self.quote_char, self.eol_char, &mut in_quotes)
+            {
+                break scanned + end;
+            }
+            scanned = self.buf.len();
+            size += self.chunk_size;
+        };
+        if end == 0 {
+            return Ok(None);
+        }
+        let rest = self.buf.split_off(end);
+        Ok(Some(std::mem::replace(&mut self.buf, rest)))
+    }
+
+    /// Decompresses the remainder of the stream.
+    pub fn read_to_end(mut self) -> PolarsResult<Vec<u8>> {
+        self.decoder.read_to_end(&mut self.buf)?;
+        Ok(self.buf)
+    }
+}
+
+/// The position after the last record terminator that is not quoted.
+///
+/// `in_quotes` is whether `bytes` starts within quotes, and is updated to whether it ends
+/// within quotes.
+fn last_record_end(
+    bytes: &[u8],
+    quote_char: Option<u8>,
+    eol_char: u8,
+    in_quotes: &mut bool,
+) -> Option<usize> {
+    let Some(quote_char) = quote_char else {
+        return memchr::memrchr(eol_char, bytes).map(|i| i + 1);
+    };
+    let mut end = None;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == quote_char {
+            *in_quotes = !*in_quotes;
+        } else if b == eol_char && !*in_quotes {
+            end = Some(i + 1);
+        }
+    }
+    end
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decompressed_chunks() {
+        let data = "0,\"a\nb\"\n".repeat(1000);
+        for compression in [
+            CompressionCodec::Gzip(None),
+            CompressionCodec::Zstd(Some(3)),
+        ] {
+            let mut compressed = vec![];
+            let mut writer = CompressedWriter::try_new(&mut compressed, Some(compression)).unwrap();
+            writer.write_all(data.as_bytes()).unwrap();
+            writer.finish().unwrap();
+            drop(writer);
+
+            let decoder = maybe_decoder(compressed.as_slice()).unwrap().unwrap();
+            let mut chunks = DecompressedChunks::new(decoder, 100, Some(b'"'), b'\n');
+            let mut out = vec![];
+            while let Some(chunk) = chunks.next_chunk().unwrap() {
+                assert!(chunk.ends_with(b"\"\n"));
+                out.extend_from_slice(&chunk);
+            }
+            assert_eq!(out, data.as_bytes());
+        }
+    }
+}
@@ -25,9 +25,15 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
+#[cfg(feature = "fwf")]
+pub(crate) use options::NullValuesCompiled;
 pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
 pub use parser::count_rows;
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
+#[cfg(feature = "fwf")]
+pub(crate) use read_impl::cast_columns;
+pub use read_impl::compressed::BatchedCompressedCsvReader;
 pub use reader::CsvReader;
 pub use schema_inference::infer_file_schema;
-pub use utils::is_compressed;
+
+pub use crate::compression::is_compressed;
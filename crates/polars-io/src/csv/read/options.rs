@@ -378,7 +378,7 @@ pub enum NullValues {
 }
 
 impl NullValues {
-    pub(super) fn compile(self, schema: &Schema) -> PolarsResult<NullValuesCompiled> {
+    pub(crate) fn compile(self, schema: &Schema) -> PolarsResult<NullValuesCompiled> {
         Ok(match self {
             NullValues::AllColumnsSingle(v) => NullValuesCompiled::AllColumnsSingle(v),
             NullValues::AllColumns(v) => NullValuesCompiled::AllColumns(v),
@@ -395,7 +395,7 @@ impl NullValues {
 }
 
 #[derive(Debug, Clone)]
-pub(super) enum NullValuesCompiled {
+pub(crate) enum NullValuesCompiled {
     /// A single value that's used for all columns
     AllColumnsSingle(String),
     // Multiple null values that are null for all columns
@@ -408,7 +408,7 @@ impl NullValuesCompiled {
     /// # Safety
     ///
     /// The caller must ensure that `index` is in bounds
-    pub(super) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
+    pub(crate) unsafe fn is_null(&self, field: &[u8], index: usize) -> bool {
         use NullValuesCompiled::*;
         match self {
             AllColumnsSingle(v) => v.as_bytes() == field,
@@ -12,6 +12,7 @@ use super::buffer::Buffer;
 use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
+use crate::compression::{maybe_decoder, DecompressedChunks, DEFAULT_DECOMPRESSED_CHUNK_SIZE};
 use crate::prelude::is_cloud_url;
 use crate::utils::get_reader_bytes;
 
@@ -42,12 +43,52 @@ pub fn count_rows(
         polars_utils::open_file(path)?
     };
     let reader_bytes = get_reader_bytes(&mut reader)?;
+
+    if let Some(decoder) = maybe_decoder(&*reader_bytes)? {
+        let mut chunks = DecompressedChunks::new(
+            decoder,
+            DEFAULT_DECOMPRESSED_CHUNK_SIZE,
+            quote_char,
+            eol_char,
+        );
+        let mut n_rows = 0;
+        while let Some(chunk) = chunks.next_chunk()? {
+            n_rows += count_rows_from_slice(
+                &chunk,
+                separator,
+                quote_char,
+                comment_prefix,
+                eol_char,
+                false,
+            )?;
+        }
+        return Ok(n_rows.saturating_sub(has_header as usize));
+    }
+
+    count_rows_from_slice(
+        &reader_bytes,
+        separator,
+        quote_char,
+        comment_prefix,
+        eol_char,
+        has_header,
+    )
+}
+
+fn count_rows_from_slice(
+    reader_bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<&CommentPrefix>,
+    eol_char: u8,
+    has_header: bool,
+) -> PolarsResult<usize> {
     const MIN_ROWS_PER_THREAD: usize = 1024;
     let max_threads = POOL.current_num_threads();
 
     // Determine if parallelism is beneficial and how many threads
     let n_threads = get_line_stats(
-        &reader_bytes,
+        reader_bytes,
         MIN_ROWS_PER_THREAD,
         eol_char,
         None,
@@ -61,7 +102,7 @@ pub fn count_rows(
     .unwrap_or(1);
 
     let file_chunks: Vec<(usize, usize)> = get_file_chunks(
-        &reader_bytes,
+        reader_bytes,
         n_threads,
         None,
         separator,
@@ -1,4 +1,5 @@
 pub(super) mod batched;
+pub(super) mod compressed;
 
 use std::fmt;
 
@@ -22,7 +23,7 @@ use super::schema_inference::{check_decimal_comma, infer_file_schema};
 use super::utils::decompress;
 use super::utils::get_file_chunks;
 #[cfg(not(any(feature = "decompress", feature = "decompress-fast")))]
-use super::utils::is_compressed;
+use crate::compression::is_compressed;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::update_row_counts;
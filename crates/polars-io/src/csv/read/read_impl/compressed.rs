@@ -0,0 +1,133 @@
+use std::io::{BufRead, Cursor};
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use super::super::options::CsvReadOptions;
+use super::super::schema_inference::SchemaInferenceResult;
+use crate::compression::{maybe_decoder, DecompressedChunks, DEFAULT_DECOMPRESSED_CHUNK_SIZE};
+use crate::mmap::ReaderBytes;
+use crate::predicates::PhysicalIoExpr;
+use crate::shared::SerReader;
+
+impl CsvReadOptions {
+    /// Creates a reader that decompresses a gzip, zlib or zstd compressed CSV file incrementally
+    /// and parses it in batches. Returns `None` if `reader` is not compressed.
+    ///
+    /// If no schema is set, it is inferred from the first batch.
+    pub fn try_into_compressed_batched_reader<'a, R: BufRead + Send + Sync + 'a>(
+        self,
+        reader: R,
+    ) -> PolarsResult<Option<BatchedCompressedCsvReader<'a>>> {
+        let Some(decoder) = maybe_decoder(reader)? else {
+            return Ok(None);
+        };
+        let parse_options = self.get_parse_options();
+        let chunks = DecompressedChunks::new(
+            decoder,
+            DEFAULT_DECOMPRESSED_CHUNK_SIZE,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        );
+        Ok(Some(BatchedCompressedCsvReader {
+            chunks,
+            options: self,
+            predicate: None,
+            n_rows_read: 0,
+            is_first: true,
+        }))
+    }
+}
+
+/// Reads a compressed CSV file in batches, so that the file is never fully inflated in memory.
+pub struct BatchedCompressedCsvReader<'a> {
+    chunks: DecompressedChunks<'a>,
+    options: CsvReadOptions,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    n_rows_read: usize,
+    is_first: bool,
+}
+
+impl<'a> BatchedCompressedCsvReader<'a> {
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// Set the number of decompressed bytes that are parsed at once.
+    pub fn with_decompressed_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunks.set_chunk_size(chunk_size);
+        self
+    }
+
+    /// Read the next batch. Returns `None` once the file is depleted or `n_rows` are read.
+    pub fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        let n_rows = self.options.n_rows;
+        if n_rows.is_some_and(|n| self.n_rows_read >= n) {
+            return Ok(None);
+        }
+        let Some(chunk) = self.chunks.next_chunk()? else {
+            return Ok(None);
+        };
+
+        if self.options.schema.is_none() {
+            let inferred = SchemaInferenceResult::try_from_reader_bytes_and_options(
+                &ReaderBytes::Borrowed(&chunk),
+                &self.options,
+            )?;
+            self.options.schema = Some(inferred.get_inferred_schema());
+        }
+
+        let mut options = self
+            .options
+            .clone()
+            .with_n_rows(n_rows.map(|n| n - self.n_rows_read))
+            .with_row_index(self.options.row_index.clone().map(|mut ri| {
+                ri.offset += self.n_rows_read as IdxSize;
+                ri
+            }))
+            .with_rechunk(false);
+        // Rows before the data only occur in the first batch.
+        if !std::mem::replace(&mut self.is_first, false) {
+            options.has_header = false;
+            options.skip_rows = 0;
+            options.skip_rows_after_header = 0;
+        }
+
+        // The predicate is applied after counting the rows, which `n_rows` and the row index
+        // refer to.
+        let df = options
+            .into_reader_with_file_handle(Cursor::new(chunk))
+            .finish()?;
+        self.n_rows_read += df.height();
+
+        match &self.predicate {
+            Some(predicate) => {
+                let mask = predicate.evaluate_io(&df)?;
+                df.filter(mask.bool()?).map(Some)
+            },
+            None => Ok(Some(df)),
+        }
+    }
+
+    /// Read the remaining batches into a single [`DataFrame`].
+    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+        let mut batches = vec![];
+        while let Some(batch) = self.next_batch()? {
+            batches.push(batch);
+        }
+        if batches.is_empty() {
+            // Let the reader produce the empty frame, or raise if the file is empty.
+            let mut options = self.options;
+            options.raise_if_empty &= options.n_rows != Some(0);
+            return options
+                .into_reader_with_file_handle(Cursor::new(vec![]))
+                .finish();
+        }
+        let mut df = accumulate_dataframes_vertical_unchecked(batches);
+        if self.options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
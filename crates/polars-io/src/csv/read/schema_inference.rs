@@ -12,6 +12,7 @@ use super::options::{CommentPrefix, CsvEncoding, NullValues};
 use super::parser::{is_comment_line, skip_bom, skip_line_ending, SplitLines};
 use super::splitfields::SplitFields;
 use super::CsvReadOptions;
+use crate::compression::{maybe_decoder, DecompressedChunks, DEFAULT_DECOMPRESSED_CHUNK_SIZE};
 use crate::mmap::ReaderBytes;
 use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
@@ -78,6 +79,30 @@ impl SchemaInferenceResult {
         Ok(this)
     }
 
+    /// Infers the schema of a compressed file from its first decompressed batch, or from the
+    /// complete file if `infer_schema_length` is `None`. Returns `None` if `reader` is not
+    /// compressed.
+    pub fn try_from_compressed_reader_and_options<R: std::io::BufRead + Send + Sync>(
+        reader: R,
+        options: &CsvReadOptions,
+    ) -> PolarsResult<Option<Self>> {
+        let Some(decoder) = maybe_decoder(reader)? else {
+            return Ok(None);
+        };
+        let parse_options = options.get_parse_options();
+        let mut chunks = DecompressedChunks::new(
+            decoder,
+            DEFAULT_DECOMPRESSED_CHUNK_SIZE,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        );
+        let bytes = match options.infer_schema_length {
+            Some(_) => chunks.next_chunk()?.unwrap_or_default(),
+            None => chunks.read_to_end()?,
+        };
+        Self::try_from_reader_bytes_and_options(&ReaderBytes::Owned(bytes), options).map(Some)
+    }
+
     pub fn with_inferred_schema(mut self, inferred_schema: SchemaRef) -> Self {
         self.inferred_schema = inferred_schema;
         self
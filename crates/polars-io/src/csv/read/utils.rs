@@ -6,6 +6,8 @@ use super::parser::next_line_position;
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 use super::parser::next_line_position_naive;
 use super::splitfields::SplitFields;
+#[cfg(any(feature = "decompress", feature = "decompress-fast"))]
+use crate::compression::{GZIP, ZLIB0, ZLIB1, ZLIB2, ZSTD};
 
 pub(crate) fn get_file_chunks(
     bytes: &[u8],
@@ -45,22 +47,6 @@ pub(crate) fn get_file_chunks(
     offsets
 }
 
-// magic numbers
-const GZIP: [u8; 2] = [31, 139];
-const ZLIB0: [u8; 2] = [0x78, 0x01];
-const ZLIB1: [u8; 2] = [0x78, 0x9C];
-const ZLIB2: [u8; 2] = [0x78, 0xDA];
-const ZSTD: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
-
-/// check if csv file is compressed
-pub fn is_compressed(bytes: &[u8]) -> bool {
-    bytes.starts_with(&ZLIB0)
-        || bytes.starts_with(&ZLIB1)
-        || bytes.starts_with(&ZLIB2)
-        || bytes.starts_with(&GZIP)
-        || bytes.starts_with(&ZSTD)
-}
-
 #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
 fn decompress_impl<R: Read>(
     decoder: &mut R,
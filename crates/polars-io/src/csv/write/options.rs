@@ -3,6 +3,8 @@ use std::num::NonZeroUsize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::compression::CompressionCodec;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +14,8 @@ pub struct CsvWriterOptions {
     pub batch_size: NonZeroUsize,
     pub maintain_order: bool,
     pub serialize_options: SerializeOptions,
+    /// Compress the output with this codec.
+    pub compression: Option<CompressionCodec>,
 }
 
 impl Default for CsvWriterOptions {
@@ -22,6 +26,7 @@ impl Default for CsvWriterOptions {
             batch_size: NonZeroUsize::new(1024).unwrap(),
             maintain_order: false,
             serialize_options: SerializeOptions::default(),
+            compression: None,
         }
     }
 }
@@ -0,0 +1,117 @@
+use arrow::io::flight::deserialize_schemas;
+use arrow_format::flight::data::flight_descriptor::DescriptorType;
+use arrow_format::flight::data::{Criteria, FlightDescriptor, Ticket};
+use arrow_format::flight::service::flight_service_client::FlightServiceClient;
+use futures::{stream, TryStreamExt};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tonic::transport::Channel;
+
+use super::{encode_frame, FrameDecoder};
+
+/// A client that exchanges [`DataFrame`]s with an Arrow Flight service.
+#[derive(Clone)]
+#[must_use]
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+    pl_flavor: bool,
+}
+
+impl FlightClient {
+    /// Connects to the Flight service at `uri`, e.g. `http://127.0.0.1:50051`.
+    pub async fn connect(uri: impl Into<String>) -> PolarsResult<Self> {
+        let inner = FlightServiceClient::connect(uri.into())
+            .await
+            .map_err(to_compute_err)?;
+        Ok(Self::new(inner))
+    }
+
+    /// Create a new [`FlightClient`] from an existing gRPC client.
+    pub fn new(inner: FlightServiceClient<Channel>) -> Self {
+        Self {
+            inner,
+            pl_flavor: false,
+        }
+    }
+
+    /// Send string and binary data as view types. Only polars and recent Arrow
+    /// implementations can read these.
+    pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
+        self.pl_flavor = pl_flavor;
+        self
+    }
+
+    /// Fetch the frame identified by `ticket`.
+    pub async fn do_get(&mut self, ticket: &str) -> PolarsResult<DataFrame> {
+        let request = Ticket {
+            ticket: ticket.as_bytes().to_vec(),
+        };
+        let mut stream = self
+            .inner
+            .do_get(request)
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+
+        let mut decoder = FrameDecoder::default();
+        while let Some(data) = stream.message().await.map_err(to_compute_err)? {
+            decoder.push(&data)?;
+        }
+        decoder.finish()
+    }
+
+    /// Upload `df` to the service under the descriptor path `name`.
+    pub async fn do_put(&mut self, name: &str, df: &DataFrame) -> PolarsResult<()> {
+        let mut messages = encode_frame(df.clone(), self.pl_flavor)?;
+        messages[0].flight_descriptor = Some(path_descriptor(name));
+
+        let mut results = self
+            .inner
+            .do_put(stream::iter(messages))
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+        // Drain the acknowledgements so that errors of the service surface here.
+        while results.message().await.map_err(to_compute_err)?.is_some() {}
+        Ok(())
+    }
+
+    /// Fetch the schema of the frame registered under `name`.
+    pub async fn get_schema(&mut self, name: &str) -> PolarsResult<Schema> {
+        let result = self
+            .inner
+            .get_schema(path_descriptor(name))
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+        let (schema, _) = deserialize_schemas(&result.schema)?;
+        Ok(Schema::from_iter(schema.fields.iter()))
+    }
+
+    /// List the descriptor paths of the frames the service offers.
+    pub async fn list_flights(&mut self) -> PolarsResult<Vec<String>> {
+        let stream = self
+            .inner
+            .list_flights(Criteria::default())
+            .await
+            .map_err(to_compute_err)?
+            .into_inner();
+        stream
+            .map_err(to_compute_err)
+            .try_filter_map(|info| async move {
+                Ok(info
+                    .flight_descriptor
+                    .map(|descriptor| descriptor.path.join("/")))
+            })
+            .try_collect()
+            .await
+    }
+}
+
+pub(super) fn path_descriptor(name: &str) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Path as i32,
+        cmd: vec![],
+        path: vec![name.to_string()],
+    }
+}
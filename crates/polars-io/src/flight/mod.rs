@@ -0,0 +1,111 @@
+//! # Arrow Flight
+//!
+//! A [`FlightClient`] to exchange [`DataFrame`]s with an Arrow Flight service over gRPC, and a
+//! minimal embeddable [`FlightServer`] that serves registered frames by name.
+//!
+//! Tickets and descriptor paths are the UTF-8 encoded names under which frames are registered.
+//!
+//! ```no_run
+//! use polars_core::prelude::*;
+//! use polars_io::flight::{FlightClient, FlightServer};
+//!
+//! # async fn example() -> PolarsResult<()> {
+//! let server = FlightServer::new();
+//! server.register("numbers", df!("a" => [1, 2, 3])?);
+//! tokio::spawn(server.clone().serve("127.0.0.1:50051".parse().unwrap()));
+//!
+//! let mut client = FlightClient::connect("http://127.0.0.1:50051").await?;
+//! let df = client.do_get("numbers").await?;
+//! # Ok(())
+//! # }
+//! ```
+mod client;
+mod server;
+
+use arrow::io::flight::{
+    default_ipc_fields, deserialize_message, deserialize_schemas, serialize_batch,
+    serialize_schema, WriteOptions,
+};
+use arrow::io::ipc::read::Dictionaries;
+use arrow::io::ipc::IpcSchema;
+use arrow_format::flight::data::FlightData;
+pub use client::FlightClient;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+pub use server::FlightServer;
+
+use crate::shared::schema_to_arrow_checked;
+
+/// A frame that can be served by a [`FlightServer`].
+pub trait FlightSource: Send + Sync {
+    /// The schema of the frame, returned by `GetSchema` and `GetFlightInfo`.
+    fn schema(&self) -> PolarsResult<SchemaRef>;
+
+    /// Materializes the frame for a `DoGet` request.
+    fn collect(&self) -> PolarsResult<DataFrame>;
+}
+
+impl FlightSource for DataFrame {
+    fn schema(&self) -> PolarsResult<SchemaRef> {
+        Ok(Arc::new(DataFrame::schema(self)))
+    }
+
+    fn collect(&self) -> PolarsResult<DataFrame> {
+        Ok(self.clone())
+    }
+}
+
+pub(super) fn to_arrow_schema(schema: &Schema, pl_flavor: bool) -> PolarsResult<ArrowSchema> {
+    schema_to_arrow_checked(schema, pl_flavor, "flight")
+}
+
+/// Encodes `df` as a schema message followed by its dictionary and record batch messages.
+pub(super) fn encode_frame(mut df: DataFrame, pl_flavor: bool) -> PolarsResult<Vec<FlightData>> {
+    let schema = to_arrow_schema(&df.schema(), pl_flavor)?;
+    let ipc_fields = default_ipc_fields(&schema.fields);
+    let options = WriteOptions { compression: None };
+
+    df.align_chunks();
+    let mut messages = vec![serialize_schema(&schema, Some(&ipc_fields))];
+    for batch in df.iter_chunks(pl_flavor, true) {
+        let (dictionaries, batch) = serialize_batch(&batch, &ipc_fields, &options)?;
+        messages.extend(dictionaries);
+        messages.push(batch);
+    }
+    Ok(messages)
+}
+
+/// Decodes a stream of [`FlightData`] messages that starts with a schema message.
+#[derive(Default)]
+pub(super) struct FrameDecoder {
+    schema: Option<(ArrowSchema, IpcSchema)>,
+    dictionaries: Dictionaries,
+    chunks: Vec<DataFrame>,
+}
+
+impl FrameDecoder {
+    pub(super) fn push(&mut self, data: &FlightData) -> PolarsResult<()> {
+        match &self.schema {
+            None => self.schema = Some(deserialize_schemas(&data.data_header)?),
+            Some((schema, ipc_schema)) => {
+                if let Some(batch) =
+                    deserialize_message(data, &schema.fields, ipc_schema, &mut self.dictionaries)?
+                {
+                    self.chunks
+                        .push(DataFrame::try_from((batch, schema.fields.as_slice()))?);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    pub(super) fn finish(self) -> PolarsResult<DataFrame> {
+        let Some((schema, _)) = self.schema else {
+            polars_bail!(ComputeError: "flight stream did not contain a schema message");
+        };
+        if self.chunks.is_empty() {
+            return Ok(DataFrame::empty_with_arrow_schema(&schema));
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(self.chunks))
+    }
+}
@@ -0,0 +1,263 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::RwLock;
+
+use arrow::io::flight::{serialize_schema_to_info, serialize_schema_to_result};
+use arrow_format::flight::data::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightEndpoint, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+use arrow_format::flight::service::flight_service_server::{FlightService, FlightServiceServer};
+use futures::stream::BoxStream;
+use futures::{stream, StreamExt};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tokio::net::TcpListener;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status, Streaming};
+
+use super::client::path_descriptor;
+use super::{encode_frame, to_arrow_schema, FlightSource, FrameDecoder};
+
+type FlightStream<T> = BoxStream<'static, Result<T, Status>>;
+
+/// A minimal Arrow Flight service that serves registered frames by name.
+///
+/// `DoGet` returns the frame registered under the ticket and `DoPut` registers the uploaded
+/// frame under the path of its descriptor. The server is cheap to clone; all clones share
+/// the same frames.
+#[derive(Clone, Default)]
+#[must_use]
+pub struct FlightServer {
+    frames: Arc<RwLock<PlHashMap<String, Arc<dyn FlightSource>>>>,
+    pl_flavor: bool,
+}
+
+impl FlightServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send string and binary data as view types. Only polars and recent Arrow
+    /// implementations can read these.
+    pub fn with_pl_flavor(mut self, pl_flavor: bool) -> Self {
+        self.pl_flavor = pl_flavor;
+        self
+    }
+
+    /// Serve `source` under `name`, replacing any frame previously registered under that name.
+    pub fn register(&self, name: impl Into<String>, source: impl FlightSource + 'static) {
+        self.frames
+            .write()
+            .unwrap()
+            .insert(name.into(), Arc::new(source));
+    }
+
+    /// Stop serving the frame registered under `name`. Returns whether it was registered.
+    pub fn deregister(&self, name: &str) -> bool {
+        self.frames.write().unwrap().remove(name).is_some()
+    }
+
+    /// Collect the frame registered under `name`, e.g. one that was uploaded with `DoPut`.
+    pub fn collect(&self, name: &str) -> PolarsResult<DataFrame> {
+        self.get(name)
+            .ok_or_else(|| polars_err!(ComputeError: "no flight registered under '{}'", name))?
+            .collect()
+    }
+
+    fn get(&self, name: &str) -> Option<Arc<dyn FlightSource>> {
+        self.frames.read().unwrap().get(name).cloned()
+    }
+
+    fn get_or_not_found(&self, name: &str) -> Result<Arc<dyn FlightSource>, Status> {
+        self.get(name)
+            .ok_or_else(|| Status::not_found(format!("no flight registered under '{name}'")))
+    }
+
+    /// The gRPC service, to embed the server in an existing [`tonic`] router.
+    pub fn into_service(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+
+    /// Serve on `addr` until the task is dropped.
+    pub async fn serve(self, addr: SocketAddr) -> PolarsResult<()> {
+        Server::builder()
+            .add_service(self.into_service())
+            .serve(addr)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    /// Serve the connections accepted by `listener` until `shutdown` completes.
+    pub async fn serve_with_listener<F>(
+        self,
+        listener: TcpListener,
+        shutdown: F,
+    ) -> PolarsResult<()>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        let incoming = stream::unfold(listener, |listener| async move {
+            let conn = listener.accept().await.map(|(stream, _)| stream);
+            Some((conn, listener))
+        });
+        Server::builder()
+            .add_service(self.into_service())
+            .serve_with_incoming_shutdown(Box::pin(incoming), shutdown)
+            .await
+            .map_err(to_compute_err)
+    }
+
+    fn flight_info(&self, name: &str, source: &dyn FlightSource) -> PolarsResult<FlightInfo> {
+        let schema = to_arrow_schema(source.schema()?.as_ref(), self.pl_flavor)?;
+        Ok(FlightInfo {
+            schema: serialize_schema_to_info(&schema, None)?,
+            flight_descriptor: Some(path_descriptor(name)),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: name.as_bytes().to_vec(),
+                }),
+                location: vec![],
+            }],
+            total_records: -1,
+            total_bytes: -1,
+        })
+    }
+}
+
+fn to_status(err: PolarsError) -> Status {
+    Status::internal(err.to_string())
+}
+
+fn descriptor_name(descriptor: &FlightDescriptor) -> Result<String, Status> {
+    if descriptor.path.is_empty() {
+        return Err(Status::invalid_argument(
+            "flight descriptor must identify a frame by path",
+        ));
+    }
+    Ok(descriptor.path.join("/"))
+}
+
+#[tonic::async_trait]
+impl FlightService for FlightServer {
+    type HandshakeStream = FlightStream<HandshakeResponse>;
+    type ListFlightsStream = FlightStream<FlightInfo>;
+    type DoGetStream = FlightStream<FlightData>;
+    type DoPutStream = FlightStream<PutResult>;
+    type DoExchangeStream = FlightStream<FlightData>;
+    type DoActionStream = FlightStream<arrow_format::flight::data::Result>;
+    type ListActionsStream = FlightStream<ActionType>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake"))
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let frames = self.frames.read().unwrap().clone();
+        let infos = frames
+            .iter()
+            .map(|(name, source)| self.flight_info(name, source.as_ref()).map_err(to_status))
+            .collect::<Vec<_>>();
+        Ok(Response::new(stream::iter(infos).boxed()))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        let source = self.get_or_not_found(&name)?;
+        let info = self
+            .flight_info(&name, source.as_ref())
+            .map_err(to_status)?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let name = descriptor_name(request.get_ref())?;
+        let source = self.get_or_not_found(&name)?;
+        let schema = source
+            .schema()
+            .and_then(|schema| to_arrow_schema(&schema, self.pl_flavor))
+            .map_err(to_status)?;
+        Ok(Response::new(serialize_schema_to_result(&schema, None)))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let name = String::from_utf8(request.into_inner().ticket)
+            .map_err(|_| Status::invalid_argument("ticket must be valid utf-8"))?;
+        let source = self.get_or_not_found(&name)?;
+
+        // Collecting a lazy source runs a query; keep it off the async workers.
+        let pl_flavor = self.pl_flavor;
+        let messages = tokio::task::spawn_blocking(move || {
+            source.collect().and_then(|df| encode_frame(df, pl_flavor))
+        })
+        .await
+        .map_err(|err| Status::internal(err.to_string()))?
+        .map_err(to_status)?;
+        Ok(Response::new(
+            stream::iter(messages.into_iter().map(Ok)).boxed(),
+        ))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let mut stream = request.into_inner();
+        let Some(first) = stream.message().await? else {
+            return Err(Status::invalid_argument("empty DoPut stream"));
+        };
+        let name = first
+            .flight_descriptor
+            .as_ref()
+            .ok_or_else(|| {
+                Status::invalid_argument("first DoPut message must contain a flight descriptor")
+            })
+            .and_then(descriptor_name)?;
+
+        let mut decoder = FrameDecoder::default();
+        decoder.push(&first).map_err(to_status)?;
+        while let Some(data) = stream.message().await? {
+            decoder.push(&data).map_err(to_status)?;
+        }
+        let df = decoder.finish().map_err(to_status)?;
+        self.register(name, df);
+
+        Ok(Response::new(stream::empty().boxed()))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange"))
+    }
+
+    async fn do_action(
+        &self,
+        _request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        Err(Status::unimplemented("do_action"))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        Ok(Response::new(stream::empty().boxed()))
+    }
+}
@@ -0,0 +1,27 @@
+//! Functionality for reading fixed-width text files.
+//!
+//! Every line of a fixed-width file holds one record and every column sits at the same
+//! offsets in all lines. The offsets are given as column spans or inferred from a header
+//! line. The fields are parsed with the buffers of the CSV reader, so the dtypes and the
+//! schema inference follow the CSV reader.
+//!
+//! # Examples
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_io::fwf::FixedWidthReadOptions;
+//! use polars_io::SerReader;
+//!
+//! fn example() -> PolarsResult<DataFrame> {
+//!     FixedWidthReadOptions::default()
+//!         .with_column_spans(Some(Arc::from([(0, 8), (10, 30)])))
+//!         .try_into_reader_with_file_path(Some("extract.txt".into()))?
+//!         .finish()
+//! }
+//! ```
+
+mod options;
+mod reader;
+
+pub use options::{FixedWidthReadOptions, SpanUnit};
+pub use reader::FixedWidthReader;
@@ -0,0 +1,237 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use polars_core::schema::SchemaRef;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::csv::read::{CsvEncoding, NullValues};
+use crate::RowIndex;
+
+/// The unit in which the column spans of a fixed-width file are measured.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SpanUnit {
+    /// Spans are byte offsets into a line.
+    #[default]
+    Bytes,
+    /// Spans are offsets in (UTF-8) characters into a line.
+    Chars,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FixedWidthReadOptions {
+    pub path: Option<PathBuf>,
+    // Performance related options
+    pub rechunk: bool,
+    pub n_threads: Option<usize>,
+    // Row-wise options
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    // Column-wise options
+    pub columns: Option<Arc<[String]>>,
+    pub projection: Option<Arc<Vec<usize>>>,
+    pub schema: Option<SchemaRef>,
+    pub schema_overwrite: Option<SchemaRef>,
+    // Fixed-width specific options
+    pub column_spans: Option<Arc<[(usize, usize)]>>,
+    pub span_unit: SpanUnit,
+    pub has_header: bool,
+    pub skip_rows: usize,
+    pub infer_schema_length: Option<usize>,
+    pub eol_char: u8,
+    pub encoding: CsvEncoding,
+    pub null_values: Option<NullValues>,
+    pub trim: bool,
+    pub try_parse_dates: bool,
+    pub decimal_comma: bool,
+    pub ignore_errors: bool,
+}
+
+impl Default for FixedWidthReadOptions {
+    fn default() -> Self {
+        Self {
+            path: None,
+
+            rechunk: true,
+            n_threads: None,
+
+            n_rows: None,
+            row_index: None,
+
+            columns: None,
+            projection: None,
+            schema: None,
+            schema_overwrite: None,
+
+            column_spans: None,
+            span_unit: SpanUnit::Bytes,
+            has_header: true,
+            skip_rows: 0,
+            infer_schema_length: Some(100),
+            eol_char: b'\n',
+            encoding: CsvEncoding::Utf8,
+            null_values: None,
+            trim: true,
+            try_parse_dates: false,
+            decimal_comma: false,
+            ignore_errors: false,
+        }
+    }
+}
+
+impl FixedWidthReadOptions {
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: Option<P>) -> Self {
+        self.path = path.map(|p| p.into());
+        self
+    }
+
+    /// Whether to makes the columns contiguous in memory.
+    pub fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    /// Number of threads to use for reading. Defaults to the size of the polars
+    /// thread pool.
+    pub fn with_n_threads(mut self, n_threads: Option<usize>) -> Self {
+        self.n_threads = n_threads;
+        self
+    }
+
+    /// Limits the number of rows to read.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Adds a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Which columns to select.
+    pub fn with_columns(mut self, columns: Option<Arc<[String]>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Which columns to select denoted by their index. The index starts from 0
+    /// (i.e. [0, 4] would select the 1st and 5th column).
+    pub fn with_projection(mut self, projection: Option<Arc<Vec<usize>>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Set the schema to use for the file. The length of the schema must match
+    /// the number of column spans. If this is [None], the schema is inferred
+    /// from the file.
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Overwrites the data types in the schema by column name.
+    pub fn with_schema_overwrite(mut self, schema_overwrite: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema_overwrite;
+        self
+    }
+
+    /// The `[start, end)` offsets of the columns in a line, in the unit set by
+    /// [with_span_unit][Self::with_span_unit]. Spans may leave gaps between columns.
+    /// If this is [None], the spans are inferred from the header line: a column
+    /// starts at every word of the header and ends where the next one starts.
+    pub fn with_column_spans(mut self, column_spans: Option<Arc<[(usize, usize)]>>) -> Self {
+        self.column_spans = column_spans;
+        self
+    }
+
+    /// Sets the column spans from the widths of consecutive columns.
+    pub fn with_column_widths(mut self, widths: &[usize]) -> Self {
+        let mut start = 0;
+        self.column_spans = Some(
+            widths
+                .iter()
+                .map(|width| {
+                    let span = (start, start + width);
+                    start += width;
+                    span
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Whether the column spans count bytes or characters.
+    pub fn with_span_unit(mut self, span_unit: SpanUnit) -> Self {
+        self.span_unit = span_unit;
+        self
+    }
+
+    /// Sets whether the file has a header line.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Number of lines to skip before the header line.
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+
+    /// Number of rows to use for schema inference. Pass [None] to use all rows.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Set the character that ends a line. A trailing `\r` is always removed.
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.eol_char = eol_char;
+        self
+    }
+
+    /// Set the encoding of the string columns.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/null. Fields are compared
+    /// after they are trimmed.
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Remove the leading and trailing whitespace of the fields. Empty fields
+    /// are read as null.
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Automatically try to parse dates/datetimes and time. If parsing fails,
+    /// columns remain of dtype [`DataType::String`].
+    ///
+    /// [`DataType::String`]: polars_core::datatypes::DataType::String
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    /// Parse floats with a comma as decimal separator.
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Set values that could not be parsed to null instead of raising an error.
+    pub fn with_ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+}
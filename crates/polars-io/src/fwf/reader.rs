@@ -0,0 +1,593 @@
+use std::fs::File;
+use std::path::PathBuf;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use super::options::{FixedWidthReadOptions, SpanUnit};
+use crate::csv::read::buffer::init_buffers;
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::csv::read::{cast_columns, NullValuesCompiled};
+use crate::mmap::MmapBytesReader;
+use crate::predicates::{apply_predicate, PhysicalIoExpr};
+use crate::shared::SerReader;
+use crate::utils::{get_reader_bytes, resolve_homedir};
+
+/// Files smaller than this are parsed on a single thread.
+const MIN_BYTES_PER_THREAD: usize = 1 << 16;
+
+/// Create a new DataFrame by reading a fixed-width text file.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::fwf::FixedWidthReadOptions;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     FixedWidthReadOptions::default()
+///         .with_column_widths(&[8, 20, 12])
+///         .try_into_reader_with_file_path(Some("accounts.txt".into()))?
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct FixedWidthReader<R>
+where
+    R: MmapBytesReader,
+{
+    /// File or Stream object.
+    reader: R,
+    /// Options for the fixed-width reader.
+    options: FixedWidthReadOptions,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+}
+
+impl FixedWidthReadOptions {
+    /// Creates a fixed-width reader using a file path.
+    ///
+    /// # Panics
+    /// If both self.path and the path parameter are non-null. Only one of them is
+    /// to be non-null.
+    pub fn try_into_reader_with_file_path(
+        mut self,
+        path: Option<PathBuf>,
+    ) -> PolarsResult<FixedWidthReader<File>> {
+        if self.path.is_some() {
+            assert!(
+                path.is_none(),
+                "impl error: only 1 of self.path or the path parameter is to be non-null"
+            );
+        } else {
+            self.path = path;
+        };
+
+        assert!(
+            self.path.is_some(),
+            "impl error: either one of self.path or the path parameter is to be non-null"
+        );
+
+        let path = resolve_homedir(self.path.as_ref().unwrap());
+        let reader = polars_utils::open_file(path)?;
+        Ok(self.into_reader_with_file_handle(reader))
+    }
+
+    /// Creates a fixed-width reader using a file handle.
+    pub fn into_reader_with_file_handle<R: MmapBytesReader>(
+        self,
+        reader: R,
+    ) -> FixedWidthReader<R> {
+        FixedWidthReader {
+            reader,
+            options: self,
+            predicate: None,
+        }
+    }
+}
+
+impl<R: MmapBytesReader> FixedWidthReader<R> {
+    pub fn _with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// The schema of the file, without the row index column.
+    pub fn schema(&mut self) -> PolarsResult<SchemaRef> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        Ok(Arc::new(Layout::try_new(&bytes, &self.options)?.schema))
+    }
+
+    /// The schema of the file, without the row index column, and the `[start, end)`
+    /// spans of the columns, inferred from the header line if they are not set.
+    pub fn schema_with_column_spans(&mut self) -> PolarsResult<(SchemaRef, Arc<[(usize, usize)]>)> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let layout = Layout::try_new(&bytes, &self.options)?;
+        Ok((Arc::new(layout.schema), layout.spans.into()))
+    }
+
+    /// The number of data rows in the file.
+    pub fn count_rows(&mut self) -> PolarsResult<usize> {
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let data = skip_header(&bytes, &self.options).0;
+        Ok(lines(data, self.options.eol_char).count())
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for FixedWidthReader<R> {
+    fn new(reader: R) -> Self {
+        FixedWidthReadOptions::default().into_reader_with_file_handle(reader)
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.options.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let options = &self.options;
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let layout = Layout::try_new(&bytes, options)?;
+        let schema = &layout.schema;
+
+        let projection = match (&options.projection, &options.columns) {
+            (Some(projection), _) => {
+                for &i in projection.iter() {
+                    polars_ensure!(
+                        i < schema.len(),
+                        OutOfBounds: "projection index {} is out of bounds for a file with {} columns",
+                        i, schema.len()
+                    );
+                }
+                projection.as_ref().clone()
+            },
+            (None, Some(columns)) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<_>>()?,
+            (None, None) => (0..schema.len()).collect(),
+        };
+
+        // The parser only supports the dtypes of the CSV reader; other columns are
+        // parsed as strings and cast afterwards.
+        let mut to_cast = vec![];
+        let parse_schema = schema
+            .iter_fields()
+            .map(|mut fld| {
+                if needs_cast(fld.data_type()) {
+                    to_cast.push(fld.clone());
+                    fld.coerce(DataType::String);
+                }
+                fld
+            })
+            .collect::<Schema>();
+
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&parse_schema))
+            .transpose()?;
+
+        let mut data = layout.data;
+        if let Some(n_rows) = options.n_rows {
+            data = &data[..end_of_n_lines(data, n_rows, options.eol_char)];
+        }
+
+        let n_threads = options
+            .n_threads
+            .unwrap_or_else(|| POOL.current_num_threads())
+            .clamp(1, (data.len() / MIN_BYTES_PER_THREAD).max(1));
+        let chunks = line_aligned_chunks(data, n_threads, options.eol_char);
+
+        let parser = ChunkParser {
+            spans: &layout.spans,
+            schema: &parse_schema,
+            projection: &projection,
+            null_values: null_values.as_ref(),
+            options,
+        };
+        let dfs = if chunks.len() == 1 {
+            vec![parser.parse(chunks[0])?]
+        } else {
+            POOL.install(|| {
+                chunks
+                    .into_par_iter()
+                    .map(|chunk| parser.parse(chunk))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })?
+        };
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+
+        cast_columns(&mut df, &to_cast, true, options.ignore_errors)?;
+        if let Some(row_index) = &options.row_index {
+            df.with_row_index_mut(row_index.name.as_ref(), Some(row_index.offset));
+        }
+        apply_predicate(&mut df, self.predicate.as_deref(), true)?;
+        if options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+fn needs_cast(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::Time => true,
+        #[cfg(feature = "dtype-decimal")]
+        DataType::Decimal(_, _) => true,
+        _ => false,
+    }
+}
+
+/// The lines of `bytes` without their line ending. Empty lines are skipped.
+fn lines(bytes: &[u8], eol_char: u8) -> impl Iterator<Item = &[u8]> {
+    bytes
+        .split(move |&b| b == eol_char)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+}
+
+/// Splits off the first line of `bytes`, including its line ending.
+fn split_line(bytes: &[u8], eol_char: u8) -> (&[u8], &[u8]) {
+    match memchr::memchr(eol_char, bytes) {
+        Some(pos) => (&bytes[..pos], &bytes[pos + 1..]),
+        None => (bytes, &[]),
+    }
+}
+
+/// Skips the BOM, the rows before the header and the header line. Returns the data
+/// and the header line.
+fn skip_header<'a>(
+    bytes: &'a [u8],
+    options: &FixedWidthReadOptions,
+) -> (&'a [u8], Option<&'a [u8]>) {
+    let mut bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    for _ in 0..options.skip_rows {
+        bytes = split_line(bytes, options.eol_char).1;
+    }
+    if !options.has_header {
+        return (bytes, None);
+    }
+    let header = loop {
+        if bytes.is_empty() {
+            return (bytes, None);
+        }
+        let (line, rest) = split_line(bytes, options.eol_char);
+        bytes = rest;
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if !line.is_empty() {
+            break line;
+        }
+    };
+    (bytes, Some(header))
+}
+
+/// The offset right after the first `n` (non-empty) lines of `bytes`.
+fn end_of_n_lines(bytes: &[u8], n: usize, eol_char: u8) -> usize {
+    let mut offset = 0;
+    let mut count = 0;
+    while count < n && offset < bytes.len() {
+        let line = split_line(&bytes[offset..], eol_char).0;
+        if !line.is_empty() && line != b"\r" {
+            count += 1;
+        }
+        offset = (offset + line.len() + 1).min(bytes.len());
+    }
+    offset
+}
+
+/// Splits `bytes` into at most `n_chunks` chunks of whole lines.
+fn line_aligned_chunks(bytes: &[u8], n_chunks: usize, eol_char: u8) -> Vec<&[u8]> {
+    let chunk_size = bytes.len() / n_chunks;
+    let mut chunks = Vec::with_capacity(n_chunks);
+    let mut rest = bytes;
+    while chunks.len() + 1 < n_chunks && rest.len() > chunk_size {
+        match memchr::memchr(eol_char, &rest[chunk_size..]) {
+            Some(pos) => {
+                let (chunk, tail) = rest.split_at(chunk_size + pos + 1);
+                chunks.push(chunk);
+                rest = tail;
+            },
+            None => break,
+        }
+    }
+    if !rest.is_empty() || chunks.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+/// Infers the column spans from a header line: a column starts at every word and
+/// ends where the next one starts. The last column extends to the end of the line.
+fn infer_spans(header: &[u8], span_unit: SpanUnit) -> PolarsResult<Vec<(usize, usize)>> {
+    let is_space = |c: char| c == ' ' || c == '\t';
+    let chars: Vec<char> = match span_unit {
+        SpanUnit::Bytes => header.iter().map(|&b| b as char).collect(),
+        SpanUnit::Chars => std::str::from_utf8(header)
+            .map_err(|_| polars_err!(ComputeError: "fixed-width header is not valid utf8"))?
+            .chars()
+            .collect(),
+    };
+    let starts = (0..chars.len())
+        .filter(|&i| !is_space(chars[i]) && (i == 0 || is_space(chars[i - 1])))
+        .collect::<Vec<_>>();
+    let spans = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| (start, starts.get(i + 1).copied().unwrap_or(usize::MAX)))
+        .collect::<Vec<_>>();
+    polars_ensure!(!spans.is_empty(), NoData: "cannot infer column spans from an empty header");
+    Ok(spans)
+}
+
+/// Extracts the fields of a line.
+struct FieldSplitter<'a> {
+    spans: &'a [(usize, usize)],
+    span_unit: SpanUnit,
+    trim: bool,
+    /// The byte offset of every character of the current line if spans are counted in
+    /// characters and the line is not ASCII, followed by the length of the line.
+    char_offsets: Vec<usize>,
+}
+
+impl<'a> FieldSplitter<'a> {
+    fn new(spans: &'a [(usize, usize)], options: &FixedWidthReadOptions) -> Self {
+        Self {
+            spans,
+            span_unit: options.span_unit,
+            trim: options.trim,
+            char_offsets: vec![],
+        }
+    }
+
+    fn set_line(&mut self, line: &[u8]) -> PolarsResult<()> {
+        self.char_offsets.clear();
+        if self.span_unit == SpanUnit::Chars && !line.is_ascii() {
+            let line = std::str::from_utf8(line).map_err(
+                |_| polars_err!(ComputeError: "fixed-width line is not valid utf8: cannot count its characters"),
+            )?;
+            self.char_offsets
+                .extend(line.char_indices().map(|(offset, _)| offset));
+            self.char_offsets.push(line.len());
+        }
+        Ok(())
+    }
+
+    fn field<'b>(&self, line: &'b [u8], i: usize) -> &'b [u8] {
+        let (start, end) = self.spans[i];
+        let (start, end) = if self.char_offsets.is_empty() {
+            (start.min(line.len()), end.min(line.len()))
+        } else {
+            let n_chars = self.char_offsets.len() - 1;
+            (
+                self.char_offsets[start.min(n_chars)],
+                self.char_offsets[end.min(n_chars)],
+            )
+        };
+        let field = &line[start..end];
+        if self.trim {
+            field.trim_ascii()
+        } else {
+            field
+        }
+    }
+}
+
+/// The column spans and schema of a file, and the bytes after its header.
+struct Layout<'a> {
+    data: &'a [u8],
+    spans: Vec<(usize, usize)>,
+    schema: Schema,
+}
+
+impl<'a> Layout<'a> {
+    fn try_new(bytes: &'a [u8], options: &FixedWidthReadOptions) -> PolarsResult<Self> {
+        let (data, header) = skip_header(bytes, options);
+
+        let spans = match (&options.column_spans, header) {
+            (Some(spans), _) => {
+                for &(start, end) in spans.iter() {
+                    polars_ensure!(
+                        start < end,
+                        InvalidOperation: "invalid column span ({}, {}): the start must be smaller than the end", start, end
+                    );
+                }
+                spans.to_vec()
+            },
+            (None, Some(header)) => infer_spans(header, options.span_unit)?,
+            (None, None) => polars_bail!(
+                InvalidOperation: "column spans must be set to read a fixed-width file without a header"
+            ),
+        };
+        let mut splitter = FieldSplitter::new(&spans, options);
+
+        if let Some(schema) = &options.schema {
+            polars_ensure!(
+                schema.len() == spans.len(),
+                SchemaMismatch: "the schema has {} columns, but {} column spans were given",
+                schema.len(), spans.len()
+            );
+            return Ok(Self {
+                data,
+                spans,
+                schema: schema.as_ref().clone(),
+            });
+        }
+
+        let names = match header {
+            Some(header) => {
+                splitter.set_line(header)?;
+                (0..spans.len())
+                    .map(|i| {
+                        let name = String::from_utf8_lossy(splitter.field(header, i).trim_ascii());
+                        if name.is_empty() {
+                            format!("column_{}", i + 1)
+                        } else {
+                            name.into_owned()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            },
+            None => (0..spans.len())
+                .map(|i| format!("column_{}", i + 1))
+                .collect(),
+        };
+        let string_schema = names
+            .iter()
+            .map(|name| Field::new(name, DataType::String))
+            .collect::<Schema>();
+        polars_ensure!(
+            string_schema.len() == names.len(),
+            Duplicate: "the header of the fixed-width file contains duplicate column names"
+        );
+        let null_values = options
+            .null_values
+            .clone()
+            .map(|nv| nv.compile(&string_schema))
+            .transpose()?;
+
+        let mut column_types = vec![PlHashSet::<DataType>::new(); spans.len()];
+        let infer_schema_length = options.infer_schema_length.unwrap_or(usize::MAX);
+        for line in lines(data, options.eol_char).take(infer_schema_length) {
+            splitter.set_line(line)?;
+            for (i, possibilities) in column_types.iter_mut().enumerate() {
+                let field = splitter.field(line, i);
+                // SAFETY: `i` is in bounds of the schema the null values were compiled for.
+                if field.is_empty()
+                    || null_values
+                        .as_ref()
+                        .is_some_and(|nv| unsafe { nv.is_null(field, i) })
+                {
+                    continue;
+                }
+                let dtype = match std::str::from_utf8(field) {
+                    Ok(s) => infer_field_schema(s, options.try_parse_dates, options.decimal_comma),
+                    Err(_) => DataType::String,
+                };
+                possibilities.insert(dtype);
+            }
+        }
+
+        let schema = names
+            .into_iter()
+            .zip(column_types)
+            .map(|(name, possibilities)| {
+                let dtype = options
+                    .schema_overwrite
+                    .as_ref()
+                    .and_then(|overwrite| overwrite.get(&name).cloned())
+                    .unwrap_or_else(|| finish_infer_field_schema(&possibilities));
+                Field::new(&name, dtype)
+            })
+            .collect();
+        Ok(Self {
+            data,
+            spans,
+            schema,
+        })
+    }
+}
+
+/// Parses chunks of lines into a [`DataFrame`] with the CSV buffers.
+struct ChunkParser<'a> {
+    spans: &'a [(usize, usize)],
+    schema: &'a Schema,
+    projection: &'a [usize],
+    null_values: Option<&'a NullValuesCompiled>,
+    options: &'a FixedWidthReadOptions,
+}
+
+impl ChunkParser<'_> {
+    fn parse(&self, chunk: &[u8]) -> PolarsResult<DataFrame> {
+        let options = self.options;
+        let capacity = memchr::memchr_iter(options.eol_char, chunk).count() + 1;
+        let mut buffers = init_buffers(
+            self.projection,
+            capacity,
+            self.schema,
+            None,
+            options.encoding,
+            options.decimal_comma,
+        )?;
+        let mut splitter = FieldSplitter::new(self.spans, options);
+
+        for line in lines(chunk, options.eol_char) {
+            splitter.set_line(line)?;
+            for (buf, &i) in buffers.iter_mut().zip(self.projection) {
+                let field = splitter.field(line, i);
+                // SAFETY: the projection is in bounds of the schema the null values were
+                // compiled for.
+                if field.is_empty()
+                    || self
+                        .null_values
+                        .is_some_and(|nv| unsafe { nv.is_null(field, i) })
+                {
+                    buf.add_null(false);
+                    continue;
+                }
+                buf.add(field, options.ignore_errors, false, true)
+                    .map_err(|e| {
+                        let unparsable = String::from_utf8_lossy(field);
+                        let column_name = self.schema.get_at_index(i).unwrap().0;
+                        polars_err!(
+                            ComputeError:
+                            "could not parse `{}` as dtype `{}` at column '{}' (column number {})\n\n\
+                            You might want to try:\n\
+                            - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
+                            - specifying correct dtype with the `schema_overwrite` argument\n\
+                            - setting `ignore_errors` to `True`,\n\
+                            - adding `{}` to the `null_values` list.\n\n\
+                            Original error: ```{}```",
+                            &unparsable,
+                            buf.dtype(),
+                            column_name,
+                            i + 1,
+                            &unparsable,
+                            e
+                        )
+                    })?;
+            }
+        }
+
+        let columns = buffers
+            .into_iter()
+            .map(|buf| buf.into_series())
+            .collect::<PolarsResult<_>>()?;
+        Ok(unsafe { DataFrame::new_no_checks(columns) })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_spans() {
+        assert_eq!(
+            infer_spans(b"ID  NAME      AMOUNT", SpanUnit::Bytes).unwrap(),
+            [(0, 4), (4, 14), (14, usize::MAX)]
+        );
+        assert_eq!(
+            infer_spans("  Größe Preis".as_bytes(), SpanUnit::Chars).unwrap(),
+            [(2, 8), (8, usize::MAX)]
+        );
+        assert!(infer_spans(b"   ", SpanUnit::Bytes).is_err());
+    }
+
+    #[test]
+    fn test_line_aligned_chunks() {
+        let bytes = b"aaa\nbbb\r\n\nccc\nddd";
+        for n_chunks in 1..6 {
+            let chunks = line_aligned_chunks(bytes, n_chunks, b'\n');
+            assert!(chunks.len() <= n_chunks);
+            assert_eq!(chunks.concat(), bytes);
+            let n_lines: usize = chunks.iter().map(|c| lines(c, b'\n').count()).sum();
+            assert_eq!(n_lines, 4);
+        }
+        assert_eq!(end_of_n_lines(bytes, 2, b'\n'), 9);
+        assert_eq!(end_of_n_lines(bytes, 3, b'\n'), 14);
+        assert_eq!(end_of_n_lines(bytes, 10, b'\n'), bytes.len());
+    }
+}
@@ -76,6 +76,7 @@ use polars_json::json::write::FallibleStreamingIterator;
 use serde::{Deserialize, Serialize};
 use simd_json::BorrowedValue;
 
+use crate::compression::{CompressedWriter, CompressionCodec};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
 
@@ -84,6 +85,8 @@ use crate::prelude::*;
 pub struct JsonWriterOptions {
     /// maintain the order the data was processed
     pub maintain_order: bool,
+    /// Compress the output with this codec.
+    pub compression: Option<CompressionCodec>,
 }
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array) or `JsonLines` (each row output on a
@@ -117,6 +120,7 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    compression: Option<CompressionCodec>,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -124,6 +128,12 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Compress the output with the given codec.
+    pub fn with_compression(mut self, compression: Option<CompressionCodec>) -> Self {
+        self.compression = compression;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -136,6 +146,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            compression: None,
         }
     }
 
@@ -153,25 +164,25 @@ where
             .iter_chunks(true, false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
+        let mut buffer = CompressedWriter::try_new(&mut self.buffer, self.compression)?;
         match self.json_format {
             JsonFormat::JsonLines => {
                 let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                let writer = polars_json::ndjson::write::FileWriter::new(&mut buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                polars_json::json::write::write(&mut buffer, serializer)?;
             },
         }
 
-        Ok(())
+        buffer.finish()
     }
 }
 
 pub struct BatchedWriter<W: Write> {
-    writer: W,
+    writer: CompressedWriter<W>,
 }
 
 impl<W> BatchedWriter<W>
@@ -179,8 +190,18 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer: CompressedWriter::Plain(writer),
+        }
     }
+
+    /// Create a writer that compresses its output with `compression`.
+    pub fn try_new(writer: W, compression: Option<CompressionCodec>) -> PolarsResult<Self> {
+        Ok(BatchedWriter {
+            writer: CompressedWriter::try_new(writer, compression)?,
+        })
+    }
+
     /// Write a batch to the json writer.
     ///
     /// # Panics
@@ -203,6 +224,11 @@ where
         }
         Ok(())
     }
+
+    /// Finishes the compressed stream and flushes the underlying writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
 }
 
 /// Reads JSON in one of the formats in [`JsonFormat`] into a DataFrame.
@@ -6,9 +6,15 @@
 pub mod avro;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
+pub mod compression;
+#[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -17,6 +23,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 #[cfg(feature = "partition")]
@@ -27,6 +35,8 @@ pub mod predicates;
 pub mod prelude;
 mod shared;
 pub mod utils;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
 
 #[cfg(feature = "cloud")]
 pub use cloud::glob as async_glob;
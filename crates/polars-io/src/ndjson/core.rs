@@ -1,6 +1,7 @@
 use std::fs::File;
-use std::io::Cursor;
+use std::io::{BufReader, Cursor};
 use std::num::NonZeroUsize;
+use std::ops::Deref;
 use std::path::PathBuf;
 
 pub use arrow::array::StructArray;
@@ -10,6 +11,7 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
 
+use crate::compression::{maybe_decoder, DecompressedChunks, DEFAULT_DECOMPRESSED_CHUNK_SIZE};
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
@@ -114,6 +116,15 @@ where
 
     pub fn count(mut self) -> PolarsResult<usize> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        if let Some(decoder) = maybe_decoder(reader_bytes.deref())? {
+            let mut chunks =
+                DecompressedChunks::new(decoder, DEFAULT_DECOMPRESSED_CHUNK_SIZE, None, NEWLINE);
+            let mut n_rows = 0;
+            while let Some(chunk) = chunks.next_chunk()? {
+                n_rows += JsonLineReader::new(Cursor::new(chunk)).count()?;
+            }
+            return Ok(n_rows);
+        }
         let json_reader = CoreJsonReader::new(
             reader_bytes,
             self.n_rows,
@@ -168,6 +179,66 @@ where
     fn finish(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
+        if let Some(decoder) = maybe_decoder(reader_bytes.deref())? {
+            // Parse batches of lines so that the file is never fully inflated in memory.
+            let mut schema = match self.schema {
+                Some(schema) => schema,
+                None => {
+                    let mut reader = BufReader::new(maybe_decoder(reader_bytes.deref())?.unwrap());
+                    Arc::new(crate::ndjson::infer_schema(
+                        &mut reader,
+                        self.infer_schema_len,
+                    )?)
+                },
+            };
+            if let Some(overwriting_schema) = self.schema_overwrite {
+                let schema = Arc::make_mut(&mut schema);
+                overwrite_schema(schema, overwriting_schema)?;
+            }
+            let mut chunks =
+                DecompressedChunks::new(decoder, DEFAULT_DECOMPRESSED_CHUNK_SIZE, None, NEWLINE);
+            let mut n_rows = self.n_rows;
+            let mut dfs = vec![];
+            while n_rows != Some(0) {
+                let Some(chunk) = chunks.next_chunk()? else {
+                    break;
+                };
+                let mut df = JsonLineReader::new(Cursor::new(chunk))
+                    .with_schema(schema.clone())
+                    .with_n_rows(n_rows)
+                    .with_n_threads(self.n_threads)
+                    .with_chunk_size(Some(self.chunk_size))
+                    .low_memory(self.low_memory)
+                    .with_ignore_errors(self.ignore_errors)
+                    .with_row_index(self.row_index.as_deref_mut())
+                    .with_projection(self.projection.clone())
+                    .with_rechunk(false)
+                    .finish()?;
+                // `n_rows` limits the rows that are read, so the predicate is applied after
+                // they are counted.
+                if let Some(n_rows) = n_rows.as_mut() {
+                    *n_rows -= df.height();
+                }
+                if let Some(predicate) = &self.predicate {
+                    let s = predicate.evaluate_io(&df)?;
+                    df = df.filter(s.bool()?)?;
+                }
+                dfs.push(df);
+            }
+            if dfs.is_empty() {
+                return JsonLineReader::new(Cursor::new(vec![]))
+                    .with_schema(schema)
+                    .with_row_index(self.row_index)
+                    .with_projection(self.projection)
+                    .finish();
+            }
+            let mut df = accumulate_dataframes_vertical(dfs)?;
+            if rechunk {
+                df.as_single_chunk_par();
+            }
+            return Ok(df);
+        }
+
         let mut json_reader = CoreJsonReader::new(
             reader_bytes,
             self.n_rows,
@@ -0,0 +1,170 @@
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The default size of a compression chunk.
+pub(super) const DEFAULT_BLOCK_SIZE: usize = 256 * 1024;
+
+/// The compression codec of an ORC file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OrcCompression {
+    Uncompressed,
+    Zlib,
+    Snappy,
+    Lz4,
+    #[default]
+    Zstd,
+}
+
+impl OrcCompression {
+    pub(super) fn from_proto(value: u64) -> PolarsResult<Self> {
+        Ok(match value {
+            0 => Self::Uncompressed,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            3 => polars_bail!(ComputeError: "LZO compressed ORC files are not supported"),
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            v => polars_bail!(ComputeError: "out-of-spec ORC metadata: unknown compression {}", v),
+        })
+    }
+
+    pub(super) fn to_proto(self) -> u64 {
+        match self {
+            Self::Uncompressed => 0,
+            Self::Zlib => 1,
+            Self::Snappy => 2,
+            Self::Lz4 => 4,
+            Self::Zstd => 5,
+        }
+    }
+}
+
+/// Decompresses a stream that consists of chunks with a 3 byte header.
+///
+/// `block_size` is the maximum decompressed size of a chunk.
+pub(super) fn decompress(
+    compression: OrcCompression,
+    block_size: usize,
+    data: &[u8],
+) -> PolarsResult<Cow<[u8]>> {
+    if compression == OrcCompression::Uncompressed {
+        return Ok(Cow::Borrowed(data));
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut data = data;
+    while !data.is_empty() {
+        polars_ensure!(data.len() >= 3, ComputeError: "out-of-spec ORC file: truncated compression chunk");
+        let header = u32::from_le_bytes([data[0], data[1], data[2], 0]);
+        let is_original = header & 1 == 1;
+        let len = (header >> 1) as usize;
+        let chunk = data.get(3..3 + len).ok_or_else(
+            || polars_err!(ComputeError: "out-of-spec ORC file: truncated compression chunk"),
+        )?;
+        data = &data[3 + len..];
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match compression {
+            OrcCompression::Uncompressed => unreachable!(),
+            OrcCompression::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk).read_to_end(&mut out)?;
+            },
+            OrcCompression::Snappy => {
+                let decompressed = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(to_compute_err)?;
+                out.extend_from_slice(&decompressed);
+            },
+            OrcCompression::Lz4 => {
+                // The block format does not store the decompressed size, but it cannot exceed
+                // the compression block size.
+                let mut buf = vec![0; block_size];
+                let n =
+                    lz4_flex::block::decompress_into(chunk, &mut buf).map_err(to_compute_err)?;
+                out.extend_from_slice(&buf[..n]);
+            },
+            OrcCompression::Zstd => {
+                zstd::stream::copy_decode(chunk, &mut out)?;
+            },
+        }
+    }
+    Ok(Cow::Owned(out))
+}
+
+/// Compresses `data` into chunks of at most `DEFAULT_BLOCK_SIZE` bytes and appends them to `out`.
+pub(super) fn compress(
+    compression: OrcCompression,
+    data: &[u8],
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    if compression == OrcCompression::Uncompressed {
+        out.extend_from_slice(data);
+        return Ok(());
+    }
+
+    for chunk in data.chunks(DEFAULT_BLOCK_SIZE) {
+        let compressed = match compression {
+            OrcCompression::Uncompressed => unreachable!(),
+            OrcCompression::Zlib => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    Vec::with_capacity(chunk.len()),
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(chunk)?;
+                encoder.finish()?
+            },
+            OrcCompression::Snappy => snap::raw::Encoder::new()
+                .compress_vec(chunk)
+                .map_err(to_compute_err)?,
+            OrcCompression::Lz4 => lz4_flex::block::compress(chunk),
+            OrcCompression::Zstd => zstd::bulk::compress(chunk, 0)?,
+        };
+
+        // Store the original bytes if compression doesn't pay off.
+        let (header, body) = if compressed.len() < chunk.len() {
+            ((compressed.len() as u32) << 1, compressed.as_slice())
+        } else {
+            (((chunk.len() as u32) << 1) | 1, chunk)
+        };
+        out.extend_from_slice(&header.to_le_bytes()[..3]);
+        out.extend_from_slice(body);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_codecs() {
+        let data = (0..100_000u32)
+            .flat_map(|i| (i % 1000).to_le_bytes())
+            .collect::<Vec<_>>();
+        for compression in [
+            OrcCompression::Uncompressed,
+            OrcCompression::Zlib,
+            OrcCompression::Snappy,
+            OrcCompression::Lz4,
+            OrcCompression::Zstd,
+        ] {
+            let mut out = vec![];
+            compress(compression, &data, &mut out).unwrap();
+            assert_eq!(
+                decompress(compression, DEFAULT_BLOCK_SIZE, &out)
+                    .unwrap()
+                    .as_ref(),
+                data
+            );
+        }
+    }
+}
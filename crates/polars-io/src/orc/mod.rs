@@ -0,0 +1,29 @@
+//! # Read and write [Apache ORC] files.
+//!
+//! [Apache ORC]: https://orc.apache.org
+mod compression;
+mod proto;
+mod read;
+mod rle;
+mod write;
+
+pub use compression::OrcCompression;
+pub use read::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use write::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrcOptions {
+    /// Use the statistics of the stripes to skip stripes that cannot match the predicate.
+    pub use_statistics: bool,
+}
+
+impl Default for OrcOptions {
+    fn default() -> Self {
+        Self {
+            use_statistics: true,
+        }
+    }
+}
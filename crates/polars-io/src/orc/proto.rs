@@ -0,0 +1,877 @@
+//! A minimal protobuf (de)serializer for the messages in the tail and the stripe footers
+//! of an ORC file.
+//!
+//! Only the fields that are needed to read and write the data are handled, unknown fields
+//! are skipped.
+//!
+//! See <https://orc.apache.org/specification/ORCv1/> for the message definitions.
+use polars_error::{polars_bail, polars_err, PolarsResult};
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_LEN: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> PolarsResult<u64> {
+    let mut out = 0u64;
+    let mut shift = 0;
+    loop {
+        let Some(&byte) = buf.get(*pos) else {
+            polars_bail!(ComputeError: "out-of-spec ORC metadata: unexpected end of varint")
+        };
+        *pos += 1;
+        if shift < 64 {
+            out |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(out);
+        }
+        shift += 7;
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+enum Value<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Bytes(&'a [u8]),
+    Fixed32(u32),
+}
+
+impl<'a> Value<'a> {
+    fn as_u64(&self) -> PolarsResult<u64> {
+        match self {
+            Value::Varint(v) | Value::Fixed64(v) => Ok(*v),
+            Value::Fixed32(v) => Ok(*v as u64),
+            Value::Bytes(_) => {
+                polars_bail!(ComputeError: "out-of-spec ORC metadata: expected an integer field")
+            },
+        }
+    }
+
+    fn as_sint(&self) -> PolarsResult<i64> {
+        self.as_u64().map(zigzag_decode)
+    }
+
+    fn as_f64(&self) -> PolarsResult<f64> {
+        match self {
+            Value::Fixed64(v) => Ok(f64::from_bits(*v)),
+            _ => polars_bail!(ComputeError: "out-of-spec ORC metadata: expected a double field"),
+        }
+    }
+
+    fn as_bytes(&self) -> PolarsResult<&'a [u8]> {
+        match self {
+            Value::Bytes(v) => Ok(v),
+            _ => polars_bail!(ComputeError: "out-of-spec ORC metadata: expected a bytes field"),
+        }
+    }
+
+    fn as_string(&self) -> PolarsResult<String> {
+        let bytes = self.as_bytes()?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| polars_err!(ComputeError: "out-of-spec ORC metadata: invalid utf8"))
+    }
+
+    /// Extends `out` with a repeated integer field, which may or may not be packed.
+    fn extend_repeated(&self, out: &mut Vec<u64>) -> PolarsResult<()> {
+        match self {
+            Value::Bytes(buf) => {
+                let mut pos = 0;
+                while pos < buf.len() {
+                    out.push(read_varint(buf, &mut pos)?);
+                }
+            },
+            v => out.push(v.as_u64()?),
+        }
+        Ok(())
+    }
+}
+
+/// Iterates over the `(field number, value)` pairs of a message.
+fn for_each_field<'a>(
+    buf: &'a [u8],
+    mut f: impl FnMut(u64, Value<'a>) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    let mut pos = 0;
+    while pos < buf.len() {
+        let key = read_varint(buf, &mut pos)?;
+        let value = match (key & 0x7) as u8 {
+            WIRE_VARINT => Value::Varint(read_varint(buf, &mut pos)?),
+            WIRE_FIXED64 => {
+                let bytes = buf.get(pos..pos + 8).ok_or_else(
+                    || polars_err!(ComputeError: "out-of-spec ORC metadata: unexpected end of message"),
+                )?;
+                pos += 8;
+                Value::Fixed64(u64::from_le_bytes(bytes.try_into().unwrap()))
+            },
+            WIRE_LEN => {
+                let len = read_varint(buf, &mut pos)? as usize;
+                let bytes = buf.get(pos..pos + len).ok_or_else(
+                    || polars_err!(ComputeError: "out-of-spec ORC metadata: unexpected end of message"),
+                )?;
+                pos += len;
+                Value::Bytes(bytes)
+            },
+            WIRE_FIXED32 => {
+                let bytes = buf.get(pos..pos + 4).ok_or_else(
+                    || polars_err!(ComputeError: "out-of-spec ORC metadata: unexpected end of message"),
+                )?;
+                pos += 4;
+                Value::Fixed32(u32::from_le_bytes(bytes.try_into().unwrap()))
+            },
+            wire_type => {
+                polars_bail!(ComputeError: "out-of-spec ORC metadata: unsupported wire type {}", wire_type)
+            },
+        };
+        f(key >> 3, value)?;
+    }
+    Ok(())
+}
+
+/// Serializes the fields of a message.
+#[derive(Default)]
+struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    fn key(&mut self, field: u64, wire_type: u8) {
+        write_varint(&mut self.buf, (field << 3) | wire_type as u64)
+    }
+
+    fn uint(&mut self, field: u64, value: u64) {
+        self.key(field, WIRE_VARINT);
+        write_varint(&mut self.buf, value);
+    }
+
+    fn sint(&mut self, field: u64, value: i64) {
+        self.uint(field, zigzag_encode(value))
+    }
+
+    fn double(&mut self, field: u64, value: f64) {
+        self.key(field, WIRE_FIXED64);
+        self.buf.extend_from_slice(&value.to_bits().to_le_bytes());
+    }
+
+    fn bytes(&mut self, field: u64, value: &[u8]) {
+        self.key(field, WIRE_LEN);
+        write_varint(&mut self.buf, value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn packed(&mut self, field: u64, values: &[u64]) {
+        if values.is_empty() {
+            return;
+        }
+        let mut packed = vec![];
+        for v in values {
+            write_varint(&mut packed, *v);
+        }
+        self.bytes(field, &packed)
+    }
+
+    fn message(&mut self, field: u64, encode: impl FnOnce(&mut Encoder)) {
+        let mut inner = Encoder::default();
+        encode(&mut inner);
+        self.bytes(field, &inner.buf)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct PostScript {
+    pub footer_length: u64,
+    pub compression: u64,
+    pub compression_block_size: Option<u64>,
+    pub version: Vec<u64>,
+    pub metadata_length: u64,
+    pub writer_version: Option<u64>,
+    pub magic: Option<String>,
+}
+
+impl PostScript {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => out.footer_length = value.as_u64()?,
+                2 => out.compression = value.as_u64()?,
+                3 => out.compression_block_size = Some(value.as_u64()?),
+                4 => value.extend_repeated(&mut out.version)?,
+                5 => out.metadata_length = value.as_u64()?,
+                6 => out.writer_version = Some(value.as_u64()?),
+                8000 => out.magic = Some(value.as_string()?),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut enc = Encoder::default();
+        enc.uint(1, self.footer_length);
+        enc.uint(2, self.compression);
+        if let Some(block_size) = self.compression_block_size {
+            enc.uint(3, block_size);
+        }
+        enc.packed(4, &self.version);
+        enc.uint(5, self.metadata_length);
+        if let Some(writer_version) = self.writer_version {
+            enc.uint(6, writer_version);
+        }
+        if let Some(magic) = &self.magic {
+            enc.bytes(8000, magic.as_bytes());
+        }
+        enc.buf
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => out.offset = value.as_u64()?,
+                2 => out.index_length = value.as_u64()?,
+                3 => out.data_length = value.as_u64()?,
+                4 => out.footer_length = value.as_u64()?,
+                5 => out.number_of_rows = value.as_u64()?,
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    fn encode(&self, enc: &mut Encoder) {
+        enc.uint(1, self.offset);
+        enc.uint(2, self.index_length);
+        enc.uint(3, self.data_length);
+        enc.uint(4, self.footer_length);
+        enc.uint(5, self.number_of_rows);
+    }
+}
+
+/// The kind of a node in the type tree of an ORC file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum TypeKind {
+    #[default]
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn from_proto(value: u64) -> PolarsResult<Self> {
+        use TypeKind::*;
+        Ok(match value {
+            0 => Boolean,
+            1 => Byte,
+            2 => Short,
+            3 => Int,
+            4 => Long,
+            5 => Float,
+            6 => Double,
+            7 => String,
+            8 => Binary,
+            9 => Timestamp,
+            10 => List,
+            11 => Map,
+            12 => Struct,
+            13 => Union,
+            14 => Decimal,
+            15 => Date,
+            16 => Varchar,
+            17 => Char,
+            18 => TimestampInstant,
+            v => polars_bail!(ComputeError: "out-of-spec ORC metadata: unknown type kind {}", v),
+        })
+    }
+
+    fn to_proto(self) -> u64 {
+        self as u64
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct Type {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u64>,
+    pub field_names: Vec<String>,
+    pub precision: Option<u64>,
+    pub scale: Option<u64>,
+}
+
+impl Type {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => out.kind = TypeKind::from_proto(value.as_u64()?)?,
+                2 => value.extend_repeated(&mut out.subtypes)?,
+                3 => out.field_names.push(value.as_string()?),
+                5 => out.precision = Some(value.as_u64()?),
+                6 => out.scale = Some(value.as_u64()?),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    fn encode(&self, enc: &mut Encoder) {
+        enc.uint(1, self.kind.to_proto());
+        enc.packed(2, &self.subtypes);
+        for name in &self.field_names {
+            enc.bytes(3, name.as_bytes());
+        }
+        if let Some(precision) = self.precision {
+            enc.uint(5, precision);
+        }
+        if let Some(scale) = self.scale {
+            enc.uint(6, scale);
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(super) struct IntegerStatistics {
+    pub minimum: Option<i64>,
+    pub maximum: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(super) struct DoubleStatistics {
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct StringStatistics {
+    pub minimum: Option<String>,
+    pub maximum: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(super) struct DateStatistics {
+    pub minimum: Option<i32>,
+    pub maximum: Option<i32>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct ColumnStatistics {
+    pub number_of_values: Option<u64>,
+    pub int_statistics: Option<IntegerStatistics>,
+    pub double_statistics: Option<DoubleStatistics>,
+    pub string_statistics: Option<StringStatistics>,
+    /// The number of `true` values of a boolean column.
+    pub bucket_statistics: Option<Vec<u64>>,
+    pub date_statistics: Option<DateStatistics>,
+    pub has_null: Option<bool>,
+}
+
+impl ColumnStatistics {
+    fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => out.number_of_values = Some(value.as_u64()?),
+                2 => {
+                    let mut stats = IntegerStatistics::default();
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stats.minimum = Some(value.as_sint()?),
+                            2 => stats.maximum = Some(value.as_sint()?),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.int_statistics = Some(stats);
+                },
+                3 => {
+                    let mut stats = DoubleStatistics::default();
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stats.minimum = Some(value.as_f64()?),
+                            2 => stats.maximum = Some(value.as_f64()?),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.double_statistics = Some(stats);
+                },
+                4 => {
+                    let mut stats = StringStatistics::default();
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stats.minimum = Some(value.as_string()?),
+                            2 => stats.maximum = Some(value.as_string()?),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.string_statistics = Some(stats);
+                },
+                5 => {
+                    let mut count = vec![];
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        if field == 1 {
+                            value.extend_repeated(&mut count)?;
+                        }
+                        Ok(())
+                    })?;
+                    out.bucket_statistics = Some(count);
+                },
+                7 => {
+                    let mut stats = DateStatistics::default();
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stats.minimum = Some(value.as_sint()? as i32),
+                            2 => stats.maximum = Some(value.as_sint()? as i32),
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.date_statistics = Some(stats);
+                },
+                10 => out.has_null = Some(value.as_u64()? != 0),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    fn encode(&self, enc: &mut Encoder) {
+        if let Some(n) = self.number_of_values {
+            enc.uint(1, n);
+        }
+        if let Some(stats) = &self.int_statistics {
+            enc.message(2, |enc| {
+                if let (Some(min), Some(max)) = (stats.minimum, stats.maximum) {
+                    enc.sint(1, min);
+                    enc.sint(2, max);
+                }
+            });
+        }
+        if let Some(stats) = &self.double_statistics {
+            enc.message(3, |enc| {
+                if let (Some(min), Some(max)) = (stats.minimum, stats.maximum) {
+                    enc.double(1, min);
+                    enc.double(2, max);
+                }
+            });
+        }
+        if let Some(stats) = &self.string_statistics {
+            enc.message(4, |enc| {
+                if let (Some(min), Some(max)) = (&stats.minimum, &stats.maximum) {
+                    enc.bytes(1, min.as_bytes());
+                    enc.bytes(2, max.as_bytes());
+                }
+            });
+        }
+        if let Some(count) = &self.bucket_statistics {
+            enc.message(5, |enc| enc.packed(1, count));
+        }
+        if let Some(stats) = &self.date_statistics {
+            enc.message(7, |enc| {
+                if let (Some(min), Some(max)) = (stats.minimum, stats.maximum) {
+                    enc.sint(1, min as i64);
+                    enc.sint(2, max as i64);
+                }
+            });
+        }
+        if let Some(has_null) = self.has_null {
+            enc.uint(10, has_null as u64);
+        }
+    }
+
+    /// Merges the statistics of two stripes of the same column.
+    pub fn merge(&mut self, other: &Self) {
+        fn merge_min_max<T: Clone + PartialOrd>(
+            min: &mut Option<T>,
+            max: &mut Option<T>,
+            other_min: &Option<T>,
+            other_max: &Option<T>,
+        ) {
+            match (&*min, other_min) {
+                (Some(a), Some(b)) if b < a => *min = Some(b.clone()),
+                (None, Some(b)) => *min = Some(b.clone()),
+                _ => {},
+            }
+            match (&*max, other_max) {
+                (Some(a), Some(b)) if b > a => *max = Some(b.clone()),
+                (None, Some(b)) => *max = Some(b.clone()),
+                _ => {},
+            }
+        }
+
+        self.number_of_values = match (self.number_of_values, other.number_of_values) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.has_null = match (self.has_null, other.has_null) {
+            (Some(a), Some(b)) => Some(a || b),
+            (a, b) => a.or(b),
+        };
+        if let (Some(a), Some(b)) = (&mut self.int_statistics, &other.int_statistics) {
+            merge_min_max(&mut a.minimum, &mut a.maximum, &b.minimum, &b.maximum);
+        }
+        // A stripe without double statistics contains NaN values.
+        match (&mut self.double_statistics, &other.double_statistics) {
+            (Some(a), Some(b)) => {
+                merge_min_max(&mut a.minimum, &mut a.maximum, &b.minimum, &b.maximum)
+            },
+            (stats, _) => *stats = None,
+        }
+        if let (Some(a), Some(b)) = (&mut self.string_statistics, &other.string_statistics) {
+            merge_min_max(&mut a.minimum, &mut a.maximum, &b.minimum, &b.maximum);
+        }
+        if let (Some(a), Some(b)) = (&mut self.bucket_statistics, &other.bucket_statistics) {
+            for (a, b) in a.iter_mut().zip(b) {
+                *a += b;
+            }
+        }
+        if let (Some(a), Some(b)) = (&mut self.date_statistics, &other.date_statistics) {
+            merge_min_max(&mut a.minimum, &mut a.maximum, &b.minimum, &b.maximum);
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct Footer {
+    pub header_length: u64,
+    pub content_length: u64,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<Type>,
+    pub number_of_rows: u64,
+    pub statistics: Vec<ColumnStatistics>,
+    pub row_index_stride: Option<u64>,
+}
+
+impl Footer {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => out.header_length = value.as_u64()?,
+                2 => out.content_length = value.as_u64()?,
+                3 => out
+                    .stripes
+                    .push(StripeInformation::decode(value.as_bytes()?)?),
+                4 => out.types.push(Type::decode(value.as_bytes()?)?),
+                6 => out.number_of_rows = value.as_u64()?,
+                7 => out
+                    .statistics
+                    .push(ColumnStatistics::decode(value.as_bytes()?)?),
+                8 => out.row_index_stride = Some(value.as_u64()?),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut enc = Encoder::default();
+        enc.uint(1, self.header_length);
+        enc.uint(2, self.content_length);
+        for stripe in &self.stripes {
+            enc.message(3, |enc| stripe.encode(enc));
+        }
+        for ty in &self.types {
+            enc.message(4, |enc| ty.encode(enc));
+        }
+        enc.uint(6, self.number_of_rows);
+        for stats in &self.statistics {
+            enc.message(7, |enc| stats.encode(enc));
+        }
+        if let Some(stride) = self.row_index_stride {
+            enc.uint(8, stride);
+        }
+        enc.buf
+    }
+}
+
+/// The statistics of every column of every stripe.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct Metadata {
+    pub stripe_stats: Vec<Vec<ColumnStatistics>>,
+}
+
+impl Metadata {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            if field == 1 {
+                let mut col_stats = vec![];
+                for_each_field(value.as_bytes()?, |field, value| {
+                    if field == 1 {
+                        col_stats.push(ColumnStatistics::decode(value.as_bytes()?)?);
+                    }
+                    Ok(())
+                })?;
+                out.stripe_stats.push(col_stats);
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut enc = Encoder::default();
+        for col_stats in &self.stripe_stats {
+            enc.message(1, |enc| {
+                for stats in col_stats {
+                    enc.message(1, |enc| stats.encode(enc));
+                }
+            });
+        }
+        enc.buf
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Index or encryption streams that are not needed to read the data.
+    Other(u64),
+}
+
+impl StreamKind {
+    fn from_proto(value: u64) -> Self {
+        match value {
+            0 => Self::Present,
+            1 => Self::Data,
+            2 => Self::Length,
+            3 => Self::DictionaryData,
+            5 => Self::Secondary,
+            v => Self::Other(v),
+        }
+    }
+
+    fn to_proto(self) -> u64 {
+        match self {
+            Self::Present => 0,
+            Self::Data => 1,
+            Self::Length => 2,
+            Self::DictionaryData => 3,
+            Self::Secondary => 5,
+            Self::Other(v) => v,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Stream {
+    pub kind: StreamKind,
+    pub column: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) enum ColumnEncodingKind {
+    #[default]
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(super) struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+    pub writer_timezone: Option<String>,
+}
+
+impl StripeFooter {
+    pub fn decode(buf: &[u8]) -> PolarsResult<Self> {
+        let mut out = Self::default();
+        for_each_field(buf, |field, value| {
+            match field {
+                1 => {
+                    let mut stream = Stream {
+                        kind: StreamKind::Other(u64::MAX),
+                        column: 0,
+                        length: 0,
+                    };
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => stream.kind = StreamKind::from_proto(value.as_u64()?),
+                            2 => stream.column = value.as_u64()?,
+                            3 => stream.length = value.as_u64()?,
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.streams.push(stream);
+                },
+                2 => {
+                    let mut encoding = ColumnEncoding::default();
+                    for_each_field(value.as_bytes()?, |field, value| {
+                        match field {
+                            1 => {
+                                encoding.kind = match value.as_u64()? {
+                                    0 => ColumnEncodingKind::Direct,
+                                    1 => ColumnEncodingKind::Dictionary,
+                                    2 => ColumnEncodingKind::DirectV2,
+                                    3 => ColumnEncodingKind::DictionaryV2,
+                                    v => polars_bail!(
+                                        ComputeError: "out-of-spec ORC metadata: unknown column encoding {}", v
+                                    ),
+                                }
+                            },
+                            2 => encoding.dictionary_size = value.as_u64()?,
+                            _ => {},
+                        }
+                        Ok(())
+                    })?;
+                    out.columns.push(encoding);
+                },
+                3 => out.writer_timezone = Some(value.as_string()?),
+                _ => {},
+            }
+            Ok(())
+        })?;
+        Ok(out)
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut enc = Encoder::default();
+        for stream in &self.streams {
+            enc.message(1, |enc| {
+                enc.uint(1, stream.kind.to_proto());
+                enc.uint(2, stream.column);
+                enc.uint(3, stream.length);
+            });
+        }
+        for encoding in &self.columns {
+            enc.message(2, |enc| {
+                enc.uint(1, encoding.kind as u64);
+                if encoding.dictionary_size > 0 {
+                    enc.uint(2, encoding.dictionary_size);
+                }
+            });
+        }
+        if let Some(tz) = &self.writer_timezone {
+            enc.bytes(3, tz.as_bytes());
+        }
+        enc.buf
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_tail() {
+        let footer = Footer {
+            header_length: 3,
+            content_length: 100,
+            stripes: vec![StripeInformation {
+                offset: 3,
+                index_length: 0,
+                data_length: 90,
+                footer_length: 7,
+                number_of_rows: 10,
+            }],
+            types: vec![
+                Type {
+                    kind: TypeKind::Struct,
+                    subtypes: vec![1, 2],
+                    field_names: vec!["a".into(), "b".into()],
+                    ..Default::default()
+                },
+                Type {
+                    kind: TypeKind::Long,
+                    ..Default::default()
+                },
+                Type {
+                    kind: TypeKind::Decimal,
+                    precision: Some(10),
+                    scale: Some(2),
+                    ..Default::default()
+                },
+            ],
+            number_of_rows: 10,
+            statistics: vec![ColumnStatistics {
+                number_of_values: Some(10),
+                int_statistics: Some(IntegerStatistics {
+                    minimum: Some(-5),
+                    maximum: Some(i64::MAX),
+                }),
+                has_null: Some(false),
+                ..Default::default()
+            }],
+            row_index_stride: Some(0),
+        };
+        assert_eq!(Footer::decode(&footer.encode()).unwrap(), footer);
+
+        let postscript = PostScript {
+            footer_length: 42,
+            compression: 5,
+            compression_block_size: Some(1 << 18),
+            version: vec![0, 12],
+            metadata_length: 7,
+            writer_version: Some(6),
+            magic: Some("ORC".into()),
+        };
+        assert_eq!(
+            PostScript::decode(&postscript.encode()).unwrap(),
+            postscript
+        );
+    }
+}
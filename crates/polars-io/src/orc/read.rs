@@ -0,0 +1,908 @@
+use std::borrow::Cow;
+
+use arrow::array::{
+    new_empty_array, new_null_array, Array, BinaryViewArray, BooleanArray, ListArray,
+    MutableBinaryViewArray, PrimitiveArray, StructArray,
+};
+use arrow::compute::take::take_unchecked;
+use arrow::datatypes::{ArrowSchemaRef, IdxArr, TimeUnit};
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::POOL;
+use rayon::prelude::*;
+
+use super::compression::{decompress, OrcCompression, DEFAULT_BLOCK_SIZE};
+use super::proto::{
+    ColumnEncodingKind, ColumnStatistics, Footer, Metadata, PostScript, StreamKind, StripeFooter,
+    StripeInformation, Type, TypeKind,
+};
+use super::rle::{
+    decode_bool_rle, decode_byte_rle, decode_int_rle, decode_varints_i128, RleVersion,
+};
+use crate::mmap::MmapBytesReader;
+use crate::predicates::{apply_predicate, BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::prelude::*;
+use crate::RowIndex;
+
+/// Seconds between the unix epoch and the ORC epoch (2015-01-01 00:00:00).
+pub(super) const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// The parsed tail of an ORC file.
+#[derive(Debug)]
+pub(super) struct FileTail {
+    pub footer: Footer,
+    pub metadata: Metadata,
+    pub compression: OrcCompression,
+    pub block_size: usize,
+}
+
+impl FileTail {
+    fn read(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() > 4 && bytes.starts_with(b"ORC"),
+            ComputeError: "not an ORC file: the magic bytes are missing"
+        );
+        let ps_end = bytes.len() - 1;
+        let ps_start = ps_end
+            .checked_sub(bytes[ps_end] as usize)
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid postscript"))?;
+        let postscript = PostScript::decode(&bytes[ps_start..ps_end])?;
+        let compression = OrcCompression::from_proto(postscript.compression)?;
+        let block_size = postscript
+            .compression_block_size
+            .map_or(DEFAULT_BLOCK_SIZE, |size| size as usize);
+
+        let footer_start = ps_start
+            .checked_sub(postscript.footer_length as usize)
+            .ok_or_else(
+                || polars_err!(ComputeError: "out-of-spec ORC file: invalid footer length"),
+            )?;
+        let footer = Footer::decode(&decompress(
+            compression,
+            block_size,
+            &bytes[footer_start..ps_start],
+        )?)?;
+
+        let metadata_start = footer_start
+            .checked_sub(postscript.metadata_length as usize)
+            .ok_or_else(
+                || polars_err!(ComputeError: "out-of-spec ORC file: invalid metadata length"),
+            )?;
+        let metadata = Metadata::decode(&decompress(
+            compression,
+            block_size,
+            &bytes[metadata_start..footer_start],
+        )?)?;
+
+        Ok(Self {
+            footer,
+            metadata,
+            compression,
+            block_size,
+        })
+    }
+
+    fn schema(&self) -> PolarsResult<ArrowSchema> {
+        let types = &self.footer.types;
+        let root = types
+            .first()
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: empty type tree"))?;
+        polars_ensure!(
+            root.kind == TypeKind::Struct,
+            ComputeError: "the root type of an ORC file must be a struct"
+        );
+        let fields = root
+            .field_names
+            .iter()
+            .zip(&root.subtypes)
+            .map(|(name, id)| {
+                Ok(ArrowField::new(
+                    name,
+                    orc_type_to_arrow(types, *id as usize)?,
+                    true,
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(ArrowSchema::from(fields))
+    }
+}
+
+fn get_type(types: &[Type], id: usize) -> PolarsResult<&Type> {
+    types
+        .get(id)
+        .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid type id {}", id))
+}
+
+fn orc_type_to_arrow(types: &[Type], id: usize) -> PolarsResult<ArrowDataType> {
+    let ty = get_type(types, id)?;
+    let child = |i: usize| -> PolarsResult<ArrowDataType> {
+        let id = *ty
+            .subtypes
+            .get(i)
+            .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: missing subtype"))?;
+        orc_type_to_arrow(types, id as usize)
+    };
+
+    Ok(match ty.kind {
+        TypeKind::Boolean => ArrowDataType::Boolean,
+        TypeKind::Byte => ArrowDataType::Int8,
+        TypeKind::Short => ArrowDataType::Int16,
+        TypeKind::Int => ArrowDataType::Int32,
+        TypeKind::Long => ArrowDataType::Int64,
+        TypeKind::Float => ArrowDataType::Float32,
+        TypeKind::Double => ArrowDataType::Float64,
+        TypeKind::String | TypeKind::Varchar | TypeKind::Char => ArrowDataType::Utf8View,
+        TypeKind::Binary => ArrowDataType::BinaryView,
+        TypeKind::Timestamp => ArrowDataType::Timestamp(TimeUnit::Nanosecond, None),
+        TypeKind::TimestampInstant => {
+            ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string()))
+        },
+        TypeKind::Date => ArrowDataType::Date32,
+        TypeKind::Decimal => ArrowDataType::Decimal(
+            ty.precision.unwrap_or(38) as usize,
+            ty.scale.unwrap_or(0) as usize,
+        ),
+        TypeKind::List => {
+            ArrowDataType::LargeList(Box::new(ArrowField::new("item", child(0)?, true)))
+        },
+        TypeKind::Map => {
+            let entries = ArrowDataType::Struct(vec![
+                ArrowField::new("key", child(0)?, true),
+                ArrowField::new("value", child(1)?, true),
+            ]);
+            ArrowDataType::LargeList(Box::new(ArrowField::new("item", entries, true)))
+        },
+        TypeKind::Struct => {
+            polars_ensure!(
+                !ty.subtypes.is_empty(),
+                ComputeError: "cannot read ORC struct types without fields"
+            );
+            let fields = ty
+                .field_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Ok(ArrowField::new(name, child(i)?, true)))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            ArrowDataType::Struct(fields)
+        },
+        TypeKind::Union => polars_bail!(ComputeError: "cannot read ORC union types"),
+    })
+}
+
+/// The streams and column encodings of a single stripe.
+struct StripeReader<'a> {
+    tail: &'a FileTail,
+    streams: PlHashMap<(usize, StreamKind), &'a [u8]>,
+    footer: StripeFooter,
+}
+
+impl<'a> StripeReader<'a> {
+    fn try_new(
+        bytes: &'a [u8],
+        tail: &'a FileTail,
+        stripe: &StripeInformation,
+    ) -> PolarsResult<Self> {
+        let out_of_spec = || polars_err!(ComputeError: "out-of-spec ORC file: invalid stripe");
+
+        let footer_start = (stripe.offset + stripe.index_length + stripe.data_length) as usize;
+        let footer_bytes = bytes
+            .get(footer_start..footer_start + stripe.footer_length as usize)
+            .ok_or_else(out_of_spec)?;
+        let footer = StripeFooter::decode(&decompress(
+            tail.compression,
+            tail.block_size,
+            footer_bytes,
+        )?)?;
+
+        // The streams are stored back to back, starting with the index streams.
+        let mut streams = PlHashMap::with_capacity(footer.streams.len());
+        let mut offset = stripe.offset as usize;
+        for stream in &footer.streams {
+            let length = stream.length as usize;
+            let data = bytes.get(offset..offset + length).ok_or_else(out_of_spec)?;
+            streams.insert((stream.column as usize, stream.kind), data);
+            offset += length;
+        }
+
+        Ok(Self {
+            tail,
+            streams,
+            footer,
+        })
+    }
+
+    /// Returns the decompressed stream. Missing streams are read as empty.
+    fn stream(&self, column: usize, kind: StreamKind) -> PolarsResult<Cow<'a, [u8]>> {
+        match self.streams.get(&(column, kind)) {
+            Some(data) => decompress(self.tail.compression, self.tail.block_size, data),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    }
+
+    fn encoding(&self, column: usize) -> ColumnEncodingKind {
+        self.footer
+            .columns
+            .get(column)
+            .map(|encoding| encoding.kind)
+            .unwrap_or_default()
+    }
+
+    fn rle_version(&self, column: usize) -> RleVersion {
+        match self.encoding(column) {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::Dictionary => RleVersion::V1,
+            ColumnEncodingKind::DirectV2 | ColumnEncodingKind::DictionaryV2 => RleVersion::V2,
+        }
+    }
+
+    fn ints(
+        &self,
+        column: usize,
+        kind: StreamKind,
+        n: usize,
+        signed: bool,
+    ) -> PolarsResult<Vec<i64>> {
+        decode_int_rle(
+            &self.stream(column, kind)?,
+            n,
+            signed,
+            self.rle_version(column),
+        )
+    }
+
+    /// Decodes `num_rows` values of the column with the given type id.
+    fn decode(
+        &self,
+        column: usize,
+        num_rows: usize,
+        data_type: &ArrowDataType,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let validity = match self.streams.get(&(column, StreamKind::Present)) {
+            Some(_) => Some(decode_bool_rle(
+                &self.stream(column, StreamKind::Present)?,
+                num_rows,
+            )?),
+            None => None,
+        };
+        let null_count = validity.as_ref().map_or(0, |v| v.unset_bits());
+        if null_count == num_rows && num_rows > 0 {
+            return Ok(new_null_array(data_type.clone(), num_rows));
+        }
+
+        // Only the non-null values are stored, also for the children of nested types.
+        let values = self.decode_values(column, num_rows - null_count, data_type)?;
+        let Some(validity) = validity.filter(|_| null_count > 0) else {
+            return Ok(values);
+        };
+
+        let mut idx = 0 as IdxSize;
+        let indices = validity
+            .iter()
+            .map(|is_valid| {
+                let i = idx;
+                idx += is_valid as IdxSize;
+                i.min(values.len() as IdxSize - 1)
+            })
+            .collect::<Vec<_>>();
+        let indices = IdxArr::from_vec(indices).with_validity(Some(validity));
+        // SAFETY: the indices are in bounds, as there is at least one value.
+        Ok(unsafe { take_unchecked(values.as_ref(), &indices) })
+    }
+
+    fn decode_values(
+        &self,
+        column: usize,
+        n: usize,
+        data_type: &ArrowDataType,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let ty = get_type(&self.tail.footer.types, column)?;
+        let data = || self.stream(column, StreamKind::Data);
+
+        Ok(match ty.kind {
+            TypeKind::Boolean => {
+                BooleanArray::new(data_type.clone(), decode_bool_rle(&data()?, n)?, None).boxed()
+            },
+            TypeKind::Byte => {
+                let values = decode_byte_rle(&data()?, n)?;
+                PrimitiveArray::from_vec(values.into_iter().map(|v| v as i8).collect()).boxed()
+            },
+            TypeKind::Short => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                PrimitiveArray::from_vec(values.into_iter().map(|v| v as i16).collect()).boxed()
+            },
+            TypeKind::Int => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                PrimitiveArray::from_vec(values.into_iter().map(|v| v as i32).collect()).boxed()
+            },
+            TypeKind::Long => {
+                PrimitiveArray::from_vec(self.ints(column, StreamKind::Data, n, true)?).boxed()
+            },
+            TypeKind::Float => {
+                let data = data()?;
+                let data = data.get(..n * 4).ok_or_else(out_of_spec_stream)?;
+                let values = data
+                    .chunks_exact(4)
+                    .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+                    .collect();
+                PrimitiveArray::<f32>::from_vec(values).boxed()
+            },
+            TypeKind::Double => {
+                let data = data()?;
+                let data = data.get(..n * 8).ok_or_else(out_of_spec_stream)?;
+                let values = data
+                    .chunks_exact(8)
+                    .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                    .collect();
+                PrimitiveArray::<f64>::from_vec(values).boxed()
+            },
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let values = self.decode_binary(column, n)?;
+                if ty.kind == TypeKind::Binary {
+                    values.boxed()
+                } else {
+                    values.to_utf8view()?.boxed()
+                }
+            },
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let seconds = self.ints(column, StreamKind::Data, n, true)?;
+                let nanos = self.ints(column, StreamKind::Secondary, n, false)?;
+                // Instants are always relative to the ORC epoch in UTC.
+                let writer_tz = match ty.kind {
+                    TypeKind::Timestamp => self.footer.writer_timezone.as_deref(),
+                    _ => None,
+                };
+                let values = decode_timestamps(seconds, nanos, writer_tz)?;
+                PrimitiveArray::<i64>::from_vec(values)
+                    .to(data_type.clone())
+                    .boxed()
+            },
+            TypeKind::Date => {
+                let values = self.ints(column, StreamKind::Data, n, true)?;
+                PrimitiveArray::from_vec(values.into_iter().map(|v| v as i32).collect())
+                    .to(data_type.clone())
+                    .boxed()
+            },
+            TypeKind::Decimal => {
+                let ArrowDataType::Decimal(_, scale) = data_type else {
+                    unreachable!()
+                };
+                let values = decode_varints_i128(&data()?, n)?;
+                let scales = self.ints(column, StreamKind::Secondary, n, true)?;
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(value, value_scale)| rescale_decimal(value, value_scale, *scale as i64))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                PrimitiveArray::<i128>::from_vec(values)
+                    .to(data_type.clone())
+                    .boxed()
+            },
+            TypeKind::List | TypeKind::Map => {
+                let lengths = self.ints(column, StreamKind::Length, n, false)?;
+                let offsets = lengths_to_offsets(&lengths)?;
+                let num_values = *offsets.last() as usize;
+
+                let ArrowDataType::LargeList(field) = data_type else {
+                    unreachable!()
+                };
+                let values = if ty.kind == TypeKind::List {
+                    self.decode(ty.subtypes[0] as usize, num_values, field.data_type())?
+                } else {
+                    let ArrowDataType::Struct(fields) = field.data_type() else {
+                        unreachable!()
+                    };
+                    let keys =
+                        self.decode(ty.subtypes[0] as usize, num_values, &fields[0].data_type)?;
+                    let values =
+                        self.decode(ty.subtypes[1] as usize, num_values, &fields[1].data_type)?;
+                    StructArray::new(field.data_type().clone(), vec![keys, values], None).boxed()
+                };
+                ListArray::<i64>::new(data_type.clone(), offsets, values, None).boxed()
+            },
+            TypeKind::Struct => {
+                let ArrowDataType::Struct(fields) = data_type else {
+                    unreachable!()
+                };
+                let values = fields
+                    .iter()
+                    .zip(&ty.subtypes)
+                    .map(|(field, id)| self.decode(*id as usize, n, field.data_type()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                StructArray::new(data_type.clone(), values, None).boxed()
+            },
+            TypeKind::Union => polars_bail!(ComputeError: "cannot read ORC union types"),
+        })
+    }
+
+    fn decode_binary(&self, column: usize, n: usize) -> PolarsResult<BinaryViewArray> {
+        let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(n);
+        match self.encoding(column) {
+            ColumnEncodingKind::Direct | ColumnEncodingKind::DirectV2 => {
+                let lengths = self.ints(column, StreamKind::Length, n, false)?;
+                let data = self.stream(column, StreamKind::Data)?;
+                let mut offset = 0;
+                for length in lengths {
+                    let end = offset + length as usize;
+                    out.push_value(data.get(offset..end).ok_or_else(out_of_spec_stream)?);
+                    offset = end;
+                }
+            },
+            ColumnEncodingKind::Dictionary | ColumnEncodingKind::DictionaryV2 => {
+                let dictionary_size = self.footer.columns[column].dictionary_size as usize;
+                let lengths = self.ints(column, StreamKind::Length, dictionary_size, false)?;
+                let data = self.stream(column, StreamKind::DictionaryData)?;
+                let mut offset = 0;
+                let dictionary = lengths
+                    .into_iter()
+                    .map(|length| {
+                        let end = offset + length as usize;
+                        let value = data.get(offset..end).ok_or_else(out_of_spec_stream);
+                        offset = end;
+                        value
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                for key in self.ints(column, StreamKind::Data, n, false)? {
+                    out.push_value(
+                        *dictionary
+                            .get(key as usize)
+                            .ok_or_else(out_of_spec_stream)?,
+                    );
+                }
+            },
+        }
+        Ok(out.freeze())
+    }
+}
+
+fn out_of_spec_stream() -> PolarsError {
+    polars_err!(ComputeError: "out-of-spec ORC file: stream is shorter than expected")
+}
+
+fn lengths_to_offsets(lengths: &[i64]) -> PolarsResult<OffsetsBuffer<i64>> {
+    let mut offsets = Vec::with_capacity(lengths.len() + 1);
+    let mut offset = 0i64;
+    offsets.push(offset);
+    for length in lengths {
+        polars_ensure!(*length >= 0, ComputeError: "out-of-spec ORC file: negative length");
+        offset += length;
+        offsets.push(offset);
+    }
+    // SAFETY: the offsets are monotonically increasing.
+    Ok(unsafe { OffsetsBuffer::new_unchecked(offsets.into()) })
+}
+
+/// Decodes ORC timestamps to nanoseconds since the unix epoch. The seconds are relative to
+/// the ORC epoch in the time zone of the writer, and timestamps without time zone hold the
+/// wall clock time of the writer, so they are converted back to its local time.
+fn decode_timestamps(
+    seconds: Vec<i64>,
+    nanos: Vec<i64>,
+    writer_tz: Option<&str>,
+) -> PolarsResult<Vec<i64>> {
+    let to_unix = |seconds: i64, nanos: i64, epoch: i64| {
+        let nanos = decode_nanos(nanos as u64);
+        let mut seconds = seconds + epoch;
+        // The seconds of timestamps before the unix epoch are rounded towards zero.
+        if seconds < 0 && nanos > 999_999 {
+            seconds -= 1;
+        }
+        (seconds, nanos)
+    };
+    let values = seconds.into_iter().zip(nanos);
+
+    match writer_tz {
+        None | Some("UTC" | "Etc/UTC" | "GMT" | "Etc/GMT") => Ok(values
+            .map(|(seconds, nanos)| {
+                let (seconds, nanos) = to_unix(seconds, nanos, ORC_EPOCH_SECONDS);
+                seconds * 1_000_000_000 + nanos
+            })
+            .collect()),
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            use arrow::legacy::time_zone::Tz;
+            use chrono::{NaiveDate, Offset, TimeZone};
+
+            let tz = tz.parse::<Tz>().map_err(
+                |_| polars_err!(ComputeError: "unable to parse ORC writer time zone: '{}'", tz),
+            )?;
+            let orc_epoch = NaiveDate::from_ymd_opt(2015, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap();
+            let epoch_offset = tz
+                .offset_from_local_datetime(&orc_epoch)
+                .earliest()
+                .unwrap_or_else(|| tz.offset_from_utc_datetime(&orc_epoch));
+            let epoch = ORC_EPOCH_SECONDS - epoch_offset.fix().local_minus_utc() as i64;
+
+            values
+                .map(|(seconds, nanos)| {
+                    let (seconds, nanos) = to_unix(seconds, nanos, epoch);
+                    let instant = chrono::DateTime::from_timestamp(seconds, 0)
+                        .ok_or_else(|| polars_err!(ComputeError: "out-of-range ORC timestamp"))?
+                        .naive_utc();
+                    let offset = tz.offset_from_utc_datetime(&instant).fix();
+                    let seconds = seconds + offset.local_minus_utc() as i64;
+                    Ok(seconds * 1_000_000_000 + nanos)
+                })
+                .collect()
+        },
+        #[cfg(not(feature = "timezones"))]
+        Some(tz) => polars_bail!(
+            ComputeError: "reading ORC timestamps written in time zone '{}' requires the 'timezones' feature",
+            tz
+        ),
+    }
+}
+
+/// The nanoseconds are stored with the number of trailing decimal zeros in the lowest 3 bits.
+fn decode_nanos(value: u64) -> i64 {
+    let zeros = value & 0x7;
+    let nanos = (value >> 3) as i64;
+    if zeros == 0 {
+        nanos
+    } else {
+        nanos * 10i64.pow(zeros as u32 + 1)
+    }
+}
+
+fn rescale_decimal(value: i128, from_scale: i64, to_scale: i64) -> PolarsResult<i128> {
+    let diff = to_scale - from_scale;
+    let factor = 10i128
+        .checked_pow(diff.unsigned_abs() as u32)
+        .ok_or_else(|| polars_err!(ComputeError: "out-of-spec ORC file: invalid decimal scale"))?;
+    if diff >= 0 {
+        value
+            .checked_mul(factor)
+            .ok_or_else(|| polars_err!(ComputeError: "decimal value does not fit in the ORC type"))
+    } else {
+        Ok(value / factor)
+    }
+}
+
+/// Converts the statistics of an ORC column to [`ColumnStats`].
+fn column_stats(stats: &ColumnStatistics, field: &ArrowField, num_rows: usize) -> ColumnStats {
+    let field = Field::from(field);
+    let dtype = field.data_type().clone();
+    let null_count = stats
+        .number_of_values
+        .map(|n| Series::new("", [num_rows.saturating_sub(n as usize) as IdxSize]));
+
+    let min_max = if let Some(int) = &stats.int_statistics {
+        int.minimum
+            .zip(int.maximum)
+            .map(|(min, max)| (Series::new("", [min]), Series::new("", [max])))
+    } else if let Some(double) = &stats.double_statistics {
+        double
+            .minimum
+            .zip(double.maximum)
+            .map(|(min, max)| (Series::new("", [min]), Series::new("", [max])))
+    } else if let Some(string) = &stats.string_statistics {
+        string
+            .minimum
+            .as_deref()
+            .zip(string.maximum.as_deref())
+            .map(|(min, max)| (Series::new("", [min]), Series::new("", [max])))
+    } else if let Some(date) = &stats.date_statistics {
+        date.minimum
+            .zip(date.maximum)
+            .map(|(min, max)| (Series::new("", [min]), Series::new("", [max])))
+    } else if let (Some(count), Some(n)) = (&stats.bucket_statistics, stats.number_of_values) {
+        count.first().filter(|_| n > 0).map(|true_count| {
+            (
+                Series::new("", [*true_count == n]),
+                Series::new("", [*true_count > 0]),
+            )
+        })
+    } else {
+        None
+    };
+
+    // Statistics that don't match the type of the column are ignored.
+    let (min, max) = match min_max {
+        Some((min, max)) if !dtype.is_nested() => (min.cast(&dtype).ok(), max.cast(&dtype).ok()),
+        _ => (None, None),
+    };
+    ColumnStats::new(field, null_count, min, max)
+}
+
+fn read_this_stripe(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    tail: &FileTail,
+    stripe_idx: usize,
+    schema: &ArrowSchema,
+) -> PolarsResult<bool> {
+    let Some(predicate) = predicate.and_then(|p| p.as_stats_evaluator()) else {
+        return Ok(true);
+    };
+    let Some(stripe_stats) = tail.metadata.stripe_stats.get(stripe_idx) else {
+        return Ok(true);
+    };
+    let num_rows = tail.footer.stripes[stripe_idx].number_of_rows as usize;
+    let root = &tail.footer.types[0];
+
+    let stats = schema
+        .fields
+        .iter()
+        .zip(&root.subtypes)
+        .map(|(field, id)| match stripe_stats.get(*id as usize) {
+            Some(stats) => column_stats(stats, field, num_rows),
+            None => ColumnStats::from_field(field.into()),
+        })
+        .collect();
+    let stats = BatchStats::new(Arc::new(schema.into()), stats, Some(num_rows));
+
+    let should_read = predicate.should_read(&stats);
+    // An ORC file may not have statistics of all columns.
+    if matches!(should_read, Ok(false)) {
+        Ok(false)
+    } else if !matches!(should_read, Err(PolarsError::ColumnNotFound(_))) {
+        should_read
+    } else {
+        Ok(true)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn read_orc(
+    bytes: &[u8],
+    tail: &FileTail,
+    schema: &ArrowSchema,
+    projection: Option<&[usize]>,
+    n_rows: usize,
+    row_index: Option<&RowIndex>,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    use_statistics: bool,
+    hive_partition_columns: Option<&[Series]>,
+) -> PolarsResult<DataFrame> {
+    // The columns are returned in the order of the file.
+    let mut projection = projection
+        .map(|p| p.to_vec())
+        .unwrap_or_else(|| (0..schema.len()).collect());
+    projection.sort_unstable();
+    let root = &tail.footer.types[0];
+    let fields = projection
+        .iter()
+        .map(|i| (&schema.fields[*i], root.subtypes[*i] as usize))
+        .collect::<Vec<_>>();
+
+    // Select the stripes that contain the first `n_rows` and may match the predicate.
+    let mut stripes = vec![];
+    let mut row_offset = 0;
+    for (i, stripe) in tail.footer.stripes.iter().enumerate() {
+        if row_offset >= n_rows {
+            break;
+        }
+        let num_rows = stripe.number_of_rows as usize;
+        if !use_statistics || read_this_stripe(predicate, tail, i, schema)? {
+            stripes.push((i, row_offset, num_rows.min(n_rows - row_offset)));
+        }
+        row_offset += num_rows;
+    }
+
+    let finish_stripe = |columns: Vec<Box<dyn Array>>, row_offset: usize, num_rows: usize| {
+        let columns = columns
+            .into_iter()
+            .zip(&fields)
+            .map(|(array, (field, _))| Series::try_from((field.name.as_str(), array)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = unsafe { DataFrame::new_no_checks(columns) }.slice(0, num_rows);
+
+        if let Some(row_index) = row_index {
+            let offset = row_index.offset + row_offset as IdxSize;
+            let mut ca = IdxCa::from_vec(
+                row_index.name.as_ref(),
+                (offset..offset + num_rows as IdxSize).collect(),
+            );
+            ca.set_sorted_flag(IsSorted::Ascending);
+            df.insert_column(0, ca.into_series())?;
+        }
+        if let Some(hive_columns) = hive_partition_columns {
+            for s in hive_columns {
+                df.with_column(s.new_from_index(0, num_rows))?;
+            }
+        }
+        apply_predicate(&mut df, predicate, false)?;
+        Ok(df)
+    };
+
+    if stripes.is_empty() {
+        let columns = fields
+            .iter()
+            .map(|(field, _)| new_empty_array(field.data_type().clone()))
+            .collect();
+        return finish_stripe(columns, 0, 0);
+    }
+
+    let dfs = POOL.install(|| {
+        stripes
+            .into_par_iter()
+            .map(|(i, row_offset, num_rows)| {
+                let stripe = &tail.footer.stripes[i];
+                let reader = StripeReader::try_new(bytes, tail, stripe)?;
+                let columns = fields
+                    .iter()
+                    .map(|(field, id)| {
+                        reader.decode(*id, stripe.number_of_rows as usize, field.data_type())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                finish_stripe(columns, row_offset, num_rows)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+    Ok(accumulate_dataframes_vertical_unchecked(dfs))
+}
+
+/// Read [Apache ORC] format into a [`DataFrame`].
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("file.orc").expect("file not found");
+///
+///     OrcReader::new(file)
+///             .finish()
+/// }
+/// ```
+#[must_use]
+pub struct OrcReader<R: MmapBytesReader> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+    projection: Option<Vec<usize>>,
+    row_index: Option<RowIndex>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
+    hive_partition_columns: Option<Vec<Series>>,
+    use_statistics: bool,
+    tail: Option<Arc<FileTail>>,
+}
+
+impl<R: MmapBytesReader> OrcReader<R> {
+    /// Stop reading at `num_rows` rows.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the reader's column projection. This counts from 0, meaning that
+    /// `vec![0, 4]` would select the 1st and 5th column.
+    pub fn with_projection(mut self, projection: Option<Vec<usize>>) -> Self {
+        self.projection = projection;
+        self
+    }
+
+    /// Add a row index column.
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.row_index = row_index;
+        self
+    }
+
+    /// Use the statistics of the stripes to determine if they can be skipped.
+    pub fn use_statistics(mut self, toggle: bool) -> Self {
+        self.use_statistics = toggle;
+        self
+    }
+
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
+        self.hive_partition_columns = columns;
+        self
+    }
+
+    fn get_tail(&mut self) -> PolarsResult<Arc<FileTail>> {
+        if self.tail.is_none() {
+            self.reader.rewind()?;
+            let bytes = get_reader_bytes(&mut self.reader)?;
+            self.tail = Some(Arc::new(FileTail::read(&bytes)?));
+        }
+        Ok(self.tail.clone().unwrap())
+    }
+
+    /// [`Schema`] of the file.
+    pub fn schema(&mut self) -> PolarsResult<ArrowSchemaRef> {
+        Ok(Arc::new(self.get_tail()?.schema()?))
+    }
+
+    /// Number of rows in the ORC file.
+    pub fn num_rows(&mut self) -> PolarsResult<usize> {
+        Ok(self.get_tail()?.footer.number_of_rows as usize)
+    }
+}
+
+impl<R: MmapBytesReader> SerReader<R> for OrcReader<R> {
+    /// Create a new [`OrcReader`] from an existing `Reader`.
+    fn new(reader: R) -> Self {
+        OrcReader {
+            reader,
+            rechunk: false,
+            n_rows: None,
+            columns: None,
+            projection: None,
+            row_index: None,
+            predicate: None,
+            hive_partition_columns: None,
+            use_statistics: true,
+            tail: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        self.reader.rewind()?;
+        let bytes = get_reader_bytes(&mut self.reader)?;
+        let tail = match self.tail.take() {
+            Some(tail) => tail,
+            None => Arc::new(FileTail::read(&bytes)?),
+        };
+        let schema = tail.schema()?;
+
+        if let Some(cols) = &self.columns {
+            self.projection = Some(columns_to_projection(cols, &schema)?);
+        }
+
+        let mut df = read_orc(
+            &bytes,
+            &tail,
+            &schema,
+            self.projection.as_deref(),
+            self.n_rows.unwrap_or(usize::MAX),
+            self.row_index.as_ref(),
+            self.predicate.as_deref(),
+            self.use_statistics,
+            self.hive_partition_columns.as_deref(),
+        )?;
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_timestamps() {
+        // 2015-01-01 00:00:00 and 2015-07-01 12:00:00.5.
+        let expected = [ORC_EPOCH_SECONDS * 1_000_000_000, 1_435_752_000_500_000_000];
+        let seconds = vec![0, 15_681_600];
+        let nanos = vec![0, 47];
+        let out = decode_timestamps(seconds, nanos, Some("UTC")).unwrap();
+        assert_eq!(out, expected);
+
+        // Written in America/Los_Angeles: the seconds are relative to 2015-01-01 00:00:00 PST
+        // and the second timestamp falls in PDT.
+        #[cfg(feature = "timezones")]
+        {
+            let seconds = vec![0, 15_678_000];
+            let nanos = vec![0, 47];
+            let out = decode_timestamps(seconds, nanos, Some("America/Los_Angeles")).unwrap();
+            assert_eq!(out, expected);
+        }
+    }
+}
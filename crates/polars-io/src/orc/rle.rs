@@ -0,0 +1,536 @@
+//! The run length encodings of ORC.
+//!
+//! See <https://orc.apache.org/specification/ORCv1/#run-length-encoding>.
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_error::{polars_ensure, polars_err, PolarsResult};
+
+/// The version of the integer run length encoding of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RleVersion {
+    V1,
+    V2,
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn next(&mut self) -> PolarsResult<u8> {
+        let byte = *self.data.get(self.pos).ok_or_else(
+            || polars_err!(ComputeError: "out-of-spec ORC file: unexpected end of stream"),
+        )?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn take(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        let out = self.data.get(self.pos..self.pos + n).ok_or_else(
+            || polars_err!(ComputeError: "out-of-spec ORC file: unexpected end of stream"),
+        )?;
+        self.pos += n;
+        Ok(out)
+    }
+
+    fn varint(&mut self) -> PolarsResult<u64> {
+        let mut out = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.next()?;
+            if shift < 64 {
+                out |= ((byte & 0x7f) as u64) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(out);
+            }
+            shift += 7;
+        }
+    }
+
+    /// Reads an unbounded signed varint, as used for the decimal values.
+    fn varint_i128(&mut self) -> PolarsResult<i128> {
+        let mut out = 0u128;
+        let mut shift = 0;
+        loop {
+            let byte = self.next()?;
+            if shift < 128 {
+                out |= ((byte & 0x7f) as u128) << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(((out >> 1) as i128) ^ -((out & 1) as i128));
+            }
+            shift += 7;
+        }
+    }
+
+    fn big_endian(&mut self, n_bytes: usize) -> PolarsResult<u64> {
+        Ok(self
+            .take(n_bytes)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | *b as u64))
+    }
+
+    /// Reads `n` big-endian bit-packed values of `width` bits. The run ends at a byte boundary.
+    fn bit_packed(&mut self, n: usize, width: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+        let n_bytes = (n * width).div_ceil(8);
+        let bytes = self.take(n_bytes)?;
+        let mut bit_pos = 0;
+        for _ in 0..n {
+            let mut value = 0u64;
+            let mut remaining = width;
+            while remaining > 0 {
+                let byte = bytes[bit_pos / 8];
+                let offset = bit_pos % 8;
+                let available = 8 - offset;
+                let n_bits = available.min(remaining);
+                let bits = (byte >> (available - n_bits)) & ((1u16 << n_bits) - 1) as u8;
+                value = (value << n_bits) | bits as u64;
+                remaining -= n_bits;
+                bit_pos += n_bits;
+            }
+            out.push(value);
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+pub(super) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+pub(super) fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+pub(super) fn write_varint_i128(out: &mut Vec<u8>, value: i128) {
+    let mut value = ((value << 1) ^ (value >> 127)) as u128;
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Decodes `n` unbounded zigzag encoded varints.
+pub(super) fn decode_varints_i128(data: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut reader = ByteReader::new(data);
+    (0..n).map(|_| reader.varint_i128()).collect()
+}
+
+pub(super) fn decode_byte_rle(data: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        let control = reader.next()? as i8;
+        if control >= 0 {
+            let value = reader.next()?;
+            out.extend(std::iter::repeat(value).take(control as usize + 3));
+        } else {
+            out.extend_from_slice(reader.take(-(control as isize) as usize)?);
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+pub(super) fn encode_byte_rle(values: &[u8], out: &mut Vec<u8>) {
+    const MAX_RUN: usize = 130;
+    const MAX_LITERALS: usize = 128;
+
+    let mut i = 0;
+    let mut literal_start = 0;
+    let flush_literals = |out: &mut Vec<u8>, literals: &[u8]| {
+        for chunk in literals.chunks(MAX_LITERALS) {
+            out.push((-(chunk.len() as i16)) as u8);
+            out.extend_from_slice(chunk);
+        }
+    };
+    while i < values.len() {
+        let run_len = values[i..]
+            .iter()
+            .take(MAX_RUN)
+            .take_while(|v| **v == values[i])
+            .count();
+        if run_len >= 3 {
+            flush_literals(out, &values[literal_start..i]);
+            out.push((run_len - 3) as u8);
+            out.push(values[i]);
+            i += run_len;
+            literal_start = i;
+        } else {
+            i += run_len;
+        }
+    }
+    flush_literals(out, &values[literal_start..]);
+}
+
+pub(super) fn decode_bool_rle(data: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let bytes = decode_byte_rle(data, n.div_ceil(8))?;
+    let mut out = MutableBitmap::with_capacity(n);
+    for i in 0..n {
+        out.push(bytes[i / 8] & (0x80 >> (i % 8)) != 0);
+    }
+    Ok(out.into())
+}
+
+pub(super) fn encode_bool_rle(values: impl Iterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    for (i, v) in values.enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0);
+        }
+        if v {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_byte_rle(&bytes, out)
+}
+
+/// Decodes `n` integers. Unsigned values are returned as their two's complement.
+pub(super) fn decode_int_rle(
+    data: &[u8],
+    n: usize,
+    signed: bool,
+    version: RleVersion,
+) -> PolarsResult<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(n);
+    while out.len() < n {
+        match version {
+            RleVersion::V1 => decode_int_rle_v1_run(&mut reader, signed, &mut out)?,
+            RleVersion::V2 => decode_int_rle_v2_run(&mut reader, signed, &mut out)?,
+        }
+    }
+    out.truncate(n);
+    Ok(out)
+}
+
+fn read_int(reader: &mut ByteReader, signed: bool) -> PolarsResult<i64> {
+    let value = reader.varint()?;
+    Ok(if signed {
+        zigzag_decode(value)
+    } else {
+        value as i64
+    })
+}
+
+fn decode_int_rle_v1_run(
+    reader: &mut ByteReader,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let control = reader.next()? as i8;
+    if control >= 0 {
+        let delta = reader.next()? as i8 as i64;
+        let base = read_int(reader, signed)?;
+        out.extend((0..control as i64 + 3).map(|i| base.wrapping_add(i * delta)));
+    } else {
+        for _ in 0..-(control as isize) {
+            out.push(read_int(reader, signed)?);
+        }
+    }
+    Ok(())
+}
+
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(n: usize) -> usize {
+    match n {
+        0 => 1,
+        1..=24 => n,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_int_rle_v2_run(
+    reader: &mut ByteReader,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let decode = |v: u64| {
+        if signed {
+            zigzag_decode(v)
+        } else {
+            v as i64
+        }
+    };
+
+    let first = reader.next()?;
+    match first >> 6 {
+        // Short repeat.
+        0 => {
+            let width = ((first >> 3) & 0x7) as usize + 1;
+            let count = (first & 0x7) as usize + 3;
+            let value = decode(reader.big_endian(width)?);
+            out.extend(std::iter::repeat(value).take(count));
+        },
+        // Direct.
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let len = (((first & 1) as usize) << 8 | reader.next()? as usize) + 1;
+            let mut values = Vec::with_capacity(len);
+            reader.bit_packed(len, width, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // Patched base.
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let len = (((first & 1) as usize) << 8 | reader.next()? as usize) + 1;
+            let third = reader.next()?;
+            let base_width = ((third >> 5) & 0x7) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+            let fourth = reader.next()?;
+            let patch_gap_width = ((fourth >> 5) & 0x7) as usize + 1;
+            let patch_len = (fourth & 0x1f) as usize;
+
+            // The base is stored in sign-magnitude representation.
+            let base = reader.big_endian(base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            reader.bit_packed(len, width, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_len);
+            reader.bit_packed(
+                patch_len,
+                closest_fixed_bits(patch_width + patch_gap_width),
+                &mut patches,
+            )?;
+            polars_ensure!(
+                patch_width + width <= 64,
+                ComputeError: "out-of-spec ORC file: invalid patch width"
+            );
+
+            let patch_mask = (1u64 << patch_width).wrapping_sub(1);
+            let mut pos = 0usize;
+            for patch in patches {
+                pos += (patch >> patch_width) as usize;
+                let patch = patch & patch_mask;
+                // A gap of 255 without a patch only moves the position.
+                if patch == 0 {
+                    continue;
+                }
+                let value = values.get_mut(pos).ok_or_else(
+                    || polars_err!(ComputeError: "out-of-spec ORC file: invalid patch position"),
+                )?;
+                *value |= patch << width;
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // Delta.
+        _ => {
+            let width_code = (first >> 1) & 0x1f;
+            let width = if width_code == 0 {
+                0
+            } else {
+                decode_bit_width(width_code)
+            };
+            let len = (((first & 1) as usize) << 8 | reader.next()? as usize) + 1;
+            let base = read_int(reader, signed)?;
+            let delta_base = zigzag_decode(reader.varint()?);
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            let mut prev = base.wrapping_add(delta_base);
+            out.push(prev);
+            if width == 0 {
+                for _ in 2..len {
+                    prev = prev.wrapping_add(delta_base);
+                    out.push(prev);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(len - 2);
+                reader.bit_packed(len - 2, width, &mut deltas)?;
+                for delta in deltas {
+                    prev = if delta_base < 0 {
+                        prev.wrapping_sub(delta as i64)
+                    } else {
+                        prev.wrapping_add(delta as i64)
+                    };
+                    out.push(prev);
+                }
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Encodes integers with version 1 of the integer run length encoding.
+pub(super) fn encode_int_rle_v1(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    const MAX_RUN: usize = 130;
+    const MAX_LITERALS: usize = 128;
+
+    let write_int = |out: &mut Vec<u8>, v: i64| {
+        if signed {
+            write_varint(out, zigzag_encode(v))
+        } else {
+            write_varint(out, v as u64)
+        }
+    };
+    let flush_literals = |out: &mut Vec<u8>, literals: &[i64]| {
+        for chunk in literals.chunks(MAX_LITERALS) {
+            out.push((-(chunk.len() as i16)) as u8);
+            for v in chunk {
+                write_int(out, *v);
+            }
+        }
+    };
+
+    let mut i = 0;
+    let mut literal_start = 0;
+    while i < values.len() {
+        // Find the longest run with a constant delta that fits in a byte.
+        let mut run_len = 1;
+        if i + 1 < values.len() {
+            if let Some(delta) = values[i + 1]
+                .checked_sub(values[i])
+                .filter(|d| (-128..=127).contains(d))
+            {
+                run_len = 2;
+                while run_len < MAX_RUN
+                    && i + run_len < values.len()
+                    && values[i + run_len].checked_sub(values[i + run_len - 1]) == Some(delta)
+                {
+                    run_len += 1;
+                }
+            }
+        }
+
+        if run_len >= 3 {
+            flush_literals(out, &values[literal_start..i]);
+            out.push((run_len - 3) as u8);
+            out.push((values[i + 1] - values[i]) as i8 as u8);
+            write_int(out, values[i]);
+            i += run_len;
+            literal_start = i;
+        } else {
+            i += 1;
+        }
+    }
+    flush_literals(out, &values[literal_start..]);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_byte_rle() {
+        let mut out = vec![];
+        encode_byte_rle(&[0; 100], &mut out);
+        assert_eq!(out, [0x61, 0x00]);
+        assert_eq!(decode_byte_rle(&out, 100).unwrap(), [0; 100]);
+
+        let mut out = vec![];
+        encode_byte_rle(&[0x44, 0x45], &mut out);
+        assert_eq!(out, [0xfe, 0x44, 0x45]);
+        assert_eq!(decode_byte_rle(&out, 2).unwrap(), [0x44, 0x45]);
+
+        let values = (0..1000).map(|i| (i / 7 % 5) as u8).collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_byte_rle(&values, &mut out);
+        assert_eq!(decode_byte_rle(&out, values.len()).unwrap(), values);
+    }
+
+    #[test]
+    fn test_bool_rle() {
+        let values = (0..1001).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_bool_rle(values.iter().copied(), &mut out);
+        let decoded = decode_bool_rle(&out, values.len()).unwrap();
+        assert_eq!(decoded.iter().collect::<Vec<_>>(), values);
+    }
+
+    #[test]
+    fn test_int_rle_v1() {
+        let mut out = vec![];
+        encode_int_rle_v1(&[7; 100], false, &mut out);
+        assert_eq!(out, [0x61, 0x00, 0x07]);
+
+        let mut out = vec![];
+        encode_int_rle_v1(&[2, 3, 6, 7, 11], false, &mut out);
+        assert_eq!(out, [0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b]);
+
+        let values = (0..1000)
+            .map(|i: i64| if i % 100 < 50 { i * 3 } else { -i * i * i })
+            .chain([i64::MIN, i64::MAX, 0])
+            .collect::<Vec<_>>();
+        let mut out = vec![];
+        encode_int_rle_v1(&values, true, &mut out);
+        assert_eq!(
+            decode_int_rle(&out, values.len(), true, RleVersion::V1).unwrap(),
+            values
+        );
+    }
+
+    #[test]
+    fn test_int_rle_v2() {
+        let decode = |data: &[u8], n| decode_int_rle(data, n, false, RleVersion::V2).unwrap();
+
+        assert_eq!(decode(&[0x0a, 0x27, 0x10], 5), [10000; 5]);
+        assert_eq!(
+            decode(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4
+            ),
+            [23713, 43806, 57005, 48879]
+        );
+
+        let mut expected = vec![2030, 2000, 2020, 1000000];
+        expected.extend((2040..=2190).step_by(10));
+        assert_eq!(
+            decode(
+                &[
+                    0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c,
+                    0x46, 0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe,
+                    0xfc, 0xe8
+                ],
+                expected.len()
+            ),
+            expected
+        );
+
+        assert_eq!(
+            decode(&[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46], 10),
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+}
@@ -0,0 +1,601 @@
+use std::io::Write;
+
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, ListArray, PrimitiveArray, StructArray, Utf8ViewArray,
+};
+use arrow::compute::take::take_unchecked;
+use arrow::datatypes::IdxArr;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::compression::{compress, OrcCompression, DEFAULT_BLOCK_SIZE};
+use super::proto::{
+    ColumnEncoding, ColumnStatistics, DateStatistics, DoubleStatistics, Footer, IntegerStatistics,
+    Metadata, PostScript, Stream, StreamKind, StringStatistics, StripeFooter, StripeInformation,
+    Type, TypeKind,
+};
+use super::read::ORC_EPOCH_SECONDS;
+use super::rle::{encode_bool_rle, encode_byte_rle, encode_int_rle_v1, write_varint_i128};
+use crate::shared::SerWriter;
+
+/// The default number of rows in a stripe.
+const DEFAULT_STRIPE_SIZE: usize = 512 * 512;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OrcWriterOptions {
+    /// Stream compression
+    pub compression: OrcCompression,
+    /// The number of rows per stripe. If `None` will be 512^2 rows.
+    pub stripe_size: Option<usize>,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+}
+
+/// Write a [`DataFrame`] to [Apache ORC] format.
+///
+/// [Apache ORC]: https://orc.apache.org
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::orc::OrcWriter;
+/// use std::fs::File;
+/// use polars_io::SerWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("file.orc").expect("could not create file");
+///
+///     OrcWriter::new(&mut file)
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct OrcWriter<W> {
+    writer: W,
+    compression: OrcCompression,
+    stripe_size: Option<usize>,
+}
+
+impl<W> OrcWriter<W>
+where
+    W: Write,
+{
+    /// Set the compression used. Defaults to `Zstd`.
+    pub fn with_compression(mut self, compression: OrcCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the number of rows per stripe. Defaults to 512^2 rows.
+    pub fn with_stripe_size(mut self, stripe_size: Option<usize>) -> Self {
+        self.stripe_size = stripe_size;
+        self
+    }
+
+    /// Write the header of the file and return a writer that buffers the batches into
+    /// stripes.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        BatchedWriter::new(self.writer, schema, self.compression, self.stripe_size)
+    }
+}
+
+impl<W> SerWriter<W> for OrcWriter<W>
+where
+    W: Write,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            compression: OrcCompression::default(),
+            stripe_size: None,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let mut writer = BatchedWriter::new(
+            &mut self.writer,
+            &df.schema(),
+            self.compression,
+            self.stripe_size,
+        )?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
+
+/// Returns the [`DataType`] a column is written as.
+fn orc_dtype(dtype: &DataType) -> PolarsResult<DataType> {
+    use DataType::*;
+    Ok(match dtype {
+        UInt8 => Int16,
+        UInt16 => Int32,
+        UInt32 | UInt64 => Int64,
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_, _) | Enum(_, _) => String,
+        #[cfg(feature = "dtype-datetime")]
+        Datetime(_, tz) => Datetime(TimeUnit::Nanoseconds, tz.clone()),
+        List(inner) => List(Box::new(orc_dtype(inner)?)),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name(), orc_dtype(f.data_type())?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        Boolean | Int8 | Int16 | Int32 | Int64 | Float32 | Float64 | String | Binary => {
+            dtype.clone()
+        },
+        #[cfg(feature = "dtype-date")]
+        Date => Date,
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(_, _) => dtype.clone(),
+        dt => polars_bail!(ComputeError: "cannot write '{}' datatype to ORC", dt),
+    })
+}
+
+/// Adds the types of `data_type` in pre-order and returns the column id of the root.
+fn add_types(data_type: &ArrowDataType, types: &mut Vec<Type>) -> PolarsResult<u64> {
+    let id = types.len();
+    types.push(Type::default());
+    let ty = match data_type {
+        ArrowDataType::Boolean => TypeKind::Boolean.into(),
+        ArrowDataType::Int8 => TypeKind::Byte.into(),
+        ArrowDataType::Int16 => TypeKind::Short.into(),
+        ArrowDataType::Int32 => TypeKind::Int.into(),
+        ArrowDataType::Int64 => TypeKind::Long.into(),
+        ArrowDataType::Float32 => TypeKind::Float.into(),
+        ArrowDataType::Float64 => TypeKind::Double.into(),
+        ArrowDataType::Utf8View => TypeKind::String.into(),
+        ArrowDataType::BinaryView => TypeKind::Binary.into(),
+        ArrowDataType::Timestamp(_, None) => TypeKind::Timestamp.into(),
+        ArrowDataType::Timestamp(_, Some(_)) => TypeKind::TimestampInstant.into(),
+        ArrowDataType::Date32 => TypeKind::Date.into(),
+        ArrowDataType::Decimal(precision, scale) => Type {
+            kind: TypeKind::Decimal,
+            precision: Some(*precision as u64),
+            scale: Some(*scale as u64),
+            ..Default::default()
+        },
+        ArrowDataType::LargeList(field) => Type {
+            kind: TypeKind::List,
+            subtypes: vec![add_types(field.data_type(), types)?],
+            ..Default::default()
+        },
+        ArrowDataType::Struct(fields) => Type {
+            kind: TypeKind::Struct,
+            subtypes: fields
+                .iter()
+                .map(|f| add_types(f.data_type(), types))
+                .collect::<PolarsResult<_>>()?,
+            field_names: fields.iter().map(|f| f.name.clone()).collect(),
+            ..Default::default()
+        },
+        dt => polars_bail!(ComputeError: "cannot write '{:?}' datatype to ORC", dt),
+    };
+    types[id] = ty;
+    Ok(id as u64)
+}
+
+impl From<TypeKind> for Type {
+    fn from(kind: TypeKind) -> Self {
+        Type {
+            kind,
+            ..Default::default()
+        }
+    }
+}
+
+/// The trailing decimal zeros of the nanoseconds are stored in the lowest 3 bits.
+fn encode_nanos(nanos: i64) -> i64 {
+    if nanos == 0 || nanos % 100 != 0 {
+        return nanos << 3;
+    }
+    let mut nanos = nanos / 100;
+    let mut zeros = 1;
+    while nanos % 10 == 0 && zeros < 7 {
+        nanos /= 10;
+        zeros += 1;
+    }
+    (nanos << 3) | zeros
+}
+
+fn min_max<T: Copy + PartialOrd>(values: impl Iterator<Item = T>) -> (Option<T>, Option<T>) {
+    values.fold((None, None), |(min, max), v| {
+        (
+            Some(min.map_or(v, |m: T| if v < m { v } else { m })),
+            Some(max.map_or(v, |m: T| if v > m { v } else { m })),
+        )
+    })
+}
+
+/// Encodes the streams and statistics of the columns of a stripe.
+struct StripeEncoder<'a> {
+    types: &'a [Type],
+    compression: OrcCompression,
+    streams: Vec<(Stream, Vec<u8>)>,
+    stats: Vec<ColumnStatistics>,
+}
+
+impl<'a> StripeEncoder<'a> {
+    fn push_stream(&mut self, column: usize, kind: StreamKind, data: &[u8]) -> PolarsResult<()> {
+        let mut buf = Vec::with_capacity(data.len() / 2);
+        compress(self.compression, data, &mut buf)?;
+        self.streams.push((
+            Stream {
+                kind,
+                column: column as u64,
+                length: buf.len() as u64,
+            },
+            buf,
+        ));
+        Ok(())
+    }
+
+    fn push_ints(
+        &mut self,
+        column: usize,
+        kind: StreamKind,
+        values: &[i64],
+        signed: bool,
+    ) -> PolarsResult<()> {
+        let mut buf = vec![];
+        encode_int_rle_v1(values, signed, &mut buf);
+        self.push_stream(column, kind, &buf)
+    }
+
+    fn encode(&mut self, array: &dyn Array, column: usize) -> PolarsResult<()> {
+        let null_count = array.null_count();
+        let stats = &mut self.stats[column];
+        stats.number_of_values = Some((array.len() - null_count) as u64);
+        stats.has_null = Some(null_count > 0);
+
+        // Only the non-null values are written, also for the children of nested types.
+        let dense = if null_count > 0 {
+            let validity = array.validity().unwrap();
+            let mut buf = vec![];
+            encode_bool_rle(validity.iter(), &mut buf);
+            self.push_stream(column, StreamKind::Present, &buf)?;
+
+            let indices = validity
+                .iter()
+                .enumerate()
+                .filter_map(|(i, is_valid)| is_valid.then_some(i as IdxSize))
+                .collect::<Vec<_>>();
+            // SAFETY: the indices are in bounds.
+            unsafe { take_unchecked(array, &IdxArr::from_vec(indices)) }
+        } else {
+            array.to_boxed()
+        };
+        let array = dense.as_ref();
+        let any = array.as_any();
+        let stats = &mut self.stats[column];
+
+        match array.data_type() {
+            ArrowDataType::Boolean => {
+                let array = any.downcast_ref::<BooleanArray>().unwrap();
+                stats.bucket_statistics =
+                    Some(vec![(array.len() - array.values().unset_bits()) as u64]);
+                let mut buf = vec![];
+                encode_bool_rle(array.values().iter(), &mut buf);
+                self.push_stream(column, StreamKind::Data, &buf)?;
+            },
+            ArrowDataType::Int8 => {
+                let array = any.downcast_ref::<PrimitiveArray<i8>>().unwrap();
+                let (minimum, maximum) = min_max(array.values().iter().map(|v| *v as i64));
+                stats.int_statistics = Some(IntegerStatistics { minimum, maximum });
+                let mut buf = vec![];
+                let values = array.values().iter().map(|v| *v as u8).collect::<Vec<_>>();
+                encode_byte_rle(&values, &mut buf);
+                self.push_stream(column, StreamKind::Data, &buf)?;
+            },
+            ArrowDataType::Int16 | ArrowDataType::Int32 | ArrowDataType::Int64 => {
+                let values: Vec<i64> = match array.data_type() {
+                    ArrowDataType::Int16 => any
+                        .downcast_ref::<PrimitiveArray<i16>>()
+                        .unwrap()
+                        .values()
+                        .iter()
+                        .map(|v| *v as i64)
+                        .collect(),
+                    ArrowDataType::Int32 => any
+                        .downcast_ref::<PrimitiveArray<i32>>()
+                        .unwrap()
+                        .values()
+                        .iter()
+                        .map(|v| *v as i64)
+                        .collect(),
+                    _ => any
+                        .downcast_ref::<PrimitiveArray<i64>>()
+                        .unwrap()
+                        .values()
+                        .to_vec(),
+                };
+                let (minimum, maximum) = min_max(values.iter().copied());
+                stats.int_statistics = Some(IntegerStatistics { minimum, maximum });
+                self.push_ints(column, StreamKind::Data, &values, true)?;
+            },
+            ArrowDataType::Float32 | ArrowDataType::Float64 => {
+                let (values, bytes): (Vec<f64>, Vec<u8>) =
+                    if let Some(array) = any.downcast_ref::<PrimitiveArray<f32>>() {
+                        (
+                            array.values().iter().map(|v| *v as f64).collect(),
+                            array
+                                .values()
+                                .iter()
+                                .flat_map(|v| v.to_le_bytes())
+                                .collect(),
+                        )
+                    } else {
+                        let array = any.downcast_ref::<PrimitiveArray<f64>>().unwrap();
+                        (
+                            array.values().to_vec(),
+                            array
+                                .values()
+                                .iter()
+                                .flat_map(|v| v.to_le_bytes())
+                                .collect(),
+                        )
+                    };
+                // The statistics are omitted if they are not well-defined.
+                if !values.iter().any(|v| v.is_nan()) {
+                    let (minimum, maximum) = min_max(values.into_iter());
+                    stats.double_statistics = Some(DoubleStatistics { minimum, maximum });
+                }
+                self.push_stream(column, StreamKind::Data, &bytes)?;
+            },
+            ArrowDataType::Utf8View | ArrowDataType::BinaryView => {
+                let (values, lengths) = if let Some(array) = any.downcast_ref::<Utf8ViewArray>() {
+                    let (minimum, maximum) = min_max(array.values_iter());
+                    stats.string_statistics = Some(StringStatistics {
+                        minimum: minimum.map(|v| v.to_string()),
+                        maximum: maximum.map(|v| v.to_string()),
+                    });
+                    let mut values = Vec::with_capacity(array.total_bytes_len());
+                    let lengths = array
+                        .values_iter()
+                        .map(|v| {
+                            values.extend_from_slice(v.as_bytes());
+                            v.len() as i64
+                        })
+                        .collect::<Vec<_>>();
+                    (values, lengths)
+                } else {
+                    let array = any.downcast_ref::<BinaryViewArray>().unwrap();
+                    let mut values = Vec::with_capacity(array.total_bytes_len());
+                    let lengths = array
+                        .values_iter()
+                        .map(|v| {
+                            values.extend_from_slice(v);
+                            v.len() as i64
+                        })
+                        .collect::<Vec<_>>();
+                    (values, lengths)
+                };
+                self.push_stream(column, StreamKind::Data, &values)?;
+                self.push_ints(column, StreamKind::Length, &lengths, false)?;
+            },
+            ArrowDataType::Timestamp(_, _) => {
+                let array = any.downcast_ref::<PrimitiveArray<i64>>().unwrap();
+                let (seconds, nanos): (Vec<_>, Vec<_>) = array
+                    .values()
+                    .iter()
+                    .map(|v| {
+                        let mut seconds = v.div_euclid(1_000_000_000);
+                        let nanos = v.rem_euclid(1_000_000_000);
+                        // Readers round the seconds of timestamps before the unix epoch down.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds += 1;
+                        }
+                        (seconds - ORC_EPOCH_SECONDS, encode_nanos(nanos))
+                    })
+                    .unzip();
+                self.push_ints(column, StreamKind::Data, &seconds, true)?;
+                self.push_ints(column, StreamKind::Secondary, &nanos, false)?;
+            },
+            ArrowDataType::Date32 => {
+                let array = any.downcast_ref::<PrimitiveArray<i32>>().unwrap();
+                let (minimum, maximum) = min_max(array.values().iter().copied());
+                stats.date_statistics = Some(DateStatistics { minimum, maximum });
+                let values = array.values().iter().map(|v| *v as i64).collect::<Vec<_>>();
+                self.push_ints(column, StreamKind::Data, &values, true)?;
+            },
+            ArrowDataType::Decimal(_, scale) => {
+                let array = any.downcast_ref::<PrimitiveArray<i128>>().unwrap();
+                let mut buf = vec![];
+                for v in array.values().iter() {
+                    write_varint_i128(&mut buf, *v);
+                }
+                self.push_stream(column, StreamKind::Data, &buf)?;
+                let scales = vec![*scale as i64; array.len()];
+                self.push_ints(column, StreamKind::Secondary, &scales, true)?;
+            },
+            ArrowDataType::LargeList(_) => {
+                let array = any.downcast_ref::<ListArray<i64>>().unwrap();
+                let offsets = array.offsets();
+                let lengths = offsets.lengths().map(|v| v as i64).collect::<Vec<_>>();
+                self.push_ints(column, StreamKind::Length, &lengths, false)?;
+
+                let start = *offsets.first() as usize;
+                let values = array.values().sliced(start, offsets.range() as usize);
+                let child = self.types[column].subtypes[0] as usize;
+                self.encode(values.as_ref(), child)?;
+            },
+            ArrowDataType::Struct(_) => {
+                let array = any.downcast_ref::<StructArray>().unwrap();
+                let children = self.types[column].subtypes.clone();
+                for (values, child) in array.values().iter().zip(children) {
+                    self.encode(values.as_ref(), child as usize)?;
+                }
+            },
+            dt => polars_bail!(ComputeError: "cannot write '{:?}' datatype to ORC", dt),
+        }
+        Ok(())
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    /// The number of bytes written so far.
+    position: u64,
+    schema: Schema,
+    types: Vec<Type>,
+    compression: OrcCompression,
+    stripe_size: usize,
+    buffer: DataFrame,
+    stripes: Vec<StripeInformation>,
+    stripe_stats: Vec<Vec<ColumnStatistics>>,
+}
+
+impl<W: Write> BatchedWriter<W> {
+    fn new(
+        mut writer: W,
+        schema: &Schema,
+        compression: OrcCompression,
+        stripe_size: Option<usize>,
+    ) -> PolarsResult<Self> {
+        let schema = schema
+            .iter_fields()
+            .map(|f| Ok(Field::new(f.name(), orc_dtype(f.data_type())?)))
+            .collect::<PolarsResult<Schema>>()?;
+        let mut types = vec![];
+        let root = schema.iter_fields().map(|f| f.to_arrow(true)).collect();
+        add_types(&ArrowDataType::Struct(root), &mut types)?;
+
+        writer.write_all(b"ORC")?;
+        Ok(Self {
+            writer,
+            position: 3,
+            buffer: DataFrame::empty_with_schema(&schema),
+            schema,
+            types,
+            compression,
+            stripe_size: stripe_size.unwrap_or(DEFAULT_STRIPE_SIZE).max(1),
+            stripes: vec![],
+            stripe_stats: vec![],
+        })
+    }
+
+    /// Write a batch to the ORC writer. Full stripes are written to the underlying writer,
+    /// the remaining rows are buffered.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let columns = df
+            .get_columns()
+            .iter()
+            .zip(self.schema.iter_dtypes())
+            .map(|(s, dtype)| s.strict_cast(dtype))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        self.buffer.vstack_mut(&DataFrame::new(columns)?)?;
+
+        while self.buffer.height() >= self.stripe_size {
+            let stripe = self.buffer.slice(0, self.stripe_size);
+            self.buffer = self.buffer.slice(self.stripe_size as i64, usize::MAX);
+            self.write_stripe(stripe)?;
+        }
+        Ok(())
+    }
+
+    fn write_stripe(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        if df.height() == 0 {
+            return Ok(());
+        }
+        df.as_single_chunk_par();
+        let num_rows = df.height();
+
+        let mut encoder = StripeEncoder {
+            types: &self.types,
+            compression: self.compression,
+            streams: vec![],
+            stats: vec![ColumnStatistics::default(); self.types.len()],
+        };
+        encoder.stats[0].number_of_values = Some(num_rows as u64);
+        encoder.stats[0].has_null = Some(false);
+        for chunk in df.iter_chunks(true, true) {
+            for (array, column) in chunk.arrays().iter().zip(&self.types[0].subtypes) {
+                encoder.encode(array.as_ref(), *column as usize)?;
+            }
+        }
+
+        let footer = StripeFooter {
+            streams: encoder.streams.iter().map(|(stream, _)| *stream).collect(),
+            columns: vec![ColumnEncoding::default(); self.types.len()],
+            writer_timezone: Some("UTC".to_string()),
+        };
+        let mut footer_bytes = vec![];
+        compress(self.compression, &footer.encode(), &mut footer_bytes)?;
+
+        let mut data_length = 0;
+        for (_, data) in &encoder.streams {
+            self.writer.write_all(data)?;
+            data_length += data.len() as u64;
+        }
+        self.writer.write_all(&footer_bytes)?;
+
+        self.stripes.push(StripeInformation {
+            offset: self.position,
+            index_length: 0,
+            data_length,
+            footer_length: footer_bytes.len() as u64,
+            number_of_rows: num_rows as u64,
+        });
+        self.stripe_stats.push(encoder.stats);
+        self.position += data_length + footer_bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Write the remaining rows and the tail of the file.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        let buffer =
+            std::mem::replace(&mut self.buffer, DataFrame::empty_with_schema(&self.schema));
+        self.write_stripe(buffer)?;
+
+        let mut statistics = self.stripe_stats.first().cloned().unwrap_or_else(|| {
+            let mut stats = vec![ColumnStatistics::default(); self.types.len()];
+            stats[0].number_of_values = Some(0);
+            stats
+        });
+        for stripe_stats in self.stripe_stats.iter().skip(1) {
+            for (stats, other) in statistics.iter_mut().zip(stripe_stats) {
+                stats.merge(other);
+            }
+        }
+
+        let metadata = Metadata {
+            stripe_stats: std::mem::take(&mut self.stripe_stats),
+        };
+        let mut metadata_bytes = vec![];
+        compress(self.compression, &metadata.encode(), &mut metadata_bytes)?;
+
+        let footer = Footer {
+            header_length: 3,
+            content_length: self.position,
+            number_of_rows: self.stripes.iter().map(|s| s.number_of_rows).sum(),
+            stripes: std::mem::take(&mut self.stripes),
+            types: self.types.clone(),
+            statistics,
+            row_index_stride: Some(0),
+        };
+        let mut footer_bytes = vec![];
+        compress(self.compression, &footer.encode(), &mut footer_bytes)?;
+
+        let postscript = PostScript {
+            footer_length: footer_bytes.len() as u64,
+            compression: self.compression.to_proto(),
+            compression_block_size: Some(DEFAULT_BLOCK_SIZE as u64),
+            version: vec![0, 12],
+            metadata_length: metadata_bytes.len() as u64,
+            writer_version: Some(6),
+            magic: Some("ORC".to_string()),
+        }
+        .encode();
+
+        self.writer.write_all(&metadata_bytes)?;
+        self.writer.write_all(&footer_bytes)?;
+        self.writer.write_all(&postscript)?;
+        self.writer.write_all(&[postscript.len() as u8])?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
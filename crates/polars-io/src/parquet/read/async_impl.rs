@@ -290,8 +290,10 @@ impl FetchRowGroupsFromObjectStore {
                 .iter()
                 .enumerate()
                 .filter(|(i, rg)| {
+                    // Bloom filters are only read from local files; fetching them would
+                    // take a request per column chunk before the row group is downloaded.
                     let should_be_read =
-                        matches!(read_this_row_group(Some(pred), rg, &schema), Ok(true));
+                        matches!(read_this_row_group(Some(pred), rg, &schema, None), Ok(true));
 
                     // Already add the row groups that will be skipped to the prefetched data.
                     if !should_be_read {
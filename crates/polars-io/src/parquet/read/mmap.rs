@@ -4,6 +4,10 @@ use bytes::Bytes;
 #[cfg(feature = "async")]
 use polars_core::datatypes::PlHashMap;
 use polars_error::PolarsResult;
+use polars_parquet::parquet::error::ParquetResult;
+use polars_parquet::parquet::indexes::FilteredPage;
+use polars_parquet::parquet::page::CompressedPage;
+use polars_parquet::parquet::read::{IndexedPageReader, PageMetaData};
 use polars_parquet::read::{
     column_iter_to_arrays, get_field_columns, ArrayIter, BasicDecompressor, ColumnChunkMetaData,
     PageReader,
@@ -26,6 +30,17 @@ pub enum ColumnStore<'a> {
     Fetched(PlHashMap<u64, Bytes>),
 }
 
+impl ColumnStore<'_> {
+    /// Returns the bytes of the whole file if it is stored locally.
+    pub(super) fn file_bytes(&self) -> Option<&[u8]> {
+        match self {
+            ColumnStore::Local(bytes) => Some(bytes),
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(_) => None,
+        }
+    }
+}
+
 /// For local files memory maps all columns that are part of the parquet field `field_name`.
 /// For cloud files the relevant memory regions should have been prefetched.
 pub(super) fn mmap_columns<'a>(
@@ -59,32 +74,63 @@ fn _mmap_single_column<'a>(
     (meta, chunk)
 }
 
+type Pages<'a> = Box<dyn Iterator<Item = ParquetResult<CompressedPage>> + Send + Sync + 'a>;
+
 // similar to arrow2 serializer, except this accepts a slice instead of a vec.
 // this allows us to memory map
+//
+// If `pages` is given, only the selected rows of those pages are deserialized. `num_rows`
+// must then be the number of selected rows.
 pub(super) fn to_deserializer<'a>(
     columns: Vec<(&ColumnChunkMetaData, &'a [u8])>,
     field: Field,
     num_rows: usize,
     chunk_size: Option<usize>,
+    pages: Option<Vec<Vec<FilteredPage>>>,
 ) -> PolarsResult<ArrayIter<'a>> {
     let chunk_size = chunk_size.unwrap_or(usize::MAX).min(num_rows);
 
-    let (columns, types): (Vec<_>, Vec<_>) = columns
-        .into_iter()
-        .map(|(column_meta, chunk)| {
-            let pages = PageReader::new(
-                std::io::Cursor::new(chunk),
-                column_meta,
-                std::sync::Arc::new(|_, _| true),
-                vec![],
-                usize::MAX,
-            );
-            (
-                BasicDecompressor::new(pages, vec![]),
-                &column_meta.descriptor().descriptor.primitive_type,
-            )
-        })
-        .unzip();
+    let (columns, types): (Vec<_>, Vec<_>) = match pages {
+        Some(pages) => columns
+            .into_iter()
+            .zip(pages)
+            .map(|((column_meta, chunk), mut pages)| {
+                // the page offsets are relative to the start of the file
+                let mut meta: PageMetaData = column_meta.into();
+                pages
+                    .iter_mut()
+                    .for_each(|page| page.start -= meta.column_start);
+                meta.column_start = 0;
+                let pages = IndexedPageReader::new_with_page_meta(
+                    std::io::Cursor::new(chunk),
+                    meta,
+                    pages,
+                    vec![],
+                    vec![],
+                );
+                (
+                    BasicDecompressor::new(Box::new(pages) as Pages<'a>, vec![]),
+                    &column_meta.descriptor().descriptor.primitive_type,
+                )
+            })
+            .unzip(),
+        None => columns
+            .into_iter()
+            .map(|(column_meta, chunk)| {
+                let pages = PageReader::new(
+                    std::io::Cursor::new(chunk),
+                    column_meta,
+                    std::sync::Arc::new(|_, _| true),
+                    vec![],
+                    usize::MAX,
+                );
+                (
+                    BasicDecompressor::new(Box::new(pages) as Pages<'a>, vec![]),
+                    &column_meta.descriptor().descriptor.primitive_type,
+                )
+            })
+            .unzip(),
+    };
 
     column_iter_to_arrays(columns, types, field, Some(chunk_size), num_rows)
 }
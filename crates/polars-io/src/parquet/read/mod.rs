@@ -18,6 +18,7 @@
 mod async_impl;
 mod mmap;
 mod options;
+mod page_index;
 mod predicates;
 mod read_impl;
 mod reader;
@@ -28,4 +29,4 @@ pub use options::{ParallelStrategy, ParquetOptions};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
-pub use utils::materialize_empty_df;
+pub use utils::{materialize_empty_df, merge_evolved_schema};
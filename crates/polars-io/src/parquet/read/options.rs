@@ -7,6 +7,9 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Allow the files of a scan to have different schemas, see
+    /// [`ParquetReader::allow_missing_columns`](super::ParquetReader::allow_missing_columns).
+    pub allow_missing_columns: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -0,0 +1,294 @@
+//! Page level pruning with the column and offset indexes (the page index) of a row group.
+use std::io::Cursor;
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_parquet::parquet::indexes::{select_pages, FilteredPage, Interval};
+use polars_parquet::read::indexes::{
+    compute_page_row_intervals, has_indexes, read_columns_indexes, FieldPageStatistics,
+};
+use polars_parquet::read::{
+    get_field_columns, get_field_pages, read_pages_locations, RowGroupMetaData,
+};
+
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr, StatsEvaluator};
+
+/// Whether the pages of a field of this type can be pruned. This requires a single parquet
+/// column and a decoder that can read a selection of the rows of a page.
+fn supports_page_selection(data_type: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    matches!(
+        data_type.to_logical_type(),
+        D::Boolean
+            | D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Float32
+            | D::Float64
+            | D::Date32
+            | D::Date64
+            | D::Time32(_)
+            | D::Time64(_)
+            | D::Timestamp(_, _)
+            | D::Duration(_)
+            | D::Binary
+            | D::LargeBinary
+            | D::Utf8
+            | D::LargeUtf8
+            | D::BinaryView
+            | D::Utf8View
+    )
+}
+
+/// The rows of a row group that have to be read.
+#[derive(Debug)]
+pub(super) struct RowSelection {
+    /// The row intervals, sorted and non-overlapping.
+    pub(super) intervals: Vec<Interval>,
+    /// The number of selected rows.
+    pub(super) num_rows: usize,
+}
+
+impl RowSelection {
+    fn new(intervals: Vec<Interval>) -> Self {
+        let num_rows = intervals.iter().map(|i| i.length).sum();
+        Self {
+            intervals,
+            num_rows,
+        }
+    }
+
+    /// Returns the pages of the flat column of `field` that overlap with the selection.
+    pub(super) fn select_pages(
+        &self,
+        md: &RowGroupMetaData,
+        field: &ArrowField,
+        bytes: &[u8],
+    ) -> PolarsResult<Vec<Vec<FilteredPage>>> {
+        let columns = get_field_columns(md.columns(), &field.name)
+            .into_iter()
+            .cloned()
+            .collect::<Vec<_>>();
+        let locations = read_pages_locations(&mut Cursor::new(bytes), &columns)?;
+        locations
+            .iter()
+            .map(|locations| Ok(select_pages(&self.intervals, locations, md.num_rows())?))
+            .collect()
+    }
+
+    /// Creates the row index of the selected rows, where `offset` is the index of the
+    /// first row of the row group.
+    pub(super) fn row_index(&self, name: &str, offset: IdxSize) -> Series {
+        let mut ca = IdxCa::from_vec(
+            name,
+            self.intervals
+                .iter()
+                .flat_map(|i| {
+                    let start = i.start as IdxSize + offset;
+                    start..start + i.length as IdxSize
+                })
+                .collect(),
+        );
+        ca.set_sorted_flag(IsSorted::Ascending);
+        ca.into_series()
+    }
+}
+
+/// Returns whether pages of all `projection` fields can be pruned with the page index.
+pub(super) fn can_select_pages(
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    projection: &[usize],
+) -> bool {
+    let has_offset_index = md
+        .columns()
+        .iter()
+        .all(|column| column.column_chunk().offset_index_offset.is_some());
+
+    has_offset_index
+        && !projection.is_empty()
+        && projection.iter().all(|i| {
+            let field = &schema.fields[*i];
+            supports_page_selection(field.data_type())
+                && get_field_columns(md.columns(), &field.name).len() == 1
+        })
+}
+
+/// Uses the page index of the row group to compute the rows that have to be read, i.e.
+/// the rows of the pages whose statistics may match `predicate` and that are within the
+/// first `limit` rows.
+///
+/// Returns `None` if all rows have to be read.
+pub(super) fn select_rows(
+    predicate: Option<&dyn PhysicalIoExpr>,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+    limit: usize,
+) -> PolarsResult<Option<RowSelection>> {
+    let num_rows = md.num_rows();
+    let mut intervals = vec![Interval::new(0, limit.min(num_rows))];
+
+    if let Some(predicate) = predicate.and_then(|p| p.as_stats_evaluator()) {
+        if let Some(selected) = prune_pages(predicate, md, schema, bytes)? {
+            intervals = intersect(&intervals, &selected);
+        }
+    }
+
+    Ok(match intervals.as_slice() {
+        [interval] if interval.start == 0 && interval.length == num_rows => None,
+        _ => Some(RowSelection::new(intervals)),
+    })
+}
+
+/// Evaluates `predicate` on the statistics of each page. As pages of different columns do
+/// not share row boundaries, the predicate is evaluated for each run of rows that is covered
+/// by the same page in every column.
+///
+/// Returns the row intervals that may match, or `None` if no page statistics are available.
+fn prune_pages(
+    predicate: &dyn StatsEvaluator,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    bytes: &[u8],
+) -> PolarsResult<Option<Vec<Interval>>> {
+    if !has_indexes(md) {
+        return Ok(None);
+    }
+    let fields = schema
+        .fields
+        .iter()
+        .filter(|field| {
+            supports_page_selection(field.data_type())
+                && get_field_columns(md.columns(), &field.name).len() == 1
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+    if fields.is_empty() {
+        return Ok(None);
+    }
+
+    let num_rows = md.num_rows();
+    let mut reader = Cursor::new(bytes);
+    let locations = read_pages_locations(&mut reader, md.columns())?;
+    let page_stats = read_columns_indexes(&mut reader, md.columns(), &fields)?;
+
+    let mut pages = Vec::with_capacity(fields.len());
+    let mut stats = Vec::with_capacity(fields.len());
+    for (field, page_stats) in fields.iter().zip(page_stats) {
+        let FieldPageStatistics::Single(page_stats) = page_stats else {
+            unreachable!()
+        };
+        let [locations] = get_field_pages(md.columns(), &locations, &field.name)[..] else {
+            unreachable!()
+        };
+        let intervals = compute_page_row_intervals(locations, num_rows)?;
+        if intervals.is_empty() {
+            return Ok(None);
+        }
+        pages.push(intervals);
+        stats.push(ColumnStats::new(
+            field.into(),
+            Some(Series::try_from(("", page_stats.null_count.boxed()))?),
+            Some(Series::try_from(("", page_stats.min))?),
+            Some(Series::try_from(("", page_stats.max))?),
+        ));
+    }
+
+    let mut boundaries = pages
+        .iter()
+        .flatten()
+        .map(|page| page.start)
+        .collect::<Vec<_>>();
+    boundaries.push(num_rows);
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let schema = Arc::new(Schema::from_iter(fields.iter().map(Field::from)));
+    let mut current_page = vec![0; fields.len()];
+    let mut selected = vec![];
+
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let run_stats = stats
+            .iter()
+            .zip(&pages)
+            .zip(current_page.iter_mut())
+            .map(|((stats, pages), current)| {
+                while pages[*current].start + pages[*current].length <= start {
+                    *current += 1;
+                }
+                let slice = |s: Option<&Series>| s.map(|s| s.slice(*current as i64, 1));
+                ColumnStats::new(
+                    Field::new(stats.field_name(), stats.dtype().clone()),
+                    slice(stats.get_null_count_state()),
+                    slice(stats.get_min_state()),
+                    slice(stats.get_max_state()),
+                )
+            })
+            .collect();
+
+        let should_read = predicate.should_read(&BatchStats::new(schema.clone(), run_stats, None));
+        // a page may not have statistics of all columns
+        let should_read = match should_read {
+            Ok(should_read) => should_read,
+            Err(PolarsError::ColumnNotFound(_)) => true,
+            Err(e) => return Err(e),
+        };
+        if should_read {
+            match selected.last_mut() {
+                Some(Interval { start: s, length }) if *s + *length == start => {
+                    *length += end - start
+                },
+                _ => selected.push(Interval::new(start, end - start)),
+            }
+        }
+    }
+
+    Ok(Some(selected))
+}
+
+/// Intersects two sorted sets of non-overlapping intervals.
+fn intersect(left: &[Interval], right: &[Interval]) -> Vec<Interval> {
+    let mut out = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < left.len() && j < right.len() {
+        let (l, r) = (left[i], right[j]);
+        let start = l.start.max(r.start);
+        let end = (l.start + l.length).min(r.start + r.length);
+        if start < end {
+            out.push(Interval::new(start, end - start));
+        }
+        if l.start + l.length < r.start + r.length {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        let i = Interval::new;
+        assert_eq!(
+            intersect(&[i(0, 10)], &[i(2, 3), i(8, 5)]),
+            [i(2, 3), i(8, 2)]
+        );
+        assert_eq!(
+            intersect(&[i(0, 4), i(6, 4)], &[i(3, 4)]),
+            [i(3, 1), i(6, 1)]
+        );
+        assert_eq!(intersect(&[i(0, 4)], &[i(4, 4)]), []);
+        assert_eq!(intersect(&[i(0, 4)], &[]), []);
+    }
+}
@@ -1,9 +1,12 @@
+use std::io::Cursor;
+
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::*;
+use polars_parquet::parquet::bloom_filter::{self, hash_byte, hash_native, is_in_set};
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetaData;
+use polars_parquet::read::{get_field_columns, PhysicalType, RowGroupMetaData};
 
-use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+use crate::predicates::{BatchStats, BloomFilter, ColumnStats, PhysicalIoExpr};
 
 impl ColumnStats {
     fn from_arrow_stats(stats: Statistics, field: &ArrowField) -> Self {
@@ -16,16 +19,106 @@ impl ColumnStats {
     }
 }
 
+/// A split-block bloom filter of a parquet column chunk.
+#[derive(Debug)]
+struct ParquetBloomFilter {
+    bitset: Vec<u8>,
+    physical_type: PhysicalType,
+}
+
+impl BloomFilter for ParquetBloomFilter {
+    fn may_contain(&self, value: &AnyValue) -> bool {
+        use {AnyValue as A, PhysicalType as P};
+        // Values are hashed in the physical type they are stored as, see the
+        // casts in `polars_parquet::write::array_to_page_simple`.
+        let hash = match (self.physical_type, value) {
+            (P::Int32, A::Int8(v)) => hash_native(*v as i32),
+            (P::Int32, A::Int16(v)) => hash_native(*v as i32),
+            (P::Int32, A::Int32(v)) => hash_native(*v),
+            (P::Int32, A::UInt8(v)) => hash_native(*v as i32),
+            (P::Int32, A::UInt16(v)) => hash_native(*v as i32),
+            (P::Int32, A::UInt32(v)) => hash_native(*v as i32),
+            #[cfg(feature = "dtype-date")]
+            (P::Int32, A::Date(v)) => hash_native(*v),
+            (P::Int64, A::Int64(v)) => hash_native(*v),
+            (P::Int64, A::UInt64(v)) => hash_native(*v as i64),
+            #[cfg(feature = "dtype-datetime")]
+            (P::Int64, A::Datetime(v, _, _)) => hash_native(*v),
+            #[cfg(feature = "dtype-time")]
+            (P::Int64, A::Time(v)) => hash_native(*v),
+            // Floats are hashed by their bit pattern, so 0.0 must also look for -0.0, and NaNs
+            // can have many bit patterns.
+            (P::Float, A::Float32(v)) if v.is_nan() => return true,
+            (P::Float, A::Float32(v)) if *v == 0.0 => {
+                return is_in_set(&self.bitset, hash_native(0.0f32))
+                    || is_in_set(&self.bitset, hash_native(-0.0f32))
+            },
+            (P::Float, A::Float32(v)) => hash_native(*v),
+            (P::Double, A::Float64(v)) if v.is_nan() => return true,
+            (P::Double, A::Float64(v)) if *v == 0.0 => {
+                return is_in_set(&self.bitset, hash_native(0.0f64))
+                    || is_in_set(&self.bitset, hash_native(-0.0f64))
+            },
+            (P::Double, A::Float64(v)) => hash_native(*v),
+            (P::ByteArray, A::String(v)) => hash_byte(v),
+            (P::ByteArray, A::StringOwned(v)) => hash_byte(v.as_str()),
+            (P::ByteArray, A::Binary(v)) => hash_byte(v),
+            (P::ByteArray, A::BinaryOwned(v)) => hash_byte(v),
+            // We cannot prove absence for other types.
+            _ => return true,
+        };
+        is_in_set(&self.bitset, hash)
+    }
+}
+
+/// Reads the bloom filter of a flat column, if it was written.
+fn read_bloom_filter(
+    md: &RowGroupMetaData,
+    field: &ArrowField,
+    bytes: &[u8],
+) -> PolarsResult<Option<Arc<dyn BloomFilter>>> {
+    let columns = get_field_columns(md.columns(), &field.name);
+    let [column] = columns.as_slice() else {
+        return Ok(None);
+    };
+    if column.metadata().bloom_filter_offset.is_none() {
+        return Ok(None);
+    }
+
+    let mut bitset = vec![];
+    bloom_filter::read(column, &mut Cursor::new(bytes), &mut bitset)?;
+    if bitset.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(Arc::new(ParquetBloomFilter {
+        bitset,
+        physical_type: column.physical_type(),
+    })))
+}
+
 /// Collect the statistics in a column chunk.
+///
+/// If the bytes of the file are given, the bloom filters of the `bloom_filter_columns` are
+/// collected as well.
 pub(crate) fn collect_statistics(
     md: &RowGroupMetaData,
     schema: &ArrowSchema,
+    bytes: Option<&[u8]>,
+    bloom_filter_columns: &[Arc<str>],
 ) -> PolarsResult<Option<BatchStats>> {
     let mut stats = vec![];
 
     for field in schema.fields.iter() {
         let st = deserialize(field, md)?;
-        stats.push(ColumnStats::from_arrow_stats(st, field));
+        let is_live = bloom_filter_columns
+            .iter()
+            .any(|name| **name == *field.name);
+        let bloom_filter = match bytes {
+            Some(bytes) if is_live => read_bloom_filter(md, field, bytes)?,
+            _ => None,
+        };
+        stats.push(ColumnStats::from_arrow_stats(st, field).with_bloom_filter(bloom_filter));
     }
 
     Ok(if stats.is_empty() {
@@ -39,15 +132,19 @@ pub(crate) fn collect_statistics(
     })
 }
 
+/// Whether the row group may contain rows that match `predicate`, according to its
+/// statistics and, if the bytes of the (local) file are given, its bloom filters.
 pub(super) fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetaData,
     schema: &ArrowSchemaRef,
+    bytes: Option<&[u8]>,
 ) -> PolarsResult<bool> {
     if let Some(pred) = predicate {
-        if let Some(pred) = pred.as_stats_evaluator() {
-            if let Some(stats) = collect_statistics(md, schema)? {
-                let should_read = pred.should_read(&stats);
+        if let Some(stats_evaluator) = pred.as_stats_evaluator() {
+            let live_variables = pred.live_variables().unwrap_or_default();
+            if let Some(stats) = collect_statistics(md, schema, bytes, &live_variables)? {
+                let should_read = stats_evaluator.should_read(&stats);
                 // a parquet file may not have statistics of all columns
                 if matches!(should_read, Ok(false)) {
                     return Ok(false);
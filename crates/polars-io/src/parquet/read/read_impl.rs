@@ -13,6 +13,7 @@ use rayon::prelude::*;
 #[cfg(feature = "cloud")]
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
+use super::page_index::{can_select_pages, select_rows, RowSelection};
 use super::predicates::read_this_row_group;
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
@@ -56,6 +57,7 @@ fn column_idx_to_series(
     file_schema: &ArrowSchema,
     store: &mmap::ColumnStore,
     chunk_size: usize,
+    selection: Option<&RowSelection>,
 ) -> PolarsResult<Series> {
     let field = &file_schema.fields[column_i];
 
@@ -65,7 +67,29 @@ fn column_idx_to_series(
     }
 
     let columns = mmap_columns(store, md.columns(), &field.name);
-    let iter = mmap::to_deserializer(columns, field.clone(), remaining_rows, Some(chunk_size))?;
+
+    if let Some(selection) = selection {
+        // only a local store has a page selection
+        let bytes = store.file_bytes().unwrap();
+        let pages = selection.select_pages(md, field, bytes)?;
+        let iter = mmap::to_deserializer(
+            columns,
+            field.clone(),
+            selection.num_rows,
+            Some(chunk_size),
+            Some(pages),
+        )?;
+        // the statistics of the row group don't describe a selection of its rows
+        return array_iter_to_series(iter, field, None);
+    }
+
+    let iter = mmap::to_deserializer(
+        columns,
+        field.clone(),
+        remaining_rows,
+        Some(chunk_size),
+        None,
+    )?;
 
     let mut series = if remaining_rows < md.num_rows() {
         array_iter_to_series(iter, field, Some(remaining_rows))
@@ -188,6 +212,41 @@ pub(crate) fn materialize_hive_partitions(
     }
 }
 
+/// Uses the page index to select the rows of the row group that have to be read, see
+/// [`select_rows`]. This is only done for local files.
+fn select_pages_of_row_group(
+    store: &mmap::ColumnStore,
+    md: &RowGroupMetaData,
+    schema: &ArrowSchema,
+    projection: &[usize],
+    predicate: Option<&dyn PhysicalIoExpr>,
+    limit: usize,
+) -> PolarsResult<Option<RowSelection>> {
+    match store.file_bytes() {
+        Some(bytes) if can_select_pages(md, schema, projection) => {
+            select_rows(predicate, md, schema, bytes, limit)
+        },
+        _ => Ok(None),
+    }
+}
+
+fn with_row_index(
+    df: &mut DataFrame,
+    name: &str,
+    offset: IdxSize,
+    selection: Option<&RowSelection>,
+) {
+    match selection {
+        Some(selection) => unsafe {
+            df.get_columns_mut()
+                .insert(0, selection.row_index(name, offset))
+        },
+        None => {
+            df.with_row_index_mut(name, Some(offset));
+        },
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -262,18 +321,41 @@ fn rg_to_dfs_optionally_par_over_columns(
         let current_row_count = md.num_rows() as IdxSize;
 
         if use_statistics
-            && !read_this_row_group(predicate, &file_metadata.row_groups[rg_idx], schema)?
+            && !read_this_row_group(
+                predicate,
+                &file_metadata.row_groups[rg_idx],
+                schema,
+                store.file_bytes(),
+            )?
         {
             *previous_row_count += current_row_count;
             continue;
         }
+
+        let projection_height = (*remaining_rows).min(md.num_rows());
+        let selection = select_pages_of_row_group(
+            store,
+            md,
+            schema,
+            projection,
+            predicate.filter(|_| use_statistics),
+            projection_height,
+        )?;
+        if selection.as_ref().is_some_and(|s| s.num_rows == 0) {
+            *remaining_rows -= projection_height;
+            *previous_row_count += current_row_count;
+            if *remaining_rows == 0 {
+                break;
+            }
+            continue;
+        }
+
         // test we don't read the parquet file if this env var is set
         #[cfg(debug_assertions)]
         {
             assert!(std::env::var("POLARS_PANIC_IF_PARQUET_PARSED").is_err())
         }
 
-        let projection_height = (*remaining_rows).min(md.num_rows());
         let chunk_size = md.num_rows();
         let columns = if let ParallelStrategy::Columns = parallel {
             POOL.install(|| {
@@ -287,6 +369,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                             schema,
                             store,
                             chunk_size,
+                            selection.as_ref(),
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()
@@ -302,6 +385,7 @@ fn rg_to_dfs_optionally_par_over_columns(
                         schema,
                         store,
                         chunk_size,
+                        selection.as_ref(),
                     )
                 })
                 .collect::<PolarsResult<Vec<_>>>()?
@@ -311,14 +395,19 @@ fn rg_to_dfs_optionally_par_over_columns(
 
         let mut df = unsafe { DataFrame::new_no_checks(columns) };
         if let Some(rc) = &row_index {
-            df.with_row_index_mut(&rc.name, Some(*previous_row_count + rc.offset));
+            with_row_index(
+                &mut df,
+                &rc.name,
+                *previous_row_count + rc.offset,
+                selection.as_ref(),
+            );
         }
 
         materialize_hive_partitions(
             &mut df,
             schema.as_ref(),
             hive_partition_columns,
-            projection_height,
+            selection.as_ref().map_or(projection_height, |s| s.num_rows),
         );
         apply_predicate(&mut df, predicate, true)?;
 
@@ -376,10 +465,24 @@ fn rg_to_dfs_par_over_rg(
                             predicate,
                             &file_metadata.row_groups[rg_idx],
                             schema,
+                            store.file_bytes(),
                         )?
                 {
                     return Ok(None);
                 }
+
+                let selection = select_pages_of_row_group(
+                    store,
+                    md,
+                    schema,
+                    projection,
+                    predicate.filter(|_| use_statistics),
+                    projection_height,
+                )?;
+                if selection.as_ref().is_some_and(|s| s.num_rows == 0) {
+                    return Ok(None);
+                }
+
                 // test we don't read the parquet file if this env var is set
                 #[cfg(debug_assertions)]
                 {
@@ -397,6 +500,7 @@ fn rg_to_dfs_par_over_rg(
                             schema,
                             store,
                             chunk_size,
+                            selection.as_ref(),
                         )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
@@ -404,14 +508,19 @@ fn rg_to_dfs_par_over_rg(
                 let mut df = unsafe { DataFrame::new_no_checks(columns) };
 
                 if let Some(rc) = &row_index {
-                    df.with_row_index_mut(&rc.name, Some(row_count_start as IdxSize + rc.offset));
+                    with_row_index(
+                        &mut df,
+                        &rc.name,
+                        row_count_start as IdxSize + rc.offset,
+                        selection.as_ref(),
+                    );
                 }
 
                 materialize_hive_partitions(
                     &mut df,
                     schema.as_ref(),
                     hive_partition_columns,
-                    projection_height,
+                    selection.as_ref().map_or(projection_height, |s| s.num_rows),
                 );
                 apply_predicate(&mut df, predicate, false)?;
 
@@ -15,11 +15,12 @@ pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{read_parquet, FetchRowGroupsFromMmapReader};
 #[cfg(feature = "cloud")]
 use super::utils::materialize_empty_df;
+use super::utils::{conform_to_schema, evolved_projection, predicate_in_file};
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
 use crate::mmap::MmapBytesReader;
 use crate::parquet::metadata::FileMetaDataRef;
-use crate::predicates::PhysicalIoExpr;
+use crate::predicates::{apply_predicate, PhysicalIoExpr};
 use crate::prelude::*;
 use crate::RowIndex;
 
@@ -39,6 +40,7 @@ pub struct ParquetReader<R: Read + Seek> {
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     hive_partition_columns: Option<Vec<Series>>,
     use_statistics: bool,
+    allow_missing_columns: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -81,7 +83,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
     }
 
     /// Set the [`Schema`] if already known. This must be exactly the same as
-    /// the schema in the file itself.
+    /// the schema in the file itself, unless [`ParquetReader::allow_missing_columns`] is set.
     pub fn with_schema(mut self, schema: Option<ArrowSchemaRef>) -> Self {
         self.schema = schema;
         self
@@ -105,6 +107,14 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Allow the schema set with [`ParquetReader::with_schema`] to differ from the schema in the
+    /// file. Columns that are absent from the file are read as nulls and columns with a narrower
+    /// type in the file are upcast, e.g. `Int32` to `Int64` or `Date` to `Datetime`.
+    pub fn allow_missing_columns(mut self, toggle: bool) -> Self {
+        self.allow_missing_columns = toggle;
+        self
+    }
+
     /// Number of rows in the parquet file.
     pub fn num_rows(&mut self) -> PolarsResult<usize> {
         let metadata = self.get_metadata()?;
@@ -168,6 +178,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             use_statistics: true,
             hive_partition_columns: None,
+            allow_missing_columns: false,
         }
     }
 
@@ -184,6 +195,30 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.projection = Some(columns_to_projection(cols, schema.as_ref())?);
         }
 
+        let rechunk = self.rechunk;
+        let mut df = if self.allow_missing_columns {
+            let file_schema = read::infer_schema(&metadata)?;
+            if file_schema.fields != schema.fields {
+                self.finish_evolved(schema, Arc::new(file_schema), metadata)?
+            } else {
+                self.finish_impl(schema, metadata)?
+            }
+        } else {
+            self.finish_impl(schema, metadata)?
+        };
+        if rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+impl<R: MmapBytesReader> ParquetReader<R> {
+    fn finish_impl(
+        self,
+        schema: ArrowSchemaRef,
+        metadata: FileMetaDataRef,
+    ) -> PolarsResult<DataFrame> {
         read_parquet(
             self.reader,
             self.n_rows.unwrap_or(usize::MAX),
@@ -196,12 +231,45 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.use_statistics,
             self.hive_partition_columns.as_deref(),
         )
-        .map(|mut df| {
-            if self.rechunk {
-                df.as_single_chunk_par();
-            }
-            df
-        })
+    }
+
+    /// Reads a file whose schema differs from the `schema` of the scan.
+    fn finish_evolved(
+        mut self,
+        schema: ArrowSchemaRef,
+        file_schema: ArrowSchemaRef,
+        metadata: FileMetaDataRef,
+    ) -> PolarsResult<DataFrame> {
+        let projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..schema.len()).collect());
+        let (file_projection, conform) = evolved_projection(&projection, &schema, &file_schema)?;
+        self.projection = Some(file_projection);
+        if !conform {
+            return self.finish_impl(file_schema, metadata);
+        }
+
+        // A predicate that refers to columns that are absent from the file or have a different
+        // type in it is applied after the columns are conformed to the schema of the scan.
+        let predicate = match &self.predicate {
+            Some(predicate) if predicate_in_file(predicate.as_ref(), &schema, &file_schema) => None,
+            _ => self.predicate.take(),
+        };
+        let hive_partition_columns = self.hive_partition_columns.take();
+        let row_index = self.row_index.clone();
+        let height = self.n_rows.unwrap_or(usize::MAX).min(metadata.num_rows);
+        let df = self.finish_impl(file_schema, metadata)?;
+        let mut df = conform_to_schema(
+            df,
+            &projection,
+            &schema,
+            row_index.as_ref(),
+            hive_partition_columns.as_deref(),
+            height,
+        )?;
+        apply_predicate(&mut df, predicate.as_deref(), true)?;
+        Ok(df)
     }
 }
 
@@ -219,6 +287,7 @@ pub struct ParquetAsyncReader {
     hive_partition_columns: Option<Vec<Series>>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    allow_missing_columns: bool,
 }
 
 #[cfg(feature = "cloud")]
@@ -240,6 +309,7 @@ impl ParquetAsyncReader {
             hive_partition_columns: None,
             schema,
             parallel: Default::default(),
+            allow_missing_columns: false,
         })
     }
 
@@ -300,6 +370,13 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Allow the schema given to [`ParquetAsyncReader::from_uri`] to differ from the schema in the
+    /// file, see [`ParquetReader::allow_missing_columns`].
+    pub fn allow_missing_columns(mut self, toggle: bool) -> Self {
+        self.allow_missing_columns = toggle;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -336,6 +413,53 @@ impl ParquetAsyncReader {
     }
 
     pub async fn finish(mut self) -> PolarsResult<DataFrame> {
+        if !self.allow_missing_columns {
+            return self.finish_impl().await;
+        }
+
+        let metadata = self.get_metadata().await?.clone();
+        let schema = self.schema().await?;
+        let file_schema = polars_parquet::arrow::read::infer_schema(&metadata)?;
+        if file_schema.fields == schema.fields {
+            return self.finish_impl().await;
+        }
+
+        // Reads a file whose schema differs from the `schema` of the scan.
+        let projection = self
+            .projection
+            .take()
+            .unwrap_or_else(|| (0..schema.len()).collect());
+        let (file_projection, conform) = evolved_projection(&projection, &schema, &file_schema)?;
+        self.projection = Some(file_projection);
+        if !conform {
+            self.schema = Some(Arc::new(file_schema));
+            return self.finish_impl().await;
+        }
+
+        // A predicate that refers to columns that are absent from the file or have a different
+        // type in it is applied after the columns are conformed to the schema of the scan.
+        let predicate = match &self.predicate {
+            Some(predicate) if predicate_in_file(predicate.as_ref(), &schema, &file_schema) => None,
+            _ => self.predicate.take(),
+        };
+        self.schema = Some(Arc::new(file_schema));
+        let hive_partition_columns = self.hive_partition_columns.take();
+        let row_index = self.row_index.clone();
+        let height = self.n_rows.unwrap_or(usize::MAX).min(metadata.num_rows);
+        let df = self.finish_impl().await?;
+        let mut df = conform_to_schema(
+            df,
+            &projection,
+            &schema,
+            row_index.as_ref(),
+            hive_partition_columns.as_deref(),
+            height,
+        )?;
+        apply_predicate(&mut df, predicate.as_deref(), true)?;
+        Ok(df)
+    }
+
+    async fn finish_impl(mut self) -> PolarsResult<DataFrame> {
         let rechunk = self.rechunk;
         let metadata = self.get_metadata().await?.clone();
         let reader_schema = self.schema().await?;
@@ -1,8 +1,9 @@
 use std::borrow::Cow;
 
-use polars_core::prelude::{ArrowSchema, DataFrame, Series, IDX_DTYPE};
+use polars_core::prelude::*;
 
 use super::read_impl::materialize_hive_partitions;
+use crate::predicates::PhysicalIoExpr;
 use crate::utils::apply_projection;
 use crate::RowIndex;
 
@@ -28,3 +29,176 @@ pub fn materialize_empty_df(
 
     df
 }
+
+/// Returns the type a column takes on if its type changes from `left` in one file to `right`
+/// in another file of a scan. Only upcasts that don't lose information are allowed.
+fn evolve_dtype(left: &ArrowDataType, right: &ArrowDataType) -> Option<ArrowDataType> {
+    use ArrowDataType as D;
+
+    fn int_rank(dtype: &ArrowDataType) -> Option<(bool, u8)> {
+        Some(match dtype {
+            D::Int8 => (true, 0),
+            D::Int16 => (true, 1),
+            D::Int32 => (true, 2),
+            D::Int64 => (true, 3),
+            D::UInt8 => (false, 0),
+            D::UInt16 => (false, 1),
+            D::UInt32 => (false, 2),
+            D::UInt64 => (false, 3),
+            _ => return None,
+        })
+    }
+
+    if left == right {
+        return Some(left.clone());
+    }
+    match (left, right) {
+        (D::Float32, D::Float64) | (D::Float64, D::Float32) => Some(D::Float64),
+        (D::Date32, D::Timestamp(tu, tz)) | (D::Timestamp(tu, tz), D::Date32) => {
+            Some(D::Timestamp(*tu, tz.clone()))
+        },
+        _ => match (int_rank(left), int_rank(right)) {
+            (Some((l_signed, l_rank)), Some((r_signed, r_rank))) if l_signed == r_signed => {
+                Some(if l_rank > r_rank { left } else { right }.clone())
+            },
+            _ => None,
+        },
+    }
+}
+
+/// Merges the schema of another file of a scan into `schema`. The columns are matched by name:
+/// new columns are appended and columns whose type changed take on the wider type.
+pub fn merge_evolved_schema(schema: &mut ArrowSchema, other: &ArrowSchema) -> PolarsResult<()> {
+    for field in &other.fields {
+        match schema.index_of(&field.name) {
+            Some(i) => {
+                let current = &mut schema.fields[i];
+                current.data_type = evolve_dtype(current.data_type(), field.data_type())
+                    .ok_or_else(|| {
+                        polars_err!(
+                            SchemaMismatch: "column '{}' has incompatible types {:?} and {:?} in the scanned files",
+                            field.name, current.data_type(), field.data_type()
+                        )
+                    })?;
+                current.is_nullable |= field.is_nullable;
+            },
+            None => {
+                let mut field = field.clone();
+                field.is_nullable = true;
+                schema.fields.push(field);
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Maps the `projection` into the `target` schema of a scan onto the schema of the file. Columns
+/// absent from the file are skipped.
+///
+/// The returned flag is set if some projected columns are absent or have a different type in
+/// the file, in which case the columns that were read have to go through [`conform_to_schema`].
+pub(super) fn evolved_projection(
+    projection: &[usize],
+    target: &ArrowSchema,
+    file_schema: &ArrowSchema,
+) -> PolarsResult<(Vec<usize>, bool)> {
+    let mut file_projection = Vec::with_capacity(projection.len());
+    let mut conform = false;
+    for &i in projection {
+        let field = &target.fields[i];
+        let Some(file_i) = file_schema.index_of(&field.name) else {
+            conform = true;
+            continue;
+        };
+        let file_dtype = file_schema.fields[file_i].data_type();
+        if file_dtype != field.data_type() {
+            polars_ensure!(
+                evolve_dtype(file_dtype, field.data_type()).as_ref() == Some(field.data_type()),
+                SchemaMismatch: "cannot read column '{}' of type {:?} as {:?}",
+                field.name, file_dtype, field.data_type()
+            );
+            conform = true;
+        }
+        file_projection.push(file_i);
+    }
+    Ok((file_projection, conform))
+}
+
+/// Returns whether the `predicate` only reads columns that are in the file with the type they
+/// have in the `target` schema. Such a predicate can be applied while reading the file, so that
+/// row groups are still pruned by their statistics.
+pub(super) fn predicate_in_file(
+    predicate: &dyn PhysicalIoExpr,
+    target: &ArrowSchema,
+    file_schema: &ArrowSchema,
+) -> bool {
+    let Some(live_variables) = predicate.live_variables() else {
+        return false;
+    };
+    live_variables.iter().all(|name| {
+        let file_field = file_schema.index_of(name).map(|i| &file_schema.fields[i]);
+        let field = target.index_of(name).map(|i| &target.fields[i]);
+        matches!((file_field, field), (Some(l), Some(r)) if l.data_type() == r.data_type())
+    })
+}
+
+/// Conforms a `df` that was read from a file with [`evolved_projection`] to the columns of the
+/// `projection` into the `target` schema. Absent columns are filled with nulls and the other
+/// columns are cast to the type in the `target` schema.
+pub(super) fn conform_to_schema(
+    df: DataFrame,
+    projection: &[usize],
+    target: &ArrowSchema,
+    row_index: Option<&RowIndex>,
+    hive_partition_columns: Option<&[Series]>,
+    height: usize,
+) -> PolarsResult<DataFrame> {
+    let mut columns = Vec::with_capacity(projection.len() + 1);
+    if let Some(row_index) = row_index {
+        columns.push(df.column(&row_index.name)?.clone());
+    }
+    for &i in projection {
+        let field = &target.fields[i];
+        let dtype = DataType::from_arrow(field.data_type(), true);
+        let s = match df.column(&field.name) {
+            Ok(s) => s.cast(&dtype)?,
+            Err(_) => Series::full_null(&field.name, height, &dtype),
+        };
+        columns.push(s);
+    }
+    let mut df = unsafe { DataFrame::new_no_checks(columns) };
+    materialize_hive_partitions(&mut df, target, hive_partition_columns, height);
+    Ok(df)
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::datatypes::{ArrowDataType as D, Field, TimeUnit};
+
+    use super::*;
+
+    #[test]
+    fn test_merge_evolved_schema() {
+        let mut schema = ArrowSchema::from(vec![
+            Field::new("a", D::Int32, false),
+            Field::new("b", D::Date32, true),
+        ]);
+        let other = ArrowSchema::from(vec![
+            Field::new("c", D::Float32, false),
+            Field::new("b", D::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("a", D::Int64, true),
+        ]);
+        merge_evolved_schema(&mut schema, &other).unwrap();
+        let expected = ArrowSchema::from(vec![
+            Field::new("a", D::Int64, true),
+            Field::new("b", D::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("c", D::Float32, true),
+        ]);
+        assert_eq!(schema, expected);
+
+        let other = ArrowSchema::from(vec![Field::new("a", D::UInt64, false)]);
+        assert!(merge_evolved_schema(&mut schema, &other).is_err());
+        let other = ArrowSchema::from(vec![Field::new("c", D::Utf8View, false)]);
+        assert!(merge_evolved_schema(&mut schema, &other).is_err());
+    }
+}
@@ -6,18 +6,25 @@ use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    array_to_columns, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions,
+    array_to_bloom_filter, array_to_columns, BloomFilterOptions, CompressedPage, Compressor,
+    DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator, FileWriter, Page,
+    ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
 };
 use rayon::prelude::*;
 
+/// The bloom filter bitsets of a row group, one per leaf column.
+pub type RowGroupBloomFilters = Vec<Option<Vec<u8>>>;
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
     pub(super) writer: Mutex<FileWriter<W>>,
     pub(super) parquet_schema: SchemaDescriptor,
     pub(super) encodings: Vec<Vec<Encoding>>,
+    /// Bloom filter settings per field.
+    pub(super) bloom_filters: Vec<Option<BloomFilterOptions>>,
+    /// Number of parquet leaf columns per field.
+    pub(super) n_leaves: Vec<usize>,
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
 }
@@ -26,11 +33,17 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<
+        Item = PolarsResult<(
+            RowGroupIterColumns<'static, PolarsError>,
+            Option<RowGroupBloomFilters>,
+        )>,
+    > + 'a {
         let rb_iter = df.iter_chunks(true, false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
+                let bloom_filters = self.create_bloom_filters(&batch);
                 let row_group = create_eager_serializer(
                     batch,
                     self.parquet_schema.fields(),
@@ -38,11 +51,33 @@ impl<W: Write> BatchedWriter<W> {
                     self.options,
                 );
 
-                Some(row_group)
+                Some(row_group.map(|row_group| (row_group, bloom_filters)))
             },
         })
     }
 
+    /// Builds the bloom filters of a row group, or `None` if no bloom filters are requested.
+    fn create_bloom_filters(&self, batch: &RecordBatch) -> Option<RowGroupBloomFilters> {
+        if self.bloom_filters.iter().all(Option::is_none) {
+            return None;
+        }
+
+        let mut bitsets = Vec::with_capacity(self.n_leaves.iter().sum());
+        for ((array, options), n_leaves) in batch
+            .columns()
+            .iter()
+            .zip(&self.bloom_filters)
+            .zip(&self.n_leaves)
+        {
+            match options {
+                // Only flat columns support bloom filters, so these have a single leaf.
+                Some(options) => bitsets.push(array_to_bloom_filter(array.as_ref(), options)),
+                None => bitsets.extend(std::iter::repeat(None).take(*n_leaves)),
+            }
+        }
+        Some(bitsets)
+    }
+
     /// Write a batch to the parquet writer.
     ///
     /// # Panics
@@ -57,8 +92,11 @@ impl<W: Write> BatchedWriter<W> {
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
-        for group in row_group_iter {
+        for (group, batch) in row_group_iter {
             writer.write(group?)?;
+            if let Some(bloom_filters) = self.create_bloom_filters(&batch) {
+                writer.write_bloom_filters(&bloom_filters)?;
+            }
         }
         Ok(())
     }
@@ -69,12 +107,18 @@ impl<W: Write> BatchedWriter<W> {
 
     pub fn write_row_groups(
         &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
+        rgs: Vec<(
+            RowGroupIterColumns<'static, PolarsError>,
+            Option<RowGroupBloomFilters>,
+        )>,
     ) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
+        for (group, bloom_filters) in rgs {
             writer.write(group)?;
+            if let Some(bloom_filters) = bloom_filters {
+                writer.write_bloom_filters(&bloom_filters)?;
+            }
         }
         Ok(())
     }
@@ -94,15 +138,25 @@ fn prepare_rg_iter<'a>(
     encodings: &'a [Vec<Encoding>],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<
+    Item = (
+        PolarsResult<RowGroupIterColumns<'static, PolarsError>>,
+        RecordBatch,
+    ),
+> + 'a {
     let rb_iter = df.iter_chunks(true, false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         _ => {
-            let row_group =
-                create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
+            let row_group = create_serializer(
+                batch.clone(),
+                parquet_schema.fields(),
+                encodings,
+                options,
+                parallel,
+            );
 
-            Some(row_group)
+            Some((row_group, batch))
         },
     })
 }
@@ -1,18 +1,20 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
-    StatisticsOptions, ZstdLevel as ZstdLevelParquet,
+    BloomFilterOptions, BrotliLevel as BrotliLevelParquet, CompressionOptions,
+    GzipLevel as GzipLevelParquet, StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParquetWriteOptions {
     /// Data page compression
     pub compression: ParquetCompression,
     /// Compute and write column statistics.
     pub statistics: StatisticsOptions,
+    /// Columns for which a bloom filter is written per row group.
+    pub bloom_filter_columns: Vec<(String, BloomFilterOptions)>,
     /// If `None` will be all written to a single row group.
     pub row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
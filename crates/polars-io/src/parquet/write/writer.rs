@@ -4,8 +4,8 @@ use std::sync::Mutex;
 use arrow::datatypes::PhysicalType;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    to_parquet_schema, transverse, CompressionOptions, Encoding, FileWriter, StatisticsOptions,
-    Version, WriteOptions,
+    supports_bloom_filter, to_parquet_leaves, to_parquet_schema, transverse, BloomFilterOptions,
+    CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
 };
 
 use super::batched_writer::BatchedWriter;
@@ -21,6 +21,8 @@ pub struct ParquetWriter<W> {
     compression: CompressionOptions,
     /// Compute and write column statistics.
     statistics: StatisticsOptions,
+    /// Columns for which a bloom filter is written per row group.
+    bloom_filter_columns: Vec<(String, BloomFilterOptions)>,
     /// if `None` will be 512^2 rows
     row_group_size: Option<usize>,
     /// if `None` will be 1024^2 bytes
@@ -42,6 +44,7 @@ where
             writer,
             compression: ParquetCompression::default().into(),
             statistics: StatisticsOptions::default(),
+            bloom_filter_columns: vec![],
             row_group_size: None,
             data_page_size: None,
             parallel: true,
@@ -63,6 +66,18 @@ where
         self
     }
 
+    /// Write a split-block bloom filter per row group for the given columns.
+    ///
+    /// Readers can use these filters to skip row groups on equality and `is_in` predicates
+    /// that min/max statistics cannot rule out, e.g. on high-cardinality id columns.
+    pub fn with_bloom_filter_columns(
+        mut self,
+        bloom_filter_columns: Vec<(String, BloomFilterOptions)>,
+    ) -> Self {
+        self.bloom_filter_columns = bloom_filter_columns;
+        self
+    }
+
     /// Set the row group size (in number of rows) during writing. This can reduce memory pressure and improve
     /// writing performance.
     pub fn with_row_group_size(mut self, size: Option<usize>) -> Self {
@@ -86,6 +101,12 @@ where
         let schema = schema_to_arrow_checked(schema, true, "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
+        let bloom_filters = get_bloom_filters(&schema, &self.bloom_filter_columns)?;
+        let n_leaves = parquet_schema
+            .fields()
+            .iter()
+            .map(|type_| to_parquet_leaves(type_.clone()).len())
+            .collect();
         let options = self.materialize_options();
         let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
 
@@ -93,6 +114,8 @@ where
             writer,
             parquet_schema,
             encodings,
+            bloom_filters,
+            n_leaves,
             options,
             parallel: self.parallel,
         })
@@ -116,6 +139,30 @@ where
     }
 }
 
+/// Resolve the bloom filter settings per field of the schema.
+fn get_bloom_filters(
+    schema: &ArrowSchema,
+    bloom_filter_columns: &[(String, BloomFilterOptions)],
+) -> PolarsResult<Vec<Option<BloomFilterOptions>>> {
+    let mut bloom_filters = vec![None; schema.fields.len()];
+    for (name, options) in bloom_filter_columns {
+        let i = schema.try_index_of(name)?;
+        let field = &schema.fields[i];
+        polars_ensure!(
+            supports_bloom_filter(&field.data_type),
+            InvalidOperation: "bloom filters are not supported for column '{}' of type {:?}",
+            name, field.data_type
+        );
+        polars_ensure!(
+            options.fpp > 0.0 && options.fpp < 1.0,
+            InvalidOperation: "bloom filter false positive probability must be in (0, 1), got {}",
+            options.fpp
+        );
+        bloom_filters[i] = Some(*options);
+    }
+    Ok(bloom_filters)
+}
+
 fn get_encodings(schema: &ArrowSchema) -> Vec<Vec<Encoding>> {
     schema
         .fields
@@ -1,9 +1,11 @@
 //! Functionality for writing a DataFrame partitioned into multiple files.
 
+use std::borrow::Cow;
 use std::fs::File;
 use std::io::BufWriter;
 use std::path::{Path, PathBuf};
 
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::POOL;
@@ -59,7 +61,7 @@ where
     }
 
     fn write_partition_df(&self, partition_df: &mut DataFrame, i: usize) -> PolarsResult<()> {
-        let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df);
+        let mut path = resolve_partition_dir(&self.rootdir, &self.by, partition_df)?;
         std::fs::create_dir_all(&path)?;
 
         path.push(format!(
@@ -112,8 +114,47 @@ where
     }
 }
 
+/// The directory value of a null key in a Hive partitioned dataset.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Characters that are percent-encoded in Hive partition values, as they would either be
+/// interpreted as path separators or can't be used in file names on some platforms.
+const HIVE_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b'/')
+    .add(b'\\')
+    .add(b'=')
+    .add(b'%')
+    .add(b':')
+    .add(b'*')
+    .add(b'?')
+    .add(b'"')
+    .add(b'<')
+    .add(b'>')
+    .add(b'|');
+
+/// Formats a value as the value of a `key=value` Hive partition directory.
+pub fn hive_partition_value(value: &AnyValue) -> String {
+    let value = match value {
+        AnyValue::Null => return HIVE_DEFAULT_PARTITION.to_string(),
+        AnyValue::String(v) => Cow::Borrowed(*v),
+        AnyValue::StringOwned(v) => Cow::Borrowed(v.as_str()),
+        v => Cow::Owned(v.to_string()),
+    };
+    if value.is_empty() {
+        return HIVE_DEFAULT_PARTITION.to_string();
+    }
+    utf8_percent_encode(&value, HIVE_VALUE_ENCODE_SET).to_string()
+}
+
+/// Returns the `key=value` directory of a partition in `rootdir`. The value of each key
+/// is taken from the first row of `partition_df`.
+///
 /// `partition_df` must be created in the same way as `partition_by`.
-fn resolve_partition_dir<I, S>(rootdir: &Path, by: I, partition_df: &DataFrame) -> PathBuf
+pub fn resolve_partition_dir<I, S>(
+    rootdir: &Path,
+    by: I,
+    partition_df: &DataFrame,
+) -> PolarsResult<PathBuf>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<str>,
@@ -122,8 +163,31 @@ where
     path.push(resolve_homedir(rootdir));
 
     for key in by.into_iter() {
-        let value = partition_df[key.as_ref()].get(0).unwrap().to_string();
-        path.push(format!("{}={}", key.as_ref(), value))
+        let value = partition_df.column(key.as_ref())?.get(0)?;
+        path.push(format!("{}={}", key.as_ref(), hive_partition_value(&value)))
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hive_partition_value() {
+        assert_eq!(hive_partition_value(&AnyValue::Int32(-1)), "-1");
+        assert_eq!(hive_partition_value(&AnyValue::String("a b")), "a b");
+        assert_eq!(
+            hive_partition_value(&AnyValue::String("a/b=c%")),
+            "a%2Fb%3Dc%25"
+        );
+        assert_eq!(
+            hive_partition_value(&AnyValue::String("")),
+            HIVE_DEFAULT_PARTITION
+        );
+        assert_eq!(
+            hive_partition_value(&AnyValue::Null),
+            HIVE_DEFAULT_PARTITION
+        );
     }
-    path
 }
@@ -13,13 +13,33 @@ pub trait PhysicalIoExpr: Send + Sync {
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         None
     }
+
+    /// Returns the names of the columns the predicate reads, if known. Readers only load
+    /// bloom filters for these columns.
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        None
+    }
 }
 
 pub trait StatsEvaluator {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool>;
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+/// A probabilistic membership test of the values in a column, e.g. a parquet bloom filter.
+///
+/// It may report values that are not in the column, but never misses values that are.
+pub trait BloomFilter: std::fmt::Debug + Send + Sync {
+    /// Returns `false` if `value` definitely does not occur in the column.
+    fn may_contain(&self, value: &AnyValue) -> bool;
+}
+
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf"
+))]
 pub fn apply_predicate(
     df: &mut DataFrame,
     predicate: Option<&dyn PhysicalIoExpr>,
@@ -44,6 +64,7 @@ pub fn apply_predicate(
 /// - Null count
 /// - Minimum value
 /// - Maximum value
+/// - Bloom filter, if one was written
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ColumnStats {
@@ -52,6 +73,8 @@ pub struct ColumnStats {
     null_count: Option<Series>,
     min_value: Option<Series>,
     max_value: Option<Series>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bloom_filter: Option<Arc<dyn BloomFilter>>,
 }
 
 impl ColumnStats {
@@ -67,9 +90,16 @@ impl ColumnStats {
             null_count,
             min_value,
             max_value,
+            bloom_filter: None,
         }
     }
 
+    /// Attaches a [`BloomFilter`] of the values in the column.
+    pub fn with_bloom_filter(mut self, bloom_filter: Option<Arc<dyn BloomFilter>>) -> Self {
+        self.bloom_filter = bloom_filter;
+        self
+    }
+
     /// Constructs a new [`ColumnStats`] with only the [`Field`] information and no statistics.
     pub fn from_field(field: Field) -> Self {
         Self {
@@ -77,6 +107,7 @@ impl ColumnStats {
             null_count: None,
             min_value: None,
             max_value: None,
+            bloom_filter: None,
         }
     }
 
@@ -88,6 +119,7 @@ impl ColumnStats {
             null_count: None,
             min_value: Some(s.clone()),
             max_value: Some(s),
+            bloom_filter: None,
         }
     }
 
@@ -115,6 +147,32 @@ impl ColumnStats {
         self.max_value.as_ref()
     }
 
+    /// Returns the [`BloomFilter`] of the column, if any.
+    pub fn get_bloom_filter(&self) -> Option<&dyn BloomFilter> {
+        self.bloom_filter.as_deref()
+    }
+
+    /// Returns whether any of the `values` may occur in the column.
+    ///
+    /// This can only return `false` if the column has a [`BloomFilter`] that rules out all
+    /// `values`.
+    pub fn may_contain_any(&self, values: &Series) -> bool {
+        let Some(bloom_filter) = self.get_bloom_filter() else {
+            return true;
+        };
+        // Nulls may match under `is_in` semantics and are not part of the filter.
+        if values.null_count() > 0 {
+            return true;
+        }
+        let Ok(values) = values.strict_cast(self.dtype()) else {
+            return true;
+        };
+        values
+            .rechunk()
+            .iter()
+            .any(|value| bloom_filter.may_contain(&value))
+    }
+
     /// Returns the null count of the column.
     pub fn null_count(&self) -> Option<usize> {
         match self.dtype() {
@@ -1,4 +1,6 @@
 pub use crate::cloud;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use crate::compression::CompressionCodec;
 #[cfg(feature = "csv")]
 pub use crate::csv::{read::*, write::*};
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
@@ -105,7 +105,11 @@ pub(crate) fn finish_reader<R: ArrowReader>(
                     Series::try_from((fld.name.as_str(), new_empty_array(fld.data_type.clone())))
                 })
                 .collect::<PolarsResult<_>>()?;
-            DataFrame::new(empty_cols)?
+            let mut df = DataFrame::new(empty_cols)?;
+            if let Some(rc) = &row_index {
+                df.with_row_index_mut(&rc.name, Some(rc.offset));
+            }
+            df
         } else {
             // If there are any rows, accumulate them into a df
             accumulate_dataframes_vertical_unchecked(parsed_dfs)
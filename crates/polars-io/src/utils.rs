@@ -103,6 +103,7 @@ pub(crate) fn apply_projection(schema: &ArrowSchema, projection: &[usize]) -> Ar
     feature = "ipc",
     feature = "ipc_streaming",
     feature = "avro",
+    feature = "orc",
     feature = "parquet"
 ))]
 pub(crate) fn columns_to_projection(
@@ -0,0 +1,191 @@
+//! # Read and write Excel (xlsx) files.
+//!
+//! Only the Office Open XML spreadsheet format (`.xlsx`) is supported. Cells are typed: numbers
+//! with a date or time number format are read as temporal values and temporal columns are
+//! written with such a format.
+mod read;
+mod write;
+
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use polars_core::prelude::*;
+pub use read::*;
+pub use write::*;
+
+/// The serial number of 1970-01-01 in the 1900 date system.
+const UNIX_EPOCH_SERIAL: f64 = 25569.0;
+/// The serial number of 1970-01-01 in the 1904 date system.
+const UNIX_EPOCH_SERIAL_1904: f64 = 24107.0;
+const MILLISECONDS_IN_DAY: f64 = 86_400_000.0;
+
+/// The maximum number of rows in a worksheet.
+const MAX_ROWS: usize = 1_048_576;
+/// The maximum number of columns in a worksheet.
+const MAX_COLUMNS: usize = 16_384;
+
+/// A rectangular range of cells, e.g. `B2:D10`. Rows and columns are zero-based and inclusive.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct CellRange {
+    first_row: usize,
+    first_col: usize,
+    last_row: usize,
+    last_col: usize,
+}
+
+impl CellRange {
+    fn parse(range: &str) -> PolarsResult<Self> {
+        let err =
+            || polars_err!(ComputeError: "invalid cell range '{}'; expected e.g. 'A1:C10'", range);
+        let (start, end) = range.split_once(':').ok_or_else(err)?;
+        let (first_row, first_col) = parse_cell_ref(start).ok_or_else(err)?;
+        let (last_row, last_col) = parse_cell_ref(end).ok_or_else(err)?;
+        polars_ensure!(
+            first_row <= last_row && first_col <= last_col,
+            ComputeError: "invalid cell range '{}'; the first cell must be the top left one", range
+        );
+        Ok(Self {
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+        })
+    }
+}
+
+/// Parses a cell reference like `B3` (or `$B$3`) into a zero-based `(row, column)`.
+fn parse_cell_ref(cell: &str) -> Option<(usize, usize)> {
+    let cell = cell.trim();
+    let split = cell.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = cell.split_at(split);
+    let letters = letters.strip_suffix('$').unwrap_or(letters);
+    let letters = letters.strip_prefix('$').unwrap_or(letters);
+    let col = parse_column_name(letters)?;
+    let row = digits.parse::<usize>().ok()?.checked_sub(1)?;
+    Some((row, col))
+}
+
+/// Parses column letters like `AB` into a zero-based column index.
+fn parse_column_name(letters: &str) -> Option<usize> {
+    if letters.is_empty() || letters.len() > 3 {
+        return None;
+    }
+    letters
+        .bytes()
+        .try_fold(0usize, |acc, b| {
+            b.is_ascii_alphabetic()
+                .then(|| acc * 26 + (b.to_ascii_uppercase() - b'A') as usize + 1)
+        })?
+        .checked_sub(1)
+}
+
+/// The letters of a zero-based column index, e.g. `27` -> `AB`.
+fn column_name(mut col: usize) -> String {
+    let mut letters = vec![];
+    loop {
+        letters.push(b'A' + (col % 26) as u8);
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// Whether `text` starts with an escaped character like `_x000D_`.
+fn is_ooxml_escape(text: &str) -> bool {
+    let b = text.as_bytes();
+    b.len() >= 7
+        && b.starts_with(b"_x")
+        && b[6] == b'_'
+        && b[2..6].iter().all(u8::is_ascii_hexdigit)
+}
+
+/// Escapes text for XML. Characters that XML does not allow are written as `_xHHHH_`, and the
+/// underscore of text that looks like such an escape as `_x005F_`.
+fn escape_xml(text: &str) -> Cow<str> {
+    let needs_escape = |i: usize, c: char| {
+        (c.is_control() && !matches!(c, '\t' | '\n' | '\r'))
+            || (c == '_' && is_ooxml_escape(&text[i..]))
+    };
+    if !text.char_indices().any(|(i, c)| needs_escape(i, c)) {
+        return quick_xml::escape::escape(text);
+    }
+    let mut out = String::with_capacity(text.len() + 8);
+    for (i, c) in text.char_indices() {
+        if needs_escape(i, c) {
+            write!(out, "_x{:04X}_", c as u32).unwrap();
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(quick_xml::escape::escape(&out).into_owned())
+}
+
+/// Replaces the `_xHHHH_` escapes of [`escape_xml`] by the characters they stand for.
+fn unescape_ooxml(text: Cow<str>) -> Cow<str> {
+    if !text.contains("_x") {
+        return text;
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text.as_ref();
+    while let Some(i) = rest.find("_x") {
+        out.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let escaped = is_ooxml_escape(rest)
+            .then(|| {
+                u32::from_str_radix(&rest[2..6], 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            })
+            .flatten();
+        match escaped {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[7..];
+            },
+            None => {
+                out.push_str("_x");
+                rest = &rest[2..];
+            },
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cell_refs() {
+        for (col, name) in [(0, "A"), (25, "Z"), (26, "AA"), (701, "ZZ"), (16383, "XFD")] {
+            assert_eq!(column_name(col), name);
+            assert_eq!(parse_column_name(name), Some(col));
+        }
+        assert_eq!(parse_cell_ref("$B$3"), Some((2, 1)));
+        assert_eq!(parse_cell_ref("B0"), None);
+        assert_eq!(
+            CellRange::parse("B2:AA10").unwrap(),
+            CellRange {
+                first_row: 1,
+                first_col: 1,
+                last_row: 9,
+                last_col: 26,
+            }
+        );
+        assert!(CellRange::parse("C2:B10").is_err());
+    }
+
+    #[test]
+    fn test_ooxml_escapes() {
+        let text = "a\u{1}<b>_x0041_x";
+        assert_eq!(escape_xml(text), "a_x0001_&lt;b&gt;_x005F_x0041_x");
+        assert_eq!(
+            unescape_ooxml(Cow::Borrowed("a_x0001_<b>_x005F_x0041_x")),
+            text
+        );
+    }
+}
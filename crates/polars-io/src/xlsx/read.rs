@@ -0,0 +1,1013 @@
+use std::borrow::Cow;
+use std::io::{BufRead, BufReader, Read, Seek};
+
+use ::zip::ZipArchive;
+use chrono::{NaiveTime, Timelike};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_time::chunkedarray::string::infer::{
+    infer_pattern_single, DatetimeInfer, TryFromWithUnit,
+};
+use polars_time::prelude::string::Pattern;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use super::{
+    parse_cell_ref, unescape_ooxml, CellRange, MILLISECONDS_IN_DAY, UNIX_EPOCH_SERIAL,
+    UNIX_EPOCH_SERIAL_1904,
+};
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::prelude::*;
+
+/// How a number is displayed, as determined by the number format of its cell.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TemporalKind {
+    Date,
+    Datetime,
+    Time,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Cell {
+    Empty,
+    Bool(bool),
+    Number(f64),
+    /// A number with a date or time number format.
+    Temporal(f64, TemporalKind),
+    String(String),
+}
+
+impl Cell {
+    fn infer_dtype(&self, try_parse_dates: bool) -> Option<DataType> {
+        Some(match self {
+            Cell::Empty => return None,
+            Cell::Bool(_) => DataType::Boolean,
+            Cell::Number(v) if is_integral(*v) => DataType::Int64,
+            Cell::Number(_) => DataType::Float64,
+            Cell::Temporal(v, TemporalKind::Date) if v.fract() == 0.0 => DataType::Date,
+            Cell::Temporal(_, TemporalKind::Date | TemporalKind::Datetime) => {
+                DataType::Datetime(TimeUnit::Microseconds, None)
+            },
+            Cell::Temporal(_, TemporalKind::Time) => DataType::Time,
+            Cell::String(s) if s.is_empty() => return None,
+            Cell::String(s) => infer_field_schema(s, try_parse_dates, false),
+        })
+    }
+}
+
+fn is_integral(v: f64) -> bool {
+    v.fract() == 0.0 && v.abs() < (1i64 << 53) as f64
+}
+
+/// A row index with the column index and value of the non-empty cells of the row.
+type Row = (usize, Vec<(usize, Cell)>);
+
+/// The parts of a workbook that are shared by its worksheets.
+struct Workbook<R: Read + Seek> {
+    archive: ZipArchive<R>,
+    /// The name and path of every sheet, in workbook order.
+    sheets: Vec<(String, String)>,
+    cell_parser: CellParser,
+    epoch: f64,
+}
+
+/// Converts the values of cells into [`Cell`]s.
+struct CellParser {
+    shared_strings: Vec<String>,
+    /// The temporal kind of every cell format, if any.
+    cell_formats: Vec<Option<TemporalKind>>,
+}
+
+impl<R: Read + Seek> Workbook<R> {
+    fn try_new(reader: R) -> PolarsResult<Self> {
+        let mut archive = ZipArchive::new(reader).map_err(to_compute_err)?;
+
+        let mut epoch = UNIX_EPOCH_SERIAL;
+        let mut sheets = vec![];
+        for_each_element(xml_reader(&mut archive, "xl/workbook.xml")?, |e| {
+            match e.local_name().as_ref() {
+                b"workbookPr" => {
+                    if matches!(attribute(e, b"date1904")?.as_deref(), Some("1" | "true")) {
+                        epoch = UNIX_EPOCH_SERIAL_1904;
+                    }
+                },
+                b"sheet" => {
+                    let name = attribute(e, b"name")?.unwrap_or_default();
+                    let id = attribute(e, b"id")?.unwrap_or_default();
+                    sheets.push((name, id));
+                },
+                _ => {},
+            }
+            Ok(())
+        })?;
+
+        let mut targets = PlHashMap::new();
+        for_each_element(
+            xml_reader(&mut archive, "xl/_rels/workbook.xml.rels")?,
+            |e| {
+                if e.local_name().as_ref() == b"Relationship" {
+                    if let (Some(id), Some(target)) =
+                        (attribute(e, b"Id")?, attribute(e, b"Target")?)
+                    {
+                        targets.insert(id, target);
+                    }
+                }
+                Ok(())
+            },
+        )?;
+        let sheets = sheets
+            .into_iter()
+            .map(|(name, id)| {
+                let target = targets.get(&id).ok_or_else(|| {
+                    polars_err!(ComputeError: "invalid xlsx file: sheet '{}' has no worksheet", name)
+                })?;
+                let path = match target.strip_prefix('/') {
+                    Some(path) => path.to_string(),
+                    None => format!("xl/{target}"),
+                };
+                Ok((name, path))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let shared_strings = if archive.by_name("xl/sharedStrings.xml").is_ok() {
+            read_shared_strings(xml_reader(&mut archive, "xl/sharedStrings.xml")?)?
+        } else {
+            vec![]
+        };
+        let cell_formats = if archive.by_name("xl/styles.xml").is_ok() {
+            read_cell_formats(xml_reader(&mut archive, "xl/styles.xml")?)?
+        } else {
+            vec![]
+        };
+
+        Ok(Self {
+            archive,
+            sheets,
+            cell_parser: CellParser {
+                shared_strings,
+                cell_formats,
+            },
+            epoch,
+        })
+    }
+
+    /// Reads the rows of a sheet until `last_row`.
+    fn read_rows(
+        &mut self,
+        sheet_name: Option<&str>,
+        last_row: Option<usize>,
+    ) -> PolarsResult<Vec<Row>> {
+        let path = match sheet_name {
+            Some(name) => self
+                .sheets
+                .iter()
+                .find(|(sheet, _)| sheet == name)
+                .ok_or_else(|| polars_err!(ComputeError: "sheet '{}' not found", name))?,
+            None => self
+                .sheets
+                .first()
+                .ok_or_else(|| polars_err!(ComputeError: "invalid xlsx file: no sheets"))?,
+        }
+        .1
+        .clone();
+        let mut reader = xml_reader(&mut self.archive, &path)?;
+
+        let mut rows: Vec<Row> = vec![];
+        let mut row = 0;
+        let mut col = 0;
+        // The column, type, format and value of the cell that is being read.
+        let mut cell: Option<(usize, Option<String>, Option<usize>, String)> = None;
+        let mut in_value = false;
+        let mut phonetic_depth = 0;
+        let mut buf = vec![];
+        loop {
+            match reader.read_event_into(&mut buf).map_err(to_compute_err)? {
+                Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"row" => {
+                    row = match attribute(&e, b"r")? {
+                        Some(r) => r
+                            .parse::<usize>()
+                            .map_err(to_compute_err)?
+                            .saturating_sub(1),
+                        None => rows.last().map_or(0, |(last, _)| last + 1),
+                    };
+                    if last_row.is_some_and(|last| row > last) {
+                        break;
+                    }
+                    rows.push((row, vec![]));
+                    col = 0;
+                },
+                Event::Start(e) if e.local_name().as_ref() == b"c" => {
+                    if let Some(r) = attribute(&e, b"r")? {
+                        col = parse_cell_ref(&r)
+                            .ok_or_else(
+                                || polars_err!(ComputeError: "invalid cell reference '{}'", r),
+                            )?
+                            .1;
+                    }
+                    let format = attribute(&e, b"s")?
+                        .map(|s| s.parse::<usize>().map_err(to_compute_err))
+                        .transpose()?;
+                    cell = Some((col, attribute(&e, b"t")?, format, String::new()));
+                    col += 1;
+                },
+                Event::Empty(e) if e.local_name().as_ref() == b"c" => {
+                    if let Some(r) = attribute(&e, b"r")? {
+                        col = parse_cell_ref(&r).map_or(col, |(_, col)| col);
+                    }
+                    col += 1;
+                },
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"v" | b"t" if cell.is_some() => in_value = true,
+                    b"rPh" => phonetic_depth += 1,
+                    _ => {},
+                },
+                Event::Text(e) if in_value && phonetic_depth == 0 => {
+                    if let Some((.., value)) = &mut cell {
+                        value.push_str(&e.unescape().map_err(to_compute_err)?);
+                    }
+                },
+                Event::CData(e) if in_value && phonetic_depth == 0 => {
+                    if let Some((.., value)) = &mut cell {
+                        value.push_str(&String::from_utf8_lossy(&e));
+                    }
+                },
+                Event::End(e) => match e.local_name().as_ref() {
+                    b"v" | b"t" => in_value = false,
+                    b"rPh" => phonetic_depth -= 1,
+                    b"c" => {
+                        if let Some((col, cell_type, format, value)) = cell.take() {
+                            let value =
+                                self.cell_parser
+                                    .parse(cell_type.as_deref(), format, value)?;
+                            if value != Cell::Empty {
+                                if rows.is_empty() {
+                                    rows.push((row, vec![]));
+                                }
+                                rows.last_mut().unwrap().1.push((col, value));
+                            }
+                        }
+                    },
+                    b"sheetData" => break,
+                    _ => {},
+                },
+                Event::Eof => break,
+                _ => {},
+            }
+            buf.clear();
+        }
+        Ok(rows)
+    }
+}
+
+impl CellParser {
+    fn parse(
+        &self,
+        cell_type: Option<&str>,
+        format: Option<usize>,
+        value: String,
+    ) -> PolarsResult<Cell> {
+        let parse_number = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| polars_err!(ComputeError: "invalid numeric cell value '{}'", value))
+        };
+        Ok(match cell_type {
+            _ if value.is_empty() && cell_type != Some("inlineStr") => Cell::Empty,
+            // Errors like `#DIV/0!` are read as missing values.
+            Some("e") => Cell::Empty,
+            Some("b") => Cell::Bool(value.trim() == "1"),
+            Some("s") => {
+                let index = parse_number(&value)? as usize;
+                let s = self.shared_strings.get(index).ok_or_else(
+                    || polars_err!(ComputeError: "invalid xlsx file: shared string {} not found", index),
+                )?;
+                Cell::String(s.clone())
+            },
+            Some("str" | "inlineStr" | "d") => {
+                Cell::String(unescape_ooxml(Cow::Owned(value)).into_owned())
+            },
+            _ => {
+                let v = parse_number(&value)?;
+                match format.and_then(|i| self.cell_formats.get(i).copied().flatten()) {
+                    Some(kind) => Cell::Temporal(v, kind),
+                    None => Cell::Number(v),
+                }
+            },
+        })
+    }
+}
+
+fn xml_reader<'a, R: Read + Seek>(
+    archive: &'a mut ZipArchive<R>,
+    path: &str,
+) -> PolarsResult<Reader<BufReader<::zip::read::ZipFile<'a>>>> {
+    let file = archive
+        .by_name(path)
+        .map_err(|_| polars_err!(ComputeError: "invalid xlsx file: '{}' is missing", path))?;
+    Ok(Reader::from_reader(BufReader::new(file)))
+}
+
+/// Calls `f` for the start of every element.
+fn for_each_element<B: BufRead>(
+    mut reader: Reader<B>,
+    mut f: impl FnMut(&BytesStart) -> PolarsResult<()>,
+) -> PolarsResult<()> {
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(to_compute_err)? {
+            Event::Start(e) | Event::Empty(e) => f(&e)?,
+            Event::Eof => return Ok(()),
+            _ => {},
+        }
+        buf.clear();
+    }
+}
+
+/// The unescaped value of the attribute with the local name `name`.
+fn attribute(e: &BytesStart, name: &[u8]) -> PolarsResult<Option<String>> {
+    for attr in e.attributes() {
+        let attr = attr.map_err(to_compute_err)?;
+        if attr.key.local_name().as_ref() == name {
+            return Ok(Some(
+                attr.unescape_value().map_err(to_compute_err)?.into_owned(),
+            ));
+        }
+    }
+    Ok(None)
+}
+
+fn read_shared_strings<B: BufRead>(mut reader: Reader<B>) -> PolarsResult<Vec<String>> {
+    let mut strings = vec![];
+    let mut current = String::new();
+    let mut in_text = false;
+    let mut phonetic_depth = 0;
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(to_compute_err)? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"t" => in_text = true,
+                b"rPh" => phonetic_depth += 1,
+                _ => {},
+            },
+            Event::Text(e) if in_text && phonetic_depth == 0 => {
+                current.push_str(&e.unescape().map_err(to_compute_err)?)
+            },
+            Event::CData(e) if in_text && phonetic_depth == 0 => {
+                current.push_str(&String::from_utf8_lossy(&e))
+            },
+            Event::End(e) => match e.local_name().as_ref() {
+                b"t" => in_text = false,
+                b"rPh" => phonetic_depth -= 1,
+                b"si" => strings
+                    .push(unescape_ooxml(Cow::Owned(std::mem::take(&mut current))).into_owned()),
+                _ => {},
+            },
+            Event::Empty(e) if e.local_name().as_ref() == b"si" => strings.push(String::new()),
+            Event::Eof => return Ok(strings),
+            _ => {},
+        }
+        buf.clear();
+    }
+}
+
+/// Determines which cell formats display numbers as dates or times.
+fn read_cell_formats<B: BufRead>(mut reader: Reader<B>) -> PolarsResult<Vec<Option<TemporalKind>>> {
+    let mut custom = PlHashMap::new();
+    let mut format_ids = vec![];
+    let mut in_cell_formats = false;
+    let mut buf = vec![];
+    loop {
+        match reader.read_event_into(&mut buf).map_err(to_compute_err)? {
+            Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                b"numFmt" => {
+                    if let (Some(id), Some(code)) =
+                        (attribute(&e, b"numFmtId")?, attribute(&e, b"formatCode")?)
+                    {
+                        custom.insert(id, code);
+                    }
+                },
+                b"cellXfs" => in_cell_formats = true,
+                b"xf" if in_cell_formats => {
+                    format_ids.push(attribute(&e, b"numFmtId")?.unwrap_or_default())
+                },
+                _ => {},
+            },
+            Event::End(e) if e.local_name().as_ref() == b"cellXfs" => in_cell_formats = false,
+            Event::Eof => break,
+            _ => {},
+        }
+        buf.clear();
+    }
+
+    Ok(format_ids
+        .iter()
+        .map(|id| match custom.get(id) {
+            Some(code) => classify_format_code(code),
+            None => builtin_format_kind(id.parse().unwrap_or(0)),
+        })
+        .collect())
+}
+
+fn builtin_format_kind(id: u32) -> Option<TemporalKind> {
+    match id {
+        14..=17 | 27..=36 | 50..=58 => Some(TemporalKind::Date),
+        22 => Some(TemporalKind::Datetime),
+        18..=21 | 45 | 47 => Some(TemporalKind::Time),
+        _ => None,
+    }
+}
+
+/// Determines whether a number format code displays a date and/or time.
+fn classify_format_code(code: &str) -> Option<TemporalKind> {
+    let (mut date, mut time, mut month_or_minute) = (false, false, false);
+    let mut chars = code.chars();
+    while let Some(c) = chars.next() {
+        match c.to_ascii_lowercase() {
+            // Only the first section applies to positive numbers.
+            ';' => break,
+            '"' => {
+                chars.by_ref().find(|&c| c == '"');
+            },
+            // Colors, conditions and locales. Elapsed time (`[h]`) is a duration.
+            '[' => {
+                let section = chars.by_ref().take_while(|&c| c != ']').collect::<String>();
+                if !section.is_empty() && section.chars().all(|c| "hmsHMS".contains(c)) {
+                    return None;
+                }
+            },
+            '\\' | '_' | '*' => {
+                chars.next();
+            },
+            'y' | 'd' => date = true,
+            'h' | 's' => time = true,
+            'm' => month_or_minute = true,
+            _ => {},
+        }
+    }
+    match (date || (month_or_minute && !time), time) {
+        (true, true) => Some(TemporalKind::Datetime),
+        (true, false) => Some(TemporalKind::Date),
+        (false, true) => Some(TemporalKind::Time),
+        (false, false) => None,
+    }
+}
+
+fn read_header(cells: impl Iterator<Item = Option<Cell>>, width: usize, epoch: f64) -> Vec<String> {
+    let mut names = PlHashMap::with_capacity(width);
+    cells
+        .take(width)
+        .enumerate()
+        .map(|(i, cell)| {
+            let name = match cell {
+                Some(Cell::Empty) | None => format!("column_{}", i + 1),
+                Some(cell) => cell_to_string(&cell, epoch),
+            };
+            let count = names.entry(name.clone()).or_insert(0usize);
+            *count += 1;
+            if *count > 1 {
+                format!("{}_duplicated_{}", name, *count - 2)
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+fn cell_to_string(cell: &Cell, epoch: f64) -> String {
+    match cell {
+        Cell::Empty => String::new(),
+        Cell::Bool(v) => v.to_string(),
+        Cell::Number(v) if is_integral(*v) => (*v as i64).to_string(),
+        Cell::Number(v) => v.to_string(),
+        Cell::Temporal(v, kind) => {
+            let ms = serial_to_ms(*v, epoch);
+            match kind {
+                TemporalKind::Date if v.fract() == 0.0 => {
+                    chrono::DateTime::from_timestamp_millis(ms)
+                        .map_or_else(String::new, |dt| dt.date_naive().to_string())
+                },
+                TemporalKind::Time => time_from_ms(ms).to_string(),
+                _ => chrono::DateTime::from_timestamp_millis(ms)
+                    .map_or_else(String::new, |dt| dt.naive_utc().to_string()),
+            }
+        },
+        Cell::String(s) => s.clone(),
+    }
+}
+
+/// Milliseconds since the unix epoch. Excel stores datetimes with millisecond precision.
+fn serial_to_ms(serial: f64, epoch: f64) -> i64 {
+    ((serial - epoch) * MILLISECONDS_IN_DAY).round() as i64
+}
+
+fn time_from_ms(ms: i64) -> NaiveTime {
+    let ms = ms.rem_euclid(MILLISECONDS_IN_DAY as i64) as u32;
+    NaiveTime::from_num_seconds_from_midnight_opt(ms / 1000, (ms % 1000) * 1_000_000).unwrap()
+}
+
+/// The data type a column of `dtype` is built with before it is cast.
+fn build_dtype(dtype: &DataType) -> DataType {
+    match dtype {
+        DataType::Boolean
+        | DataType::Int64
+        | DataType::Float64
+        | DataType::Date
+        | DataType::Datetime(_, _)
+        | DataType::Time
+        | DataType::String => dtype.clone(),
+        dt if dt.is_integer() => DataType::Int64,
+        dt if dt.is_float() => DataType::Float64,
+        _ => DataType::String,
+    }
+}
+
+fn build_column(name: &str, cells: &[Cell], dtype: &DataType, epoch: f64) -> PolarsResult<Series> {
+    let number = |cell: &Cell| match cell {
+        Cell::Number(v) | Cell::Temporal(v, _) => Some(*v),
+        Cell::Bool(v) => Some(*v as u8 as f64),
+        Cell::String(s) => s.trim().parse::<f64>().ok(),
+        Cell::Empty => None,
+    };
+    let s = match build_dtype(dtype) {
+        DataType::Boolean => BooleanChunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| match cell {
+                Cell::Bool(v) => Some(*v),
+                Cell::Number(v) => Some(*v != 0.0),
+                Cell::String(s) if s.eq_ignore_ascii_case("true") => Some(true),
+                Cell::String(s) if s.eq_ignore_ascii_case("false") => Some(false),
+                _ => None,
+            }),
+        )
+        .into_series(),
+        DataType::Int64 => Int64Chunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| match cell {
+                Cell::String(s) => s.trim().parse::<i64>().ok(),
+                cell => number(cell).filter(|v| is_integral(*v)).map(|v| v as i64),
+            }),
+        )
+        .into_series(),
+        DataType::Float64 => {
+            Float64Chunked::from_iter_options(name, cells.iter().map(number)).into_series()
+        },
+        DataType::Date => {
+            let mut parser = DateParser::<Int32Type>::default();
+            Int32Chunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| match cell {
+                    Cell::String(s) => parser.parse(s, None),
+                    cell => number(cell).map(|v| {
+                        serial_to_ms(v, epoch).div_euclid(MILLISECONDS_IN_DAY as i64) as i32
+                    }),
+                }),
+            )
+            .into_date()
+            .into_series()
+        },
+        DataType::Datetime(tu, tz) => {
+            let mut parser = DateParser::<Int64Type>::default();
+            let scale = match tu {
+                TimeUnit::Nanoseconds => 1_000_000,
+                TimeUnit::Microseconds => 1_000,
+                TimeUnit::Milliseconds => 1,
+            };
+            Int64Chunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| match cell {
+                    Cell::String(s) => parser.parse(s, Some(tu)),
+                    cell => number(cell).map(|v| serial_to_ms(v, epoch) * scale),
+                }),
+            )
+            .into_datetime(tu, tz)
+            .into_series()
+        },
+        DataType::Time => Int64Chunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| {
+                let time = match cell {
+                    Cell::String(s) => ["%H:%M:%S%.f", "%H:%M"]
+                        .iter()
+                        .find_map(|fmt| NaiveTime::parse_from_str(s.trim(), fmt).ok())?,
+                    cell => time_from_ms(serial_to_ms(number(cell)?, epoch)),
+                };
+                Some(
+                    time.num_seconds_from_midnight() as i64 * 1_000_000_000
+                        + time.nanosecond() as i64,
+                )
+            }),
+        )
+        .into_time()
+        .into_series(),
+        _ => StringChunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| match cell {
+                Cell::Empty => None,
+                cell => Some(cell_to_string(cell, epoch)),
+            }),
+        )
+        .into_series(),
+    };
+    if s.dtype() == dtype {
+        Ok(s)
+    } else {
+        s.cast(dtype)
+    }
+}
+
+/// Parses dates and datetimes of text cells with the patterns CSV schema inference detects.
+struct DateParser<T: PolarsNumericType> {
+    compiled: Option<DatetimeInfer<T>>,
+}
+
+impl<T: PolarsNumericType> Default for DateParser<T> {
+    fn default() -> Self {
+        Self { compiled: None }
+    }
+}
+
+impl<T: PolarsNumericType> DateParser<T>
+where
+    DatetimeInfer<T>: TryFromWithUnit<Pattern>,
+{
+    fn parse(&mut self, s: &str, tu: Option<TimeUnit>) -> Option<T::Native> {
+        if let Some(parsed) = self.compiled.as_mut().and_then(|infer| infer.parse(s)) {
+            return Some(parsed);
+        }
+        let mut infer = DatetimeInfer::try_from_with_unit(infer_pattern_single(s)?, tu).ok()?;
+        let parsed = infer.parse(s);
+        self.compiled = Some(infer);
+        parsed
+    }
+}
+
+/// Read an Excel (xlsx) worksheet into a [`DataFrame`].
+///
+/// The data types of the columns are inferred like those of CSV files, except that cells that
+/// are typed in the file keep their type. Numbers with a date or time number format are read as
+/// [`DataType::Date`], [`DataType::Datetime`] or [`DataType::Time`].
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::xlsx::XlsxReader;
+/// use polars_io::SerReader;
+/// use std::fs::File;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("report.xlsx").expect("file not found");
+///
+///     XlsxReader::new(file)
+///         .with_sheet_name(Some("sales".into()))
+///         .with_range(Some("B2:E100".into()))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct XlsxReader<R> {
+    reader: R,
+    sheet_name: Option<String>,
+    range: Option<String>,
+    has_header: bool,
+    schema: Option<SchemaRef>,
+    schema_overwrite: Option<SchemaRef>,
+    infer_schema_length: Option<usize>,
+    try_parse_dates: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<String>>,
+}
+
+impl<R: Read + Seek> XlsxReader<R> {
+    /// Read the sheet with this name. Defaults to the first sheet.
+    pub fn with_sheet_name(mut self, sheet_name: Option<String>) -> Self {
+        self.sheet_name = sheet_name;
+        self
+    }
+
+    /// Only read the cells in this range, e.g. `"B2:E100"`. Defaults to the smallest range that
+    /// holds all non-empty cells of the sheet.
+    pub fn with_range(mut self, range: Option<String>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Set whether the first row of the range holds the column names. Defaults to `true`.
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Set the schema of the columns instead of inferring it.
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// Overwrite the inferred data types of these columns.
+    pub fn with_schema_overwrite(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Set the number of rows used to infer the schema. `None` uses all rows and `Some(0)`
+    /// reads all columns as [`DataType::String`]. Defaults to `Some(100)`.
+    pub fn infer_schema_length(mut self, num_rows: Option<usize>) -> Self {
+        self.infer_schema_length = num_rows;
+        self
+    }
+
+    /// Infer date and datetime columns from text cells.
+    pub fn with_try_parse_dates(mut self, toggle: bool) -> Self {
+        self.try_parse_dates = toggle;
+        self
+    }
+
+    /// Stop reading at `num_rows` rows.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// The names of the sheets of the workbook.
+    pub fn sheet_names(&mut self) -> PolarsResult<Vec<String>> {
+        self.reader.rewind()?;
+        let workbook = Workbook::try_new(&mut self.reader)?;
+        Ok(workbook.sheets.into_iter().map(|(name, _)| name).collect())
+    }
+}
+
+impl<R: Read + Seek> SerReader<R> for XlsxReader<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            sheet_name: None,
+            range: None,
+            has_header: true,
+            schema: None,
+            schema_overwrite: None,
+            infer_schema_length: Some(100),
+            try_parse_dates: false,
+            n_rows: None,
+            columns: None,
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        self.reader.rewind()?;
+        let mut workbook = Workbook::try_new(&mut self.reader)?;
+        let range = self.range.as_deref().map(CellRange::parse).transpose()?;
+        let epoch = workbook.epoch;
+
+        // With an explicit range we can stop early; otherwise the range starts at the first
+        // non-empty row, which we don't know in advance.
+        let last_row = range.map(|range| {
+            let n_rows = self
+                .n_rows
+                .map_or(usize::MAX, |n| n.saturating_add(self.has_header as usize));
+            range
+                .last_row
+                .min(range.first_row.saturating_add(n_rows).saturating_sub(1))
+        });
+        let rows = workbook.read_rows(self.sheet_name.as_deref(), last_row)?;
+
+        let (first_row, first_col, last_row, last_col) = match range {
+            Some(range) => (
+                range.first_row,
+                range.first_col,
+                range.last_row,
+                range.last_col,
+            ),
+            None => {
+                let non_empty = rows.iter().filter(|(_, cells)| !cells.is_empty());
+                let first_row = non_empty.clone().next().map_or(0, |(row, _)| *row);
+                let last_row = non_empty.clone().last().map_or(0, |(row, _)| *row);
+                let first_col = non_empty
+                    .clone()
+                    .flat_map(|(_, cells)| cells.iter().map(|(col, _)| *col))
+                    .min();
+                let last_col = non_empty
+                    .flat_map(|(_, cells)| cells.iter().map(|(col, _)| *col))
+                    .max();
+                match (first_col, last_col) {
+                    (Some(first_col), Some(last_col)) => (first_row, first_col, last_row, last_col),
+                    // An empty sheet.
+                    _ => (0, 0, 0, 0),
+                }
+            },
+        };
+        let width = last_col - first_col + 1;
+
+        // Lay the cells out column by column.
+        let data_start = first_row + self.has_header as usize;
+        let mut height = (last_row + 1).saturating_sub(data_start);
+        if let Some(n_rows) = self.n_rows {
+            height = height.min(n_rows);
+        }
+        let mut header = vec![None; width];
+        let mut columns = vec![vec![Cell::Empty; height]; width];
+        for (row, cells) in rows {
+            if row < first_row || row >= data_start + height {
+                continue;
+            }
+            for (col, cell) in cells {
+                if col < first_col || col > last_col {
+                    continue;
+                }
+                if self.has_header && row == first_row {
+                    header[col - first_col] = Some(cell);
+                } else {
+                    columns[col - first_col][row - data_start] = cell;
+                }
+            }
+        }
+        let is_empty_sheet = rows_are_empty(&header, &columns);
+
+        let names = if self.has_header {
+            read_header(header.into_iter(), width, epoch)
+        } else {
+            (0..width).map(|i| format!("column_{}", i + 1)).collect()
+        };
+
+        let schema = match &self.schema {
+            Some(schema) => {
+                polars_ensure!(
+                    schema.len() == width || is_empty_sheet,
+                    ComputeError: "the schema has {} columns, but the range has {}", schema.len(), width
+                );
+                schema.clone()
+            },
+            None => {
+                let infer_length = self.infer_schema_length.unwrap_or(usize::MAX);
+                let mut schema = names
+                    .iter()
+                    .zip(&columns)
+                    .map(|(name, cells)| {
+                        let mut possibilities = cells
+                            .iter()
+                            .take(infer_length)
+                            .filter_map(|cell| cell.infer_dtype(self.try_parse_dates))
+                            .collect::<PlHashSet<_>>();
+                        // Dates and datetimes are both serial numbers in Excel; a date is a
+                        // datetime at midnight.
+                        if possibilities.contains(&DataType::Datetime(TimeUnit::Microseconds, None))
+                        {
+                            possibilities.remove(&DataType::Date);
+                        }
+                        Field::new(name, finish_infer_field_schema(&possibilities))
+                    })
+                    .collect::<Schema>();
+                if let Some(overwrite) = &self.schema_overwrite {
+                    for (name, dtype) in overwrite.iter() {
+                        schema.set_dtype(name, dtype.clone());
+                    }
+                }
+                Arc::new(schema)
+            },
+        };
+        if is_empty_sheet && self.schema.is_none() {
+            return Ok(DataFrame::empty());
+        }
+
+        let projection = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| schema.try_index_of(name))
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..schema.len()).collect(),
+        };
+        let series = projection
+            .into_iter()
+            .map(|i| {
+                let (name, dtype) = schema.get_at_index(i).unwrap();
+                match columns.get(i) {
+                    Some(cells) => build_column(name, cells, dtype, epoch),
+                    None => Ok(Series::full_null(name, 0, dtype)),
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        DataFrame::new(series)
+    }
+}
+
+fn rows_are_empty(header: &[Option<Cell>], columns: &[Vec<Cell>]) -> bool {
+    header.iter().all(Option::is_none)
+        && columns
+            .iter()
+            .all(|cells| cells.iter().all(|cell| *cell == Cell::Empty))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::{Cursor, Write};
+
+    use ::zip::write::FileOptions;
+    use ::zip::ZipWriter;
+
+    use super::*;
+
+    /// A workbook as Excel writes it, with features that [`super::super::XlsxWriter`] doesn't
+    /// use.
+    fn excel_workbook() -> Cursor<Vec<u8>> {
+        let files = [
+            (
+                "xl/workbook.xml",
+                r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><workbookPr date1904="1"/><sheets><sheet name="first" sheetId="1" r:id="rId1"/><sheet name="Q&amp;A" sheetId="2" r:id="rId2"/></sheets></workbook>"#,
+            ),
+            (
+                "xl/_rels/workbook.xml.rels",
+                r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Target="/xl/worksheets/sheet2.xml"/></Relationships>"#,
+            ),
+            (
+                "xl/sharedStrings.xml",
+                r#"<sst><si><t>name</t></si><si><r><t>wh</t></r><r><t xml:space="preserve">en </t></r><rPh><t>X</t></rPh></si><si><t>a</t></si></sst>"#,
+            ),
+            (
+                "xl/styles.xml",
+                r#"<styleSheet><numFmts><numFmt numFmtId="164" formatCode="d/m/yyyy\ h:mm;@"/><numFmt numFmtId="165" formatCode="[Red]0.00"/></numFmts><cellStyleXfs><xf numFmtId="14"/></cellStyleXfs><cellXfs><xf numFmtId="0"/><xf numFmtId="14"/><xf numFmtId="164"/><xf numFmtId="165"/></cellXfs></styleSheet>"#,
+            ),
+            (
+                "xl/worksheets/sheet1.xml",
+                r#"<worksheet><sheetData/></worksheet>"#,
+            ),
+            (
+                "xl/worksheets/sheet2.xml",
+                r#"<worksheet><sheetData><row r="3"><c r="B3" t="s"><v>0</v></c><c t="s"><v>1</v></c><c r="E3" t="inlineStr"><is><t>value</t></is></c></row><row r="4"><c r="B4" t="s"><v>2</v></c><c r="C4" s="1"><v>0</v></c><c r="E4" s="3"><v>1.5</v></c></row><row r="5"><c r="B5" t="str"><f>A1</f><v>b_x000D_</v></c><c r="C5" s="2"><v>1.25</v></c><c r="E5" t="e"><v>#DIV/0!</v></c></row></sheetData></worksheet>"#,
+            ),
+        ];
+        let mut zip = ZipWriter::new(Cursor::new(vec![]));
+        for (path, content) in files {
+            zip.start_file(path, FileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        let mut buf = zip.finish().unwrap();
+        buf.set_position(0);
+        buf
+    }
+
+    #[test]
+    fn test_read_excel_workbook() -> PolarsResult<()> {
+        let mut reader = XlsxReader::new(excel_workbook());
+        assert_eq!(reader.sheet_names()?, ["first", "Q&A"]);
+        assert!(reader.finish()?.is_empty());
+
+        let df = XlsxReader::new(excel_workbook())
+            .with_sheet_name(Some("Q&A".into()))
+            .finish()?;
+        // 1904-01-01 and 1904-01-02 06:00 in the 1904 date system.
+        let when = Series::new(
+            "when ",
+            [
+                -24107i64 * 86_400_000_000,
+                -24106 * 86_400_000_000 + 21_600_000_000,
+            ],
+        )
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+        let expected = DataFrame::new(vec![
+            Series::new("name", ["a", "b\r"]),
+            when.clone(),
+            Series::new("column_3", [None::<i32>, None]).cast(&DataType::String)?,
+            Series::new("value", [Some(1.5), None]),
+        ])?;
+        assert!(df.equals_missing(&expected));
+
+        let df = XlsxReader::new(excel_workbook())
+            .with_sheet_name(Some("Q&A".into()))
+            .with_range(Some("C4:C5".into()))
+            .has_header(false)
+            .finish()?;
+        assert!(df.column("column_1")?.equals(&when));
+
+        // a date header is rendered in the date system of the workbook
+        let df = XlsxReader::new(excel_workbook())
+            .with_sheet_name(Some("Q&A".into()))
+            .with_range(Some("C4:C5".into()))
+            .finish()?;
+        assert_eq!(df.get_column_names(), ["1904-01-01"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_classify_format_code() {
+        for (code, kind) in [
+            ("yyyy\\-mm\\-dd", Some(TemporalKind::Date)),
+            ("mmm", Some(TemporalKind::Date)),
+            ("d/m/yyyy h:mm", Some(TemporalKind::Datetime)),
+            ("hh:mm:ss", Some(TemporalKind::Time)),
+            ("mm:ss", Some(TemporalKind::Time)),
+            ("[h]:mm", None),
+            ("0.00\" days\"", None),
+            ("#,##0;[Red]-#,##0", None),
+            ("General", None),
+        ] {
+            assert_eq!(classify_format_code(code), kind, "{code}");
+        }
+    }
+}
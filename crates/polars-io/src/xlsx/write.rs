@@ -0,0 +1,346 @@
+use std::io::{Seek, Write};
+
+use ::zip::write::FileOptions;
+use ::zip::{CompressionMethod, ZipWriter};
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use super::{
+    column_name, escape_xml, MAX_COLUMNS, MAX_ROWS, MILLISECONDS_IN_DAY, UNIX_EPOCH_SERIAL,
+};
+use crate::shared::SerWriter;
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/><Override PartName="/xl/sharedStrings.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml"/></Types>"#;
+
+const ROOT_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#;
+
+const WORKBOOK_RELS: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/sharedStrings" Target="sharedStrings.xml"/></Relationships>"#;
+
+/// The cell formats are, in order: general, date, datetime and time.
+const STYLES: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><numFmts count="3"><numFmt numFmtId="164" formatCode="yyyy\-mm\-dd"/><numFmt numFmtId="165" formatCode="yyyy\-mm\-dd\ hh:mm:ss"/><numFmt numFmtId="166" formatCode="hh:mm:ss"/></numFmts><fonts count="1"><font><sz val="11"/><name val="Calibri"/><family val="2"/></font></fonts><fills count="2"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="4"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="164" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="165" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/><xf numFmtId="166" fontId="0" fillId="0" borderId="0" xfId="0" applyNumberFormat="1"/></cellXfs><cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles></styleSheet>"#;
+
+const DATE_FORMAT: u8 = 1;
+const DATETIME_FORMAT: u8 = 2;
+const TIME_FORMAT: u8 = 3;
+
+/// Write a [`DataFrame`] to an Excel (xlsx) workbook with a single sheet.
+///
+/// Cells are typed: numeric columns are written as numbers, boolean columns as booleans and
+/// temporal columns as numbers with a date, datetime or time number format. Datetimes with a
+/// time zone are written as their wall time in that time zone. Integers beyond 2^53 and
+/// non-finite floats can't be represented in Excel; they are written as rounded numbers and
+/// empty cells respectively.
+///
+/// # Example
+///
+/// ```
+/// use polars_core::prelude::*;
+/// use polars_io::xlsx::XlsxWriter;
+/// use polars_io::SerWriter;
+/// use std::fs::File;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut file = File::create("report.xlsx").expect("could not create file");
+///
+///     XlsxWriter::new(&mut file)
+///         .with_sheet_name("sales".into())
+///         .finish(df)
+/// }
+/// ```
+#[must_use]
+pub struct XlsxWriter<W> {
+    writer: W,
+    sheet_name: String,
+    include_header: bool,
+}
+
+impl<W> XlsxWriter<W>
+where
+    W: Write + Seek,
+{
+    /// Set the name of the sheet. Defaults to `"Sheet1"`.
+    pub fn with_sheet_name(mut self, sheet_name: String) -> Self {
+        self.sheet_name = sheet_name;
+        self
+    }
+
+    /// Set whether to write the column names in the first row. Defaults to `true`.
+    pub fn include_header(mut self, include_header: bool) -> Self {
+        self.include_header = include_header;
+        self
+    }
+}
+
+impl<W> SerWriter<W> for XlsxWriter<W>
+where
+    W: Write + Seek,
+{
+    fn new(writer: W) -> Self {
+        Self {
+            writer,
+            sheet_name: "Sheet1".to_string(),
+            include_header: true,
+        }
+    }
+
+    fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        let name = &self.sheet_name;
+        polars_ensure!(
+            !name.is_empty()
+                && name.chars().count() <= 31
+                && !name.contains(['[', ']', ':', '*', '?', '/', '\\']),
+            ComputeError: "invalid sheet name '{}'; it must have 1 to 31 characters and none of []:*?/\\", name
+        );
+        let n_rows = df.height() + self.include_header as usize;
+        polars_ensure!(
+            n_rows <= MAX_ROWS && df.width() <= MAX_COLUMNS,
+            ComputeError: "cannot write {} rows and {} columns to xlsx; a sheet holds at most {} rows and {} columns",
+            n_rows, df.width(), MAX_ROWS, MAX_COLUMNS
+        );
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|s| ColumnValues::try_new(&s.rechunk()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let cell_refs = (0..df.width()).map(column_name).collect::<Vec<_>>();
+
+        let mut zip = ZipWriter::new(&mut self.writer);
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        let write_file = |zip: &mut ZipWriter<&mut W>, path: &str, content: &str| {
+            zip.start_file(path, options).map_err(to_compute_err)?;
+            zip.write_all(content.as_bytes())?;
+            PolarsResult::Ok(())
+        };
+        write_file(&mut zip, "[Content_Types].xml", CONTENT_TYPES)?;
+        write_file(&mut zip, "_rels/.rels", ROOT_RELS)?;
+        write_file(&mut zip, "xl/_rels/workbook.xml.rels", WORKBOOK_RELS)?;
+        write_file(&mut zip, "xl/styles.xml", STYLES)?;
+        write_file(
+            &mut zip,
+            "xl/workbook.xml",
+            &format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+                escape_xml(name)
+            ),
+        )?;
+
+        zip.start_file("xl/worksheets/sheet1.xml", options)
+            .map_err(to_compute_err)?;
+        let mut sheet = std::io::BufWriter::new(&mut zip);
+        sheet.write_all(
+            br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+        )?;
+        if n_rows > 0 && df.width() > 0 {
+            write!(
+                sheet,
+                r#"<dimension ref="A1:{}{}"/>"#,
+                cell_refs.last().unwrap(),
+                n_rows
+            )?;
+        }
+        sheet.write_all(b"<sheetData>")?;
+
+        let mut shared_strings = SharedStrings::default();
+        let mut row = 1;
+        if self.include_header {
+            write!(sheet, r#"<row r="{row}">"#)?;
+            for (s, cell_ref) in df.get_columns().iter().zip(&cell_refs) {
+                let index = shared_strings.insert(s.name());
+                write!(sheet, r#"<c r="{cell_ref}{row}" t="s"><v>{index}</v></c>"#)?;
+            }
+            sheet.write_all(b"</row>")?;
+            row += 1;
+        }
+        for i in 0..df.height() {
+            write!(sheet, r#"<row r="{row}">"#)?;
+            for (values, cell_ref) in columns.iter().zip(&cell_refs) {
+                values.write_cell(&mut sheet, i, cell_ref, row, &mut shared_strings)?;
+            }
+            sheet.write_all(b"</row>")?;
+            row += 1;
+        }
+        sheet.write_all(b"</sheetData></worksheet>")?;
+        sheet.flush()?;
+        drop(sheet);
+
+        zip.start_file("xl/sharedStrings.xml", options)
+            .map_err(to_compute_err)?;
+        shared_strings.write(&mut std::io::BufWriter::new(&mut zip))?;
+        zip.finish().map_err(to_compute_err)?;
+        Ok(())
+    }
+}
+
+/// The values of a column, in the representation they are written with.
+enum ColumnValues {
+    Null,
+    Bool(BooleanChunked),
+    Int(Int64Chunked),
+    Float(Float64Chunked),
+    /// Serial date numbers with their cell format.
+    Serial(Float64Chunked, u8),
+    String(StringChunked),
+}
+
+impl ColumnValues {
+    fn try_new(s: &Series) -> PolarsResult<Self> {
+        Ok(match s.dtype() {
+            DataType::Null => Self::Null,
+            DataType::Boolean => Self::Bool(s.bool()?.clone()),
+            DataType::UInt64 => Self::Float(s.cast(&DataType::Float64)?.f64()?.clone()),
+            dt if dt.is_integer() => Self::Int(s.cast(&DataType::Int64)?.i64()?.clone()),
+            dt if dt.is_float() => Self::Float(s.cast(&DataType::Float64)?.f64()?.clone()),
+            #[cfg(feature = "dtype-decimal")]
+            DataType::Decimal(_, _) => Self::Float(s.cast(&DataType::Float64)?.f64()?.clone()),
+            DataType::Date => Self::Serial(
+                s.date()?
+                    .apply_values_generic(|days| days as f64 + UNIX_EPOCH_SERIAL),
+                DATE_FORMAT,
+            ),
+            DataType::Datetime(tu, tz) => {
+                let per_day = MILLISECONDS_IN_DAY
+                    * match tu {
+                        TimeUnit::Nanoseconds => 1e6,
+                        TimeUnit::Microseconds => 1e3,
+                        TimeUnit::Milliseconds => 1.0,
+                    };
+                let ca = s.datetime()?;
+                let serials = match tz {
+                    // Excel datetimes have no time zone; the local wall time is written
+                    #[cfg(feature = "timezones")]
+                    Some(tz) => {
+                        use arrow::legacy::time_zone::Tz;
+                        use arrow::temporal_conversions::{
+                            timestamp_ms_to_datetime_opt, timestamp_ns_to_datetime_opt,
+                            timestamp_us_to_datetime_opt,
+                        };
+                        use chrono::{Offset, TimeZone};
+
+                        let tz = tz.parse::<Tz>().map_err(
+                            |_| polars_err!(ComputeError: "unable to parse time zone: '{}'", tz),
+                        )?;
+                        let to_datetime = match tu {
+                            TimeUnit::Nanoseconds => timestamp_ns_to_datetime_opt,
+                            TimeUnit::Microseconds => timestamp_us_to_datetime_opt,
+                            TimeUnit::Milliseconds => timestamp_ms_to_datetime_opt,
+                        };
+                        ca.apply_values_generic(|v| {
+                            let offset = to_datetime(v).map_or(0, |ndt| {
+                                tz.offset_from_utc_datetime(&ndt).fix().local_minus_utc()
+                            });
+                            v as f64 / per_day
+                                + offset as f64 * 1000.0 / MILLISECONDS_IN_DAY
+                                + UNIX_EPOCH_SERIAL
+                        })
+                    },
+                    #[cfg(not(feature = "timezones"))]
+                    Some(_) => polars_bail!(
+                        ComputeError: "cannot write time zone aware datetimes to xlsx; \
+                        compile with feature 'timezones'"
+                    ),
+                    None => ca.apply_values_generic(|v| v as f64 / per_day + UNIX_EPOCH_SERIAL),
+                };
+                Self::Serial(serials, DATETIME_FORMAT)
+            },
+            DataType::Time => Self::Serial(
+                s.time()?
+                    .apply_values_generic(|ns| ns as f64 / (MILLISECONDS_IN_DAY * 1e6)),
+                TIME_FORMAT,
+            ),
+            DataType::String => Self::String(s.str()?.clone()),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) | DataType::Enum(_, _) => {
+                Self::String(s.cast(&DataType::String)?.str()?.clone())
+            },
+            dt => polars_bail!(ComputeError: "cannot write '{}' datatype to xlsx", dt),
+        })
+    }
+
+    fn write_cell<W: Write>(
+        &self,
+        w: &mut W,
+        i: usize,
+        cell_ref: &str,
+        row: usize,
+        shared_strings: &mut SharedStrings,
+    ) -> PolarsResult<()> {
+        match self {
+            Self::Null => {},
+            Self::Bool(ca) => {
+                if let Some(v) = ca.get(i) {
+                    write!(w, r#"<c r="{cell_ref}{row}" t="b"><v>{}</v></c>"#, v as u8)?;
+                }
+            },
+            Self::Int(ca) => {
+                if let Some(v) = ca.get(i) {
+                    write!(w, r#"<c r="{cell_ref}{row}"><v>{v}</v></c>"#)?;
+                }
+            },
+            Self::Float(ca) => {
+                if let Some(v) = ca.get(i).filter(|v| v.is_finite()) {
+                    write!(w, r#"<c r="{cell_ref}{row}"><v>{v}</v></c>"#)?;
+                }
+            },
+            Self::Serial(ca, format) => {
+                if let Some(v) = ca.get(i).filter(|v| v.is_finite()) {
+                    write!(w, r#"<c r="{cell_ref}{row}" s="{format}"><v>{v}</v></c>"#)?;
+                }
+            },
+            Self::String(ca) => {
+                if let Some(v) = ca.get(i) {
+                    let index = shared_strings.insert(v);
+                    write!(w, r#"<c r="{cell_ref}{row}" t="s"><v>{index}</v></c>"#)?;
+                }
+            },
+        }
+        Ok(())
+    }
+}
+
+/// The shared string table of the workbook; every distinct string is stored once.
+#[derive(Default)]
+struct SharedStrings {
+    strings: PlIndexSet<String>,
+    count: usize,
+}
+
+impl SharedStrings {
+    fn insert(&mut self, s: &str) -> usize {
+        self.count += 1;
+        match self.strings.get_index_of(s) {
+            Some(index) => index,
+            None => self.strings.insert_full(s.to_string()).0,
+        }
+    }
+
+    fn write<W: Write>(&self, w: &mut W) -> PolarsResult<()> {
+        write!(
+            w,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<sst xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" count="{}" uniqueCount="{}">"#,
+            self.count,
+            self.strings.len()
+        )?;
+        for s in &self.strings {
+            // Leading and trailing whitespace is dropped unless it is preserved explicitly.
+            if s.starts_with(char::is_whitespace) || s.ends_with(char::is_whitespace) {
+                write!(
+                    w,
+                    r#"<si><t xml:space="preserve">{}</t></si>"#,
+                    escape_xml(s)
+                )?;
+            } else {
+                write!(w, "<si><t>{}</t></si>", escape_xml(s))?;
+            }
+        }
+        w.write_all(b"</sst>")?;
+        w.flush()?;
+        Ok(())
+    }
+}
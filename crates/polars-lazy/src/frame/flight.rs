@@ -0,0 +1,14 @@
+use polars_io::flight::FlightSource;
+
+use super::*;
+
+/// Serves the result of the query; it is executed anew for every `DoGet` request.
+impl FlightSource for LazyFrame {
+    fn schema(&self) -> PolarsResult<SchemaRef> {
+        LazyFrame::schema(&mut self.clone())
+    }
+
+    fn collect(&self) -> PolarsResult<DataFrame> {
+        LazyFrame::collect(self.clone())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::prelude::*;
+
+/// Utility struct for multi-level aggregations over several grouping sets
+/// (`GROUPING SETS`, `ROLLUP` and `CUBE` in SQL).
+///
+/// Created by [`LazyFrame::group_by_sets`], [`LazyFrame::rollup`] and [`LazyFrame::cube`].
+#[derive(Clone)]
+#[must_use]
+pub struct LazyGroupBySets {
+    input: LazyFrame,
+    /// All distinct keys, in order of first appearance.
+    keys: Vec<Expr>,
+    /// Indices into `keys` for every grouping set.
+    sets: Vec<Vec<usize>>,
+    maintain_order: bool,
+    grouping_id: Option<String>,
+}
+
+impl LazyGroupBySets {
+    pub(crate) fn new(input: LazyFrame, sets: Vec<Vec<Expr>>) -> Self {
+        let mut keys: Vec<Expr> = vec![];
+        let mut set_indices = Vec::with_capacity(sets.len());
+        for set in sets {
+            let mut indices = Vec::with_capacity(set.len());
+            for key in set {
+                let idx = keys.iter().position(|k| *k == key).unwrap_or_else(|| {
+                    keys.push(key);
+                    keys.len() - 1
+                });
+                if !indices.contains(&idx) {
+                    indices.push(idx);
+                }
+            }
+            set_indices.push(indices);
+        }
+        // no grouping sets is a single aggregation over the whole frame
+        if set_indices.is_empty() {
+            set_indices.push(vec![]);
+        }
+        Self {
+            input,
+            keys,
+            sets: set_indices,
+            maintain_order: false,
+            grouping_id: None,
+        }
+    }
+
+    /// Maintain the order of the groups within each grouping set.
+    pub fn maintain_order(mut self, toggle: bool) -> Self {
+        self.maintain_order = toggle;
+        self
+    }
+
+    /// Add a `UInt32` column with the given name that identifies the grouping set of
+    /// each row; as with SQL `GROUPING`, it is a bitmask over the distinct keys (the
+    /// first key being the most significant bit) in which a bit is set if the key is
+    /// *not* part of the grouping set.
+    pub fn with_grouping_id<S: AsRef<str>>(mut self, name: S) -> Self {
+        self.grouping_id = Some(name.as_ref().to_string());
+        self
+    }
+
+    /// Group by each of the grouping sets and aggregate, returning the results of all
+    /// sets concatenated (in order). Keys that are not part of a grouping set are null.
+    ///
+    /// Returns an error if there are more than 32 distinct keys, as the grouping id
+    /// can't identify their grouping sets.
+    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<LazyFrame> {
+        let aggs = aggs.as_ref();
+        let n_keys = self.keys.len();
+        polars_ensure!(
+            n_keys <= 32,
+            InvalidOperation: "grouping sets support at most 32 distinct keys; found {}", n_keys
+        );
+        let names = self
+            .keys
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // every grouping set is aggregated from the same (cached) input
+        let input = self.input.cache();
+        let frames = self
+            .sets
+            .iter()
+            .map(|set| {
+                let keys = set
+                    .iter()
+                    .map(|&i| self.keys[i].clone())
+                    .collect::<Vec<_>>();
+                let aggregated = if keys.is_empty() {
+                    input.clone().select(aggs)
+                } else if self.maintain_order {
+                    input.clone().group_by_stable(keys).agg(aggs)
+                } else {
+                    input.clone().group_by(keys).agg(aggs)
+                };
+
+                let mut projection = Vec::with_capacity(n_keys + 2);
+                let mut grouping_id = 0u32;
+                for (i, name) in names.iter().enumerate() {
+                    if set.contains(&i) {
+                        projection.push(col(name));
+                    } else {
+                        projection.push(lit(NULL).alias(name));
+                        grouping_id |= 1u32 << (n_keys - 1 - i);
+                    }
+                }
+                if let Some(name) = &self.grouping_id {
+                    projection.push(typed_lit(grouping_id).alias(name));
+                }
+                let set_names = set.iter().map(|&i| names[i].as_ref());
+                projection.push(col("*").exclude(set_names));
+                aggregated.select(projection)
+            })
+            .collect::<Vec<_>>();
+
+        let args = UnionArgs {
+            to_supertypes: true,
+            ..Default::default()
+        };
+        concat(frames, args)
+    }
+}
@@ -6,6 +6,9 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "flight")]
+mod flight;
+mod group_by_sets;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
@@ -13,21 +16,31 @@ pub mod pivot;
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf"
 ))]
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "fwf")]
+pub use fwf::*;
+pub use group_by_sets::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_core::prelude::*;
@@ -864,12 +877,71 @@ impl LazyFrame {
         )
     }
 
+    /// Stream a query result into an avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(self, path: impl AsRef<Path>, options: AvroWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            SinkType::File {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Avro(options),
+            },
+            "collect().write_avro()",
+        )
+    }
+
+    /// Stream a query result into an ORC file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "orc")]
+    pub fn sink_orc(self, path: impl AsRef<Path>, options: OrcWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            SinkType::File {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Orc(options),
+            },
+            "collect().write_orc()",
+        )
+    }
+
+    /// Stream a query result into a Hive partitioned dataset in the directory `path`. Every
+    /// combination of the values of the [`PartitionBy`] keys is written to its own
+    /// `key=value/` directory, which can be read back with `hive_partitioning` enabled. This
+    /// method will return an error if the query cannot be completely done in a streaming
+    /// fashion.
+    #[cfg(any(
+        feature = "ipc",
+        feature = "parquet",
+        feature = "csv",
+        feature = "json",
+        feature = "avro",
+        feature = "orc",
+    ))]
+    pub fn sink_partitioned(
+        self,
+        path: impl AsRef<Path>,
+        file_type: FileType,
+        partition_by: PartitionBy,
+    ) -> PolarsResult<()> {
+        self.sink(
+            SinkType::Partition {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type,
+                partition_by,
+            },
+            "collect()` and a `PartitionedWriter",
+        )
+    }
+
     #[cfg(any(
         feature = "ipc",
         feature = "parquet",
         feature = "cloud_write",
         feature = "csv",
         feature = "json",
+        feature = "avro",
+        feature = "orc",
     ))]
     fn sink(mut self, payload: SinkType, msg_alternative: &str) -> Result<(), PolarsError> {
         self.opt_state.streaming = true;
@@ -1130,6 +1202,60 @@ impl LazyFrame {
         }
     }
 
+    /// Group by several sets of keys at once (SQL `GROUPING SETS`), producing a
+    /// [`LazyGroupBySets`] that aggregates each grouping set over the same input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///       df.lazy()
+    ///        .group_by_sets([vec![col("region"), col("city")], vec![col("region")], vec![]])
+    ///        .agg([col("sales").sum()])
+    /// }
+    /// ```
+    pub fn group_by_sets<S, E, IE>(self, sets: S) -> LazyGroupBySets
+    where
+        S: IntoIterator<Item = E>,
+        E: AsRef<[IE]>,
+        IE: Into<Expr> + Clone,
+    {
+        let sets = sets
+            .into_iter()
+            .map(|set| set.as_ref().iter().map(|e| e.clone().into()).collect())
+            .collect();
+        LazyGroupBySets::new(self, sets)
+    }
+
+    /// Group by every prefix of the given keys (SQL `ROLLUP`), from all keys down to
+    /// none (the grand total).
+    pub fn rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, keys: E) -> LazyGroupBySets {
+        let keys = keys.as_ref();
+        self.group_by_sets((0..=keys.len()).rev().map(|n| keys[..n].to_vec()))
+    }
+
+    /// Group by every subset of the given keys (SQL `CUBE`).
+    ///
+    /// As the number of grouping sets grows exponentially, at most 12 keys are supported.
+    pub fn cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        keys: E,
+    ) -> PolarsResult<LazyGroupBySets> {
+        let keys = keys.as_ref();
+        let n = keys.len();
+        polars_ensure!(n <= 12, InvalidOperation: "cube supports at most 12 keys; found {}", n);
+        Ok(self.group_by_sets((0..1usize << n).rev().map(|mask| {
+            keys.iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .map(|(_, key)| key.clone())
+                .collect::<Vec<_>>()
+        })))
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1167,6 +1293,48 @@ impl LazyFrame {
         )
     }
 
+    /// Join with another lazy query on the rows for which all `predicates` hold.
+    ///
+    /// The predicates are evaluated on the columns of both frames, where a column that
+    /// occurs in both frames refers to the left one, and the right one is referred to
+    /// with the `"_right"` suffix (as in the output). Equalities between columns of both
+    /// frames are planned as an inner join and otherwise (up to two) inequalities (`<`,
+    /// `<=`, `>`, `>=`) as an inequality join, rather than filtering the Cartesian
+    /// product; the order of the output is unspecified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn events_in_windows(windows: LazyFrame, events: LazyFrame) -> LazyFrame {
+    ///     windows.join_where(
+    ///         events,
+    ///         [col("start").lt_eq(col("ts")), col("ts").lt(col("end"))],
+    ///     )
+    /// }
+    /// ```
+    #[cfg(feature = "iejoin")]
+    pub fn join_where<E: AsRef<[Expr]>>(self, other: LazyFrame, predicates: E) -> LazyFrame {
+        let predicates = predicates.as_ref().to_vec();
+        if predicates.is_empty() {
+            return self.cross_join(other, None);
+        }
+        let mut opt_state = self.opt_state;
+        opt_state.file_caching |= other.opt_state.file_caching;
+        let options = JoinOptions {
+            args: JoinArgs::new(JoinType::Cross),
+            allow_keyed_cross_join: true,
+            ..Default::default()
+        };
+        let lp = self
+            .get_plan_builder()
+            .join_where(other.logical_plan, predicates, options.into())
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     /// Left outer join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1192,6 +1360,31 @@ impl LazyFrame {
         )
     }
 
+    /// Right outer join this query with another lazy query.
+    ///
+    /// Matches on the values of the expressions `left_on` and `right_on`. For more
+    /// flexible join logic, see [`join`](LazyFrame::join) or
+    /// [`join_builder`](LazyFrame::join_builder).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    /// fn right_join_dataframes(ldf: LazyFrame, other: LazyFrame) -> LazyFrame {
+    ///         ldf
+    ///         .right_join(other, col("foo"), col("bar"))
+    /// }
+    /// ```
+    pub fn right_join<E: Into<Expr>>(self, other: LazyFrame, left_on: E, right_on: E) -> LazyFrame {
+        self.join(
+            other,
+            [left_on.into()],
+            [right_on.into()],
+            JoinArgs::new(JoinType::Right),
+        )
+    }
+
     /// Inner join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1883,6 +2076,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    allow_keyed_cross_join: bool,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1899,6 +2093,7 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
+            allow_keyed_cross_join: false,
         }
     }
 
@@ -1976,6 +2171,14 @@ impl JoinBuilder {
         self
     }
 
+    /// Allow a cross join that is filtered on comparisons of columns of both tables to be
+    /// planned as an inner or inequality join. This doesn't preserve the row order of the
+    /// cross join.
+    pub fn allow_keyed_cross_join(mut self, allow: bool) -> Self {
+        self.allow_keyed_cross_join = allow;
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -2004,6 +2207,7 @@ impl JoinBuilder {
                     allow_parallel: self.allow_parallel,
                     force_parallel: self.force_parallel,
                     args,
+                    allow_keyed_cross_join: self.allow_keyed_cross_join,
                     ..Default::default()
                 }
                 .into(),
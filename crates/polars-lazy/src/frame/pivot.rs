@@ -1,14 +1,17 @@
-//! Polars lazy does not implement a pivot because it is impossible to know the schema without
-//! materializing the whole dataset. This makes a pivot quite a terrible operation for performant
-//! workflows. An optimization can never be pushed down passed a pivot.
+//! The schema of a pivot depends on the values of the `on` column, so it can't be known
+//! without looking at the data. A lazy pivot therefore needs the values that become columns
+//! up front. If they aren't given, they are collected in a separate (schema discovery) query
+//! before the pivot is added to the plan. With the schema known, the pivot is a node in the
+//! plan that the optimizer can push projections and predicates on the index columns through.
 //!
-//! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
-//! pivot is here, because we want to be able to pass expressions to the pivot operation.
+//! We can also do a pivot on an eager `DataFrame`, as that is already materialized. That code
+//! is here, because we want to be able to pass expressions to the pivot operation.
 //!
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_ops::pivot::PivotAgg;
+use smartstring::alias::String as SmartString;
 
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::prelude::*;
@@ -81,3 +84,55 @@ where
     });
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+impl LazyFrame {
+    /// Pivot the frame from long to wide format: the values of `on` become new columns that
+    /// hold the aggregated `values` of every unique combination of the `index` columns.
+    ///
+    /// `on_columns` are the values of `on` that become columns. If they are `None`, they are
+    /// first collected from the data, which runs the query up to the pivot. Columns are named
+    /// after the values of `on`, prefixed by the value column and `separator` (defaults to
+    /// `"_"`) if there is more than one value column.
+    #[allow(clippy::too_many_arguments)]
+    pub fn pivot(
+        self,
+        on: &str,
+        on_columns: Option<Series>,
+        index: impl IntoVec<SmartString>,
+        values: impl IntoVec<SmartString>,
+        agg: PivotAggregation,
+        sort_columns: bool,
+        separator: Option<&str>,
+    ) -> PolarsResult<LazyFrame> {
+        let on_columns = match on_columns {
+            Some(on_columns) => on_columns,
+            None => self
+                .clone()
+                .select([col(on).unique_stable()])
+                .collect()?
+                .drop_in_place(on)?,
+        };
+        // Name the columns as the eager pivot does.
+        let on_columns = on_columns.unique_stable()?.cast(&DataType::String)?;
+        let mut on_columns = on_columns
+            .str()?
+            .iter()
+            .map(|value| SmartString::from(value.unwrap_or("null")))
+            .collect::<Vec<_>>();
+        if sort_columns {
+            on_columns.sort_unstable();
+        }
+
+        let args = PivotArgs {
+            on: on.into(),
+            on_columns: on_columns.into(),
+            index: index.into_vec(),
+            values: values.into_vec(),
+            agg,
+            separator: separator.unwrap_or("_").into(),
+        };
+        let opt_state = self.get_opt_state();
+        let lp = self.get_plan_builder().pivot(args).build();
+        Ok(Self::from_logical_plan(lp, opt_state))
+    }
+}
@@ -29,6 +29,7 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         #[cfg(feature = "cross_join")]
         JoinType::Cross => true,
         JoinType::Left => true,
+        JoinType::Right => true,
         JoinType::Inner => {
             // no-coalescing not yet supported in streaming
             matches!(
@@ -37,6 +38,9 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
             )
         },
         JoinType::Full { .. } => true,
+        // Duration string tolerances are only resolved by the in-memory engine.
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(ref options) => options.tolerance_str.is_none(),
         _ => false,
     };
     supported && !args.validation.needs_checks()
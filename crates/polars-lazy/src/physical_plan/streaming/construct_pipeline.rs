@@ -28,6 +28,9 @@ impl PhysicalIoExpr for Wrap {
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         self.0.as_stats_evaluator()
     }
+    fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+        self.0.as_expression().map(expr_to_leaf_column_names)
+    }
 }
 impl PhysicalPipedExpr for Wrap {
     fn evaluate(&self, chunk: &DataChunk, state: &ExecutionState) -> PolarsResult<Series> {
@@ -261,7 +261,8 @@ pub(crate) fn insert_streaming_nodes(
                 // and then we stream the larger table
                 // *except* for a left join. In a left join we use the right
                 // table as build table and we stream the left table. This way
-                // we maintain order in the left join.
+                // we maintain order in the left join. A right join does the
+                // opposite. An asof join builds the (sorted) right table as well.
                 let (input_left, input_right) = if swap_join_order(options) {
                     (input_right, input_left)
                 } else {
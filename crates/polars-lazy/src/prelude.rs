@@ -1,10 +1,14 @@
 pub(crate) use polars_expr::prelude::*;
+#[cfg(feature = "avro")]
+pub use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 pub use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 pub use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 pub use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+pub use polars_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
 pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
@@ -14,8 +18,19 @@ pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
+#[cfg(feature = "pivot")]
+pub use polars_plan::prelude::PivotAggregation;
 pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(any(
+    feature = "ipc",
+    feature = "parquet",
+    feature = "csv",
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
+))]
+pub use polars_plan::prelude::{FileType, PartitionBy};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
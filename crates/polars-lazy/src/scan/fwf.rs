@@ -0,0 +1,246 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::csv::read::{CsvEncoding, NullValues};
+use polars_io::fwf::{FixedWidthReadOptions, SpanUnit};
+use polars_io::RowIndex;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct LazyFixedWidthReader {
+    paths: Arc<[PathBuf]>,
+    glob: bool,
+    cache: bool,
+    read_options: FixedWidthReadOptions,
+}
+
+impl LazyFixedWidthReader {
+    pub fn new_paths(paths: Arc<[PathBuf]>) -> Self {
+        Self::new("").with_paths(paths)
+    }
+
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        LazyFixedWidthReader {
+            paths: Arc::new([path.as_ref().to_path_buf()]),
+            glob: true,
+            cache: true,
+            read_options: Default::default(),
+        }
+    }
+
+    /// Set all the options of the reader at once.
+    #[must_use]
+    pub fn with_read_options(mut self, read_options: FixedWidthReadOptions) -> Self {
+        self.read_options = read_options;
+        self
+    }
+
+    /// The `[start, end)` offsets of the columns in a line. If this is `None`, the spans are
+    /// inferred from the header line.
+    #[must_use]
+    pub fn with_column_spans(mut self, column_spans: Option<Arc<[(usize, usize)]>>) -> Self {
+        self.read_options.column_spans = column_spans;
+        self
+    }
+
+    /// Set the column spans from the widths of consecutive columns.
+    #[must_use]
+    pub fn with_column_widths(mut self, widths: &[usize]) -> Self {
+        self.read_options = self.read_options.with_column_widths(widths);
+        self
+    }
+
+    /// Whether the column spans count bytes or characters.
+    #[must_use]
+    pub fn with_span_unit(mut self, span_unit: SpanUnit) -> Self {
+        self.read_options.span_unit = span_unit;
+        self
+    }
+
+    /// Add a row index column.
+    #[must_use]
+    pub fn with_row_index(mut self, row_index: Option<RowIndex>) -> Self {
+        self.read_options.row_index = row_index;
+        self
+    }
+
+    /// Stop parsing when `n` rows are parsed.
+    #[must_use]
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.read_options.n_rows = num_rows;
+        self
+    }
+
+    /// Set the number of rows to use when inferring the schema.
+    /// the default is 100 rows.
+    /// Setting to `None` will do a full table scan, very slow.
+    #[must_use]
+    pub fn with_infer_schema_length(mut self, num_rows: Option<usize>) -> Self {
+        self.read_options.infer_schema_length = num_rows;
+        self
+    }
+
+    /// Set values that could not be parsed to null.
+    #[must_use]
+    pub fn with_ignore_errors(mut self, ignore: bool) -> Self {
+        self.read_options.ignore_errors = ignore;
+        self
+    }
+
+    /// Set the file's schema
+    #[must_use]
+    pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
+        self.read_options.schema = schema;
+        self
+    }
+
+    /// Skip the first `n` lines during parsing. The header will be parsed at line `n`.
+    #[must_use]
+    pub fn with_skip_rows(mut self, skip_rows: usize) -> Self {
+        self.read_options.skip_rows = skip_rows;
+        self
+    }
+
+    /// Overwrite the schema with the dtypes in this given Schema. The given schema may be a subset
+    /// of the total schema.
+    #[must_use]
+    pub fn with_dtype_overwrite(mut self, schema: Option<SchemaRef>) -> Self {
+        self.read_options.schema_overwrite = schema;
+        self
+    }
+
+    /// Set whether the file has a header line.
+    #[must_use]
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.read_options.has_header = has_header;
+        self
+    }
+
+    /// Set the `char` used as end of line. The default is `b'\n'`.
+    #[must_use]
+    pub fn with_eol_char(mut self, eol_char: u8) -> Self {
+        self.read_options.eol_char = eol_char;
+        self
+    }
+
+    /// Set values that will be interpreted as missing/ null.
+    #[must_use]
+    pub fn with_null_values(mut self, null_values: Option<NullValues>) -> Self {
+        self.read_options.null_values = null_values;
+        self
+    }
+
+    /// Remove the leading and trailing whitespace of the fields.
+    #[must_use]
+    pub fn with_trim(mut self, trim: bool) -> Self {
+        self.read_options.trim = trim;
+        self
+    }
+
+    /// Cache the DataFrame after reading.
+    #[must_use]
+    pub fn with_cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Set  [`CsvEncoding`]
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.read_options.encoding = encoding;
+        self
+    }
+
+    /// Automatically try to parse dates/datetimes and time.
+    /// If parsing fails, columns remain of dtype `[DataType::String]`.
+    #[cfg(feature = "temporal")]
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.read_options.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    #[must_use]
+    pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.read_options.decimal_comma = decimal_comma;
+        self
+    }
+
+    #[must_use]
+    /// Expand path given via globbing rules.
+    pub fn with_glob(mut self, toggle: bool) -> Self {
+        self.glob = toggle;
+        self
+    }
+}
+
+impl LazyFileListReader for LazyFixedWidthReader {
+    /// Get the final [LazyFrame].
+    fn finish(self) -> PolarsResult<LazyFrame> {
+        // `expand_paths` respects globs
+        let paths = self.expand_paths(false)?.0;
+
+        let mut lf: LazyFrame = DslBuilder::scan_fwf(paths, self.read_options, self.cache)?
+            .build()
+            .into();
+        lf.opt_state.file_caching = true;
+        Ok(lf)
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!();
+    }
+
+    fn glob(&self) -> bool {
+        self.glob
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn with_paths(mut self, paths: Arc<[PathBuf]>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.read_options.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.read_options.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.read_options.rechunk
+    }
+
+    /// Rechunk the memory to contiguous chunks when parsing is done.
+    fn with_rechunk(mut self, rechunk: bool) -> Self {
+        self.read_options.rechunk = rechunk;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.read_options.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.read_options.row_index.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from a fixed-width file scan.
+    pub fn scan_fwf(
+        path: impl AsRef<Path>,
+        read_options: FixedWidthReadOptions,
+    ) -> PolarsResult<Self> {
+        LazyFixedWidthReader::new(path)
+            .with_read_options(read_options)
+            .finish()
+    }
+}
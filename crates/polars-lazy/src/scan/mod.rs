@@ -1,10 +1,16 @@
 pub(super) mod anonymous_scan;
+#[cfg(feature = "avro")]
+pub(super) mod avro;
 #[cfg(feature = "csv")]
 pub(super) mod csv;
 pub(super) mod file_list_reader;
+#[cfg(feature = "fwf")]
+pub(super) mod fwf;
 #[cfg(feature = "ipc")]
 pub(super) mod ipc;
 #[cfg(feature = "json")]
 pub(super) mod ndjson;
+#[cfg(feature = "orc")]
+pub(super) mod orc;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
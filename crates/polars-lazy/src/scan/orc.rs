@@ -0,0 +1,138 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::{HiveOptions, RowIndex};
+
+use crate::prelude::*;
+use crate::scan::file_list_reader::get_glob_start_idx;
+
+#[derive(Clone)]
+pub struct ScanArgsOrc {
+    pub n_rows: Option<usize>,
+    pub cache: bool,
+    pub rechunk: bool,
+    pub row_index: Option<RowIndex>,
+    /// Prune stripes with the column statistics in the file metadata.
+    pub use_statistics: bool,
+    pub hive_options: HiveOptions,
+    /// Expand path given via globbing rules.
+    pub glob: bool,
+}
+
+impl Default for ScanArgsOrc {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            cache: true,
+            rechunk: false,
+            row_index: None,
+            use_statistics: true,
+            hive_options: Default::default(),
+            glob: true,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct LazyOrcReader {
+    args: ScanArgsOrc,
+    paths: Arc<[PathBuf]>,
+}
+
+impl LazyOrcReader {
+    fn new(args: ScanArgsOrc) -> Self {
+        Self {
+            args,
+            paths: Arc::new([]),
+        }
+    }
+}
+
+impl LazyFileListReader for LazyOrcReader {
+    fn finish(mut self) -> PolarsResult<LazyFrame> {
+        let (paths, hive_start_idx) =
+            self.expand_paths(self.args.hive_options.enabled.unwrap_or(false))?;
+        self.args.hive_options.enabled =
+            Some(self.args.hive_options.enabled.unwrap_or_else(|| {
+                self.paths.len() == 1
+                    && get_glob_start_idx(self.paths[0].to_str().unwrap().as_bytes()).is_none()
+                    && !paths.is_empty()
+                    && (paths[0].is_dir() || paths[0] != self.paths[0])
+            }));
+        self.args.hive_options.hive_start_idx = hive_start_idx;
+        let args = self.args;
+
+        let mut lf: LazyFrame = DslBuilder::scan_orc(
+            paths,
+            args.n_rows,
+            args.cache,
+            args.row_index,
+            args.rechunk,
+            args.use_statistics,
+            args.hive_options,
+        )?
+        .build()
+        .into();
+        lf.opt_state.file_caching = true;
+
+        Ok(lf)
+    }
+
+    fn glob(&self) -> bool {
+        self.args.glob
+    }
+
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        unreachable!()
+    }
+
+    fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    fn with_paths(mut self, paths: Arc<[PathBuf]>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    fn with_n_rows(mut self, n_rows: impl Into<Option<usize>>) -> Self {
+        self.args.n_rows = n_rows.into();
+        self
+    }
+
+    fn with_row_index(mut self, row_index: impl Into<Option<RowIndex>>) -> Self {
+        self.args.row_index = row_index.into();
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_index(&self) -> Option<&RowIndex> {
+        self.args.row_index.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an ORC scan.
+    pub fn scan_orc(path: impl AsRef<Path>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        LazyOrcReader::new(args)
+            .with_paths(Arc::new([path.as_ref().to_path_buf()]))
+            .finish()
+    }
+
+    /// Create a LazyFrame directly from an ORC scan.
+    pub fn scan_orc_files(paths: Arc<[PathBuf]>, args: ScanArgsOrc) -> PolarsResult<Self> {
+        LazyOrcReader::new(args).with_paths(paths).finish()
+    }
+}
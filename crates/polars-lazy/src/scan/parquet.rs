@@ -17,6 +17,10 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
+    /// Allow the files to have different schemas. The scan has the union of the schemas of all
+    /// files: columns are matched by name, absent columns are filled with nulls and columns with
+    /// a narrower type in some files are upcast, e.g. `Int32` to `Int64` or `Date` to `Datetime`.
+    pub allow_missing_columns: bool,
     pub low_memory: bool,
     pub rechunk: bool,
     pub cache: bool,
@@ -33,6 +37,7 @@ impl Default for ScanArgsParquet {
             cloud_options: None,
             hive_options: Default::default(),
             use_statistics: true,
+            allow_missing_columns: false,
             rechunk: false,
             low_memory: false,
             cache: true,
@@ -85,6 +90,7 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.allow_missing_columns,
             self.args.hive_options,
         )?
         .build()
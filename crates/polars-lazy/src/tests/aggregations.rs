@@ -571,3 +571,64 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_group_by_sets() -> PolarsResult<()> {
+    let df = df![
+        "region" => ["n", "n", "s", "s"],
+        "city" => ["a", "b", "c", "c"],
+        "sales" => [1, 2, 3, 4],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .rollup([col("region"), col("city")])
+        .maintain_order(true)
+        .with_grouping_id("gid")
+        .agg([col("sales").sum()])?
+        .collect()?;
+    let expected = df![
+        "region" => [Some("n"), Some("n"), Some("s"), Some("n"), Some("s"), None],
+        "city" => [Some("a"), Some("b"), Some("c"), None, None, None],
+        "gid" => [0u32, 0, 0, 1, 1, 3],
+        "sales" => [1, 2, 7, 3, 7, 10],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+    assert_eq!(out.column("gid")?.dtype(), &DataType::UInt32);
+
+    let out = df
+        .clone()
+        .lazy()
+        .cube([col("region"), col("city")])?
+        .agg([col("sales").sum()])?
+        .collect()?;
+    assert_eq!(out.height(), 9);
+    assert_eq!(out.column("sales")?.sum::<i32>()?, 40);
+
+    // keys shared by several grouping sets are deduplicated
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_sets([vec![col("city")], vec![col("region"), col("city")]])
+        .maintain_order(true)
+        .with_grouping_id("gid")
+        .agg([len()])?
+        .collect()?;
+    let expected = df![
+        "city" => ["a", "b", "c", "a", "b", "c"],
+        "region" => [None, None, None, Some("n"), Some("n"), Some("s")],
+        "gid" => [1u32, 1, 1, 0, 0, 0],
+        "len" => [1 as IdxSize, 1, 2, 1, 1, 2],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+
+    // the grouping id can't identify the grouping sets of more than 32 keys, and a cube of
+    // more than 12 keys has too many grouping sets
+    let keys = (0..33)
+        .map(|i| lit(i).alias(&format!("k{i}")))
+        .collect::<Vec<_>>();
+    assert!(df.clone().lazy().rollup(&keys).agg([len()]).is_err());
+    assert!(df.lazy().cube(&keys[..13]).is_err());
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use polars_io::RowIndex;
+use polars_io::{HiveOptions, RowIndex};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
 
@@ -355,6 +355,281 @@ fn test_parquet_statistics() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "is_in")]
+fn test_parquet_bloom_filter() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_parquet_bloom_filter.parquet");
+
+    // Every row group spans the full range of even ids, so min/max statistics can't skip any.
+    let ids = (0..1000i64)
+        .map(|i| (i * 37 % 1000) * 2)
+        .collect::<Vec<_>>();
+    let names = ids.iter().map(|i| format!("id-{i}")).collect::<Vec<_>>();
+    let mut df = df![
+        "id" => ids,
+        "name" => names,
+    ]?;
+    let f = std::fs::File::create(&path).unwrap();
+    let bloom_filter = BloomFilterOptions {
+        ndv: None,
+        fpp: 0.01,
+    };
+    ParquetWriter::new(f)
+        .with_row_group_size(Some(100))
+        .with_bloom_filter_columns(vec![
+            ("id".to_string(), bloom_filter),
+            ("name".to_string(), bloom_filter),
+        ])
+        .finish(&mut df)?;
+
+    let scan = || LazyFrame::scan_parquet(&path, Default::default());
+
+    let out = scan()?.filter(col("id").eq(lit(74i64))).collect()?;
+    assert_eq!(out.shape(), (1, 2));
+    let out = scan()?
+        .filter(col("name").is_in(lit(Series::new("", ["id-74", "id-75"]))))
+        .collect()?;
+    assert_eq!(out.shape(), (1, 2));
+
+    // Odd ids are within the min/max bounds, but never written.
+    std::env::set_var("POLARS_PANIC_IF_PARQUET_PARSED", "1");
+    let out = scan()?.filter(col("id").eq(lit(75i64))).collect();
+    let out_is_in = scan()?
+        .filter(col("id").is_in(lit(Series::new("", [75i64, 1001]))))
+        .collect();
+    let out_str = scan()?.filter(col("name").eq(lit("id-75"))).collect();
+    std::env::remove_var("POLARS_PANIC_IF_PARQUET_PARSED");
+    assert_eq!(out?.shape(), (0, 2));
+    assert_eq!(out_is_in?.shape(), (0, 2));
+    assert_eq!(out_str?.shape(), (0, 2));
+
+    // -0.0 equals 0.0, but has a different bit pattern in the filter.
+    let mut df = df![
+        "f32" => [-1.5f32, -0.0, 2.5],
+        "f64" => [-1.5f64, -0.0, 2.5],
+    ]?;
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_bloom_filter_columns(vec![
+            ("f32".to_string(), bloom_filter),
+            ("f64".to_string(), bloom_filter),
+        ])
+        .finish(&mut df)?;
+    let out = scan()?.filter(col("f32").eq(lit(0.0f32))).collect()?;
+    assert_eq!(out.shape(), (1, 2));
+    let out = scan()?.filter(col("f64").eq(lit(0.0f64))).collect()?;
+    assert_eq!(out.shape(), (1, 2));
+
+    Ok(())
+}
+
+#[test]
+fn test_parquet_page_index() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_parquet_page_index.parquet");
+
+    // A single row group with many small pages.
+    let n = 100_000i64;
+    let mut df = df![
+        "ts" => (0..n).collect::<Vec<_>>(),
+        "name" => (0..n).map(|i| format!("name-{}", i % 7)).collect::<Vec<_>>(),
+    ]?;
+    let f = std::fs::File::create(&path).unwrap();
+    ParquetWriter::new(f)
+        .with_data_page_size(Some(4096))
+        .finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(std::fs::File::open(&path).unwrap());
+    let md = reader.get_metadata()?;
+    assert_eq!(md.row_groups.len(), 1);
+    let column = md.row_groups[0].columns()[0].column_chunk();
+    assert!(column.column_index_offset.is_some());
+    assert!(column.offset_index_offset.is_some());
+
+    let scan = |n_rows, row_index| {
+        LazyFrame::scan_parquet(
+            &path,
+            ScanArgsParquet {
+                n_rows,
+                row_index,
+                ..Default::default()
+            },
+        )
+    };
+    let row_index = || {
+        Some(RowIndex {
+            name: Arc::from("idx"),
+            offset: 10,
+        })
+    };
+
+    let predicate = col("ts")
+        .gt_eq(lit(50_000i64))
+        .and(col("ts").lt(lit(50_010i64)))
+        .or(col("ts").eq(lit(n - 1)));
+    for row_index in [None, row_index()] {
+        let out = scan(None, row_index.clone())?
+            .filter(predicate.clone())
+            .collect()?;
+        let mut expected = df.clone().lazy();
+        if let Some(ri) = row_index {
+            expected = expected.with_row_index(&ri.name, Some(ri.offset));
+        }
+        let expected = expected.filter(predicate.clone()).collect()?;
+        assert!(out.equals(&expected));
+        assert_eq!(out.height(), 11);
+    }
+
+    // The predicate and the slice are both applied to the pages.
+    let out = scan(Some(60_000), None)?
+        .filter(col("ts").gt_eq(lit(59_995i64)))
+        .collect()?;
+    assert_eq!(out.column("ts")?.i64()?.get(0), Some(59_995));
+    assert_eq!(out.height(), 5);
+    let out = scan(Some(60_000), row_index())?
+        .filter(col("ts").gt_eq(lit(59_995i64)))
+        .collect()?;
+    assert_eq!(out.column("idx")?.idx()?.get(0), Some(59_995 + 10));
+    assert_eq!(out.height(), 5);
+
+    let out = scan(None, None)?
+        .filter(col("ts").gt(lit(n)).or(col("name").eq(lit("none"))))
+        .collect()?;
+    assert_eq!(out.height(), 0);
+
+    // Pages that are skipped are not read: corrupt the pages of `ts` that hold the rows
+    // ~20_000..40_000, which makes reading them fail.
+    let (start, length) = md.row_groups[0].columns()[0].byte_range();
+    let mut bytes = std::fs::read(&path)?;
+    let corrupt_start = (start + length / 5) as usize;
+    let corrupt_end = (start + 2 * length / 5) as usize;
+    bytes[corrupt_start..corrupt_end].fill(0xff);
+    std::fs::write(&path, bytes)?;
+
+    assert!(scan(None, None)?.collect().is_err());
+    let out = scan(None, None)?.filter(predicate).collect()?;
+    let expected = df!["ts" => (50_000..50_010).chain([n - 1]).collect::<Vec<_>>()]?;
+    assert!(out.select(["ts"])?.equals(&expected));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_parquet_allow_missing_columns() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_scan_parquet_allow_missing_columns");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let write = |name: &str, mut df: DataFrame| -> PolarsResult<()> {
+        let f = std::fs::File::create(dir.join(name))?;
+        ParquetWriter::new(f).finish(&mut df)?;
+        Ok(())
+    };
+    // The files gained columns over time, reordered them and widened their types.
+    write(
+        "0.parquet",
+        df![
+            "a" => [1i32, 2],
+            "d" => [0i32, 1],
+        ]?
+        .lazy()
+        .with_column(col("d").cast(DataType::Date))
+        .collect()?,
+    )?;
+    write(
+        "1.parquet",
+        df![
+            "b" => ["x", "y"],
+            "a" => [3i64, 4],
+            "d" => [Some(86_400_000_000i64), None],
+        ]?
+        .lazy()
+        .with_column(col("d").cast(DataType::Datetime(TimeUnit::Microseconds, None)))
+        .collect()?,
+    )?;
+    write(
+        "2.parquet",
+        df![
+            "a" => [5i32],
+            "c" => [0.5f32],
+        ]?,
+    )?;
+
+    let scan = |args: ScanArgsParquet| {
+        LazyFrame::scan_parquet(
+            dir.join("*.parquet"),
+            ScanArgsParquet {
+                allow_missing_columns: true,
+                ..args
+            },
+        )
+    };
+
+    let out = scan(Default::default())?.collect()?;
+    let expected = df![
+        "a" => [1i64, 2, 3, 4, 5],
+        "d" => [Some(0i64), Some(86_400_000_000), Some(86_400_000_000), None, None],
+        "b" => [None, None, Some("x"), Some("y"), None],
+        "c" => [None, None, None, None, Some(0.5f64)],
+    ]?
+    .lazy()
+    .with_column(col("d").cast(DataType::Datetime(TimeUnit::Microseconds, None)))
+    .collect()?;
+    assert!(out.equals_missing(&expected));
+
+    // The predicate can refer to columns that are absent from some files.
+    let out = scan(Default::default())?
+        .filter(col("b").is_null().and(col("a").gt(lit(1i64))))
+        .select([col("a"), col("c")])
+        .collect()?;
+    let expected = df![
+        "a" => [2i64, 5],
+        "c" => [None, Some(0.5f64)],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // The predicate is applied while reading the files that have the column with the same type
+    // and after conforming the other files.
+    let out = scan(Default::default())?
+        .filter(col("b").eq(lit("y")))
+        .select([col("a"), col("b")])
+        .collect()?;
+    let expected = df![
+        "a" => [4i64],
+        "b" => ["y"],
+    ]?;
+    assert!(out.equals(&expected), "{out}");
+
+    let args = ScanArgsParquet {
+        n_rows: Some(4),
+        row_index: Some(RowIndex {
+            name: Arc::from("idx"),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = scan(args)?
+        .filter(col("a").gt_eq(lit(2i64)))
+        .select([col("idx"), col("a"), col("b")])
+        .collect()?;
+    let expected = df![
+        "idx" => [11 as IdxSize, 12, 13],
+        "a" => [2i64, 3, 4],
+        "b" => [None, Some("x"), Some("y")],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // Types that cannot be upcast safely are an error.
+    write("3.parquet", df!["a" => ["not a number"]]?)?;
+    assert!(scan(Default::default())?.collect().is_err());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
 #[test]
 #[cfg(not(target_os = "windows"))]
 fn test_parquet_globbing() -> PolarsResult<()> {
@@ -725,3 +1000,331 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_scan_parquet_hive_encoded_key() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_scan_parquet_hive_encoded_key");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let path = dir.join("my%20key=a%2Fb").join("data.parquet");
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    let mut df = df!["value" => [1i64, 2]]?;
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            enabled: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(&dir, args)?.collect()?;
+    let expected = df![
+        "value" => [1i64, 2],
+        "my key" => ["a/b", "a/b"],
+    ]?;
+    assert!(out.equals(&expected), "{out}");
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_sink_partitioned_parquet() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_sink_partitioned_parquet");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let df = df![
+        "year" => [2023i64, 2024, 2023, 2024, 2023, 2024, 2023],
+        "kind" => [Some("a/b"), Some("c"), None, Some("c"), Some("a/b"), Some("c"), Some("a/b")],
+        "value" => (0..7i64).collect::<Vec<_>>(),
+    ]?;
+    df.clone().lazy().sink_partitioned(
+        &dir,
+        FileType::Parquet(Default::default()),
+        PartitionBy::new(["year", "kind"]).with_max_rows_per_file(Some(2)),
+    )?;
+
+    let files = |partition: &str| {
+        let mut files = std::fs::read_dir(dir.join(partition))
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    };
+    assert_eq!(
+        files("year=2023/kind=a%2Fb"),
+        ["data-0000.parquet", "data-0001.parquet"]
+    );
+    assert_eq!(
+        files("year=2024/kind=c"),
+        ["data-0000.parquet", "data-0001.parquet"]
+    );
+    assert_eq!(
+        files("year=2023/kind=__HIVE_DEFAULT_PARTITION__"),
+        ["data-0000.parquet"]
+    );
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            enabled: Some(true),
+            try_parse_dates: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(&dir, args)?
+        .select([col("year"), col("kind"), col("value")])
+        .sort(["value"], Default::default())
+        .collect()?;
+    assert!(out.equals_missing(&df));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_sink_partitioned_max_open_files() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_sink_partitioned_max_open_files");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    // the partitions recur in every input frame, so their files are finished and reopened
+    let frames = (0..4i64)
+        .map(|i| {
+            df![
+                "key" => (0..10i64).collect::<Vec<_>>(),
+                "value" => (0..10i64).map(|v| i * 10 + v).collect::<Vec<_>>(),
+            ]
+            .map(|df| df.lazy())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let args = UnionArgs {
+        rechunk: false,
+        ..Default::default()
+    };
+    concat(frames, args)?.sink_partitioned(
+        &dir,
+        FileType::Parquet(Default::default()),
+        PartitionBy::new(["key"]).with_max_open_files(3),
+    )?;
+
+    let args = ScanArgsParquet {
+        hive_options: HiveOptions {
+            enabled: Some(true),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(&dir, args)?
+        .select([col("key"), col("value")])
+        .sort(["value"], Default::default())
+        .collect()?;
+    let expected = df![
+        "key" => (0..40i64).map(|v| v % 10).collect::<Vec<_>>(),
+        "value" => (0..40i64).collect::<Vec<_>>(),
+    ]?;
+    assert!(out.equals(&expected), "{out}");
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "avro")]
+fn test_scan_avro() -> PolarsResult<()> {
+    use polars_io::avro::{AvroCompression, AvroWriter};
+    use polars_io::SerWriter;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_scan_avro");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let df1 = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+        "c" => [1.0f64, 2.0, 3.0],
+    ]?;
+    let df2 = df![
+        "a" => [4i64, 5, 6],
+        "b" => ["u", "v", "w"],
+        "c" => [4.0f64, 5.0, 6.0],
+    ]?;
+    // Write the second file as two compressed data blocks.
+    let mut df2_chunked = df2.slice(0, 2);
+    df2_chunked.vstack_mut(&df2.slice(2, 1))?;
+    AvroWriter::new(std::fs::File::create(dir.join("0.avro"))?).finish(&mut df1.clone())?;
+    AvroWriter::new(std::fs::File::create(dir.join("1.avro"))?)
+        .with_compression(Some(AvroCompression::Deflate))
+        .finish(&mut df2_chunked)?;
+
+    let path = dir.join("*.avro");
+    let expected = df1.vstack(&df2)?;
+    let out = LazyFrame::scan_avro(&path, Default::default())?.collect()?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFrame::scan_avro(&path, Default::default())?
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(6));
+
+    let args = ScanArgsAvro {
+        n_rows: Some(4),
+        row_index: Some(RowIndex {
+            name: Arc::from("idx"),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_avro(&path, args.clone())?
+        .select([col("c"), col("idx")])
+        .collect()?;
+    let expected = df![
+        "c" => [1.0f64, 2.0, 3.0, 4.0],
+        "idx" => [10 as IdxSize, 11, 12, 13],
+    ]?;
+    assert!(out.equals(&expected));
+
+    // The predicate is applied after the slice and the row index.
+    let out = LazyFrame::scan_avro(&path, args)?
+        .filter(col("idx").gt_eq(lit(12 as IdxSize)))
+        .select([col("idx"), col("b")])
+        .collect()?;
+    let expected = df![
+        "idx" => [12 as IdxSize, 13],
+        "b" => ["z", "u"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "avro", feature = "streaming"))]
+fn test_sink_avro() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_sink_avro.avro");
+
+    let df = df![
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+    let options = AvroWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    df.clone().lazy().sink_avro(&path, options)?;
+
+    let out = LazyFrame::scan_avro(&path, Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "orc")]
+fn test_scan_orc() -> PolarsResult<()> {
+    use polars_io::orc::OrcWriter;
+    use polars_io::SerWriter;
+
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let dir = std::env::temp_dir().join("polars_test_scan_orc");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let write = |year: i32, df: &DataFrame| -> PolarsResult<()> {
+        let part = dir.join(format!("year={year}"));
+        std::fs::create_dir_all(&part)?;
+        // Write small stripes, so that the statistics can prune some of them.
+        OrcWriter::new(std::fs::File::create(part.join("0.orc"))?)
+            .with_stripe_size(Some(2))
+            .finish(&mut df.clone())
+    };
+    write(
+        2023,
+        &df![
+            "a" => [1i64, 2, 3, 4],
+            "b" => [Some("x"), None, Some("z"), Some("w")],
+        ]?,
+    )?;
+    write(
+        2024,
+        &df![
+            "a" => [5i64, 6],
+            "b" => ["u", "v"],
+        ]?,
+    )?;
+
+    let out = LazyFrame::scan_orc(&dir, Default::default())?.collect()?;
+    let expected = df![
+        "a" => [1i64, 2, 3, 4, 5, 6],
+        "b" => [Some("x"), None, Some("z"), Some("w"), Some("u"), Some("v")],
+        "year" => [2023i64, 2023, 2023, 2023, 2024, 2024],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = LazyFrame::scan_orc(&dir, Default::default())?
+        .filter(col("a").gt(lit(2i64)).and(col("year").eq(lit(2023i64))))
+        .select([col("b")])
+        .collect()?;
+    let expected = df!["b" => ["z", "w"]]?;
+    assert!(out.equals(&expected));
+
+    let args = ScanArgsOrc {
+        n_rows: Some(5),
+        row_index: Some(RowIndex {
+            name: Arc::from("idx"),
+            offset: 10,
+        }),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_orc(&dir, args)?
+        .filter(col("a").gt_eq(lit(4i64)))
+        .select([col("idx"), col("a"), col("year")])
+        .collect()?;
+    let expected = df![
+        "idx" => [13 as IdxSize, 14],
+        "a" => [4i64, 5],
+        "year" => [2023i64, 2024],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFrame::scan_orc(&dir, Default::default())?
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(6));
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "orc", feature = "streaming"))]
+fn test_sink_orc() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let path = std::env::temp_dir().join("polars_test_sink_orc.orc");
+
+    let df = df![
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+    let options = OrcWriterOptions {
+        maintain_order: true,
+        ..Default::default()
+    };
+    df.clone().lazy().sink_orc(&path, options)?;
+
+    let out = LazyFrame::scan_orc(&path, Default::default())?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
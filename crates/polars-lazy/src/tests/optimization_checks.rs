@@ -691,3 +691,147 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "cross_join")]
+fn test_cross_join_to_equi_join() -> PolarsResult<()> {
+    let left = df![
+        "k" => [1, 2, 3, 4],
+        "a" => ["w", "x", "y", "z"],
+    ]?
+    .lazy();
+    let right = df![
+        "k" => [2, 3, 3, 5],
+        "b" => [1.0, 2.0, 3.0, 4.0],
+    ]?
+    .lazy();
+
+    let predicate = col("k").eq(col("k_right")).and(col("b").gt(lit(1.5)));
+
+    // a cross join preserves its row order, so it is not planned as an inner join
+    let q = left
+        .clone()
+        .cross_join(right.clone(), None)
+        .filter(predicate.clone());
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::Join { options, .. } if matches!(options.args.how, JoinType::Cross)
+    )));
+
+    // unless it allows it: the equality becomes the join key; the other predicate
+    // remains a filter
+    let q = left
+        .join_builder()
+        .with(right)
+        .how(JoinType::Cross)
+        .allow_keyed_cross_join(true)
+        .finish()
+        .filter(predicate);
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| match lp {
+        IR::Join { options, .. } => matches!(options.args.how, JoinType::Inner),
+        IR::Filter { .. } => false,
+        _ => true,
+    }));
+
+    let out = q.clone().sort(["b"], Default::default()).collect()?;
+    let expected = q
+        .with_predicate_pushdown(false)
+        .sort(["b"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["k", "a", "k_right", "b"]);
+    assert!(out.equals(&expected));
+    assert_eq!(out.height(), 2);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "iejoin")]
+fn test_join_where_to_iejoin() -> PolarsResult<()> {
+    let windows = df![
+        "id" => [1, 2, 3, 4],
+        "start" => [0, 5, 10, 2],
+        "end" => [4, 9, 12, 2],
+    ]?
+    .lazy();
+    let events = df![
+        "id" => [10, 11, 12, 13, 14],
+        "ts" => [Some(1), Some(4), None, Some(8), Some(2)],
+    ]?
+    .lazy();
+
+    let check = |predicates: &[Expr], how: fn(&JoinType) -> bool, expected_height: usize| {
+        let q = windows.clone().join_where(events.clone(), predicates);
+        // the join is planned on the predicates, also without predicate pushdown
+        for q in [q.clone(), q.clone().with_predicate_pushdown(false)] {
+            let (mut expr_arena, mut lp_arena) = get_arenas();
+            let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+            assert!((&lp_arena).iter(lp).any(|(_, lp)| match lp {
+                IR::Join { options, .. } => how(&options.args.how),
+                _ => false,
+            }));
+        }
+
+        let predicate = predicates.iter().cloned().reduce(|acc, e| acc.and(e));
+        let sort_options = SortMultipleOptions::default();
+        let out = q.sort(["id", "id_right"], sort_options.clone()).collect()?;
+        let expected = windows
+            .clone()
+            .cross_join(events.clone(), None)
+            .filter(predicate.unwrap())
+            .with_predicate_pushdown(false)
+            .sort(["id", "id_right"], sort_options)
+            .collect()?;
+        assert!(out.equals(&expected), "{out}\n{expected}");
+        assert_eq!(out.height(), expected_height);
+        PolarsResult::Ok(())
+    };
+
+    // range join on two inequalities, written in either direction
+    check(
+        &[col("start").lt_eq(col("ts")), col("ts").lt(col("end"))],
+        |how| matches!(how, JoinType::IEJoin(o) if o.operator2.is_some()),
+        3,
+    )?;
+
+    // a single inequality; other predicates remain a filter or are pushed down
+    check(
+        &[
+            col("end").gt(col("ts")),
+            (col("id") + col("id_right")).neq(lit(12)),
+            col("start").gt_eq(lit(2)),
+        ],
+        |how| matches!(how, JoinType::IEJoin(o) if o.operator2.is_none()),
+        8,
+    )?;
+
+    // an equality is planned as an inner join
+    check(
+        &[col("start").eq(col("ts")), col("end").gt_eq(col("ts"))],
+        |how| matches!(how, JoinType::Inner),
+        1,
+    )?;
+
+    // a predicate that is not elementwise is evaluated on the Cartesian product, so no join
+    // keys are taken
+    check(
+        &[col("start").lt(col("ts")), col("ts").gt(col("ts").mean())],
+        |how| matches!(how, JoinType::Cross),
+        5,
+    )?;
+
+    // a user-written cross join and filter keeps the order of the cross join
+    let q = windows
+        .cross_join(events, None)
+        .filter(col("start").lt_eq(col("ts")).and(col("ts").lt(col("end"))));
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).any(|(_, lp)| matches!(
+        lp,
+        IR::Join { options, .. } if matches!(options.args.how, JoinType::Cross)
+    )));
+    Ok(())
+}
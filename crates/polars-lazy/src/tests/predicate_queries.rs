@@ -333,3 +333,30 @@ fn test_push_join_col_predicates_to_both_sides_semi_12565() -> PolarsResult<()>
     assert_eq!(out, expected);
     Ok(())
 }
+
+#[test]
+fn test_right_join_predicates_on_left_columns() -> PolarsResult<()> {
+    let df1 = df! {
+        "a" => [1, 2],
+        "b" => [Some(10), None],
+    }?;
+    let df2 = df! {
+        "a" => [1, 2, 3],
+        "b" => [Some(1), Some(2), None],
+    }?;
+    let q = df1
+        .lazy()
+        .right_join(df2.lazy(), col("a"), col("a"))
+        .filter(col("b").is_null())
+        .sort(["a"], Default::default());
+
+    // `b` is the column of the left table, which the right join fills with nulls
+    let out = q.collect()?;
+    let expected = df![
+        "b" => [None::<i32>, None],
+        "a" => [2, 3],
+        "b_right" => [Some(2), None],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+    Ok(())
+}
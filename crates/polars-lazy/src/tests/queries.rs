@@ -65,6 +65,66 @@ fn test_lazy_unpivot() {
     assert_eq!(out.shape(), (7, 3));
 }
 
+#[test]
+#[cfg(feature = "pivot")]
+fn test_lazy_pivot() -> PolarsResult<()> {
+    let df = df! {
+        "store" => ["a", "a", "b", "b", "c", "a"],
+        "item" => ["y", "x", "x", "z", "y", "y"],
+        "price" => [1, 2, 3, 4, 5, 6],
+    }?;
+    let expected = polars_ops::pivot::pivot_stable(
+        &df,
+        ["item"],
+        Some(["store"]),
+        Some(["price"]),
+        true,
+        Some(polars_ops::pivot::PivotAgg::Sum),
+        None,
+    )?;
+
+    let q = df.clone().lazy().pivot(
+        "item",
+        None,
+        ["store"],
+        ["price"],
+        PivotAggregation::Sum,
+        true,
+        None,
+    )?;
+    // The schema is known without running the pivot.
+    let schema = q.schema()?;
+    assert_eq!(
+        schema.iter_names().collect::<Vec<_>>(),
+        &["store", "x", "y", "z"]
+    );
+    assert!(q.collect()?.equals_missing(&expected));
+
+    // Predicates on the index are pushed down, values of `on` that aren't known are dropped.
+    let q = df
+        .lazy()
+        .pivot(
+            "item",
+            Some(Series::new("", ["z", "x", "w"])),
+            ["store"],
+            ["price"],
+            PivotAggregation::Count,
+            false,
+            None,
+        )?
+        .filter(col("store").neq(lit("c")));
+    assert!(predicate_at_scan(q.clone()));
+    let out = q.collect()?;
+    let expected = df! {
+        "store" => ["a", "b"],
+        "z" => [None, Some(1 as IdxSize)],
+        "x" => [Some(1 as IdxSize), Some(1)],
+        "w" => [None::<IdxSize>, None],
+    }?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_lazy_drop_nulls() {
     let df = df! {
@@ -1059,6 +1119,28 @@ fn test_arg_sort_multiple() -> PolarsResult<()> {
             SortMultipleOptions::default().with_order_descending_multi([true, false]),
         )])
         .collect()?;
+
+    // within groups
+    let df = df![
+        "grp" => ["a", "b", "a", "a", "b", "a", "b"],
+        "ts" => [3, 1, 1, 2, 3, 2, 2],
+        "id" => [1, 2, 3, 4, 5, 6, 7]
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            arg_sort_by([col("ts"), col("id")], SortMultipleOptions::default()).over([col("grp")]),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("ts")?.idx()?),
+        [1, 0, 2, 3, 2, 0, 1]
+            .iter()
+            .copied()
+            .map(Some)
+            .collect::<Vec<_>>()
+    );
     Ok(())
 }
 
@@ -1298,6 +1380,51 @@ fn test_sort_by() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_sort_by_empty_groups() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4, 5],
+        "b" => [1, 1, 1, 2, 2],
+        "c" => [2, 3, 1, 2, 1]
+    ]?;
+
+    // the filter leaves the second group empty
+    let predicate = col("c").gt(lit(1)).and(col("b").eq(lit(1)));
+    let out = df
+        .lazy()
+        .group_by_stable([col("b")])
+        .agg([
+            col("a")
+                .filter(predicate.clone())
+                .sort_by([col("c").filter(predicate.clone())], Default::default())
+                .alias("single_by"),
+            col("a")
+                .filter(predicate.clone())
+                .sort_by(
+                    [
+                        col("c").filter(predicate.clone()),
+                        col("a").filter(predicate),
+                    ],
+                    Default::default(),
+                )
+                .alias("multiple_by"),
+        ])
+        .collect()?;
+
+    for name in ["single_by", "multiple_by"] {
+        let lengths = out
+            .column(name)?
+            .list()?
+            .into_iter()
+            .map(|s| s.unwrap().len());
+        assert_eq!(lengths.collect::<Vec<_>>(), &[2, 0]);
+        let a = out.column(name)?.explode()?;
+        assert_eq!(Vec::from(a.i32().unwrap()), &[Some(1), Some(2), None]);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_filter_after_shift_in_groups() -> PolarsResult<()> {
     let df = fruits_cars();
@@ -1921,3 +2048,31 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_union_of_converted_plan() -> PolarsResult<()> {
+    let mut lf = df![
+        "a" => [1, 2, 3],
+        "b" => [4, 5, 6],
+    ]?
+    .lazy()
+    .filter(col("a").gt(lit(1)));
+
+    // Convert the plan, so that both inputs of the union refer to the same IR nodes,
+    // which must not be shared by the optimizations of either input.
+    let mut lp_arena = Arena::with_capacity(16);
+    let mut expr_arena = Arena::with_capacity(16);
+    lf.schema_with_arenas(&mut lp_arena, &mut expr_arena)?;
+    let q = concat(
+        [
+            lf.clone().select([col("a")]),
+            lf.select([col("b").alias("a")]),
+        ],
+        Default::default(),
+    )?;
+    q.set_cached_arena(lp_arena, expr_arena);
+
+    let out = q.collect()?;
+    assert!(out.equals(&df!["a" => [2, 3, 5, 6]]?));
+    Ok(())
+}
@@ -260,6 +260,109 @@ fn test_streaming_left_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_right_join() -> PolarsResult<()> {
+    let lf_left = df![
+        "a"=> [10, 18, 13, 9, 1, 13, 14, 12, 15, 11],
+        "b"=> [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]
+    ]?
+    .lazy();
+
+    let lf_right = df![
+        "a"=> [0, 0, 0, 3, 0, 1, 3, 3, 3, 1, 4, 4, 2, 1, 1, 3, 1, 4, 2, 2],
+        "b"=> [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]
+    ]?
+    .lazy();
+
+    let q = lf_left
+        .clone()
+        .right_join(lf_right.clone(), col("a"), col("a"));
+    assert_streaming_with_default(q, true, false);
+
+    let q = lf_left.join(
+        lf_right,
+        [col("a")],
+        [col("a")],
+        JoinArgs::new(JoinType::Right).with_coalesce(JoinCoalesce::KeepColumns),
+    );
+    assert_streaming_with_default(q, true, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_streaming_asof_join() -> PolarsResult<()> {
+    use polars_ops::frame::{AsOfOptions, AsofStrategy};
+
+    // Both tables consist of several chunks, so the join crosses chunk boundaries
+    // regardless of the number of threads.
+    let chunked = |df: DataFrame, n_chunks: usize| -> PolarsResult<DataFrame> {
+        let chunk_size = df.height() / n_chunks;
+        let mut out = df.slice(0, chunk_size);
+        for i in 1..n_chunks {
+            out.vstack_mut(&df.slice((i * chunk_size) as i64, chunk_size))?;
+        }
+        Ok(out)
+    };
+
+    let n = 2000i64;
+    let df_left = chunked(
+        df![
+            "time" => (0..n).map(|i| i * 3).collect::<Vec<_>>(),
+            "ticker" => (0..n).map(|i| ["a", "b", "c"][(i % 3) as usize]).collect::<Vec<_>>(),
+        ]?,
+        8,
+    )?;
+    assert_eq!(df_left.n_chunks(), 8);
+    let lf_left = df_left.lazy();
+
+    // The right table has duplicate times and lacks ticker "c".
+    let n = 1000i64;
+    let df_right = chunked(
+        df![
+            "time" => (0..n).map(|i| i * 11 / 2).collect::<Vec<_>>(),
+            "ticker" => (0..n).map(|i| ["a", "b"][(i % 2) as usize]).collect::<Vec<_>>(),
+            "bid" => (0..n).collect::<Vec<_>>(),
+        ]?,
+        4,
+    )?;
+    let lf_right = df_right.lazy();
+
+    let asof_join = |options: AsOfOptions| {
+        lf_left
+            .clone()
+            .join_builder()
+            .with(lf_right.clone())
+            .left_on([col("time")])
+            .right_on([col("time")])
+            .how(JoinType::AsOf(options))
+            .finish()
+    };
+
+    for strategy in [
+        AsofStrategy::Backward,
+        AsofStrategy::Forward,
+        AsofStrategy::Nearest,
+    ] {
+        for allow_eq in [true, false] {
+            let options = AsOfOptions {
+                strategy,
+                allow_eq,
+                ..Default::default()
+            };
+            assert_streaming_with_default(asof_join(options.clone()), true, false);
+
+            let options = AsOfOptions {
+                left_by: Some(vec!["ticker".into()]),
+                right_by: Some(vec!["ticker".into()]),
+                ..options
+            };
+            assert_streaming_with_default(asof_join(options), true, false);
+        }
+    }
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "cross_join")]
 fn test_streaming_slice() -> PolarsResult<()> {
@@ -19,8 +19,11 @@ impl Executor for ExternalContext {
             .iter_mut()
             .map(|e| e.execute(state))
             .collect::<PolarsResult<Vec<_>>>()?;
-        state.ext_contexts = Arc::new(contexts);
+        let contexts = Arc::new(contexts);
+        state.ext_contexts = contexts.clone();
         let df = self.input.execute(state)?;
+        // a nested context in the input may have replaced ours.
+        state.ext_contexts = contexts;
 
         Ok(df)
     }
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::avro::AvroReader;
+use polars_io::predicates::apply_predicate;
+use polars_io::SerReader;
+
+use super::*;
+
+pub struct AvroExec {
+    pub(crate) paths: Arc<[PathBuf]>,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) file_options: FileScanOptions,
+}
+
+impl AvroExec {
+    fn read(&mut self) -> PolarsResult<DataFrame> {
+        let columns = self
+            .file_options
+            .with_columns
+            .as_deref()
+            // Interpret selecting no columns as selecting all columns.
+            .filter(|columns| !columns.is_empty())
+            .map(|columns| columns.to_vec());
+        let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let mut n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
+        let mut row_index = self.file_options.row_index.clone();
+
+        let mut dfs = Vec::with_capacity(self.paths.len());
+        for path in self.paths.iter() {
+            // Always read the first file, so that the output has the projected schema.
+            if n_rows == Some(0) && !dfs.is_empty() {
+                break;
+            }
+            let file = polars_utils::open_file(path)?;
+            let mut df = AvroReader::new(file)
+                .with_columns(columns.clone())
+                .with_n_rows(n_rows)
+                .with_row_index(row_index.clone())
+                // We rechunk at the end to avoid rechunking multiple times in the
+                // case of reading multiple files.
+                .set_rechunk(false)
+                .finish()?;
+
+            if let Some(n_rows) = n_rows.as_mut() {
+                *n_rows -= df.height();
+            }
+            if let Some(row_index) = row_index.as_mut() {
+                row_index.offset += df.height() as IdxSize;
+            }
+            // The predicate is applied after the slice and the row index.
+            apply_predicate(&mut df, predicate.as_deref(), true)?;
+            dfs.push(df);
+        }
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.file_options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+impl Executor for AvroExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.has_node_timer() {
+            let mut ids = vec![self.paths[0].to_string_lossy().into()];
+            if self.predicate.is_some() {
+                ids.push("predicate".into())
+            }
+            let name = comma_delimited("avro".to_string(), &ids);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state.record(|| self.read(), profile_name)
+    }
+}
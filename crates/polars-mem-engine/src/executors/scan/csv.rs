@@ -1,3 +1,4 @@
+use std::io::BufReader;
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -5,6 +6,7 @@ use polars_core::config;
 use polars_core::utils::{
     accumulate_dataframes_vertical, accumulate_dataframes_vertical_unchecked,
 };
+use polars_io::compression::is_compressed_reader;
 
 use super::*;
 
@@ -61,31 +63,36 @@ impl CsvExec {
 
         let finish_read =
             |i: usize, options: CsvReadOptions, predicate: Option<Arc<dyn PhysicalIoExpr>>| {
-                if run_async {
+                let mut file = if run_async {
                     #[cfg(feature = "cloud")]
                     {
-                        options
-                            .into_reader_with_file_handle(
-                                polars_io::file_cache::FILE_CACHE
-                                    .get_entry(self.paths.get(i).unwrap().to_str().unwrap())
-                                    // Safety: This was initialized by schema inference.
-                                    .unwrap()
-                                    .try_open_assume_latest()?,
-                            )
-                            ._with_predicate(predicate.clone())
-                            .finish()
+                        polars_io::file_cache::FILE_CACHE
+                            .get_entry(self.paths.get(i).unwrap().to_str().unwrap())
+                            // Safety: This was initialized by schema inference.
+                            .unwrap()
+                            .try_open_assume_latest()?
                     }
                     #[cfg(not(feature = "cloud"))]
                     {
                         panic!("required feature `cloud` is not enabled")
                     }
                 } else {
-                    options
-                        .try_into_reader_with_file_path(Some(self.paths.get(i).unwrap().clone()))
+                    polars_utils::open_file(resolve_homedir(self.paths.get(i).unwrap()))?
+                };
+
+                // Compressed files are decompressed and parsed in batches.
+                if is_compressed_reader(&mut file)? {
+                    return options
+                        .try_into_compressed_batched_reader(BufReader::new(file))?
                         .unwrap()
-                        ._with_predicate(predicate.clone())
-                        .finish()
+                        .with_predicate(predicate)
+                        .finish();
                 }
+
+                options
+                    .into_reader_with_file_handle(file)
+                    ._with_predicate(predicate)
+                    .finish()
             };
 
         let mut df = if n_rows.is_some()
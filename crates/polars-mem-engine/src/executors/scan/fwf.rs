@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::fwf::FixedWidthReadOptions;
+use polars_io::predicates::apply_predicate;
+use polars_io::utils::resolve_homedir;
+use polars_io::SerReader;
+
+use super::*;
+
+pub struct FixedWidthExec {
+    pub(crate) paths: Arc<[PathBuf]>,
+    pub(crate) file_info: FileInfo,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) options: FixedWidthReadOptions,
+    pub(crate) file_options: FileScanOptions,
+}
+
+impl FixedWidthExec {
+    fn read(&mut self) -> PolarsResult<DataFrame> {
+        let with_columns = self
+            .file_options
+            .with_columns
+            .clone()
+            // Interpret selecting no columns as selecting all columns.
+            .filter(|columns| !columns.is_empty());
+        let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let mut n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
+        let mut row_index = self.file_options.row_index.clone();
+
+        let options_base = self
+            .options
+            .clone()
+            .with_schema(Some(
+                self.file_info.reader_schema.clone().unwrap().unwrap_right(),
+            ))
+            .with_columns(with_columns)
+            // We rechunk at the end to avoid rechunking multiple times in the
+            // case of reading multiple files.
+            .with_rechunk(false)
+            .with_path::<&str>(None);
+
+        let mut dfs = Vec::with_capacity(self.paths.len());
+        for path in self.paths.iter() {
+            // Always read the first file, so that the output has the projected schema.
+            if n_rows == Some(0) && !dfs.is_empty() {
+                break;
+            }
+            let file = polars_utils::open_file(resolve_homedir(path))?;
+            let mut df = options_base
+                .clone()
+                .with_n_rows(n_rows)
+                .with_row_index(row_index.clone())
+                .into_reader_with_file_handle(file)
+                .finish()?;
+
+            // The slice and the row index are applied before the predicate.
+            if let Some(n_rows) = n_rows.as_mut() {
+                *n_rows = n_rows.saturating_sub(df.height());
+            }
+            if let Some(row_index) = row_index.as_mut() {
+                row_index.offset += df.height() as IdxSize;
+            }
+            apply_predicate(&mut df, predicate.as_deref(), true)?;
+            dfs.push(df);
+        }
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.file_options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+impl Executor for FixedWidthExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.has_node_timer() {
+            let mut ids = vec![self.paths[0].to_string_lossy().into()];
+            if self.predicate.is_some() {
+                ids.push("predicate".into())
+            }
+            let name = comma_delimited("fwf".to_string(), &ids);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state.record(|| self.read(), profile_name)
+    }
+}
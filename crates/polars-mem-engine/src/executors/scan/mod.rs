@@ -1,9 +1,15 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
+#[cfg(feature = "fwf")]
+mod fwf;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "json")]
 mod ndjson;
+#[cfg(feature = "orc")]
+mod orc;
 #[cfg(feature = "parquet")]
 mod parquet;
 
@@ -11,12 +17,18 @@ mod parquet;
 mod support;
 use std::mem;
 
+#[cfg(feature = "avro")]
+pub(crate) use avro::AvroExec;
 #[cfg(feature = "csv")]
 pub(crate) use csv::CsvExec;
+#[cfg(feature = "fwf")]
+pub(crate) use fwf::FixedWidthExec;
 #[cfg(feature = "ipc")]
 pub(crate) use ipc::IpcExec;
 #[cfg(feature = "json")]
 pub(crate) use ndjson::JsonExec;
+#[cfg(feature = "orc")]
+pub(crate) use orc::OrcExec;
 #[cfg(feature = "parquet")]
 pub(crate) use parquet::ParquetExec;
 #[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
@@ -0,0 +1,92 @@
+use std::path::PathBuf;
+
+use hive::HivePartitions;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_io::orc::{OrcOptions, OrcReader};
+use polars_io::utils::materialize_projection;
+use polars_io::SerReader;
+
+use super::*;
+
+pub struct OrcExec {
+    pub(crate) paths: Arc<[PathBuf]>,
+    pub(crate) file_info: FileInfo,
+    pub(crate) hive_parts: Option<Arc<[HivePartitions]>>,
+    pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub(crate) options: OrcOptions,
+    pub(crate) file_options: FileScanOptions,
+}
+
+impl OrcExec {
+    fn read(&mut self) -> PolarsResult<DataFrame> {
+        let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let mut n_rows = self.file_options.n_rows;
+        let mut row_index = self.file_options.row_index.clone();
+
+        let mut dfs = Vec::with_capacity(self.paths.len());
+        for (i, path) in self.paths.iter().enumerate() {
+            // Always read the first file, so that the output has the projected schema.
+            if n_rows == Some(0) && !dfs.is_empty() {
+                break;
+            }
+            let hive_partitions = self
+                .hive_parts
+                .as_ref()
+                .map(|x| x[i].materialize_partition_columns());
+            let projection = materialize_projection(
+                self.file_options.with_columns.as_deref(),
+                &self.file_info.schema,
+                hive_partitions.as_deref(),
+                row_index.is_some(),
+            );
+
+            let file = polars_utils::open_file(path)?;
+            let mut reader = OrcReader::new(file);
+            let num_rows = reader.num_rows()?;
+            let df = reader
+                .with_projection(projection)
+                .with_n_rows(n_rows)
+                .with_row_index(row_index.clone())
+                .with_predicate(predicate.clone())
+                .use_statistics(self.options.use_statistics)
+                .with_hive_partition_columns(hive_partitions)
+                // We rechunk at the end to avoid rechunking multiple times in the
+                // case of reading multiple files.
+                .set_rechunk(false)
+                .finish()?;
+
+            // The slice and the row index are applied before the predicate, so they advance
+            // by the number of rows in the file.
+            if let Some(n_rows) = n_rows.as_mut() {
+                *n_rows = n_rows.saturating_sub(num_rows);
+            }
+            if let Some(row_index) = row_index.as_mut() {
+                row_index.offset += num_rows as IdxSize;
+            }
+            dfs.push(df);
+        }
+
+        let mut df = accumulate_dataframes_vertical(dfs)?;
+        if self.file_options.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+impl Executor for OrcExec {
+    fn execute(&mut self, state: &mut ExecutionState) -> PolarsResult<DataFrame> {
+        let profile_name = if state.has_node_timer() {
+            let mut ids = vec![self.paths[0].to_string_lossy().into()];
+            if self.predicate.is_some() {
+                ids.push("predicate".into())
+            }
+            let name = comma_delimited("orc".to_string(), &ids);
+            Cow::Owned(name)
+        } else {
+            Cow::Borrowed("")
+        };
+
+        state.record(|| self.read(), profile_name)
+    }
+}
@@ -100,6 +100,7 @@ impl ParquetExec {
                         .read_parallel(parallel)
                         .set_low_memory(self.options.low_memory)
                         .use_statistics(self.options.use_statistics)
+                        .allow_missing_columns(self.options.allow_missing_columns)
                         .set_rechunk(false)
                         .with_hive_partition_columns(hive_partitions);
 
@@ -174,6 +175,7 @@ impl ParquetExec {
         let first_metadata = &self.metadata;
         let cloud_options = self.cloud_options.as_ref();
         let with_columns = self.file_options.with_columns.as_ref().map(|v| v.as_ref());
+        let allow_missing_columns = self.options.allow_missing_columns;
 
         let mut result = vec![];
         let batch_size = get_file_prefetch_size();
@@ -209,6 +211,9 @@ impl ParquetExec {
                 // use the cached one as this saves a cloud call
                 let (metadata, schema) = if first_file {
                     (first_metadata.clone(), Some((*first_schema).clone()))
+                } else if allow_missing_columns {
+                    // The readers conform the files to the schema of the scan.
+                    (None, Some((*first_schema).clone()))
                 } else {
                     (None, None)
                 };
@@ -219,9 +224,10 @@ impl ParquetExec {
                     schema,
                     metadata,
                 )
-                .await?;
+                .await?
+                .allow_missing_columns(allow_missing_columns);
 
-                if !first_file {
+                if !first_file && !allow_missing_columns {
                     let schema = reader.schema().await?;
                     check_projected_arrow_schema(
                         first_schema.as_ref(),
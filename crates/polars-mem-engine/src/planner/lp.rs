@@ -172,6 +172,9 @@ fn create_physical_plan_impl(
             SinkType::Cloud { .. } => {
                 polars_bail!(InvalidOperation: "cloud sink not supported in standard engine.")
             },
+            SinkType::Partition { .. } => {
+                polars_bail!(InvalidOperation: "partitioned sink not supported in standard engine.")
+            },
         },
         Union { inputs, options } => {
             let inputs = inputs
@@ -299,6 +302,29 @@ fn create_physical_plan_impl(
                     file_info,
                     predicate,
                 ))),
+                #[cfg(feature = "avro")]
+                FileScan::Avro => Ok(Box::new(executors::AvroExec {
+                    paths,
+                    predicate,
+                    file_options,
+                })),
+                #[cfg(feature = "orc")]
+                FileScan::Orc { options } => Ok(Box::new(executors::OrcExec {
+                    paths,
+                    file_info,
+                    hive_parts,
+                    predicate,
+                    options,
+                    file_options,
+                })),
+                #[cfg(feature = "fwf")]
+                FileScan::FixedWidth { options } => Ok(Box::new(executors::FixedWidthExec {
+                    paths,
+                    file_info,
+                    predicate,
+                    options,
+                    file_options,
+                })),
                 FileScan::Anonymous { function, .. } => {
                     Ok(Box::new(executors::AnonymousScanExec {
                         function,
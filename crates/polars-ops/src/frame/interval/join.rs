@@ -0,0 +1,254 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+
+use polars_core::prelude::*;
+use polars_core::utils::slice_offsets;
+
+use super::{Interval, IntervalColumns, Intervals};
+use crate::frame::join::_finish_join;
+
+/// How the interval of a left row must relate to that of a right row for the rows to be
+/// joined.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IntervalJoinType {
+    /// The intervals share at least one point: `left.start < right.end` and
+    /// `right.start < left.end`. Empty intervals overlap nothing.
+    #[default]
+    Overlap,
+    /// The left interval contains the right interval: `left.start <= right.start` and
+    /// `right.end <= left.end`.
+    Contains,
+    /// The left interval lies within the right interval: `right.start <= left.start` and
+    /// `left.end <= right.end`.
+    Within,
+}
+
+/// Merges the intervals of two tables (both sorted) by group and start, where the first
+/// table comes first on ties. Yields whether an interval is of the second table.
+fn merge_by_start<'a>(
+    a: &'a [Interval],
+    b: &'a [Interval],
+) -> impl Iterator<Item = (bool, &'a Interval)> {
+    let mut a = a.iter().peekable();
+    let mut b = b.iter().peekable();
+    std::iter::from_fn(move || {
+        let is_b = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) => (y.group, y.start) < (x.group, x.start),
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+        if is_b {
+            b.next().map(|iv| (true, iv))
+        } else {
+            a.next().map(|iv| (false, iv))
+        }
+    })
+}
+
+/// Sweep over the intervals in order of their start, keeping the intervals of both tables
+/// that haven't ended yet. Those of the other table overlap an interval when it starts.
+fn overlap_join(left: &[Interval], right: &[Interval]) -> Vec<(IdxSize, IdxSize)> {
+    let mut out = vec![];
+    // (end, row) of the active intervals, with the first to end on top
+    let mut active_left: BinaryHeap<Reverse<(IdxSize, IdxSize)>> = BinaryHeap::new();
+    let mut active_right = BinaryHeap::new();
+    let mut group = None;
+    for (is_right, iv) in merge_by_start(left, right) {
+        if iv.group != group {
+            active_left.clear();
+            active_right.clear();
+            group = iv.group;
+        }
+        let (active, other) = if is_right {
+            (&mut active_right, &mut active_left)
+        } else {
+            (&mut active_left, &mut active_right)
+        };
+        while other
+            .peek()
+            .is_some_and(|Reverse((end, _))| *end <= iv.start)
+        {
+            other.pop();
+        }
+        out.extend(other.iter().map(|&Reverse((_, row))| {
+            if is_right {
+                (row, iv.row)
+            } else {
+                (iv.row, row)
+            }
+        }));
+        active.push(Reverse((iv.end, iv.row)));
+    }
+    out
+}
+
+/// Sweep over the intervals in order of their start, keeping the outer intervals that
+/// started so far by their end. Those that end at or after the end of an inner interval
+/// contain it. Returns (outer row, inner row) pairs.
+fn contains_join(outer: &[Interval], inner: &[Interval]) -> Vec<(IdxSize, IdxSize)> {
+    let mut out = vec![];
+    let mut started = BTreeSet::<(IdxSize, IdxSize)>::new();
+    let mut group = None;
+    for (is_inner, iv) in merge_by_start(outer, inner) {
+        if iv.group != group {
+            started.clear();
+            group = iv.group;
+        }
+        if is_inner {
+            out.extend(started.range((iv.end, 0)..).map(|&(_, row)| (row, iv.row)));
+        } else {
+            started.insert((iv.end, iv.row));
+        }
+    }
+    out
+}
+
+pub(super) fn interval_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &IntervalColumns,
+    right_on: &IntervalColumns,
+    how: IntervalJoinType,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let intervals = Intervals::new(&[(left, left_on), (right, right_on)])?;
+    let (left_iv, right_iv) = (intervals.matchable(0), intervals.matchable(1));
+
+    let mut pairs = match how {
+        IntervalJoinType::Overlap => {
+            let non_empty = |ivs: &[Interval]| {
+                ivs.iter()
+                    .filter(|iv| !iv.is_empty())
+                    .copied()
+                    .collect::<Vec<_>>()
+            };
+            overlap_join(&non_empty(left_iv), &non_empty(right_iv))
+        },
+        IntervalJoinType::Contains => contains_join(left_iv, right_iv),
+        IntervalJoinType::Within => contains_join(right_iv, left_iv)
+            .into_iter()
+            .map(|(right_row, left_row)| (left_row, right_row))
+            .collect(),
+    };
+    pairs.sort_unstable();
+
+    let pairs = match slice {
+        Some((offset, len)) => {
+            let (offset, len) = slice_offsets(offset, len, pairs.len());
+            &pairs[offset..offset + len]
+        },
+        None => &pairs[..],
+    };
+    let (left_idx, right_idx): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+
+    // SAFETY: the indices are rows of the tables
+    let (df_left, df_right) = unsafe {
+        (
+            left._take_unchecked_slice(&left_idx, true),
+            right._take_unchecked_slice(&right_idx, true),
+        )
+    };
+    _finish_join(df_left, df_right, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_utils::total_ord::TotalOrd;
+
+    use super::*;
+    use crate::frame::interval::IntervalOps;
+
+    #[test]
+    fn test_interval_join_matches_nested_loop() {
+        let left = df! {
+            "key" => [Some("a"), Some("a"), Some("b"), None, Some("a"), Some("b"), Some("a"), Some("a")],
+            "start" => [Some(1.0), Some(4.0), Some(2.0), Some(0.0), None, Some(5.0), Some(3.0), Some(6.0)],
+            "end" => [Some(5.0), Some(4.0), Some(7.0), Some(9.0), Some(3.0), Some(6.0), Some(8.0), Some(2.0)],
+        }
+        .unwrap();
+        let right = df! {
+            "key" => [Some("a"), Some("b"), Some("a"), Some("a"), None, Some("b"), Some("a")],
+            "start" => [Some(0i32), Some(2), Some(4), Some(5), Some(1), Some(6), Some(3)],
+            "end" => [Some(4i32), Some(6), Some(4), Some(9), Some(2), None, Some(8)],
+        }
+        .unwrap();
+        let rows = |df: &DataFrame| {
+            let df = df.select(["key", "start", "end"]).unwrap();
+            let key = df.column("key").unwrap().str().unwrap();
+            let start = df
+                .column("start")
+                .unwrap()
+                .cast(&DataType::Float64)
+                .unwrap();
+            let end = df.column("end").unwrap().cast(&DataType::Float64).unwrap();
+            let (start, end) = (start.f64().unwrap(), end.f64().unwrap());
+            key.iter()
+                .zip(start)
+                .zip(end)
+                .map(|((key, start), end)| Some((key.map(str::to_string), start?, end?)))
+                .collect::<Vec<_>>()
+        };
+        let relates = |how: IntervalJoinType, (ls, le): (f64, f64), (rs, re): (f64, f64)| {
+            if le.tot_lt(&ls) || re.tot_lt(&rs) {
+                return false;
+            }
+            match how {
+                IntervalJoinType::Overlap => {
+                    ls.tot_lt(&le) && rs.tot_lt(&re) && ls.tot_lt(&re) && rs.tot_lt(&le)
+                },
+                IntervalJoinType::Contains => ls.tot_le(&rs) && re.tot_le(&le),
+                IntervalJoinType::Within => rs.tot_le(&ls) && le.tot_le(&re),
+            }
+        };
+
+        let on = IntervalColumns::new("start", "end");
+        for by in [false, true] {
+            let on = if by {
+                on.clone().with_by(["key"])
+            } else {
+                on.clone()
+            };
+            for how in [
+                IntervalJoinType::Overlap,
+                IntervalJoinType::Contains,
+                IntervalJoinType::Within,
+            ] {
+                let mut expected = vec![];
+                for (i, l) in rows(&left).into_iter().enumerate() {
+                    for (j, r) in rows(&right).into_iter().enumerate() {
+                        let (Some((lk, ls, le)), Some((rk, rs, re))) = (&l, r) else {
+                            continue;
+                        };
+                        let same_key = lk.is_some() && *lk == rk;
+                        if (!by || same_key) && relates(how, (*ls, *le), (rs, re)) {
+                            expected.push((i as i32, j as i32));
+                        }
+                    }
+                }
+
+                let out = left
+                    .with_row_index("i", None)
+                    .unwrap()
+                    .interval_join(
+                        &right.with_row_index("j", None).unwrap(),
+                        &on,
+                        &on,
+                        how,
+                        None,
+                        None,
+                    )
+                    .unwrap();
+                let i = out.column("i").unwrap().cast(&DataType::Int32).unwrap();
+                let j = out.column("j").unwrap().cast(&DataType::Int32).unwrap();
+                let actual = i
+                    .i32()
+                    .unwrap()
+                    .into_no_null_iter()
+                    .zip(j.i32().unwrap().into_no_null_iter())
+                    .collect::<Vec<_>>();
+                assert_eq!(actual, expected, "{how:?} by: {by}");
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+mod join;
+mod set_ops;
+
+pub use join::IntervalJoinType;
+use polars_core::prelude::sort::arg_sort_multiple::encode_rows_vertical_par_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use smartstring::alias::String as SmartString;
+
+use super::join::dense_ranks;
+use super::IntoDf;
+
+/// The columns that hold the half-open intervals `[start, end)` of a table.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalColumns {
+    pub start: SmartString,
+    pub end: SmartString,
+    /// Only the intervals of rows with equal keys in these columns are related.
+    pub by: Vec<SmartString>,
+}
+
+impl IntervalColumns {
+    pub fn new(start: &str, end: &str) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+            by: vec![],
+        }
+    }
+
+    pub fn with_by<I, S>(mut self, by: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.by = by.into_iter().map(|s| s.as_ref().into()).collect();
+        self
+    }
+}
+
+/// The interval of a row, of which the bounds are replaced by their rank among the bounds
+/// of all tables involved and the `by` keys by a group id.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Interval {
+    /// `None` if any of the `by` keys is null.
+    group: Option<IdxSize>,
+    start: IdxSize,
+    end: IdxSize,
+    row: IdxSize,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// The intervals of one or more tables, in a form in which they can be compared.
+struct Intervals {
+    /// The bounds of all tables in their supertype: the starts of the first table, its
+    /// ends, the starts of the second table, etc.
+    bounds: Series,
+    /// The position of the bounds of every table in `bounds`.
+    offsets: Vec<usize>,
+    heights: Vec<usize>,
+    /// The intervals of every table, sorted by group, start and end. Rows with a null
+    /// bound or with an end before their start have no interval.
+    tables: Vec<Vec<Interval>>,
+}
+
+impl Intervals {
+    fn new(tables: &[(&DataFrame, &IntervalColumns)]) -> PolarsResult<Self> {
+        let n_by = tables[0].1.by.len();
+        polars_ensure!(
+            tables.iter().all(|(_, cols)| cols.by.len() == n_by),
+            ComputeError: "the interval operation expects as many 'by' columns on both sides"
+        );
+
+        let mut dtype = DataType::Null;
+        for (df, cols) in tables {
+            for name in [&cols.start, &cols.end] {
+                dtype = try_get_supertype(&dtype, df.column(name)?.dtype())?;
+            }
+        }
+        polars_ensure!(
+            !dtype.is_nested() && !dtype.is_categorical() && !dtype.is_enum(),
+            InvalidOperation: "intervals with bounds of type {} are not supported", dtype
+        );
+        let mut bounds = Series::new_empty("", &dtype);
+        let mut offsets = Vec::with_capacity(tables.len());
+        for (df, cols) in tables {
+            offsets.push(bounds.len());
+            bounds.append(&df.column(&cols.start)?.cast(&dtype)?)?;
+            bounds.append(&df.column(&cols.end)?.cast(&dtype)?)?;
+        }
+        let bounds = bounds.rechunk();
+        let ranks = dense_ranks(&bounds)?;
+
+        let heights = tables.iter().map(|(df, _)| df.height()).collect::<Vec<_>>();
+        let groups = group_ids(tables)?;
+        let mut group_offset = 0;
+        let mut intervals = Vec::with_capacity(tables.len());
+        for (&offset, &height) in offsets.iter().zip(&heights) {
+            let groups = &groups[group_offset..group_offset + height];
+            let mut table = (0..height)
+                .filter_map(|row| {
+                    let start = ranks[offset + row]?;
+                    let end = ranks[offset + height + row]?;
+                    (start <= end).then_some(Interval {
+                        group: groups[row],
+                        start,
+                        end,
+                        row: row as IdxSize,
+                    })
+                })
+                .collect::<Vec<_>>();
+            table.sort_unstable();
+            intervals.push(table);
+            group_offset += height;
+        }
+
+        Ok(Self {
+            bounds,
+            offsets,
+            heights,
+            tables: intervals,
+        })
+    }
+
+    /// The position of the start of a row of a table in `bounds`.
+    fn start_idx(&self, table: usize, row: IdxSize) -> IdxSize {
+        (self.offsets[table] + row as usize) as IdxSize
+    }
+
+    /// The position of the end of a row of a table in `bounds`.
+    fn end_idx(&self, table: usize, row: IdxSize) -> IdxSize {
+        (self.offsets[table] + self.heights[table] + row as usize) as IdxSize
+    }
+
+    /// The intervals of a table that can relate to those of another table, i.e. those
+    /// without null `by` keys.
+    fn matchable(&self, table: usize) -> &[Interval] {
+        let intervals = &self.tables[table];
+        &intervals[intervals.partition_point(|iv| iv.group.is_none())..]
+    }
+}
+
+/// The id of the group of `by` keys of every row of all tables (the first table first),
+/// where equal keys get the same id. Rows with a null key have no group.
+fn group_ids(tables: &[(&DataFrame, &IntervalColumns)]) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let height = tables.iter().map(|(df, _)| df.height()).sum::<usize>();
+    let n_by = tables[0].1.by.len();
+    if n_by == 0 {
+        return Ok(vec![Some(0); height]);
+    }
+
+    let by = (0..n_by)
+        .map(|i| {
+            let columns = tables
+                .iter()
+                .map(|(df, cols)| df.column(&cols.by[i]))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut dtype = DataType::Null;
+            for s in &columns {
+                dtype = try_get_supertype(&dtype, s.dtype())?;
+            }
+            // Categoricals of different tables don't share their encoding.
+            if dtype.is_categorical() || dtype.is_enum() {
+                dtype = DataType::String;
+            }
+            let mut out = Series::new_empty("", &dtype);
+            for s in columns {
+                out.append(&s.cast(&dtype)?)?;
+            }
+            Ok(out)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let valid = by
+        .iter()
+        .map(|s| s.is_not_null())
+        .reduce(|acc, valid| &acc & &valid)
+        .unwrap();
+    let rows = encode_rows_vertical_par_unordered(&by)?;
+
+    let mut ids = PlHashMap::new();
+    Ok(rows
+        .iter()
+        .zip(valid.iter())
+        .map(|(row, valid)| {
+            if valid != Some(true) {
+                return None;
+            }
+            let n_groups = ids.len() as IdxSize;
+            Some(*ids.entry(row.unwrap()).or_insert(n_groups))
+        })
+        .collect())
+}
+
+/// Operations on the half-open intervals `[start, end)` of the rows of a table, such as
+/// joining the intervals of two tables that overlap.
+///
+/// All operations sort the intervals and sweep over them, rather than comparing every
+/// interval with every other one. Rows with a null bound or with an end before their
+/// start have no interval. Rows with a null `by` key never relate to the rows of another
+/// table, but form a group of their own when clustering the intervals of a single table.
+pub trait IntervalOps: IntoDf {
+    /// Join the rows of which the intervals relate as given by `how`. The output is ordered
+    /// by the rows of this table and then by those of `other`.
+    fn interval_join(
+        &self,
+        other: &DataFrame,
+        left_on: &IntervalColumns,
+        right_on: &IntervalColumns,
+        how: IntervalJoinType,
+        suffix: Option<&str>,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        join::interval_join(self.to_df(), other, left_on, right_on, how, suffix, slice)
+    }
+
+    /// Assign every row the id of its cluster of overlapping intervals, such that an
+    /// interval is in the cluster of the (sorted) intervals before it if it starts before
+    /// the end of that cluster. Ids are ordered by group and start; rows without an
+    /// interval get a null id.
+    fn cluster_intervals(&self, on: &IntervalColumns) -> PolarsResult<IdxCa> {
+        set_ops::cluster_intervals(self.to_df(), on)
+    }
+
+    /// Merge the clusters of overlapping intervals (see [`IntervalOps::cluster_intervals`])
+    /// into a table of the `by` keys, start and end of every cluster.
+    fn merge_intervals(&self, on: &IntervalColumns) -> PolarsResult<DataFrame> {
+        set_ops::merge_intervals(self.to_df(), on)
+    }
+
+    /// The parts of the intervals of this table that are covered by the intervals of
+    /// `other`, with one row per contiguous part. The other columns are those of the rows
+    /// of this table.
+    fn interval_intersect(
+        &self,
+        other: &DataFrame,
+        left_on: &IntervalColumns,
+        right_on: &IntervalColumns,
+    ) -> PolarsResult<DataFrame> {
+        set_ops::interval_intersect(self.to_df(), other, left_on, right_on)
+    }
+
+    /// The parts of the intervals of this table that are not covered by the intervals of
+    /// `other`, with one row per contiguous part. The other columns are those of the rows
+    /// of this table.
+    fn interval_subtract(
+        &self,
+        other: &DataFrame,
+        left_on: &IntervalColumns,
+        right_on: &IntervalColumns,
+    ) -> PolarsResult<DataFrame> {
+        set_ops::interval_subtract(self.to_df(), other, left_on, right_on)
+    }
+}
+
+impl IntervalOps for DataFrame {}
@@ -0,0 +1,254 @@
+use polars_core::prelude::*;
+
+use super::{Interval, IntervalColumns, Intervals};
+
+/// Splits the (sorted) intervals into clusters: an interval is in the cluster of the
+/// intervals of its group before it if it starts before the end of that cluster.
+fn clusters(intervals: &[Interval]) -> Vec<&[Interval]> {
+    let mut clusters = vec![];
+    let mut begin = 0;
+    let mut end = 0;
+    for (i, iv) in intervals.iter().enumerate() {
+        if i > begin && (iv.group != intervals[begin].group || iv.start >= end) {
+            clusters.push(&intervals[begin..i]);
+            begin = i;
+        }
+        end = if begin == i { iv.end } else { end.max(iv.end) };
+    }
+    if begin < intervals.len() {
+        clusters.push(&intervals[begin..]);
+    }
+    clusters
+}
+
+pub(super) fn cluster_intervals(df: &DataFrame, on: &IntervalColumns) -> PolarsResult<IdxCa> {
+    let intervals = Intervals::new(&[(df, on)])?;
+    let mut ids = vec![None; df.height()];
+    for (id, cluster) in clusters(&intervals.tables[0]).into_iter().enumerate() {
+        for iv in cluster {
+            ids[iv.row as usize] = Some(id as IdxSize);
+        }
+    }
+    Ok(IdxCa::from_iter_options("cluster", ids.into_iter()))
+}
+
+pub(super) fn merge_intervals(df: &DataFrame, on: &IntervalColumns) -> PolarsResult<DataFrame> {
+    let intervals = Intervals::new(&[(df, on)])?;
+    let clusters = clusters(&intervals.tables[0]);
+    // a cluster starts at its first interval, but may end at any of them
+    let first_rows = clusters.iter().map(|c| c[0].row).collect::<Vec<_>>();
+    let end_rows = clusters
+        .iter()
+        .map(|c| c.iter().max_by_key(|iv| iv.end).unwrap().row)
+        .collect::<Vec<_>>();
+
+    let out = df.select(on.by.iter().chain([&on.start]))?;
+    // SAFETY: the indices are rows of the table
+    let mut out = unsafe { out._take_unchecked_slice(&first_rows, true) };
+    out.with_column(df.column(&on.end)?.take_slice(&end_rows)?)?;
+    Ok(out)
+}
+
+/// The merged intervals of the second table, as the positions of their bounds in
+/// `Intervals::bounds` next to their group and the ranks of those bounds. Segments are
+/// disjoint, so they are sorted by both their start and end within a group.
+struct Segment {
+    group: Option<IdxSize>,
+    start: IdxSize,
+    end: IdxSize,
+    start_idx: IdxSize,
+    end_idx: IdxSize,
+}
+
+fn segments(intervals: &Intervals) -> Vec<Segment> {
+    // empty intervals don't cover anything
+    let covering = intervals
+        .matchable(1)
+        .iter()
+        .filter(|iv| !iv.is_empty())
+        .copied()
+        .collect::<Vec<_>>();
+    clusters(&covering)
+        .into_iter()
+        .map(|cluster| {
+            let first = &cluster[0];
+            let last = cluster.iter().max_by_key(|iv| iv.end).unwrap();
+            Segment {
+                group: first.group,
+                start: first.start,
+                end: last.end,
+                start_idx: intervals.start_idx(1, first.row),
+                end_idx: intervals.end_idx(1, last.row),
+            }
+        })
+        .collect()
+}
+
+/// Visits the segments that overlap every non-empty interval of the first table.
+fn for_each_overlap<F>(intervals: &Intervals, segments: &[Segment], mut f: F)
+where
+    F: FnMut(&Interval, &[Segment]),
+{
+    for iv in intervals.tables[0].iter().filter(|iv| !iv.is_empty()) {
+        let first = segments.partition_point(|s| (s.group, s.end) <= (iv.group, iv.start));
+        let len = segments[first..]
+            .iter()
+            .take_while(|s| iv.group.is_some() && s.group == iv.group && s.start < iv.end)
+            .count();
+        f(iv, &segments[first..first + len]);
+    }
+}
+
+/// Builds the rows of the first table with the given bounds, which are `(row, start_idx,
+/// end_idx)` triples in the order of the rows.
+fn finish_parts(
+    df: &DataFrame,
+    on: &IntervalColumns,
+    intervals: &Intervals,
+    mut parts: Vec<(IdxSize, IdxSize, IdxSize)>,
+) -> PolarsResult<DataFrame> {
+    // the parts of an interval are already in order
+    parts.sort_by_key(|&(row, _, _)| row);
+    let rows = parts.iter().map(|p| p.0).collect::<Vec<_>>();
+    let start_idx = parts.iter().map(|p| p.1).collect::<Vec<_>>();
+    let end_idx = parts.iter().map(|p| p.2).collect::<Vec<_>>();
+
+    // SAFETY: the indices are rows of the table
+    let mut out = unsafe { df._take_unchecked_slice(&rows, true) };
+    for (name, idx) in [(&on.start, start_idx), (&on.end, end_idx)] {
+        let dtype = df.column(name)?.dtype();
+        let mut bound = intervals.bounds.take_slice(&idx)?.cast(dtype)?;
+        bound.rename(name);
+        out.with_column(bound)?;
+    }
+    Ok(out)
+}
+
+pub(super) fn interval_intersect(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &IntervalColumns,
+    right_on: &IntervalColumns,
+) -> PolarsResult<DataFrame> {
+    let intervals = Intervals::new(&[(left, left_on), (right, right_on)])?;
+    let segments = segments(&intervals);
+
+    let mut parts = vec![];
+    for_each_overlap(&intervals, &segments, |iv, overlapping| {
+        for s in overlapping {
+            let start_idx = if s.start > iv.start {
+                s.start_idx
+            } else {
+                intervals.start_idx(0, iv.row)
+            };
+            let end_idx = if s.end < iv.end {
+                s.end_idx
+            } else {
+                intervals.end_idx(0, iv.row)
+            };
+            parts.push((iv.row, start_idx, end_idx));
+        }
+    });
+    finish_parts(left, left_on, &intervals, parts)
+}
+
+pub(super) fn interval_subtract(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &IntervalColumns,
+    right_on: &IntervalColumns,
+) -> PolarsResult<DataFrame> {
+    let intervals = Intervals::new(&[(left, left_on), (right, right_on)])?;
+    let segments = segments(&intervals);
+
+    let mut parts = vec![];
+    for_each_overlap(&intervals, &segments, |iv, overlapping| {
+        // the start of the part that isn't covered yet
+        let (mut start, mut start_idx) = (iv.start, intervals.start_idx(0, iv.row));
+        for s in overlapping {
+            if s.start > start {
+                parts.push((iv.row, start_idx, s.start_idx));
+            }
+            (start, start_idx) = (s.end, s.end_idx);
+        }
+        if start < iv.end {
+            parts.push((iv.row, start_idx, intervals.end_idx(0, iv.row)));
+        }
+    });
+    finish_parts(left, left_on, &intervals, parts)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::frame::interval::IntervalOps;
+
+    #[test]
+    fn test_cluster_and_merge_intervals() -> PolarsResult<()> {
+        let df = df! {
+            "chrom" => ["1", "1", "2", "1", "1", "2", "1", "1"],
+            "start" => [Some(1), Some(3), Some(2), Some(8), Some(5), Some(4), None, Some(9)],
+            "end" => [Some(4), Some(6), Some(4), Some(9), Some(7), Some(6), Some(2), Some(12)],
+        }?;
+        let on = IntervalColumns::new("start", "end").with_by(["chrom"]);
+
+        // [1, 4), [3, 6) and [5, 7) overlap, but [2, 4) and [4, 6) only touch
+        let ids = df.cluster_intervals(&on)?;
+        let expected = [
+            Some(0),
+            Some(0),
+            Some(3),
+            Some(1),
+            Some(0),
+            Some(4),
+            None,
+            Some(2),
+        ];
+        assert_eq!(Vec::from(&ids), expected);
+
+        let out = df.merge_intervals(&on)?;
+        let expected = df! {
+            "chrom" => ["1", "1", "1", "2", "2"],
+            "start" => [1, 8, 9, 2, 4],
+            "end" => [7, 9, 12, 4, 6],
+        }?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_interval_intersect_and_subtract() -> PolarsResult<()> {
+        let left = df! {
+            "name" => ["a", "b", "c", "d", "e"],
+            "chrom" => ["1", "1", "1", "2", "1"],
+            "start" => [0, 10, 30, 0, 40],
+            "end" => [20, 12, 35, 10, 40],
+        }?;
+        let right = df! {
+            "chrom" => ["1", "1", "1", "2", "1"],
+            "start" => [2, 5, 11, 20, 15],
+            "end" => [6, 8, 13, 30, 18],
+        }?;
+        let on = IntervalColumns::new("start", "end").with_by(["chrom"]);
+
+        // the right intervals merge into [2, 8), [11, 13) and [15, 18) on chromosome 1
+        let out = left.interval_intersect(&right, &on, &on)?;
+        let expected = df! {
+            "name" => ["a", "a", "a", "b"],
+            "chrom" => ["1", "1", "1", "1"],
+            "start" => [2, 11, 15, 11],
+            "end" => [8, 13, 18, 12],
+        }?;
+        assert!(out.equals(&expected));
+
+        let out = left.interval_subtract(&right, &on, &on)?;
+        let expected = df! {
+            "name" => ["a", "a", "a", "a", "b", "c", "d"],
+            "chrom" => ["1", "1", "1", "1", "1", "1", "2"],
+            "start" => [0, 8, 13, 18, 10, 30, 0],
+            "end" => [2, 11, 15, 20, 11, 35, 10],
+        }?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+}
@@ -49,7 +49,7 @@ impl JoinCoalesce {
         use JoinCoalesce::*;
         use JoinType::*;
         match join_type {
-            Left | Inner => {
+            Left | Right | Inner => {
                 matches!(self, JoinSpecific | CoalesceColumns)
             },
             Full { .. } => {
@@ -58,6 +58,8 @@ impl JoinCoalesce {
             #[cfg(feature = "asof_join")]
             AsOf(_) => matches!(self, JoinSpecific | CoalesceColumns),
             Cross => false,
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => false,
             #[cfg(feature = "semi_anti_join")]
             Semi | Anti => false,
         }
@@ -109,10 +111,14 @@ impl JoinArgs {
 pub enum JoinType {
     Inner,
     Left,
+    Right,
     Full,
     #[cfg(feature = "asof_join")]
     AsOf(AsOfOptions),
     Cross,
+    /// Join on one or two inequalities between the keys of both tables.
+    #[cfg(feature = "iejoin")]
+    IEJoin(IEJoinOptions),
     #[cfg(feature = "semi_anti_join")]
     Semi,
     #[cfg(feature = "semi_anti_join")]
@@ -130,11 +136,14 @@ impl Display for JoinType {
         use JoinType::*;
         let val = match self {
             Left => "LEFT",
+            Right => "RIGHT",
             Inner => "INNER",
             Full { .. } => "FULL",
             #[cfg(feature = "asof_join")]
             AsOf(_) => "ASOF",
             Cross => "CROSS",
+            #[cfg(feature = "iejoin")]
+            IEJoin(_) => "IEJOIN",
             #[cfg(feature = "semi_anti_join")]
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
@@ -182,7 +191,7 @@ impl JoinValidation {
         !matches!(self, JoinValidation::ManyToMany)
     }
 
-    fn swap(self, swap: bool) -> Self {
+    pub(super) fn swap(self, swap: bool) -> Self {
         use JoinValidation::*;
         if swap {
             match self {
@@ -200,7 +209,7 @@ impl JoinValidation {
         if !self.needs_checks() {
             return Ok(());
         }
-        polars_ensure!(matches!(join_type, JoinType::Inner | JoinType::Full{..} | JoinType::Left),
+        polars_ensure!(matches!(join_type, JoinType::Inner | JoinType::Full{..} | JoinType::Left | JoinType::Right),
                       ComputeError: "{self} validation on a {join_type} join is not supported");
         Ok(())
     }
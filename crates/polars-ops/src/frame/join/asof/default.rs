@@ -8,7 +8,12 @@ use super::{
     AsofJoinBackwardState, AsofJoinForwardState, AsofJoinNearestState, AsofJoinState, AsofStrategy,
 };
 
-fn join_asof_impl<'a, T, S, F>(left: &'a T::Array, right: &'a T::Array, mut filter: F) -> IdxCa
+fn join_asof_impl<'a, T, S, F>(
+    left: &'a T::Array,
+    right: &'a T::Array,
+    mut filter: F,
+    allow_eq: bool,
+) -> IdxCa
 where
     T: PolarsDataType,
     S: AsofJoinState<T::Physical<'a>>,
@@ -20,7 +25,7 @@ where
 
     let mut out = vec![0; left.len()];
     let mut mask = vec![0; (left.len() + 7) / 8];
-    let mut state = S::default();
+    let mut state = S::new(allow_eq);
 
     if left.null_count() == 0 && right.null_count() == 0 {
         for (i, val_l) in left.values_iter().enumerate() {
@@ -58,31 +63,46 @@ where
     IdxCa::from_vec_validity("", out, Some(bitmap))
 }
 
-fn join_asof_forward<'a, T, F>(left: &'a T::Array, right: &'a T::Array, filter: F) -> IdxCa
+fn join_asof_forward<'a, T, F>(
+    left: &'a T::Array,
+    right: &'a T::Array,
+    filter: F,
+    allow_eq: bool,
+) -> IdxCa
 where
     T: PolarsDataType,
     T::Physical<'a>: PartialOrd,
     F: FnMut(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    join_asof_impl::<'a, T, AsofJoinForwardState, _>(left, right, filter)
+    join_asof_impl::<'a, T, AsofJoinForwardState, _>(left, right, filter, allow_eq)
 }
 
-fn join_asof_backward<'a, T, F>(left: &'a T::Array, right: &'a T::Array, filter: F) -> IdxCa
+fn join_asof_backward<'a, T, F>(
+    left: &'a T::Array,
+    right: &'a T::Array,
+    filter: F,
+    allow_eq: bool,
+) -> IdxCa
 where
     T: PolarsDataType,
     T::Physical<'a>: PartialOrd,
     F: FnMut(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    join_asof_impl::<'a, T, AsofJoinBackwardState, _>(left, right, filter)
+    join_asof_impl::<'a, T, AsofJoinBackwardState, _>(left, right, filter, allow_eq)
 }
 
-fn join_asof_nearest<'a, T, F>(left: &'a T::Array, right: &'a T::Array, filter: F) -> IdxCa
+fn join_asof_nearest<'a, T, F>(
+    left: &'a T::Array,
+    right: &'a T::Array,
+    filter: F,
+    allow_eq: bool,
+) -> IdxCa
 where
     T: PolarsDataType,
     T::Physical<'a>: NumericNative,
     F: FnMut(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    join_asof_impl::<'a, T, AsofJoinNearestState, _>(left, right, filter)
+    join_asof_impl::<'a, T, AsofJoinNearestState, _>(left, right, filter, allow_eq)
 }
 
 pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
@@ -90,6 +110,7 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
     other: &Series,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
 ) -> PolarsResult<IdxCa> {
     let other = input_ca.unpack_series_matching_type(other)?;
 
@@ -103,16 +124,16 @@ pub(crate) fn join_asof_numeric<T: PolarsNumericType>(
         let abs_tolerance = native_tolerance.abs_diff(T::Native::zero());
         let filter = |l: T::Native, r: T::Native| l.abs_diff(r) <= abs_tolerance;
         match strategy {
-            AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter),
-            AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter),
-            AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter),
+            AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
         }
     } else {
         let filter = |_l: T::Native, _r: T::Native| true;
         match strategy {
-            AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter),
-            AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter),
-            AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter),
+            AsofStrategy::Forward => join_asof_forward::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Backward => join_asof_backward::<T, _>(left, right, filter, allow_eq),
+            AsofStrategy::Nearest => join_asof_nearest::<T, _>(left, right, filter, allow_eq),
         }
     };
     Ok(out)
@@ -122,6 +143,7 @@ pub(crate) fn join_asof<T>(
     input_ca: &ChunkedArray<T>,
     other: &Series,
     strategy: AsofStrategy,
+    allow_eq: bool,
 ) -> PolarsResult<IdxCa>
 where
     T: PolarsDataType,
@@ -136,9 +158,11 @@ where
 
     let filter = |_l: T::Physical<'_>, _r: T::Physical<'_>| true;
     Ok(match strategy {
-        AsofStrategy::Forward => join_asof_impl::<T, AsofJoinForwardState, _>(left, right, filter),
+        AsofStrategy::Forward => {
+            join_asof_impl::<T, AsofJoinForwardState, _>(left, right, filter, allow_eq)
+        },
         AsofStrategy::Backward => {
-            join_asof_impl::<T, AsofJoinBackwardState, _>(left, right, filter)
+            join_asof_impl::<T, AsofJoinBackwardState, _>(left, right, filter, allow_eq)
         },
         AsofStrategy::Nearest => unimplemented!(),
     })
@@ -155,7 +179,7 @@ mod test {
         let a = PrimitiveArray::from_slice([-1, 2, 3, 3, 3, 4]);
         let b = PrimitiveArray::from_slice([1, 2, 3, 3]);
 
-        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true);
+        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true, true);
         assert_eq!(tuples.len(), a.len());
         assert_eq!(
             tuples.to_vec(),
@@ -163,7 +187,7 @@ mod test {
         );
 
         let b = PrimitiveArray::from_slice([1, 2, 4, 5]);
-        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true);
+        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true, true);
         assert_eq!(
             tuples.to_vec(),
             &[None, Some(1), Some(1), Some(1), Some(1), Some(2)]
@@ -171,7 +195,7 @@ mod test {
 
         let a = PrimitiveArray::from_slice([2, 4, 4, 4]);
         let b = PrimitiveArray::from_slice([1, 2, 3, 3]);
-        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true);
+        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true, true);
         assert_eq!(tuples.to_vec(), &[Some(1), Some(3), Some(3), Some(3)]);
     }
 
@@ -179,7 +203,7 @@ mod test {
     fn test_asof_backward_tolerance() {
         let a = PrimitiveArray::from_slice([-1, 20, 25, 30, 30, 40]);
         let b = PrimitiveArray::from_slice([10, 20, 30, 30]);
-        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 4u32);
+        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 4u32, true);
         assert_eq!(
             tuples.to_vec(),
             &[None, Some(1), None, Some(3), Some(3), None]
@@ -190,7 +214,7 @@ mod test {
     fn test_asof_forward_tolerance() {
         let a = PrimitiveArray::from_slice([-1, 20, 25, 30, 30, 40, 52]);
         let b = PrimitiveArray::from_slice([10, 20, 33, 55]);
-        let tuples = join_asof_forward::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 4u32);
+        let tuples = join_asof_forward::<Int32Type, _>(&a, &b, |l, r| l.abs_diff(r) <= 4u32, true);
         assert_eq!(
             tuples.to_vec(),
             &[None, Some(1), None, Some(2), Some(2), None, Some(3)]
@@ -202,8 +226,32 @@ mod test {
         let a = PrimitiveArray::from_slice([-1, 1, 2, 4, 6]);
         let b = PrimitiveArray::from_slice([1, 2, 4, 5]);
 
-        let tuples = join_asof_forward::<Int32Type, _>(&a, &b, |_, _| true);
+        let tuples = join_asof_forward::<Int32Type, _>(&a, &b, |_, _| true, true);
         assert_eq!(tuples.len(), a.len());
         assert_eq!(tuples.to_vec(), &[Some(0), Some(0), Some(1), Some(2), None]);
     }
+
+    #[test]
+    fn test_asof_exclude_exact_matches() {
+        let a = PrimitiveArray::from_slice([-1, 2, 3, 3, 3, 4]);
+        let b = PrimitiveArray::from_slice([1, 2, 3, 3]);
+        let tuples = join_asof_backward::<Int32Type, _>(&a, &b, |_, _| true, false);
+        assert_eq!(
+            tuples.to_vec(),
+            &[None, Some(0), Some(1), Some(1), Some(1), Some(3)]
+        );
+
+        let a = PrimitiveArray::from_slice([-1, 1, 2, 4, 6]);
+        let b = PrimitiveArray::from_slice([1, 2, 4, 5]);
+        let tuples = join_asof_forward::<Int32Type, _>(&a, &b, |_, _| true, false);
+        assert_eq!(tuples.to_vec(), &[Some(0), Some(1), Some(2), Some(3), None]);
+
+        let a = PrimitiveArray::from_slice([1, 2, 3, 3, 5]);
+        let b = PrimitiveArray::from_slice([1, 2, 2, 3, 4, 4, 6]);
+        let tuples = join_asof_nearest::<Int32Type, _>(&a, &b, |_, _| true, false);
+        assert_eq!(
+            tuples.to_vec(),
+            &[Some(2), Some(3), Some(5), Some(5), Some(6)]
+        );
+    }
 }
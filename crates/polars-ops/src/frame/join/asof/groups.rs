@@ -1,105 +1,23 @@
 use std::hash::Hash;
 
 use ahash::RandomState;
-use hashbrown::HashMap;
+use arrow::array::StaticArray;
 use num_traits::Zero;
-use polars_core::hashing::{
-    IdxHash, _df_rows_to_hashes_threaded_vertical, populate_multiple_key_hashmap,
-    _HASHMAP_INIT_SIZE,
-};
+use polars_core::hashing::_HASHMAP_INIT_SIZE;
 use polars_core::prelude::*;
 use polars_core::series::BitRepr;
 use polars_core::utils::flatten::flatten_nullable;
-use polars_core::utils::{_set_partition_size, split_and_flatten};
-use polars_core::{with_match_physical_float_polars_type, IdBuildHasher, POOL};
+use polars_core::utils::{split_and_flatten, try_get_supertype};
+use polars_core::{with_match_physical_float_polars_type, POOL};
 use polars_utils::abs_diff::AbsDiff;
 use polars_utils::hashing::{hash_to_partition, DirtyHash};
-use polars_utils::idx_vec::IdxVec;
 use polars_utils::nulls::IsNull;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
-use polars_utils::unitvec;
 use rayon::prelude::*;
 use smartstring::alias::String as SmartString;
 
 use super::*;
 
-/// Compare the rows of two [`DataFrame`]s
-pub(crate) unsafe fn compare_df_rows2(
-    left: &DataFrame,
-    right: &DataFrame,
-    left_idx: usize,
-    right_idx: usize,
-    join_nulls: bool,
-) -> bool {
-    for (l, r) in left.get_columns().iter().zip(right.get_columns()) {
-        let l = l.get_unchecked(left_idx);
-        let r = r.get_unchecked(right_idx);
-        if !l.eq_missing(&r, join_nulls) {
-            return false;
-        }
-    }
-    true
-}
-
-pub(crate) fn create_probe_table(
-    hashes: &[UInt64Chunked],
-    keys: &DataFrame,
-) -> Vec<HashMap<IdxHash, IdxVec, IdBuildHasher>> {
-    let n_partitions = _set_partition_size();
-
-    // We will create a hashtable in every thread.
-    // We use the hash to partition the keys to the matching hashtable.
-    // Every thread traverses all keys/hashes and ignores the ones that doesn't fall in that partition.
-    POOL.install(|| {
-        (0..n_partitions)
-            .into_par_iter()
-            .map(|part_no| {
-                let mut hash_tbl: HashMap<IdxHash, IdxVec, IdBuildHasher> =
-                    HashMap::with_capacity_and_hasher(_HASHMAP_INIT_SIZE, Default::default());
-
-                let mut offset = 0;
-                for hashes in hashes {
-                    for hashes in hashes.data_views() {
-                        let len = hashes.len();
-                        let mut idx = 0;
-                        hashes.iter().for_each(|h| {
-                            // partition hashes by thread no.
-                            // So only a part of the hashes go to this hashmap
-                            if part_no == hash_to_partition(*h, n_partitions) {
-                                let idx = idx + offset;
-                                populate_multiple_key_hashmap(
-                                    &mut hash_tbl,
-                                    idx,
-                                    *h,
-                                    keys,
-                                    || unitvec![idx],
-                                    |v| v.push(idx),
-                                )
-                            }
-                            idx += 1;
-                        });
-
-                        offset += len as IdxSize;
-                    }
-                }
-                hash_tbl
-            })
-            .collect()
-    })
-}
-
-pub(crate) fn get_offsets(probe_hashes: &[UInt64Chunked]) -> Vec<usize> {
-    probe_hashes
-        .iter()
-        .map(|ph| ph.len())
-        .scan(0, |state, val| {
-            let out = *state;
-            *state += val;
-            Some(out)
-        })
-        .collect()
-}
-
 fn compute_len_offsets<I: IntoIterator<Item = usize>>(iter: I) -> Vec<usize> {
     let mut cumlen = 0;
     iter.into_iter()
@@ -125,6 +43,7 @@ fn asof_in_group<'a, T, A, F>(
     right_grp_idxs: &[IdxSize],
     group_states: &mut PlHashMap<IdxSize, A>,
     filter: F,
+    allow_eq: bool,
 ) -> Option<IdxSize>
 where
     T: PolarsDataType,
@@ -134,7 +53,7 @@ where
     // We use the index of the first element in a group as an identifier to
     // associate with the group state.
     let id = right_grp_idxs.first()?;
-    let grp_state = group_states.entry(*id).or_default();
+    let grp_state = group_states.entry(*id).or_insert_with(|| A::new(allow_eq));
 
     unsafe {
         let r_grp_idx = grp_state.next(
@@ -161,6 +80,7 @@ fn asof_join_by_numeric<T, S, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
@@ -228,6 +148,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    allow_eq,
                 );
                 results.push(materialize_nullable(id));
             }
@@ -238,14 +159,17 @@ where
     Ok(flatten_nullable(&bufs))
 }
 
-fn asof_join_by_binary<T, A, F>(
-    by_left: &BinaryChunked,
-    by_right: &BinaryChunked,
+fn asof_join_by_binary<B, T, A, F>(
+    by_left: &ChunkedArray<B>,
+    by_right: &ChunkedArray<B>,
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
+    allow_eq: bool,
 ) -> IdxArr
 where
+    B: PolarsDataType,
+    for<'b> <B::Array as StaticArray>::ValueT<'b>: AsRef<[u8]>,
     T: PolarsDataType,
     A: for<'a> AsofJoinState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
@@ -254,14 +178,10 @@ where
     let left_val_arr = left_asof.downcast_iter().next().unwrap();
     let right_val_arr = right_asof.downcast_iter().next().unwrap();
 
-    let n_threads = POOL.current_num_threads();
-    let split_by_left = split_and_flatten(by_left, n_threads);
-    let split_by_right = split_and_flatten(by_right, n_threads);
-    let offsets = compute_len_offsets(split_by_left.iter().map(|s| s.len()));
-
     let hb = RandomState::default();
-    let prep_by_left = prepare_bytes(&split_by_left, &hb);
-    let prep_by_right = prepare_bytes(&split_by_right, &hb);
+    let prep_by_left = by_left.to_bytes_hashes(true, hb.clone());
+    let prep_by_right = by_right.to_bytes_hashes(true, hb);
+    let offsets = compute_len_offsets(prep_by_left.iter().map(|s| s.len()));
     let hash_tbls = build_tables(prep_by_right, false);
     let n_tables = hash_tbls.len();
 
@@ -293,6 +213,7 @@ where
                     right_grp_idxs.as_slice(),
                     &mut group_states,
                     &filter,
+                    allow_eq,
                 );
 
                 results.push(materialize_nullable(id));
@@ -303,162 +224,96 @@ where
     flatten_nullable(&bufs)
 }
 
-fn asof_join_by_multiple<T, A, F>(
-    by_left: &mut DataFrame,
-    by_right: &mut DataFrame,
+/// Joins on 'by' keys that have no specialized implementation (multiple
+/// columns or nested/boolean dtypes) by row-encoding them into a single
+/// binary key.
+fn asof_join_by_row_encoded<T, A, F>(
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
     filter: F,
-) -> IdxArr
+    allow_eq: bool,
+) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
     A: for<'a> AsofJoinState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    let (left_asof, right_asof) = POOL.join(|| left_asof.rechunk(), || right_asof.rechunk());
-    let left_val_arr = left_asof.downcast_iter().next().unwrap();
-    let right_val_arr = right_asof.downcast_iter().next().unwrap();
-
-    let n_threads = POOL.current_num_threads();
-    let split_by_left = split_and_flatten(by_left, n_threads);
-    let split_by_right = split_and_flatten(by_right, n_threads);
-
-    let (build_hashes, random_state) =
-        _df_rows_to_hashes_threaded_vertical(&split_by_right, None).unwrap();
-    let (probe_hashes, _) =
-        _df_rows_to_hashes_threaded_vertical(&split_by_left, Some(random_state)).unwrap();
-
-    let hash_tbls = create_probe_table(&build_hashes, by_right);
-    drop(build_hashes); // Early drop to reduce memory pressure.
-    let offsets = get_offsets(&probe_hashes);
-    let n_tables = hash_tbls.len();
-
-    // Now we probe the right hand side for each left hand side.
-    let iter = probe_hashes
-        .into_par_iter()
-        .zip(offsets)
-        .map(|(hash_by_left, offset)| {
-            let mut results = Vec::with_capacity(hash_by_left.len());
-            let mut group_states: PlHashMap<_, A> = PlHashMap::with_capacity(_HASHMAP_INIT_SIZE);
-
-            let mut ctr = 0;
-            for by_left_view in hash_by_left.data_views() {
-                for h_left in by_left_view.iter().copied() {
-                    let idx_left = offset + ctr;
-                    ctr += 1;
-                    let opt_left_val = left_val_arr.get(idx_left);
-
-                    let Some(left_val) = opt_left_val else {
-                        results.push(NullableIdxSize::null());
-                        continue;
-                    };
-
-                    let group_probe_table =
-                        unsafe { hash_tbls.get_unchecked(hash_to_partition(h_left, n_tables)) };
-
-                    let entry = group_probe_table.raw_entry().from_hash(h_left, |idx_hash| {
-                        let idx_right = idx_hash.idx;
-                        // SAFETY: indices in a join operation are always in bounds.
-                        unsafe {
-                            compare_df_rows2(by_left, by_right, idx_left, idx_right as usize, false)
-                        }
-                    });
-                    let Some((_, right_grp_idxs)) = entry else {
-                        results.push(NullableIdxSize::null());
-                        continue;
-                    };
-                    let id = asof_in_group::<T, A, &F>(
-                        left_val,
-                        right_val_arr,
-                        &right_grp_idxs[..],
-                        &mut group_states,
-                        &filter,
-                    );
-
-                    results.push(materialize_nullable(id));
-                }
-            }
-            results
-        });
-    let bufs = POOL.install(|| iter.collect::<Vec<_>>());
-    flatten_nullable(&bufs)
+    let (left_by, right_by) = POOL.join(
+        || prepare_keys_multiple(left_by.get_columns(), false),
+        || prepare_keys_multiple(right_by.get_columns(), false),
+    );
+    Ok(asof_join_by_binary::<BinaryOffsetType, T, A, F>(
+        &left_by?, &right_by?, left_asof, right_asof, filter, allow_eq,
+    ))
 }
 
 #[allow(clippy::too_many_arguments)]
 fn dispatch_join_by_type<T, A, F>(
     left_asof: &ChunkedArray<T>,
     right_asof: &ChunkedArray<T>,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     filter: F,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     T: PolarsDataType,
     A: for<'a> AsofJoinState<T::Physical<'a>>,
     F: Sync + for<'a> Fn(T::Physical<'a>, T::Physical<'a>) -> bool,
 {
-    let out = if left_by.width() == 1 {
-        let left_by_s = left_by.get_columns()[0].to_physical_repr().into_owned();
-        let right_by_s = right_by.get_columns()[0].to_physical_repr().into_owned();
-        let left_dtype = left_by_s.dtype();
-        let right_dtype = right_by_s.dtype();
-        polars_ensure!(left_dtype == right_dtype,
-            ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{left_dtype}` and `{right_dtype}`",
+    // The 'by' columns are already cast to a common physical dtype.
+    if left_by.width() > 1 {
+        return asof_join_by_row_encoded::<T, A, F>(
+            left_by, right_by, left_asof, right_asof, filter, allow_eq,
         );
-        match left_dtype {
-            DataType::String => {
-                let left_by = &left_by_s.str().unwrap().as_binary();
-                let right_by = right_by_s.str().unwrap().as_binary();
-                asof_join_by_binary::<T, A, F>(left_by, &right_by, left_asof, right_asof, filter)
-            },
-            DataType::Binary => {
-                let left_by = &left_by_s.binary().unwrap();
-                let right_by = right_by_s.binary().unwrap();
-                asof_join_by_binary::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
-            },
-            x if x.is_float() => {
-                with_match_physical_float_polars_type!(left_by_s.dtype(), |$T| {
-                    let left_by: &ChunkedArray<$T> = left_by_s.as_ref().as_ref().as_ref();
-                    let right_by: &ChunkedArray<$T> = right_by_s.as_ref().as_ref().as_ref();
-                    asof_join_by_numeric::<T, $T, A, F>(
-                        left_by, right_by, left_asof, right_asof, filter,
-                    )?
-                })
-            },
-            _ => {
-                let left_by = left_by_s.bit_repr();
-                let right_by = right_by_s.bit_repr();
-
-                let (Some(left_by), Some(right_by)) = (left_by, right_by) else {
-                    polars_bail!(nyi = "Dispatch join for {left_dtype} and {right_dtype}");
-                };
+    }
 
-                use BitRepr as B;
-                match (left_by, right_by) {
-                    (B::Small(left_by), B::Small(right_by)) => {
-                        asof_join_by_numeric::<T, UInt32Type, A, F>(
-                            &left_by, &right_by, left_asof, right_asof, filter,
-                        )?
-                    },
-                    (B::Large(left_by), B::Large(right_by)) => {
-                        asof_join_by_numeric::<T, UInt64Type, A, F>(
-                            &left_by, &right_by, left_asof, right_asof, filter,
-                        )?
-                    },
-                    // We have already asserted that the datatypes are the same.
-                    _ => unreachable!(),
-                }
-            },
-        }
-    } else {
-        for (lhs, rhs) in left_by.get_columns().iter().zip(right_by.get_columns()) {
-            polars_ensure!(lhs.dtype() == rhs.dtype(),
-                ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`", lhs.dtype(), rhs.dtype()
-            );
-            #[cfg(feature = "dtype-categorical")]
-            _check_categorical_src(lhs.dtype(), rhs.dtype())?;
-        }
-        asof_join_by_multiple::<T, A, F>(left_by, right_by, left_asof, right_asof, filter)
+    let left_by_s = &left_by.get_columns()[0];
+    let right_by_s = &right_by.get_columns()[0];
+    let out = match left_by_s.dtype() {
+        DataType::String => {
+            let left_by = &left_by_s.str().unwrap().as_binary();
+            let right_by = right_by_s.str().unwrap().as_binary();
+            asof_join_by_binary::<BinaryType, T, A, F>(
+                left_by, &right_by, left_asof, right_asof, filter, allow_eq,
+            )
+        },
+        DataType::Binary => {
+            let left_by = &left_by_s.binary().unwrap();
+            let right_by = right_by_s.binary().unwrap();
+            asof_join_by_binary::<BinaryType, T, A, F>(
+                left_by, right_by, left_asof, right_asof, filter, allow_eq,
+            )
+        },
+        x if x.is_float() => {
+            with_match_physical_float_polars_type!(left_by_s.dtype(), |$T| {
+                let left_by: &ChunkedArray<$T> = left_by_s.as_ref().as_ref().as_ref();
+                let right_by: &ChunkedArray<$T> = right_by_s.as_ref().as_ref().as_ref();
+                asof_join_by_numeric::<T, $T, A, F>(
+                    left_by, right_by, left_asof, right_asof, filter, allow_eq,
+                )?
+            })
+        },
+        _ => {
+            use BitRepr as B;
+            match (left_by_s.bit_repr(), right_by_s.bit_repr()) {
+                (Some(B::Small(left_by)), Some(B::Small(right_by))) => {
+                    asof_join_by_numeric::<T, UInt32Type, A, F>(
+                        &left_by, &right_by, left_asof, right_asof, filter, allow_eq,
+                    )?
+                },
+                (Some(B::Large(left_by)), Some(B::Large(right_by))) => {
+                    asof_join_by_numeric::<T, UInt64Type, A, F>(
+                        &left_by, &right_by, left_asof, right_asof, filter, allow_eq,
+                    )?
+                },
+                _ => asof_join_by_row_encoded::<T, A, F>(
+                    left_by, right_by, left_asof, right_asof, filter, allow_eq,
+                )?,
+            }
+        },
     };
     Ok(out)
 }
@@ -467,9 +322,10 @@ where
 fn dispatch_join_strategy<T: PolarsDataType>(
     left_asof: &ChunkedArray<T>,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr>
 where
     for<'a> T::Physical<'a>: PartialOrd,
@@ -479,10 +335,10 @@ where
     let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
     match strategy {
         AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-            left_asof, right_asof, left_by, right_by, filter,
+            left_asof, right_asof, left_by, right_by, filter, allow_eq,
         ),
         AsofStrategy::Nearest => unimplemented!(),
     }
@@ -492,10 +348,11 @@ where
 fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
     left_asof: &ChunkedArray<T>,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr> {
     let right_ca = left_asof.unpack_series_matching_type(right_asof)?;
 
@@ -505,26 +362,26 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
         let filter = |a: T::Native, b: T::Native| a.abs_diff(b) <= abs_tolerance;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
         }
     } else {
         let filter = |_a: T::Physical<'_>, _b: T::Physical<'_>| true;
         match strategy {
             AsofStrategy::Backward => dispatch_join_by_type::<T, AsofJoinBackwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Forward => dispatch_join_by_type::<T, AsofJoinForwardState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
             AsofStrategy::Nearest => dispatch_join_by_type::<T, AsofJoinNearestState, _>(
-                left_asof, right_ca, left_by, right_by, filter,
+                left_asof, right_ca, left_by, right_by, filter, allow_eq,
             ),
         }
     }
@@ -534,43 +391,60 @@ fn dispatch_join_strategy_numeric<T: PolarsNumericType>(
 fn dispatch_join_type(
     left_asof: &Series,
     right_asof: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
+    left_by: &DataFrame,
+    right_by: &DataFrame,
     strategy: AsofStrategy,
     tolerance: Option<AnyValue<'static>>,
+    allow_eq: bool,
 ) -> PolarsResult<IdxArr> {
     match left_asof.dtype() {
         DataType::Int64 => {
             let ca = left_asof.i64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Int32 => {
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::UInt64 => {
             let ca = left_asof.u64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::UInt32 => {
             let ca = left_asof.u32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Float32 => {
             let ca = left_asof.f32().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Float64 => {
             let ca = left_asof.f64().unwrap();
-            dispatch_join_strategy_numeric(ca, right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca, right_asof, left_by, right_by, strategy, tolerance, allow_eq,
+            )
         },
         DataType::Boolean => {
             let ca = left_asof.bool().unwrap();
-            dispatch_join_strategy::<BooleanType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BooleanType>(
+                ca, right_asof, left_by, right_by, strategy, allow_eq,
+            )
         },
         DataType::Binary => {
             let ca = left_asof.binary().unwrap();
-            dispatch_join_strategy::<BinaryType>(ca, right_asof, left_by, right_by, strategy)
+            dispatch_join_strategy::<BinaryType>(
+                ca, right_asof, left_by, right_by, strategy, allow_eq,
+            )
         },
         DataType::String => {
             let ca = left_asof.str().unwrap();
@@ -581,13 +455,22 @@ fn dispatch_join_type(
                 left_by,
                 right_by,
                 strategy,
+                allow_eq,
             )
         },
         _ => {
             let left_asof = left_asof.cast(&DataType::Int32).unwrap();
             let right_asof = right_asof.cast(&DataType::Int32).unwrap();
             let ca = left_asof.i32().unwrap();
-            dispatch_join_strategy_numeric(ca, &right_asof, left_by, right_by, strategy, tolerance)
+            dispatch_join_strategy_numeric(
+                ca,
+                &right_asof,
+                left_by,
+                right_by,
+                strategy,
+                tolerance,
+                allow_eq,
+            )
         },
     }
 }
@@ -604,6 +487,7 @@ pub trait AsofJoinBy: IntoDf {
         right_by: Vec<SmartString>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        allow_eq: bool,
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
@@ -639,6 +523,11 @@ pub trait AsofJoinBy: IntoDf {
 
         let mut left_by = self_df.select(left_by)?;
         let mut right_by = other_df.select(right_by)?;
+        polars_ensure!(
+            left_by.width() == right_by.width(),
+            ComputeError: "the number of 'by' columns of an asof join should be equal, got {} and {}",
+            left_by.width(), right_by.width()
+        );
 
         unsafe {
             for (l, r) in left_by
@@ -648,6 +537,16 @@ pub trait AsofJoinBy: IntoDf {
             {
                 #[cfg(feature = "dtype-categorical")]
                 _check_categorical_src(l.dtype(), r.dtype())?;
+                if l.dtype() != r.dtype() {
+                    let dtype = try_get_supertype(l.dtype(), r.dtype()).map_err(|_| {
+                        polars_err!(
+                            ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`",
+                            l.dtype(), r.dtype()
+                        )
+                    })?;
+                    *l = l.cast(&dtype)?;
+                    *r = r.cast(&dtype)?;
+                }
                 *l = l.to_physical_repr().into_owned();
                 *r = r.to_physical_repr().into_owned();
             }
@@ -656,10 +555,11 @@ pub trait AsofJoinBy: IntoDf {
         let right_join_tuples = dispatch_join_type(
             &left_asof,
             &right_asof,
-            &mut left_by,
-            &mut right_by,
+            &left_by,
+            &right_by,
             strategy,
             tolerance,
+            allow_eq,
         )?;
 
         let mut drop_these = right_by.get_column_names();
@@ -687,7 +587,10 @@ pub trait AsofJoinBy: IntoDf {
     /// This is similar to a left-join except that we match on nearest key
     /// rather than equal keys. The keys must be sorted to perform an asof join.
     /// This is a special implementation of an asof join that searches for the
-    /// nearest keys within a subgroup set by `by`.
+    /// nearest keys within a subgroup set by `by`. The `by` columns may be of
+    /// any (and differing, but compatible) dtypes.
+    ///
+    /// If `allow_eq` is `false`, right keys equal to the left key are never matched.
     #[allow(clippy::too_many_arguments)]
     fn join_asof_by<I, S>(
         &self,
@@ -698,6 +601,7 @@ pub trait AsofJoinBy: IntoDf {
         right_by: I,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        allow_eq: bool,
     ) -> PolarsResult<DataFrame>
     where
         I: IntoIterator<Item = S>,
@@ -709,7 +613,8 @@ pub trait AsofJoinBy: IntoDf {
         let left_key = self_df.column(left_on)?;
         let right_key = other.column(right_on)?;
         self_df._join_asof_by(
-            other, left_key, right_key, left_by, right_by, strategy, tolerance, None, None, true,
+            other, left_key, right_key, left_by, right_by, strategy, tolerance, allow_eq, None,
+            None, true,
         )
     }
 }
@@ -733,7 +638,16 @@ mod test {
             "right_vals" => [1, 2, 3, 4]
         ]?;
 
-        let out = a.join_asof_by(&b, "a", "a", ["b"], ["b"], AsofStrategy::Backward, None)?;
+        let out = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["b"],
+            ["b"],
+            AsofStrategy::Backward,
+            None,
+            true,
+        )?;
         assert_eq!(out.get_column_names(), &["a", "b", "right_vals"]);
         let out = out.column("right_vals").unwrap();
         let out = out.i32().unwrap();
@@ -776,6 +690,7 @@ mod test {
             ["ticker"],
             AsofStrategy::Backward,
             None,
+            true,
         )?;
         let a = out.column("bid_right").unwrap();
         let a = a.f64().unwrap();
@@ -791,6 +706,7 @@ mod test {
             ["groups_numeric"],
             AsofStrategy::Backward,
             None,
+            true,
         )?;
         let a = out.column("bid_right").unwrap();
         let a = a.f64().unwrap();
@@ -813,7 +729,16 @@ mod test {
         "right_vals" => [  1,   3,   2,   3,   4]
         ]?;
 
-        let out = a.join_asof_by(&b, "a", "a", ["b"], ["b"], AsofStrategy::Forward, None)?;
+        let out = a.join_asof_by(
+            &b,
+            "a",
+            "a",
+            ["b"],
+            ["b"],
+            AsofStrategy::Forward,
+            None,
+            true,
+        )?;
         assert_eq!(out.get_column_names(), &["a", "b", "right_vals"]);
         let out = out.column("right_vals").unwrap();
         let out = out.i32().unwrap();
@@ -830,6 +755,7 @@ mod test {
             ["b"],
             AsofStrategy::Forward,
             Some(AnyValue::Int32(1)),
+            true,
         )?;
         assert_eq!(out.get_column_names(), &["a", "b", "right_vals"]);
         let out = out.column("right_vals").unwrap();
@@ -898,6 +824,7 @@ mod test {
             ["ticker"],
             AsofStrategy::Forward,
             None,
+            true,
         )?;
         let a = out.column("bid_right").unwrap();
         let a = a.f64().unwrap();
@@ -919,6 +846,7 @@ mod test {
             ["groups_numeric"],
             AsofStrategy::Forward,
             None,
+            true,
         )?;
         let a = out.column("bid_right").unwrap();
         let a = a.f64().unwrap();
@@ -927,4 +855,51 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_multiple_mixed_dtypes() -> PolarsResult<()> {
+        let a = df![
+            "time" => [1i64, 2, 3, 4],
+            "ticker" => ["a", "a", "b", "b"],
+            "venue" => [1i32, 2, 1, 1]
+        ]?;
+
+        let b = df![
+            "time" => [0i64, 1, 2, 3, 3],
+            "ticker" => ["a", "a", "a", "b", "b"],
+            "venue" => [1i64, 2, 2, 1, 1],
+            "right_vals" => [10, 11, 12, 13, 14]
+        ]?;
+
+        let out = a.join_asof_by(
+            &b,
+            "time",
+            "time",
+            ["ticker", "venue"],
+            ["ticker", "venue"],
+            AsofStrategy::Backward,
+            None,
+            true,
+        )?;
+        assert_eq!(
+            out.get_column_names(),
+            &["time", "ticker", "venue", "right_vals"]
+        );
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(Vec::from(out), &[Some(10), Some(12), Some(14), Some(14)]);
+
+        let out = a.join_asof_by(
+            &b,
+            "time",
+            "time",
+            ["ticker", "venue"],
+            ["ticker", "venue"],
+            AsofStrategy::Backward,
+            None,
+            false,
+        )?;
+        let out = out.column("right_vals")?.i32()?;
+        assert_eq!(Vec::from(out), &[Some(10), Some(11), None, Some(14)]);
+        Ok(())
+    }
 }
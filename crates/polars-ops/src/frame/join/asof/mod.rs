@@ -11,11 +11,13 @@ use smartstring::alias::String as SmartString;
 
 #[cfg(feature = "dtype-categorical")]
 use super::_check_categorical_src;
-use super::{_finish_join, build_tables, prepare_bytes};
+use super::{_finish_join, build_tables, prepare_keys_multiple};
 use crate::frame::IntoDf;
 use crate::series::SeriesMethods;
 
-trait AsofJoinState<T>: Default {
+trait AsofJoinState<T> {
+    fn new(allow_eq: bool) -> Self;
+
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
         left_val: &T,
@@ -24,12 +26,19 @@ trait AsofJoinState<T>: Default {
     ) -> Option<IdxSize>;
 }
 
-#[derive(Default)]
 struct AsofJoinForwardState {
     scan_offset: IdxSize,
+    allow_eq: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinForwardState {
+            scan_offset: 0,
+            allow_eq,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -39,7 +48,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     ) -> Option<IdxSize> {
         while (self.scan_offset) < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val >= *left_val {
+                if right_val > *left_val || (self.allow_eq && right_val == *left_val) {
                     return Some(self.scan_offset);
                 }
             }
@@ -49,14 +58,22 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinForwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinBackwardState {
-    // best_bound is the greatest right index <= left_val.
+    // best_bound is the greatest right index <= left_val (< left_val if !allow_eq).
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    allow_eq: bool,
 }
 
 impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinBackwardState {
+            best_bound: None,
+            scan_offset: 0,
+            allow_eq,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -66,7 +83,7 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     ) -> Option<IdxSize> {
         while self.scan_offset < n_right {
             if let Some(right_val) = right(self.scan_offset) {
-                if right_val <= *left_val {
+                if right_val < *left_val || (self.allow_eq && right_val == *left_val) {
                     self.best_bound = Some(self.scan_offset);
                 } else {
                     break;
@@ -78,14 +95,90 @@ impl<T: PartialOrd> AsofJoinState<T> for AsofJoinBackwardState {
     }
 }
 
-#[derive(Default)]
 struct AsofJoinNearestState {
     // best_bound is the nearest value to left_val, with ties broken towards the last element.
     best_bound: Option<IdxSize>,
     scan_offset: IdxSize,
+    allow_eq: bool,
+    // Only used if exact matches are not allowed. These are the first right
+    // index > left_val and the last index of the run of values equal to it.
+    forward_offset: IdxSize,
+    run_end: IdxSize,
+}
+
+impl AsofJoinNearestState {
+    /// Variant of `next` that never matches a right value equal to left_val.
+    ///
+    /// The right values equal to left_val are skipped without being consumed,
+    /// as a later (greater) left value may still need them as its backward
+    /// bound. All offsets only move forward, so this stays linear.
+    #[inline]
+    fn next_exclusive<T: NumericNative, F: FnMut(IdxSize) -> Option<T>>(
+        &mut self,
+        left_val: &T,
+        mut right: F,
+        n_right: IdxSize,
+    ) -> Option<IdxSize> {
+        // The greatest right index < left_val.
+        while self.scan_offset < n_right {
+            if let Some(right_val) = right(self.scan_offset) {
+                if right_val < *left_val {
+                    self.best_bound = Some(self.scan_offset);
+                } else {
+                    break;
+                }
+            }
+            self.scan_offset += 1;
+        }
+
+        // The first right index > left_val.
+        self.forward_offset = self.forward_offset.max(self.scan_offset);
+        let mut forward_val = None;
+        while self.forward_offset < n_right {
+            if let Some(right_val) = right(self.forward_offset) {
+                if right_val > *left_val {
+                    forward_val = Some(right_val);
+                    break;
+                }
+            }
+            self.forward_offset += 1;
+        }
+
+        let Some(forward_val) = forward_val else {
+            return self.best_bound;
+        };
+
+        // Ties are broken towards the last element.
+        self.run_end = self.run_end.max(self.forward_offset);
+        while self.run_end + 1 < n_right && right(self.run_end + 1) == Some(forward_val) {
+            self.run_end += 1;
+        }
+
+        match self.best_bound {
+            Some(best_idx) => {
+                let best_right_val = unsafe { right(best_idx).unwrap_unchecked() };
+                if left_val.abs_diff(forward_val) <= left_val.abs_diff(best_right_val) {
+                    Some(self.run_end)
+                } else {
+                    Some(best_idx)
+                }
+            },
+            None => Some(self.run_end),
+        }
+    }
 }
 
 impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
+    fn new(allow_eq: bool) -> Self {
+        AsofJoinNearestState {
+            best_bound: None,
+            scan_offset: 0,
+            allow_eq,
+            forward_offset: 0,
+            run_end: 0,
+        }
+    }
+
     #[inline]
     fn next<F: FnMut(IdxSize) -> Option<T>>(
         &mut self,
@@ -93,6 +186,10 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
         mut right: F,
         n_right: IdxSize,
     ) -> Option<IdxSize> {
+        if !self.allow_eq {
+            return self.next_exclusive(left_val, right, n_right);
+        }
+
         // Skipping ahead to the first value greater than left_val. This is
         // cheaper than computing differences.
         while self.scan_offset < n_right {
@@ -142,8 +239,10 @@ impl<T: NumericNative> AsofJoinState<T> for AsofJoinNearestState {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+// Plans serialized before `allow_eq` was added deserialize with the defaults.
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct AsOfOptions {
     pub strategy: AsofStrategy,
     /// A tolerance in the same unit as the asof column
@@ -155,6 +254,23 @@ pub struct AsOfOptions {
     pub tolerance_str: Option<SmartString>,
     pub left_by: Option<Vec<SmartString>>,
     pub right_by: Option<Vec<SmartString>>,
+    /// Allow matching with a right key that is equal to the left key.
+    /// If `false`, only strictly smaller (backward) or greater (forward)
+    /// keys are matched.
+    pub allow_eq: bool,
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        Self {
+            strategy: Default::default(),
+            tolerance: None,
+            tolerance_str: None,
+            left_by: None,
+            right_by: None,
+            allow_eq: true,
+        }
+    }
 }
 
 fn check_asof_columns(
@@ -194,9 +310,11 @@ fn check_asof_columns(
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AsofStrategy {
     /// selects the last row in the right DataFrame whose ‘on’ key is less than or equal to the left’s key
+    /// (strictly less than if exact matches are not allowed)
     #[default]
     Backward,
-    /// selects the first row in the right DataFrame whose ‘on’ key is greater than or equal to the left’s key.
+    /// selects the first row in the right DataFrame whose ‘on’ key is greater than or equal to the left’s key
+    /// (strictly greater than if exact matches are not allowed).
     Forward,
     /// selects the right in the right DataFrame whose 'on' key is nearest to the left's key.
     Nearest,
@@ -212,6 +330,7 @@ pub trait AsofJoin: IntoDf {
         right_key: &Series,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        allow_eq: bool,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
         coalesce: bool,
@@ -225,46 +344,46 @@ pub trait AsofJoin: IntoDf {
         let mut take_idx = match left_key.dtype() {
             DataType::Int64 => {
                 let ca = left_key.i64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Int32 => {
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::UInt64 => {
                 let ca = left_key.u64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::UInt32 => {
                 let ca = left_key.u32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Float32 => {
                 let ca = left_key.f32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Float64 => {
                 let ca = left_key.f64().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
             DataType::Boolean => {
                 let ca = left_key.bool().unwrap();
-                join_asof::<BooleanType>(ca, &right_key, strategy)
+                join_asof::<BooleanType>(ca, &right_key, strategy, allow_eq)
             },
             DataType::Binary => {
                 let ca = left_key.binary().unwrap();
-                join_asof::<BinaryType>(ca, &right_key, strategy)
+                join_asof::<BinaryType>(ca, &right_key, strategy, allow_eq)
             },
             DataType::String => {
                 let ca = left_key.str().unwrap();
                 let right_binary = right_key.cast(&DataType::Binary).unwrap();
-                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy)
+                join_asof::<BinaryType>(&ca.as_binary(), &right_binary, strategy, allow_eq)
             },
             _ => {
                 let left_key = left_key.cast(&DataType::Int32).unwrap();
                 let right_key = right_key.cast(&DataType::Int32).unwrap();
                 let ca = left_key.i32().unwrap();
-                join_asof_numeric(ca, &right_key, strategy, tolerance)
+                join_asof_numeric(ca, &right_key, strategy, tolerance, allow_eq)
             },
         }?;
 
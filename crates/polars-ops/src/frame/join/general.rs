@@ -104,3 +104,34 @@ pub(crate) fn create_chunked_index_mapping(chunks: &[ArrayRef], len: usize) -> V
 
     vals
 }
+
+/// The dense ranks of the values in a single ascending order; null values have no rank.
+#[cfg(any(feature = "iejoin", feature = "interval_ops"))]
+pub(crate) fn dense_ranks(values: &Series) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let n = values.len();
+    let n_valid = n - values.null_count();
+    let mut ranks = vec![None; n];
+    if n_valid == 0 {
+        return Ok(ranks);
+    }
+    let idx = values.arg_sort(SortOptions {
+        nulls_last: true,
+        multithreaded: true,
+        ..Default::default()
+    });
+    let sorted = values.take(&idx)?;
+    let changed = sorted
+        .slice(1, n_valid - 1)
+        .not_equal(&sorted.slice(0, n_valid - 1))?;
+
+    let mut rank = 0 as IdxSize;
+    let mut idx = idx.into_no_null_iter();
+    ranks[idx.next().unwrap() as usize] = Some(rank);
+    for (row, changed) in idx.zip(changed.into_iter()) {
+        if changed.unwrap_or(true) {
+            rank += 1;
+        }
+        ranks[row as usize] = Some(rank);
+    }
+    Ok(ranks)
+}
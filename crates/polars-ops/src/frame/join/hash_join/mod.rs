@@ -11,8 +11,6 @@ use polars_core::utils::_set_partition_size;
 use polars_core::POOL;
 use polars_utils::index::ChunkId;
 pub(super) use single_keys::*;
-#[cfg(feature = "asof_join")]
-pub(super) use single_keys_dispatch::prepare_bytes;
 pub use single_keys_dispatch::SeriesJoin;
 use single_keys_inner::*;
 use single_keys_left::*;
@@ -93,13 +91,14 @@ pub trait JoinDispatch: IntoDf {
         }
     }
 
+    /// Take the rows of both tables of a left join, where `self` is the left table.
     #[cfg(not(feature = "chunked_ids"))]
-    fn _finish_left_join(
+    fn _materialize_left_join(
         &self,
         ids: LeftJoinIds,
         other: &DataFrame,
-        args: JoinArgs,
-    ) -> PolarsResult<DataFrame> {
+        _args: &JoinArgs,
+    ) -> (DataFrame, DataFrame) {
         let ca_self = self.to_df();
         let (left_idx, right_idx) = ids;
         let materialize_left =
@@ -109,20 +108,18 @@ pub trait JoinDispatch: IntoDf {
             let right_idx = &*right_idx;
             unsafe { IdxCa::with_nullable_idx(right_idx, |idx| other.take_unchecked(idx)) }
         };
-        let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
-
-        _finish_join(df_left, df_right, args.suffix.as_deref())
+        POOL.join(materialize_left, materialize_right)
     }
 
+    /// Take the rows of both tables of a left join, where `self` is the left table.
     #[cfg(feature = "chunked_ids")]
-    fn _finish_left_join(
+    fn _materialize_left_join(
         &self,
         ids: LeftJoinIds,
         other: &DataFrame,
-        args: JoinArgs,
-    ) -> PolarsResult<DataFrame> {
+        args: &JoinArgs,
+    ) -> (DataFrame, DataFrame) {
         let ca_self = self.to_df();
-        let suffix = &args.suffix;
         let (left_idx, right_idx) = ids;
         let materialize_left = || match left_idx {
             ChunkJoinIds::Left(left_idx) => unsafe {
@@ -157,9 +154,17 @@ pub trait JoinDispatch: IntoDf {
                 other._take_opt_chunked_unchecked(right_idx)
             },
         };
-        let (df_left, df_right) = POOL.join(materialize_left, materialize_right);
+        POOL.join(materialize_left, materialize_right)
+    }
 
-        _finish_join(df_left, df_right, suffix.as_deref())
+    fn _finish_left_join(
+        &self,
+        ids: LeftJoinIds,
+        other: &DataFrame,
+        args: JoinArgs,
+    ) -> PolarsResult<DataFrame> {
+        let (df_left, df_right) = self._materialize_left_join(ids, other, &args);
+        _finish_join(df_left, df_right, args.suffix.as_deref())
     }
 
     fn _left_join_from_series(
@@ -208,6 +213,61 @@ pub trait JoinDispatch: IntoDf {
         left._finish_left_join(ids, &right, args)
     }
 
+    fn _right_join_from_series(
+        &self,
+        other: &DataFrame,
+        s_left: &Series,
+        s_right: &Series,
+        args: JoinArgs,
+        verbose: bool,
+        drop_names: Option<&[&str]>,
+    ) -> PolarsResult<DataFrame> {
+        let df_self = self.to_df();
+        #[cfg(feature = "dtype-categorical")]
+        _check_categorical_src(s_left.dtype(), s_right.dtype())?;
+
+        let mut right = other.clone();
+        let mut s_right = s_right.clone();
+        // Eagerly limit right if possible.
+        if let Some((offset, len)) = args.slice {
+            if offset == 0 {
+                right = right.slice(0, len);
+                s_right = s_right.slice(0, len);
+            }
+        }
+
+        // Ensure that the chunks are aligned otherwise we go OOB.
+        let mut left = Cow::Borrowed(df_self);
+        let mut s_left = s_left.clone();
+        if right.should_rechunk() {
+            right.as_single_chunk_par();
+            s_right = s_right.rechunk();
+        }
+        if left.should_rechunk() {
+            let mut df_self = df_self.clone();
+            df_self.as_single_chunk_par();
+            left = Cow::Owned(df_self);
+            s_left = s_left.rechunk();
+        }
+
+        // A right join is a left join of the right table with the left table, of which
+        // the output keeps the left columns first.
+        let ids = sort_or_hash_left(
+            &s_right,
+            &s_left,
+            verbose,
+            args.validation.swap(true),
+            args.join_nulls,
+        )?;
+        let left = if let Some(drop_names) = drop_names {
+            left.drop_many(drop_names)
+        } else {
+            left.drop(s_left.name()).unwrap()
+        };
+        let (df_right, df_left) = right._materialize_left_join(ids, &left, &args);
+        _finish_join(df_left, df_right, args.suffix.as_deref())
+    }
+
     #[cfg(feature = "semi_anti_join")]
     /// # Safety
     /// `idx` must be in bounds
@@ -0,0 +1,49 @@
+/// A bitset with a summary bit per word, marking the words that have any bit set, such
+/// that iterating over the set bits skips empty stretches quickly.
+pub(super) struct SummaryBitset {
+    words: Vec<u64>,
+    summary: Vec<u64>,
+}
+
+impl SummaryBitset {
+    pub(super) fn new(len: usize) -> Self {
+        let n_words = len.div_ceil(64);
+        Self {
+            words: vec![0; n_words],
+            summary: vec![0; n_words.div_ceil(64)],
+        }
+    }
+
+    pub(super) fn set(&mut self, i: usize) {
+        let word = i / 64;
+        self.words[word] |= 1 << (i % 64);
+        self.summary[word / 64] |= 1 << (word % 64);
+    }
+
+    /// Call `f` with every set bit before `end`, in ascending order.
+    pub(super) fn for_each_set_before(&self, end: usize, mut f: impl FnMut(usize)) {
+        let end_word = end / 64;
+        for (s, &summary) in self.summary.iter().enumerate() {
+            if s * 64 > end_word {
+                break;
+            }
+            let mut summary = summary;
+            while summary != 0 {
+                let word = s * 64 + summary.trailing_zeros() as usize;
+                summary &= summary - 1;
+                if word > end_word {
+                    return;
+                }
+                let mut bits = self.words[word];
+                if word == end_word {
+                    // only the bits before `end`
+                    bits &= (1u64 << (end % 64)) - 1;
+                }
+                while bits != 0 {
+                    f(word * 64 + bits.trailing_zeros() as usize);
+                    bits &= bits - 1;
+                }
+            }
+        }
+    }
+}
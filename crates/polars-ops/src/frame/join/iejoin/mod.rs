@@ -0,0 +1,308 @@
+mod bitset;
+
+use polars_core::prelude::*;
+use polars_core::utils::{slice_offsets, try_get_supertype};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use self::bitset::SummaryBitset;
+use super::{_finish_join, dense_ranks};
+
+/// An inequality that the key of a left row must satisfy with respect to the key of a
+/// right row, e.g. `Lt` joins rows where `left < right`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum InequalityOperator {
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl InequalityOperator {
+    fn is_strict(&self) -> bool {
+        matches!(self, Self::Lt | Self::Gt)
+    }
+
+    fn is_descending(&self) -> bool {
+        matches!(self, Self::Gt | Self::GtEq)
+    }
+
+    /// The operator with its operands swapped, such that `a op b` is `b op.swap() a`.
+    pub fn swap(&self) -> Self {
+        match self {
+            Self::Lt => Self::Gt,
+            Self::LtEq => Self::GtEq,
+            Self::Gt => Self::Lt,
+            Self::GtEq => Self::LtEq,
+        }
+    }
+}
+
+impl std::fmt::Display for InequalityOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = match self {
+            Self::Lt => "<",
+            Self::LtEq => "<=",
+            Self::Gt => ">",
+            Self::GtEq => ">=",
+        };
+        write!(f, "{op}")
+    }
+}
+
+/// Options of an inequality join, which joins the rows of which the first key satisfies
+/// `operator1` and the (optional) second key satisfies `operator2`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IEJoinOptions {
+    pub operator1: InequalityOperator,
+    pub operator2: Option<InequalityOperator>,
+}
+
+impl IEJoinOptions {
+    pub fn operators(&self) -> impl Iterator<Item = InequalityOperator> {
+        std::iter::once(self.operator1).chain(self.operator2)
+    }
+}
+
+/// The position of the rows of both tables in a single order, such that a left row `l`
+/// comes before a right row `r` if, and only if, the key of `l` satisfies `op` with
+/// respect to the key of `r`. Rows with a null key are left out, as they never match.
+struct JointOrder {
+    /// The rows in order, as (is_right, row) pairs.
+    rows: Vec<(bool, IdxSize)>,
+    n_left: usize,
+    n_right: usize,
+}
+
+impl JointOrder {
+    fn new(left: &Series, right: &Series, op: InequalityOperator) -> PolarsResult<Self> {
+        let ranks = key_ranks(left, right)?;
+        let n_left = left.len();
+        // order by rank; ties are broken by the side of the row, where a strict inequality
+        // puts the right rows first (as equal keys don't match) and otherwise the left rows
+        let mut keyed = ranks
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, rank)| {
+                let rank = rank?;
+                let rank = if op.is_descending() {
+                    IdxSize::MAX - rank
+                } else {
+                    rank
+                };
+                let is_right = i >= n_left;
+                let row = if is_right { i - n_left } else { i } as IdxSize;
+                let tie = is_right != op.is_strict();
+                Some((rank, tie, is_right, row))
+            })
+            .collect::<Vec<_>>();
+        keyed.sort_unstable();
+        let rows = keyed
+            .into_iter()
+            .map(|(_, _, is_right, row)| (is_right, row))
+            .collect();
+        Ok(Self {
+            rows,
+            n_left,
+            n_right: right.len(),
+        })
+    }
+}
+
+/// The dense ranks of the values of both series (left first), in a single ascending
+/// order; null values have no rank.
+fn key_ranks(left: &Series, right: &Series) -> PolarsResult<Vec<Option<IdxSize>>> {
+    let dtype = try_get_supertype(left.dtype(), right.dtype())?;
+    polars_ensure!(
+        !dtype.is_nested() && !dtype.is_categorical() && !dtype.is_enum(),
+        InvalidOperation: "inequality joins on keys of type {} are not supported", dtype
+    );
+    let mut values = left.cast(&dtype)?;
+    values.append(&right.cast(&dtype)?)?;
+    dense_ranks(&values.rechunk())
+}
+
+/// Join on a single inequality: a right row matches all left rows that precede it.
+fn range_join(order: &JointOrder) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    let mut preceding = vec![];
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    for &(is_right, row) in &order.rows {
+        if is_right {
+            left_idx.extend_from_slice(&preceding);
+            right_idx.extend(std::iter::repeat(row).take(preceding.len()));
+        } else {
+            preceding.push(row);
+        }
+    }
+    (left_idx, right_idx)
+}
+
+/// Join on two inequalities (IEJoin): a right row matches the left rows that precede it
+/// in both orders. Visiting the rows in the first order, we mark the positions of the left
+/// rows in the second order; the marked positions before that of a right row are its
+/// matches.
+fn ie_join(x_order: &JointOrder, y_order: &JointOrder) -> (Vec<IdxSize>, Vec<IdxSize>) {
+    // rows with a null second key have no position
+    let mut left_pos = vec![usize::MAX; y_order.n_left];
+    let mut right_pos = vec![usize::MAX; y_order.n_right];
+    // the left row at each position of the second order
+    let mut y_left_rows = Vec::with_capacity(y_order.rows.len());
+    for (pos, &(is_right, row)) in y_order.rows.iter().enumerate() {
+        if is_right {
+            right_pos[row as usize] = pos;
+        } else {
+            left_pos[row as usize] = pos;
+        }
+        y_left_rows.push(row);
+    }
+
+    let mut marked = SummaryBitset::new(y_order.rows.len());
+    let mut left_idx = vec![];
+    let mut right_idx = vec![];
+    for &(is_right, row) in &x_order.rows {
+        if is_right {
+            let end = right_pos[row as usize];
+            if end != usize::MAX {
+                marked.for_each_set_before(end, |pos| {
+                    left_idx.push(y_left_rows[pos]);
+                    right_idx.push(row);
+                });
+            }
+        } else {
+            let pos = left_pos[row as usize];
+            if pos != usize::MAX {
+                marked.set(pos);
+            }
+        }
+    }
+    (left_idx, right_idx)
+}
+
+/// Inequality join of two tables, on one or two pairs of keys; the order of the output
+/// rows is unspecified.
+pub fn iejoin(
+    left: &DataFrame,
+    right: &DataFrame,
+    selected_left: Vec<Series>,
+    selected_right: Vec<Series>,
+    options: &IEJoinOptions,
+    suffix: Option<&str>,
+    slice: Option<(i64, usize)>,
+) -> PolarsResult<DataFrame> {
+    let n_keys = options.operators().count();
+    polars_ensure!(
+        selected_left.len() == n_keys && selected_right.len() == n_keys,
+        ComputeError: "an inequality join on {} operator(s) expects as many keys on both sides (left: {}, right: {})",
+        n_keys, selected_left.len(), selected_right.len()
+    );
+
+    let x_order = JointOrder::new(&selected_left[0], &selected_right[0], options.operator1)?;
+    let (mut left_idx, mut right_idx) = match options.operator2 {
+        None => range_join(&x_order),
+        Some(operator2) => {
+            let y_order = JointOrder::new(&selected_left[1], &selected_right[1], operator2)?;
+            ie_join(&x_order, &y_order)
+        },
+    };
+
+    if let Some((offset, len)) = slice {
+        let (offset, len) = slice_offsets(offset, len, left_idx.len());
+        left_idx = left_idx[offset..offset + len].to_vec();
+        right_idx = right_idx[offset..offset + len].to_vec();
+    }
+
+    // SAFETY: the indices are rows of the tables
+    let (df_left, df_right) = unsafe {
+        (
+            left._take_unchecked_slice(&left_idx, true),
+            right._take_unchecked_slice(&right_idx, true),
+        )
+    };
+    _finish_join(df_left, df_right, suffix)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_utils::total_ord::TotalOrd;
+
+    use super::*;
+
+    #[test]
+    fn test_iejoin_matches_nested_loop() {
+        use InequalityOperator::*;
+        let left = df! {
+            "x" => [Some(3), Some(1), None, Some(2), Some(2), Some(5), Some(4)],
+            "y" => [Some(1.5), Some(0.0), Some(2.0), None, Some(3.0), Some(1.5), Some(f64::NAN)],
+        }
+        .unwrap();
+        let right = df! {
+            "x" => [Some(2), None, Some(4), Some(1), Some(2), Some(6)],
+            "y" => [Some(1.5), Some(1.0), Some(2.5), Some(-1.0), None, Some(f64::NAN)],
+        }
+        .unwrap();
+        let rows = |df: &DataFrame| {
+            let x = df.column("x").unwrap().i32().unwrap().clone();
+            let y = df.column("y").unwrap().f64().unwrap().clone();
+            x.into_iter().zip(&y).collect::<Vec<_>>()
+        };
+        let cmp = |op: InequalityOperator, l: f64, r: f64| match op {
+            Lt => l.tot_lt(&r),
+            LtEq => l.tot_le(&r),
+            Gt => l.tot_gt(&r),
+            GtEq => l.tot_ge(&r),
+        };
+
+        let ops = [Lt, LtEq, Gt, GtEq];
+        for op1 in ops {
+            for op2 in std::iter::once(None).chain(ops.map(Some)) {
+                let options = IEJoinOptions {
+                    operator1: op1,
+                    operator2: op2,
+                };
+                let mut expected = vec![];
+                for (lx, ly) in rows(&left) {
+                    for (rx, ry) in rows(&right) {
+                        let (Some(lx), Some(rx)) = (lx, rx) else {
+                            continue;
+                        };
+                        let y_match = match (op2, ly, ry) {
+                            (None, _, _) => true,
+                            (Some(op2), Some(ly), Some(ry)) => cmp(op2, ly, ry),
+                            _ => false,
+                        };
+                        if cmp(op1, lx as f64, rx as f64) && y_match {
+                            expected.push(format!("{lx} {ly:?} {rx} {ry:?}"));
+                        }
+                    }
+                }
+                expected.sort_unstable();
+
+                let n_keys = options.operators().count();
+                let out = iejoin(
+                    &left,
+                    &right,
+                    left.select_series(["x", "y"]).unwrap()[..n_keys].to_vec(),
+                    right.select_series(["x", "y"]).unwrap()[..n_keys].to_vec(),
+                    &options,
+                    None,
+                    None,
+                )
+                .unwrap();
+                let mut out_right = out.select(["x_right", "y_right"]).unwrap();
+                out_right.set_column_names(&["x", "y"]).unwrap();
+                let mut actual = rows(&out)
+                    .into_iter()
+                    .zip(rows(&out_right))
+                    .map(|((lx, ly), (rx, ry))| {
+                        format!("{} {ly:?} {} {ry:?}", lx.unwrap(), rx.unwrap())
+                    })
+                    .collect::<Vec<_>>();
+                actual.sort_unstable();
+                assert_eq!(actual, expected, "{op1} {op2:?}");
+            }
+        }
+    }
+}
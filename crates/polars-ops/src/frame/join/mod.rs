@@ -6,6 +6,8 @@ mod checks;
 mod cross_join;
 mod general;
 mod hash_join;
+#[cfg(feature = "iejoin")]
+mod iejoin;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 
@@ -25,9 +27,13 @@ pub use cross_join::CrossJoin;
 use either::Either;
 #[cfg(feature = "chunked_ids")]
 use general::create_chunked_index_mapping;
+#[cfg(any(feature = "iejoin", feature = "interval_ops"))]
+pub(crate) use general::dense_ranks;
 pub use general::{_coalesce_full_join, _finish_join, _join_suffix_name};
 pub use hash_join::*;
 use hashbrown::hash_map::{Entry, RawEntryMut};
+#[cfg(feature = "iejoin")]
+pub use iejoin::{iejoin, IEJoinOptions, InequalityOperator};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::hashing::_HASHMAP_INIT_SIZE;
@@ -117,6 +123,19 @@ pub trait DataFrameJoinOps: IntoDf {
             return left_df.cross_join(other, args.suffix.as_deref(), args.slice);
         }
 
+        #[cfg(feature = "iejoin")]
+        if let JoinType::IEJoin(options) = &args.how {
+            return iejoin(
+                left_df,
+                other,
+                selected_left,
+                selected_right,
+                options,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
         // Clear literals if a frame is empty. Otherwise we could get an oob
         fn clear(s: &mut [Series]) {
             for s in s.iter_mut() {
@@ -141,7 +160,7 @@ pub trait DataFrameJoinOps: IntoDf {
             // the others not yet.
             // TODO! change this to other join types once they support chunked-id joins
             if _check_rechunk
-                && !(matches!(args.how, JoinType::Left)
+                && !(matches!(args.how, JoinType::Left | JoinType::Right)
                     || std::env::var("POLARS_NO_CHUNKED_JOIN").is_ok())
             {
                 let mut left = Cow::Borrowed(left_df);
@@ -211,6 +230,8 @@ pub trait DataFrameJoinOps: IntoDf {
                     ._inner_join_from_series(other, s_left, s_right, args, _verbose, drop_names),
                 JoinType::Left => left_df
                     ._left_join_from_series(other, s_left, s_right, args, _verbose, drop_names),
+                JoinType::Right => left_df
+                    ._right_join_from_series(other, s_left, s_right, args, _verbose, drop_names),
                 JoinType::Full => left_df._full_join_from_series(other, s_left, s_right, args),
                 #[cfg(feature = "semi_anti_join")]
                 JoinType::Anti => left_df._semi_anti_join_from_series(
@@ -238,6 +259,7 @@ pub trait DataFrameJoinOps: IntoDf {
                         right_by,
                         options.strategy,
                         options.tolerance,
+                        options.allow_eq,
                         args.suffix.as_deref(),
                         args.slice,
                         should_coalesce,
@@ -248,6 +270,7 @@ pub trait DataFrameJoinOps: IntoDf {
                         s_right,
                         options.strategy,
                         options.tolerance,
+                        options.allow_eq,
                         args.suffix,
                         args.slice,
                         should_coalesce,
@@ -259,6 +282,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 },
+                #[cfg(feature = "iejoin")]
+                JoinType::IEJoin(_) => {
+                    unreachable!()
+                },
             };
         }
 
@@ -266,7 +293,13 @@ pub trait DataFrameJoinOps: IntoDf {
         let rhs_keys = prepare_keys_multiple(&selected_right, args.join_nulls)?.into_series();
 
         let drop_names = if should_coalesce {
-            Some(selected_right.iter().map(|s| s.name()).collect::<Vec<_>>())
+            // a right join keeps the keys of the right table
+            let dropped = if matches!(args.how, JoinType::Right) {
+                &selected_left
+            } else {
+                &selected_right
+            };
+            Some(dropped.iter().map(|s| s.name()).collect::<Vec<_>>())
         } else {
             Some(vec![])
         };
@@ -280,6 +313,10 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             },
+            #[cfg(feature = "iejoin")]
+            JoinType::IEJoin(_) => {
+                unreachable!()
+            },
             JoinType::Full => {
                 let names_left = selected_left.iter().map(|s| s.name()).collect::<Vec<_>>();
                 args.coalesce = JoinCoalesce::KeepColumns;
@@ -314,6 +351,14 @@ pub trait DataFrameJoinOps: IntoDf {
                 _verbose,
                 drop_names.as_deref(),
             ),
+            JoinType::Right => left_df._right_join_from_series(
+                other,
+                &lhs_keys,
+                &rhs_keys,
+                args,
+                _verbose,
+                drop_names.as_deref(),
+            ),
             #[cfg(feature = "semi_anti_join")]
             JoinType::Anti | JoinType::Semi => self._join_impl(
                 other,
@@ -393,6 +438,31 @@ pub trait DataFrameJoinOps: IntoDf {
         self.join(other, left_on, right_on, JoinArgs::new(JoinType::Left))
     }
 
+    /// Perform a right outer join on two DataFrames. The rows of `other` keep their order
+    /// and the columns of `self` come first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(left: &DataFrame, right: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     left.right_join(right, ["join_column_left"], ["join_column_right"])
+    /// }
+    /// ```
+    fn right_join<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinArgs::new(JoinType::Right))
+    }
+
     /// Perform a full outer join on two DataFrames
     /// # Example
     ///
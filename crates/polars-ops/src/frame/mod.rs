@@ -1,3 +1,5 @@
+#[cfg(feature = "interval_ops")]
+pub mod interval;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
@@ -4,6 +4,8 @@ pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
+#[cfg(feature = "interval_ops")]
+pub use crate::frame::interval::{IntervalColumns, IntervalJoinType, IntervalOps};
 pub use crate::frame::join::*;
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
 pub use crate::series::*;
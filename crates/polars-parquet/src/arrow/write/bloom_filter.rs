@@ -0,0 +1,144 @@
+use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, Utf8Array, Utf8ViewArray};
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+use num_traits::AsPrimitive;
+
+use super::BloomFilterOptions;
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, num_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// Returns whether [`array_to_bloom_filter`] supports arrays of `data_type`.
+pub fn supports_bloom_filter(data_type: &ArrowDataType) -> bool {
+    use ArrowDataType as D;
+    matches!(
+        data_type.to_logical_type(),
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Float32
+            | D::Float64
+            | D::Date32
+            | D::Date64
+            | D::Time32(_)
+            | D::Time64(_)
+            | D::Timestamp(_, _)
+            | D::Duration(_)
+            | D::Binary
+            | D::LargeBinary
+            | D::Utf8
+            | D::LargeUtf8
+            | D::BinaryView
+            | D::Utf8View
+    )
+}
+
+fn insert_primitive<T, P>(array: &dyn Array, bitset: &mut [u8])
+where
+    T: NativeType + AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    for value in array.iter().flatten() {
+        insert(bitset, hash_native::<P>(value.as_()));
+    }
+}
+
+fn insert_bytes<'a, I: Iterator<Item = Option<&'a [u8]>>>(iter: I, bitset: &mut [u8]) {
+    for value in iter.flatten() {
+        insert(bitset, hash_byte(value));
+    }
+}
+
+/// Builds a split-block bloom filter of the non-null values in `array`.
+///
+/// Values are hashed in the parquet physical type they are written as, e.g. a `UInt8` is hashed
+/// as an `INT32`, such that readers of the file can probe the filter.
+///
+/// Returns `None` if the [`ArrowDataType`] is not supported, see [`supports_bloom_filter`].
+pub fn array_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    use ArrowDataType as D;
+
+    if !supports_bloom_filter(array.data_type()) {
+        return None;
+    }
+
+    let ndv = options
+        .ndv
+        .unwrap_or((array.len() - array.null_count()) as u64);
+    let mut bitset = vec![0; num_bytes(ndv, options.fpp)];
+
+    // casts below MUST match the casts done in `array_to_page_simple`.
+    match array.data_type().to_logical_type() {
+        D::Int8 => insert_primitive::<i8, i32>(array, &mut bitset),
+        D::Int16 => insert_primitive::<i16, i32>(array, &mut bitset),
+        D::Int32 | D::Date32 | D::Time32(_) => insert_primitive::<i32, i32>(array, &mut bitset),
+        D::UInt8 => insert_primitive::<u8, i32>(array, &mut bitset),
+        D::UInt16 => insert_primitive::<u16, i32>(array, &mut bitset),
+        D::UInt32 => insert_primitive::<u32, i32>(array, &mut bitset),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            insert_primitive::<i64, i64>(array, &mut bitset)
+        },
+        D::UInt64 => insert_primitive::<u64, i64>(array, &mut bitset),
+        D::Float32 => insert_primitive::<f32, f32>(array, &mut bitset),
+        D::Float64 => insert_primitive::<f64, f64>(array, &mut bitset),
+        D::Binary => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .iter(),
+            &mut bitset,
+        ),
+        D::LargeBinary => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .iter(),
+            &mut bitset,
+        ),
+        D::Utf8 => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            &mut bitset,
+        ),
+        D::LargeUtf8 => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            &mut bitset,
+        ),
+        D::BinaryView => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .unwrap()
+                .iter(),
+            &mut bitset,
+        ),
+        D::Utf8View => insert_bytes(
+            array
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+            &mut bitset,
+        ),
+        _ => unreachable!(),
+    }
+
+    Some(bitset)
+}
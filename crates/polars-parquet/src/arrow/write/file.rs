@@ -75,6 +75,12 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(row_group)?)
     }
 
+    /// Writes the bloom filters of the last written row group, one per leaf column.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filters(&mut self, bitsets: &[Option<Vec<u8>>]) -> PolarsResult<()> {
+        Ok(self.writer.write_bloom_filters(bitsets)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> PolarsResult<u64> {
         let key_value_metadata = add_arrow_schema(&self.schema, key_value_metadata);
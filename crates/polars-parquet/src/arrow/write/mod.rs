@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -71,6 +73,35 @@ impl Default for StatisticsOptions {
     }
 }
 
+/// The settings of a split-block bloom filter for a column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BloomFilterOptions {
+    /// The expected number of distinct values per row group. If `None`, the number of non-null
+    /// values in the row group is used, which is an upper bound.
+    pub ndv: Option<u64>,
+    /// The false positive probability, in the open interval `(0, 1)`.
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: None,
+            fpp: 0.05,
+        }
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl std::hash::Hash for BloomFilterOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.ndv.hash(state);
+        self.fpp.to_bits().hash(state);
+    }
+}
+
 /// Currently supported options to write to parquet
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct WriteOptions {
@@ -86,6 +117,8 @@ pub struct WriteOptions {
 
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filter, supports_bloom_filter};
 pub use file::FileWriter;
 pub use pages::{array_to_columns, arrays_to_columns, Nested};
 use polars_error::{polars_bail, PolarsResult};
@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
 pub use read::read;
 pub use split_block::{insert, is_in_set};
+pub use write::{num_bytes, write};
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,14 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn sizing() {
+        assert_eq!(num_bytes(0, 0.05), 32);
+        assert_eq!(num_bytes(10, 0.05), 32);
+        // ~6.9 bits per value at 5% fpp, rounded up to a power of two
+        assert_eq!(num_bytes(1_000_000, 0.05), 1024 * 1024);
+        assert!(num_bytes(1_000_000, 0.01) > num_bytes(1_000_000, 0.05));
+        assert!(num_bytes(u64::MAX, 0.01) <= 128 * 1024 * 1024);
+    }
 }
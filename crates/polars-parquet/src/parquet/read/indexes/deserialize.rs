@@ -9,7 +9,9 @@ pub fn deserialize(
     data: &[u8],
     primitive_type: PrimitiveType,
 ) -> Result<Box<dyn Index>, ParquetError> {
-    let mut prot = TCompactInputProtocol::new(data, data.len() * 2 + 1024);
+    // every list element is accounted for as a `usize`, and may be a single byte, e.g. an
+    // empty min value. Indexes of many pages would otherwise hit the limit.
+    let mut prot = TCompactInputProtocol::new(data, data.len() * 8 + 1024);
 
     let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
 
@@ -171,6 +171,38 @@ impl<W: Write> FileWriter<W> {
         Ok(())
     }
 
+    /// Writes the bloom filters of the last written row group to the file.
+    ///
+    /// `bitsets` holds an optional split-block bloom filter per leaf column of the row group.
+    ///
+    /// # Errors
+    /// Returns an error if no row group has been written yet or if the number of bitsets
+    /// does not match the number of columns.
+    #[cfg(feature = "bloom_filter")]
+    pub fn write_bloom_filters(&mut self, bitsets: &[Option<Vec<u8>>]) -> ParquetResult<()> {
+        let Some(group) = self.row_groups.last_mut() else {
+            return Err(ParquetError::InvalidParameter(
+                "Bloom filters can only be written after a row group".to_string(),
+            ));
+        };
+        if group.columns.len() != bitsets.len() {
+            return Err(ParquetError::InvalidParameter(format!(
+                "Expected {} bloom filters, got {}",
+                group.columns.len(),
+                bitsets.len()
+            )));
+        }
+
+        for (column, bitset) in group.columns.iter_mut().zip(bitsets) {
+            if let Some(bitset) = bitset {
+                let offset = self.offset;
+                self.offset += crate::parquet::bloom_filter::write(&mut self.writer, bitset)?;
+                column.meta_data.as_mut().unwrap().bloom_filter_offset = Some(offset as i64);
+            }
+        }
+        Ok(())
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file and the
     /// underlying writer.
     pub fn end(&mut self, key_value_metadata: Option<Vec<KeyValue>>) -> ParquetResult<u64> {
@@ -0,0 +1,378 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::BinaryArray;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_ops::prelude::{AsOfOptions, AsofJoin, AsofJoinBy, AsofStrategy};
+use polars_ops::series::{search_sorted, SearchSortedSide, SeriesMethods};
+use polars_utils::arena::Node;
+use smartstring::alias::String as SmartString;
+
+use crate::executors::operators::PlaceHolder;
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{
+    chunks_to_df_unchecked, DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext,
+    Sink, SinkResult,
+};
+
+/// Build side of the streaming asof join.
+///
+/// The right table is collected in memory and must be sorted by its asof key
+/// (within the `by` groups, if any). The left table is streamed through the
+/// [`AsofJoinProbe`], which joins every chunk with only the part of the right
+/// table that can match the key range of that chunk.
+///
+/// Only the left table is processed out-of-core: the right table and the index
+/// of its `by` groups must fit in memory.
+pub struct AsofJoinBuild {
+    chunks: Vec<DataChunk>,
+    suffix: Arc<str>,
+    options: Arc<AsOfOptions>,
+    coalesce: bool,
+    join_column_left: Arc<dyn PhysicalPipedExpr>,
+    join_column_right: Arc<dyn PhysicalPipedExpr>,
+    /// The dtypes the `by` columns of both tables are cast to before they are
+    /// row-encoded, so that the encodings of equal keys match.
+    by_dtypes: Arc<[DataType]>,
+    node: Node,
+    placeholder: PlaceHolder,
+}
+
+impl AsofJoinBuild {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        suffix: Arc<str>,
+        options: Arc<AsOfOptions>,
+        coalesce: bool,
+        join_column_left: Arc<dyn PhysicalPipedExpr>,
+        join_column_right: Arc<dyn PhysicalPipedExpr>,
+        by_dtypes: Arc<[DataType]>,
+        node: Node,
+        placeholder: PlaceHolder,
+    ) -> Self {
+        AsofJoinBuild {
+            chunks: vec![],
+            suffix,
+            options,
+            coalesce,
+            join_column_left,
+            join_column_right,
+            by_dtypes,
+            node,
+            placeholder,
+        }
+    }
+}
+
+impl Sink for AsofJoinBuild {
+    fn node(&self) -> Node {
+        self.node
+    }
+    fn is_join_build(&self) -> bool {
+        true
+    }
+
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.chunks.push(chunk);
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        let other_chunks = std::mem::take(&mut other.chunks);
+        self.chunks.extend(other_chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(
+            self.suffix.clone(),
+            self.options.clone(),
+            self.coalesce,
+            self.join_column_left.clone(),
+            self.join_column_right.clone(),
+            self.by_dtypes.clone(),
+            self.node,
+            self.placeholder.clone(),
+        ))
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // The asof join depends on the order of the right table.
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        let mut df = chunks_to_df_unchecked(chunks);
+        df.as_single_chunk_par();
+
+        let chunk = DataChunk {
+            data: df,
+            chunk_index: 0,
+        };
+        let mut right_key = self
+            .join_column_right
+            .evaluate(&chunk, &context.execution_state)?;
+        let df = chunk.data;
+
+        let groups = match &self.options.right_by {
+            Some(right_by) => Some(Arc::new(build_groups(
+                &df,
+                &right_key,
+                right_by,
+                &self.by_dtypes,
+            )?)),
+            None => {
+                right_key.ensure_sorted_arg("asof_join")?;
+                // Ensures the slices we take in the probe phase don't check this again.
+                right_key.set_sorted_flag(IsSorted::Ascending);
+                None
+            },
+        };
+
+        let op = Box::new(AsofJoinProbe {
+            df: Arc::new(df),
+            right_key,
+            groups,
+            suffix: self.suffix.clone(),
+            options: self.options.clone(),
+            coalesce: self.coalesce,
+            join_column_left: self.join_column_left.clone(),
+            by_dtypes: self.by_dtypes.clone(),
+        });
+        self.placeholder.replace(op);
+
+        Ok(FinalizedSink::Operator)
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "asof_join_sink"
+    }
+}
+
+struct AsofGroup {
+    /// Indices of the rows of this group in the right table.
+    idx: IdxCa,
+    /// The asof keys of those rows.
+    key: Series,
+}
+
+type AsofGroups = PlHashMap<Vec<u8>, AsofGroup>;
+
+fn encode_by(
+    df: &DataFrame,
+    by: &[SmartString],
+    by_dtypes: &[DataType],
+) -> PolarsResult<BinaryArray<i64>> {
+    let columns = by
+        .iter()
+        .zip(by_dtypes)
+        .map(|(name, dtype)| {
+            let s = df.column(name)?.cast(dtype)?;
+            let s = s.to_physical_repr();
+            let s = match s.dtype() {
+                DataType::Float32 => s.f32().unwrap().to_canonical().into_series(),
+                DataType::Float64 => s.f64().unwrap().to_canonical().into_series(),
+                _ => s.into_owned(),
+            };
+            Ok(s.rechunk().array_ref(0).clone())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(polars_row::convert_columns_no_order(&columns).into_array())
+}
+
+fn build_groups(
+    df: &DataFrame,
+    right_key: &Series,
+    right_by: &[SmartString],
+    by_dtypes: &[DataType],
+) -> PolarsResult<AsofGroups> {
+    let rows = encode_by(df, right_by, by_dtypes)?;
+    let mut groups: PlHashMap<Vec<u8>, Vec<IdxSize>> = PlHashMap::new();
+    for (idx, row) in rows.values_iter().enumerate() {
+        match groups.get_mut(row) {
+            Some(group) => group.push(idx as IdxSize),
+            None => {
+                groups.insert(row.to_vec(), vec![idx as IdxSize]);
+            },
+        }
+    }
+
+    Ok(groups
+        .into_iter()
+        .map(|(row, idx)| {
+            let idx = IdxCa::from_vec("", idx);
+            // SAFETY: the indices are in bounds.
+            let key = unsafe { right_key.take_unchecked(&idx) };
+            (row, AsofGroup { idx, key })
+        })
+        .collect())
+}
+
+/// Returns the `(offset, len)` slice of the sorted `right_key` that contains
+/// every possible match of a left key in the range `[bounds[0], bounds[1]]`.
+fn asof_window(
+    right_key: &Series,
+    bounds: &Series,
+    strategy: AsofStrategy,
+) -> PolarsResult<(usize, usize)> {
+    let n_right = right_key.len();
+    // Boolean keys can't be searched, but have at most two distinct values anyway.
+    if right_key.dtype().to_physical() == DataType::Boolean {
+        return Ok((0, n_right));
+    }
+    let bounds = bounds.cast(right_key.dtype())?;
+
+    // The first right key >= the smallest left key and > the largest left key.
+    let lower = search_sorted(right_key, &bounds, SearchSortedSide::Left, false)?
+        .get(0)
+        .unwrap() as usize;
+    let upper = search_sorted(right_key, &bounds, SearchSortedSide::Right, false)?
+        .get(1)
+        .unwrap() as usize;
+
+    let (start, end) = match strategy {
+        AsofStrategy::Backward => (lower.saturating_sub(1), upper),
+        AsofStrategy::Forward => (lower, (upper + 1).min(n_right)),
+        AsofStrategy::Nearest => {
+            // Ties are broken towards the last element, so we need the whole run
+            // of right keys that are equal to the first key > the largest left key.
+            let end = if upper < n_right {
+                let next = right_key.slice(upper as i64, 1);
+                search_sorted(right_key, &next, SearchSortedSide::Right, false)?
+                    .get(0)
+                    .unwrap() as usize
+            } else {
+                n_right
+            };
+            (lower.saturating_sub(1), end.max(upper))
+        },
+    };
+    Ok((start, end - start))
+}
+
+#[derive(Clone)]
+pub struct AsofJoinProbe {
+    /// The sorted right table.
+    df: Arc<DataFrame>,
+    right_key: Series,
+    /// The row indices of the right table per `by` key, if joined by groups.
+    groups: Option<Arc<AsofGroups>>,
+    suffix: Arc<str>,
+    options: Arc<AsOfOptions>,
+    coalesce: bool,
+    join_column_left: Arc<dyn PhysicalPipedExpr>,
+    by_dtypes: Arc<[DataType]>,
+}
+
+impl AsofJoinProbe {
+    fn join(&self, left: &DataFrame, left_key: &Series) -> PolarsResult<DataFrame> {
+        let left_key_valid = left_key.drop_nulls();
+        let (offset, len) = if left_key_valid.is_empty() {
+            (0, 0)
+        } else {
+            let bounds = left_key_valid.take_slice(&[0, (left_key_valid.len() - 1) as IdxSize])?;
+            asof_window(&self.right_key, &bounds, self.options.strategy)?
+        };
+        let right = self.df.slice(offset as i64, len);
+        let right_key = self.right_key.slice(offset as i64, len);
+
+        left._join_asof(
+            &right,
+            left_key,
+            &right_key,
+            self.options.strategy,
+            self.options.tolerance.clone(),
+            self.options.allow_eq,
+            Some(self.suffix.to_string()),
+            None,
+            self.coalesce,
+        )
+    }
+
+    fn join_by(
+        &self,
+        groups: &AsofGroups,
+        left: &DataFrame,
+        left_key: &Series,
+    ) -> PolarsResult<DataFrame> {
+        let left_by = self.options.left_by.as_deref().unwrap();
+        let right_by = self.options.right_by.as_deref().unwrap();
+        let rows = encode_by(left, left_by, &self.by_dtypes)?;
+
+        // The left table is sorted within its groups, so the first and last
+        // valid key of a group in this chunk bound the keys of that group.
+        let valid = left_key.is_not_null();
+        let mut ranges: PlHashMap<&[u8], (IdxSize, IdxSize)> = PlHashMap::new();
+        for (idx, (row, is_valid)) in rows.values_iter().zip(&valid).enumerate() {
+            if is_valid == Some(true) {
+                let idx = idx as IdxSize;
+                ranges
+                    .entry(row)
+                    .and_modify(|range| range.1 = idx)
+                    .or_insert((idx, idx));
+            }
+        }
+
+        // Gather the rows of the right table that can be matched. Every group
+        // stays sorted, which is all the in-memory asof join needs.
+        let mut take = vec![];
+        for (row, (first, last)) in ranges {
+            let Some(group) = groups.get(row) else {
+                continue;
+            };
+            let bounds = left_key.take_slice(&[first, last])?;
+            let (offset, len) = asof_window(&group.key, &bounds, self.options.strategy)?;
+            take.extend(group.idx.slice(offset as i64, len).into_no_null_iter());
+        }
+        let take = IdxCa::from_vec("", take);
+        // SAFETY: the indices are in bounds.
+        let (right, right_key) = unsafe {
+            (
+                self.df.take_unchecked(&take),
+                self.right_key.take_unchecked(&take),
+            )
+        };
+
+        left._join_asof_by(
+            &right,
+            left_key,
+            &right_key,
+            left_by.to_vec(),
+            right_by.to_vec(),
+            self.options.strategy,
+            self.options.tolerance.clone(),
+            self.options.allow_eq,
+            Some(self.suffix.as_ref()),
+            None,
+            self.coalesce,
+        )
+    }
+}
+
+impl Operator for AsofJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let left_key = self
+            .join_column_left
+            .evaluate(chunk, &context.execution_state)?;
+        let df = match &self.groups {
+            None => self.join(&chunk.data, &left_key)?,
+            Some(groups) => self.join_by(groups, &chunk.data, &left_key)?,
+        };
+        Ok(OperatorResult::Finished(chunk.with_data(df)))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "asof_join_probe"
+    }
+}
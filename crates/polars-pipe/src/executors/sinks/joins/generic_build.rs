@@ -319,7 +319,7 @@ impl<K: ExtraPayload> Sink for GenericBuild<K> {
         hashes.clear();
 
         match self.join_args.how {
-            JoinType::Inner | JoinType::Left => {
+            JoinType::Inner | JoinType::Left | JoinType::Right => {
                 let probe_operator = GenericJoinProbe::new(
                     left_df,
                     materialized_join_cols,
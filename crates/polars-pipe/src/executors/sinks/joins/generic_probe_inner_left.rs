@@ -37,10 +37,10 @@ pub struct GenericJoinProbe<K: ExtraPayload> {
     hash_tables: Arc<PartitionedMap<K>>,
 
     /// Amortize allocations
-    /// In inner join these are the left table.
+    /// In inner and right join these are the left table.
     /// In left join there are the right table.
     join_tuples_a: Vec<ChunkId>,
-    /// in inner join these are the right table
+    /// in inner and right join these are the right table
     /// in left join there are the left table
     join_tuples_b: Vec<DfIdx>,
     hashes: Vec<u64>,
@@ -70,7 +70,10 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         args: JoinArgs,
         join_nulls: bool,
     ) -> Self {
-        if swapped_or_left && args.should_coalesce() {
+        // The build table holds the join columns that are dropped, except in an inner join
+        // that is not swapped, which drops those of the streamed table.
+        let drop_build_keys = swapped_or_left || matches!(args.how, JoinType::Right);
+        if drop_build_keys && args.should_coalesce() {
             let tmp = DataChunk {
                 data: df_a.slice(0, 1),
                 chunk_index: 0,
@@ -103,7 +106,7 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             output_names: None,
             args,
             join_nulls,
-            row_values: RowValues::new(join_columns_right, !swapped_or_left),
+            row_values: RowValues::new(join_columns_right, !drop_build_keys),
         }
     }
 
@@ -175,15 +178,9 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         }
     }
 
-    fn execute_left(
-        &mut self,
-        context: &PExecutionContext,
-        chunk: &DataChunk,
-    ) -> PolarsResult<OperatorResult> {
-        // A left join holds the right table as build table
-        // and streams the left table through. This allows us to maintain
-        // the left table order
-
+    /// Match every row of the streamed table with the rows of the build table, or with
+    /// a null row if there are none.
+    fn probe_outer(&mut self, context: &PExecutionContext, chunk: &DataChunk) -> PolarsResult<()> {
         self.join_tuples_a.clear();
         self.join_tuples_b.clear();
         let mut hashes = std::mem::take(&mut self.hashes);
@@ -200,6 +197,18 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
             self.match_left(iter);
         }
         self.hashes = hashes;
+        Ok(())
+    }
+
+    fn execute_left(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        // A left join holds the right table as build table
+        // and streams the left table through. This allows us to maintain
+        // the left table order
+        self.probe_outer(context, chunk)?;
         let right_df = self.df_a.as_ref();
 
         // join tuples of left joins are always sorted
@@ -220,6 +229,37 @@ impl<K: ExtraPayload> GenericJoinProbe<K> {
         Ok(OperatorResult::Finished(chunk.with_data(out)))
     }
 
+    fn execute_right(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        // A right join holds the left table as build table
+        // and streams the right table through. This allows us to maintain
+        // the right table order
+        self.probe_outer(context, chunk)?;
+        let left_df = unsafe {
+            self.df_a
+                ._take_opt_chunked_unchecked_seq(&self.join_tuples_a)
+        };
+
+        // join tuples of right joins are always sorted
+        // this will ensure sorted flags maintain
+        let right_df = unsafe {
+            chunk
+                .data
+                ._take_unchecked_slice_sorted(&self.join_tuples_b, false, IsSorted::Ascending)
+        };
+
+        let out = self.finish_join(left_df, right_df)?;
+
+        // Clear memory.
+        self.row_values.clear();
+        self.hashes.clear();
+
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
     fn match_inner<'b, I>(&mut self, iter: I)
     where
         I: Iterator<Item = (usize, (&'b u64, &'b [u8]))> + 'b,
@@ -313,6 +353,7 @@ impl<K: ExtraPayload> Operator for GenericJoinProbe<K> {
         match self.args.how {
             JoinType::Inner => self.execute_inner(context, chunk),
             JoinType::Left => self.execute_left(context, chunk),
+            JoinType::Right => self.execute_right(context, chunk),
             _ => unreachable!(),
         }
     }
@@ -1,3 +1,5 @@
+#[cfg(feature = "asof_join")]
+mod asof;
 #[cfg(feature = "cross_join")]
 mod cross;
 mod generic_build;
@@ -8,6 +10,8 @@ mod row_values;
 use std::hash::{BuildHasherDefault, Hash, Hasher};
 use std::sync::atomic::AtomicBool;
 
+#[cfg(feature = "asof_join")]
+pub(crate) use asof::*;
 #[cfg(feature = "cross_join")]
 pub(crate) use cross::*;
 pub(crate) use generic_build::GenericBuild;
@@ -17,7 +17,9 @@ pub(crate) use ordered::*;
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
 ))]
 pub(crate) use output::*;
 pub(crate) use reproject::*;
@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_io::avro::{AvroWriter, AvroWriterOptions};
+use polars_io::SerWriter;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+pub struct AvroSink {}
+impl AvroSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        options: AvroWriterOptions,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        let file = std::fs::File::create(path)?;
+        let writer = AvroWriter::new(file)
+            .with_compression(options.compression)
+            .with_name(options.name)
+            .batched(schema)?;
+
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            options.maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
+impl<W: std::io::Write> SinkWriter for polars_io::avro::BatchedWriter<W> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()
+    }
+}
@@ -2,7 +2,7 @@ use std::path::Path;
 
 use crossbeam_channel::bounded;
 use polars_core::prelude::*;
-use polars_io::csv::write::{CsvWriter, CsvWriterOptions};
+use polars_io::csv::write::{BatchedWriter, CsvWriter, CsvWriterOptions};
 use polars_io::SerWriter;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
@@ -13,22 +13,8 @@ impl CsvSink {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(path: &Path, options: CsvWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = CsvWriter::new(file)
-            .include_bom(options.include_bom)
-            .include_header(options.include_header)
-            .with_separator(options.serialize_options.separator)
-            .with_line_terminator(options.serialize_options.line_terminator)
-            .with_quote_char(options.serialize_options.quote_char)
-            .with_batch_size(options.batch_size)
-            .with_datetime_format(options.serialize_options.datetime_format)
-            .with_date_format(options.serialize_options.date_format)
-            .with_time_format(options.serialize_options.time_format)
-            .with_float_scientific(options.serialize_options.float_scientific)
-            .with_float_precision(options.serialize_options.float_precision)
-            .with_null_value(options.serialize_options.null)
-            .with_quote_style(options.serialize_options.quote_style)
-            .n_threads(1)
-            .batched(schema)?;
+        let maintain_order = options.maintain_order;
+        let writer = batched_csv_writer(file, options, schema)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
 
@@ -39,7 +25,7 @@ impl CsvSink {
         let io_thread_handle = Arc::new(Some(init_writer_thread(
             receiver,
             writer,
-            options.maintain_order,
+            maintain_order,
             morsels_per_sink,
         )));
 
@@ -50,7 +36,31 @@ impl CsvSink {
     }
 }
 
-impl SinkWriter for polars_io::csv::write::BatchedWriter<std::fs::File> {
+pub(super) fn batched_csv_writer(
+    file: std::fs::File,
+    options: CsvWriterOptions,
+    schema: &Schema,
+) -> PolarsResult<BatchedWriter<std::fs::File>> {
+    CsvWriter::new(file)
+        .include_bom(options.include_bom)
+        .include_header(options.include_header)
+        .with_separator(options.serialize_options.separator)
+        .with_line_terminator(options.serialize_options.line_terminator)
+        .with_quote_char(options.serialize_options.quote_char)
+        .with_batch_size(options.batch_size)
+        .with_datetime_format(options.serialize_options.datetime_format)
+        .with_date_format(options.serialize_options.date_format)
+        .with_time_format(options.serialize_options.time_format)
+        .with_float_scientific(options.serialize_options.float_scientific)
+        .with_float_precision(options.serialize_options.float_precision)
+        .with_null_value(options.serialize_options.null)
+        .with_quote_style(options.serialize_options.quote_style)
+        .with_compression(options.compression)
+        .n_threads(1)
+        .batched(schema)
+}
+
+impl SinkWriter for BatchedWriter<std::fs::File> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
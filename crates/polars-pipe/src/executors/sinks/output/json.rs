@@ -13,7 +13,7 @@ impl SinkWriter for BatchedWriter<std::fs::File> {
     }
 
     fn _finish(&mut self) -> PolarsResult<()> {
-        Ok(())
+        self.finish()
     }
 }
 
@@ -26,7 +26,7 @@ impl JsonSink {
         _schema: &Schema,
     ) -> PolarsResult<FilesSink> {
         let file = std::fs::File::create(path)?;
-        let writer = BatchedWriter::new(file);
+        let writer = BatchedWriter::try_new(file, options.compression)?;
 
         let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
 
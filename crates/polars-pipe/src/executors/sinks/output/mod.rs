@@ -1,24 +1,52 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(any(
     feature = "parquet",
     feature = "ipc",
     feature = "csv",
-    feature = "json"
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
 ))]
 mod file_sink;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "json")]
 mod json;
+#[cfg(feature = "orc")]
+mod orc;
 #[cfg(feature = "parquet")]
 mod parquet;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
+))]
+mod partition;
 
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
 pub use json::*;
+#[cfg(feature = "orc")]
+pub use orc::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "csv",
+    feature = "json",
+    feature = "avro",
+    feature = "orc"
+))]
+pub use partition::*;
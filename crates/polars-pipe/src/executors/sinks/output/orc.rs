@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_io::orc::{OrcWriter, OrcWriterOptions};
+use polars_io::SerWriter;
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+pub struct OrcSink {}
+impl OrcSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(path: &Path, options: OrcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
+        let file = std::fs::File::create(path)?;
+        let writer = OrcWriter::new(file)
+            .with_compression(options.compression)
+            .with_stripe_size(options.stripe_size)
+            .batched(schema)?;
+
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            options.maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
+impl<W: std::io::Write> SinkWriter for polars_io::orc::BatchedWriter<W> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        self.finish()
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use crossbeam_channel::bounded;
+use polars_core::prelude::*;
+use polars_io::partition::resolve_partition_dir;
+#[cfg(any(feature = "ipc", feature = "avro", feature = "orc"))]
+use polars_io::SerWriter;
+use polars_plan::prelude::{FileType, PartitionBy};
+
+use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
+use crate::pipeline::morsels_per_sink;
+
+/// Writes its input into a Hive partitioned dataset, with a `key=value/` directory per
+/// combination of partition keys.
+pub struct PartitionSink {}
+impl PartitionSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        file_type: FileType,
+        partition_by: PartitionBy,
+        schema: &Schema,
+    ) -> PolarsResult<FilesSink> {
+        polars_ensure!(
+            !partition_by.keys.is_empty(),
+            InvalidOperation: "partitioned sink requires at least one partition key"
+        );
+        polars_ensure!(
+            partition_by.max_rows_per_file != Some(0),
+            InvalidOperation: "`max_rows_per_file` must be greater than 0"
+        );
+        polars_ensure!(
+            partition_by.max_open_files > 0,
+            InvalidOperation: "`max_open_files` must be greater than 0"
+        );
+        let mut file_schema = schema.clone();
+        for key in &partition_by.keys {
+            polars_ensure!(
+                file_schema.shift_remove(key).is_some(),
+                ColumnNotFound: "partition key {:?} not found in schema: {:?}", key, schema
+            );
+        }
+        polars_ensure!(
+            !file_schema.is_empty(),
+            InvalidOperation: "partitioned sink requires at least one column that is not a partition key"
+        );
+
+        let maintain_order = match &file_type {
+            #[cfg(feature = "parquet")]
+            FileType::Parquet(options) => options.maintain_order,
+            #[cfg(feature = "ipc")]
+            FileType::Ipc(options) => options.maintain_order,
+            #[cfg(feature = "csv")]
+            FileType::Csv(options) => options.maintain_order,
+            #[cfg(feature = "json")]
+            FileType::Json(options) => options.maintain_order,
+            #[cfg(feature = "avro")]
+            FileType::Avro(options) => options.maintain_order,
+            #[cfg(feature = "orc")]
+            FileType::Orc(options) => options.maintain_order,
+        };
+
+        let writer = PartitionedWriter {
+            rootdir: path.to_path_buf(),
+            file_type,
+            partition_by,
+            file_schema,
+            partitions: Default::default(),
+            open_files: Default::default(),
+            n_writes: 0,
+        };
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
+/// The file that is currently written for a partition.
+struct PartitionFile {
+    /// `None` if the file is finished, e.g. to limit the number of open files.
+    writer: Option<Box<dyn SinkWriter + Send>>,
+    /// Index of the next file in the partition directory.
+    next_index: usize,
+    /// The number of rows written to the file.
+    rows: usize,
+    /// The write after which the file was last written to.
+    last_write: u64,
+}
+
+struct PartitionedWriter {
+    rootdir: PathBuf,
+    file_type: FileType,
+    partition_by: PartitionBy,
+    /// The schema of the written files, i.e. without the partition keys.
+    file_schema: Schema,
+    partitions: PlHashMap<PathBuf, PartitionFile>,
+    /// The partitions with an open file, by the write after which they were last written to.
+    open_files: BTreeMap<u64, PathBuf>,
+    n_writes: u64,
+}
+
+impl PartitionedWriter {
+    fn write_partition(&mut self, dir: PathBuf, mut df: DataFrame) -> PolarsResult<()> {
+        let max_rows_per_file = self.partition_by.max_rows_per_file.unwrap_or(usize::MAX);
+
+        match self.partitions.get(&dir) {
+            Some(file) if file.writer.is_some() => {
+                self.open_files.remove(&file.last_write);
+            },
+            Some(_) => self.finish_least_recent_files()?,
+            None => {
+                std::fs::create_dir_all(&dir)?;
+                self.finish_least_recent_files()?;
+                let file = PartitionFile {
+                    writer: None,
+                    next_index: 0,
+                    rows: 0,
+                    last_write: 0,
+                };
+                self.partitions.insert(dir.clone(), file);
+            },
+        }
+        let file = self.partitions.get_mut(&dir).unwrap();
+
+        while df.height() > 0 {
+            // Only roll over once there are rows left, so that we never write empty files.
+            if file.rows == max_rows_per_file {
+                if let Some(mut writer) = file.writer.take() {
+                    writer._finish()?;
+                }
+            }
+            let writer = match &mut file.writer {
+                Some(writer) => writer,
+                None => {
+                    let writer =
+                        create_writer(&dir, file.next_index, &self.file_type, &self.file_schema)?;
+                    file.next_index += 1;
+                    file.rows = 0;
+                    file.writer.insert(writer)
+                },
+            };
+            let n = (max_rows_per_file - file.rows).min(df.height());
+            let (head, tail) = df.split_at(n as i64);
+            writer._write_batch(&head)?;
+            file.rows += n;
+            df = tail;
+        }
+
+        if file.writer.is_some() {
+            file.last_write = self.n_writes;
+            self.open_files.insert(self.n_writes, dir);
+        }
+        self.n_writes += 1;
+        Ok(())
+    }
+
+    /// Finish the files of the partitions that were written to least recently, until
+    /// another file can be opened.
+    fn finish_least_recent_files(&mut self) -> PolarsResult<()> {
+        while self.open_files.len() >= self.partition_by.max_open_files {
+            let (_, dir) = self.open_files.pop_first().unwrap();
+            let file = self.partitions.get_mut(&dir).unwrap();
+            file.writer.take().unwrap()._finish()?;
+        }
+        Ok(())
+    }
+}
+
+impl SinkWriter for PartitionedWriter {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let keys = self.partition_by.keys.clone();
+        for partition in df.partition_by_stable(keys.as_slice(), true)? {
+            let dir = resolve_partition_dir(&self.rootdir, &keys, &partition)?;
+            let partition = partition.drop_many(&keys);
+            self.write_partition(dir, partition)?;
+        }
+        Ok(())
+    }
+
+    fn _finish(&mut self) -> PolarsResult<()> {
+        for file in self.partitions.values_mut() {
+            if let Some(writer) = &mut file.writer {
+                writer._finish()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates the writer of the file with `index` in the partition directory `dir`. Files are
+/// named `data-{index:04}.{extension}`, followed by the extension of the compression codec of
+/// text formats.
+fn create_writer(
+    dir: &Path,
+    index: usize,
+    file_type: &FileType,
+    schema: &Schema,
+) -> PolarsResult<Box<dyn SinkWriter + Send>> {
+    let extension = match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(_) => "parquet",
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(_) => "ipc",
+        #[cfg(feature = "csv")]
+        FileType::Csv(_) => "csv",
+        #[cfg(feature = "json")]
+        FileType::Json(_) => "ndjson",
+        #[cfg(feature = "avro")]
+        FileType::Avro(_) => "avro",
+        #[cfg(feature = "orc")]
+        FileType::Orc(_) => "orc",
+    };
+    let compression = match file_type {
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => options.compression,
+        #[cfg(feature = "json")]
+        FileType::Json(options) => options.compression,
+        #[allow(unreachable_patterns)]
+        _ => None,
+    };
+    let file_name = match compression {
+        Some(compression) => format!("data-{index:04}.{extension}.{}", compression.extension()),
+        None => format!("data-{index:04}.{extension}"),
+    };
+    let file = File::create(dir.join(file_name))?;
+
+    let writer: Box<dyn SinkWriter + Send> = match file_type {
+        #[cfg(feature = "parquet")]
+        FileType::Parquet(options) => Box::new(super::parquet::batched_parquet_writer(
+            file,
+            options.clone(),
+            schema,
+        )?),
+        #[cfg(feature = "ipc")]
+        FileType::Ipc(options) => Box::new(
+            polars_io::ipc::IpcWriter::new(file)
+                .with_compression(options.compression)
+                .batched(schema)?,
+        ),
+        #[cfg(feature = "csv")]
+        FileType::Csv(options) => Box::new(super::csv::batched_csv_writer(
+            file,
+            options.clone(),
+            schema,
+        )?),
+        #[cfg(feature = "json")]
+        FileType::Json(options) => Box::new(polars_io::json::BatchedWriter::try_new(
+            file,
+            options.compression,
+        )?),
+        #[cfg(feature = "avro")]
+        FileType::Avro(options) => Box::new(
+            polars_io::avro::AvroWriter::new(file)
+                .with_compression(options.compression)
+                .with_name(options.name.clone())
+                .batched(schema)?,
+        ),
+        #[cfg(feature = "orc")]
+        FileType::Orc(options) => Box::new(
+            polars_io::orc::OrcWriter::new(file)
+                .with_compression(options.compression)
+                .with_stripe_size(options.stripe_size)
+                .batched(schema)?,
+        ),
+    };
+    Ok(writer)
+}
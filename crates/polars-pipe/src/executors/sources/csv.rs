@@ -1,9 +1,13 @@
 use std::fs::File;
+use std::io::BufReader;
 use std::path::PathBuf;
 
 use polars_core::{config, POOL};
-use polars_io::csv::read::{BatchedCsvReader, CsvReadOptions, CsvReader};
-use polars_io::utils::is_cloud_url;
+use polars_io::compression::is_compressed_reader;
+use polars_io::csv::read::{
+    BatchedCompressedCsvReader, BatchedCsvReader, CsvReadOptions, CsvReader,
+};
+use polars_io::utils::{is_cloud_url, resolve_homedir};
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::FileScanOptions;
 use polars_utils::iter::EnumerateIdxTrait;
@@ -19,6 +23,7 @@ pub(crate) struct CsvSource {
     // (so we have to order the `batched_reader` first in the struct fields)
     batched_reader: Option<BatchedCsvReader<'static>>,
     reader: Option<CsvReader<File>>,
+    compressed_reader: Option<BatchedCompressedCsvReader<'static>>,
     n_threads: usize,
     paths: Arc<[PathBuf]>,
     options: Option<CsvReadOptions>,
@@ -96,27 +101,31 @@ impl CsvSource {
             .with_rechunk(false)
             .with_row_index(row_index);
 
-        let reader: CsvReader<File> = if run_async {
+        let mut file = if run_async {
             #[cfg(feature = "cloud")]
             {
-                options.into_reader_with_file_handle(
-                    polars_io::file_cache::FILE_CACHE
-                        .get_entry(path.to_str().unwrap())
-                        // Safety: This was initialized by schema inference.
-                        .unwrap()
-                        .try_open_assume_latest()?,
-                )
+                polars_io::file_cache::FILE_CACHE
+                    .get_entry(path.to_str().unwrap())
+                    // Safety: This was initialized by schema inference.
+                    .unwrap()
+                    .try_open_assume_latest()?
             }
             #[cfg(not(feature = "cloud"))]
             {
                 panic!("required feature `cloud` is not enabled")
             }
         } else {
-            options
-                .with_path(Some(path))
-                .try_into_reader_with_file_path(None)?
+            polars_utils::open_file(resolve_homedir(path))?
         };
 
+        // Compressed files are decompressed and parsed in batches.
+        if is_compressed_reader(&mut file)? {
+            self.compressed_reader =
+                options.try_into_compressed_batched_reader(BufReader::new(file))?;
+            return Ok(());
+        }
+
+        let reader: CsvReader<File> = options.into_reader_with_file_handle(file);
         self.reader = Some(reader);
         let reader = self.reader.as_mut().unwrap();
 
@@ -138,6 +147,7 @@ impl CsvSource {
             schema,
             reader: None,
             batched_reader: None,
+            compressed_reader: None,
             n_threads: POOL.current_num_threads(),
             paths,
             options: Some(options),
@@ -153,24 +163,27 @@ impl CsvSource {
 impl Source for CsvSource {
     fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
         loop {
-            let first_read_from_file = self.reader.is_none();
+            let first_read_from_file = self.reader.is_none() && self.compressed_reader.is_none();
 
             if first_read_from_file {
                 self.init_next_reader()?;
             }
 
-            if self.reader.is_none() {
+            let batches = if let Some(reader) = self.compressed_reader.as_mut() {
+                reader.next_batch()?.map(|df| vec![df])
+            } else if self.reader.is_some() {
+                self.batched_reader
+                    .as_mut()
+                    .unwrap()
+                    .next_batches(self.n_threads)?
+            } else {
                 // No more readers
                 return Ok(SourceResult::Finished);
-            }
+            };
 
-            let Some(batches) = self
-                .batched_reader
-                .as_mut()
-                .unwrap()
-                .next_batches(self.n_threads)?
-            else {
+            let Some(batches) = batches else {
                 self.reader = None;
+                self.compressed_reader = None;
                 continue;
             };
 
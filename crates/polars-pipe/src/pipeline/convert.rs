@@ -133,6 +133,9 @@ where
                                 fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
                                     self.p.as_stats_evaluator()
                                 }
+                                fn live_variables(&self) -> Option<Vec<Arc<str>>> {
+                                    self.p.live_variables()
+                                }
                             }
 
                             PolarsResult::Ok(Arc::new(Wrap { p }) as Arc<dyn PhysicalIoExpr>)
@@ -184,7 +187,7 @@ where
                     match &file_type {
                         #[cfg(feature = "parquet")]
                         FileType::Parquet(options) => {
-                            Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
+                            Box::new(ParquetSink::new(path, options.clone(), input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
                         #[cfg(feature = "ipc")]
@@ -202,10 +205,52 @@ where
                             Box::new(JsonSink::new(path, *options, input_schema.as_ref())?)
                                 as Box<dyn SinkTrait>
                         },
+                        #[cfg(feature = "avro")]
+                        FileType::Avro(options) => {
+                            Box::new(AvroSink::new(path, options.clone(), input_schema.as_ref())?)
+                                as Box<dyn SinkTrait>
+                        },
+                        #[cfg(feature = "orc")]
+                        FileType::Orc(options) => {
+                            Box::new(OrcSink::new(path, *options, input_schema.as_ref())?)
+                                as Box<dyn SinkTrait>
+                        },
                         #[allow(unreachable_patterns)]
                         _ => unreachable!(),
                     }
                 },
+                #[allow(unused_variables)]
+                SinkType::Partition {
+                    path,
+                    file_type,
+                    partition_by,
+                } => {
+                    #[cfg(any(
+                        feature = "parquet",
+                        feature = "ipc",
+                        feature = "csv",
+                        feature = "json",
+                        feature = "avro",
+                        feature = "orc"
+                    ))]
+                    {
+                        Box::new(PartitionSink::new(
+                            path.as_ref().as_path(),
+                            file_type.clone(),
+                            partition_by.clone(),
+                            input_schema.as_ref(),
+                        )?) as Box<dyn SinkTrait>
+                    }
+                    #[cfg(not(any(
+                        feature = "parquet",
+                        feature = "ipc",
+                        feature = "csv",
+                        feature = "json",
+                        feature = "avro",
+                        feature = "orc"
+                    )))]
+                    unreachable!()
+                },
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     #[cfg(any(feature = "parquet", feature = "ipc"))]
@@ -220,7 +265,7 @@ where
                         FileType::Parquet(parquet_options) => Box::new(ParquetCloudSink::new(
                             uri.as_ref().as_str(),
                             cloud_options.as_ref(),
-                            *parquet_options,
+                            parquet_options.clone(),
                             lp_arena.get(*input).schema(lp_arena).as_ref(),
                         )?)
                             as Box<dyn SinkTrait>,
@@ -284,7 +329,7 @@ where
                     };
 
                     match jt {
-                        JoinType::Inner | JoinType::Left => {
+                        JoinType::Inner | JoinType::Left | JoinType::Right => {
                             let (join_columns_left, join_columns_right) = swap_eval();
 
                             Box::new(GenericBuild::<()>::new(
@@ -327,6 +372,40 @@ where
                                 placeholder,
                             )) as Box<dyn SinkTrait>
                         },
+                        #[cfg(feature = "asof_join")]
+                        JoinType::AsOf(asof_options) => {
+                            let by_dtypes: Arc<[DataType]> =
+                                match (&asof_options.left_by, &asof_options.right_by) {
+                                    (Some(left_by), Some(right_by)) => left_by
+                                        .iter()
+                                        .zip(right_by)
+                                        .map(|(l, r)| {
+                                            let dtype = polars_core::utils::try_get_supertype(
+                                                input_schema_left.try_get(l)?,
+                                                input_schema_right.try_get(r)?,
+                                            )?;
+                                            // The physical encodings of categoricals don't match.
+                                            Ok(if dtype.is_categorical() || dtype.is_enum() {
+                                                DataType::String
+                                            } else {
+                                                dtype
+                                            })
+                                        })
+                                        .collect::<PolarsResult<_>>()?,
+                                    _ => Arc::from([]),
+                                };
+
+                            Box::new(AsofJoinBuild::new(
+                                Arc::from(options.args.suffix()),
+                                Arc::new(asof_options.clone()),
+                                options.args.should_coalesce(),
+                                join_columns_left[0].clone(),
+                                join_columns_right[0].clone(),
+                                by_dtypes,
+                                node,
+                                placeholder,
+                            )) as Box<dyn SinkTrait>
+                        },
                         _ => unimplemented!(),
                     }
                 },
@@ -747,9 +826,15 @@ where
 }
 
 pub fn swap_join_order(options: &JoinOptions) -> bool {
-    matches!(options.args.how, JoinType::Left)
-        || match (options.rows_left, options.rows_right) {
+    match options.args.how {
+        JoinType::Left => true,
+        JoinType::Right => false,
+        // The right table is the build table, the left table is streamed.
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        _ => match (options.rows_left, options.rows_right) {
             ((Some(left), _), (Some(right), _)) => left > right,
             ((_, left), (_, right)) => left > right,
-        }
+        },
+    }
 }
@@ -38,6 +38,7 @@ impl Display for RollingFunction {
 
 impl Hash for RollingFunction {
     fn hash<H: Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "moment")]
         use RollingFunction::*;
 
         std::mem::discriminant(self).hash(state);
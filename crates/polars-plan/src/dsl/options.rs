@@ -48,6 +48,10 @@ pub struct JoinOptions {
     /// Holds `(Option<known_size>, estimated_size)`
     pub rows_left: (Option<usize>, usize),
     pub rows_right: (Option<usize>, usize),
+    /// Whether a cross join that is filtered on comparisons of columns of both tables may
+    /// be planned as an inner or inequality join, which doesn't preserve the row order of
+    /// the cross join.
+    pub allow_keyed_cross_join: bool,
 }
 
 impl Default for JoinOptions {
@@ -58,6 +62,7 @@ impl Default for JoinOptions {
             args: JoinArgs::new(JoinType::Left),
             rows_left: (None, usize::MAX),
             rows_right: (None, usize::MAX),
+            allow_keyed_cross_join: false,
         }
     }
 }
@@ -3,12 +3,22 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FixedWidthReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
 use polars_io::HiveOptions;
-#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "csv",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
 use polars_io::RowIndex;
 
 use crate::constants::UNLIMITED_CACHE;
@@ -82,6 +92,7 @@ impl DslBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        allow_missing_columns: bool,
         hive_options: HiveOptions,
     ) -> PolarsResult<Self> {
         let paths = paths.into();
@@ -106,6 +117,7 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    allow_missing_columns,
                 },
                 cloud_options,
                 metadata: None,
@@ -153,6 +165,71 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro<P: Into<Arc<[std::path::PathBuf]>>>(
+        paths: P,
+        n_rows: Option<usize>,
+        cache: bool,
+        row_index: Option<RowIndex>,
+        rechunk: bool,
+    ) -> PolarsResult<Self> {
+        let paths = paths.into();
+
+        Ok(DslPlan::Scan {
+            paths,
+            file_info: None,
+            hive_parts: None,
+            file_options: FileScanOptions {
+                with_columns: None,
+                cache,
+                n_rows,
+                rechunk,
+                row_index,
+                file_counter: Default::default(),
+                hive_options: HiveOptions {
+                    enabled: Some(false),
+                    ..Default::default()
+                },
+            },
+            predicate: None,
+            scan_type: FileScan::Avro,
+        }
+        .into())
+    }
+
+    #[cfg(feature = "orc")]
+    pub fn scan_orc<P: Into<Arc<[std::path::PathBuf]>>>(
+        paths: P,
+        n_rows: Option<usize>,
+        cache: bool,
+        row_index: Option<RowIndex>,
+        rechunk: bool,
+        use_statistics: bool,
+        hive_options: HiveOptions,
+    ) -> PolarsResult<Self> {
+        let paths = paths.into();
+
+        Ok(DslPlan::Scan {
+            paths,
+            file_info: None,
+            hive_parts: None,
+            file_options: FileScanOptions {
+                with_columns: None,
+                cache,
+                n_rows,
+                rechunk,
+                row_index,
+                file_counter: Default::default(),
+                hive_options,
+            },
+            predicate: None,
+            scan_type: FileScan::Orc {
+                options: OrcOptions { use_statistics },
+            },
+        }
+        .into())
+    }
+
     #[allow(clippy::too_many_arguments)]
     #[cfg(feature = "csv")]
     pub fn scan_csv<P: Into<Arc<[std::path::PathBuf]>>>(
@@ -193,6 +270,39 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "fwf")]
+    pub fn scan_fwf<P: Into<Arc<[std::path::PathBuf]>>>(
+        paths: P,
+        read_options: FixedWidthReadOptions,
+        cache: bool,
+    ) -> PolarsResult<Self> {
+        let paths = paths.into();
+
+        let options = FileScanOptions {
+            with_columns: None,
+            cache,
+            n_rows: read_options.n_rows,
+            rechunk: read_options.rechunk,
+            row_index: read_options.row_index.clone(),
+            file_counter: Default::default(),
+            hive_options: HiveOptions {
+                enabled: Some(false),
+                ..Default::default()
+            },
+        };
+        Ok(DslPlan::Scan {
+            paths,
+            file_info: None,
+            hive_parts: None,
+            file_options: options,
+            predicate: None,
+            scan_type: FileScan::FixedWidth {
+                options: read_options,
+            },
+        }
+        .into())
+    }
+
     pub fn cache(self) -> Self {
         let input = Arc::new(self.0);
         let id = input.as_ref() as *const DslPlan as usize;
@@ -347,6 +457,15 @@ impl DslBuilder {
         .into()
     }
 
+    #[cfg(feature = "pivot")]
+    pub fn pivot(self, args: PivotArgs) -> Self {
+        DslPlan::MapFunction {
+            input: Arc::new(self.0),
+            function: DslFunction::Pivot { args },
+        }
+        .into()
+    }
+
     pub fn row_index(self, name: &str, offset: Option<IdxSize>) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
@@ -387,6 +506,25 @@ impl DslBuilder {
             input_right: Arc::new(other),
             left_on,
             right_on,
+            predicates: vec![],
+            options,
+        }
+        .into()
+    }
+
+    #[cfg(feature = "iejoin")]
+    pub fn join_where(
+        self,
+        other: DslPlan,
+        predicates: Vec<Expr>,
+        options: Arc<JoinOptions>,
+    ) -> Self {
+        DslPlan::Join {
+            input_left: Arc::new(self.0),
+            input_right: Arc::new(other),
+            left_on: vec![],
+            right_on: vec![],
+            predicates,
             options,
         }
         .into()
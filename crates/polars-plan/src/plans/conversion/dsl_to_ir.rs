@@ -96,13 +96,17 @@ pub fn to_alp_impl(
                 match &mut scan_type {
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet {
+                        options,
                         cloud_options,
                         metadata,
-                        ..
                     } => {
-                        let (file_info, md) =
-                            scans::parquet_file_info(&paths, &file_options, cloud_options.as_ref())
-                                .map_err(|e| e.context(failed_here!(parquet scan)))?;
+                        let (file_info, md) = scans::parquet_file_info(
+                            &paths,
+                            &file_options,
+                            options,
+                            cloud_options.as_ref(),
+                        )
+                        .map_err(|e| e.context(failed_here!(parquet scan)))?;
                         *metadata = md;
                         file_info
                     },
@@ -131,6 +135,17 @@ pub fn to_alp_impl(
                         scans::ndjson_file_info(&paths, &file_options, options)
                             .map_err(|e| e.context(failed_here!(ndjson scan)))?
                     },
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro => scans::avro_file_info(&paths, &file_options)
+                        .map_err(|e| e.context(failed_here!(avro scan)))?,
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => scans::orc_file_info(&paths, &file_options)
+                        .map_err(|e| e.context(failed_here!(orc scan)))?,
+                    #[cfg(feature = "fwf")]
+                    FileScan::FixedWidth { options } => {
+                        scans::fwf_file_info(&paths, &file_options, options)
+                            .map_err(|e| e.context(failed_here!(fwf scan)))?
+                    },
                     // FileInfo should be set.
                     FileScan::Anonymous { .. } => unreachable!(),
                 }
@@ -397,8 +412,21 @@ pub fn to_alp_impl(
             input_right,
             left_on,
             right_on,
+            predicates,
             mut options,
         } => {
+            if !predicates.is_empty() {
+                return resolve_join_where(
+                    input_left,
+                    input_right,
+                    predicates,
+                    options,
+                    expr_arena,
+                    lp_arena,
+                    convert,
+                );
+            }
+
             let mut turn_off_coalesce = false;
             for e in left_on.iter().chain(right_on.iter()) {
                 if has_expr(e, |e| matches!(e, Expr::Alias(_, _))) {
@@ -649,10 +677,16 @@ pub fn to_alp_impl(
         },
         DslPlan::IR { node, dsl, version } => {
             return if let (true, Some(node)) = (version == lp_arena.version(), node) {
-                Ok(node)
+                if convert.claim_ir_node(node, lp_arena) {
+                    Ok(node)
+                } else {
+                    // The same plan is used more than once (e.g. in a self-union), so
+                    // the other references need their own copy of the subplan.
+                    to_alp_impl(owned(dsl), expr_arena, lp_arena, convert)
+                }
             } else {
                 to_alp_impl(owned(dsl), expr_arena, lp_arena, convert)
-            }
+            };
         },
     };
     Ok(lp_arena.add(v))
@@ -713,6 +747,83 @@ fn expand_filter(predicate: Expr, input: Node, lp_arena: &Arena<IR>) -> PolarsRe
     Ok(predicate)
 }
 
+/// Converts a join on predicates (`join_where`) to a cross join that is filtered on the
+/// predicates, of which the comparisons of columns of both tables become the keys of an
+/// inner or inequality join right away.
+fn resolve_join_where(
+    input_left: Arc<DslPlan>,
+    input_right: Arc<DslPlan>,
+    predicates: Vec<Expr>,
+    options: Arc<JoinOptions>,
+    expr_arena: &mut Arena<AExpr>,
+    lp_arena: &mut Arena<IR>,
+    convert: &mut ConversionOptimizer,
+) -> PolarsResult<Node> {
+    let cross_join = DslPlan::Join {
+        input_left,
+        input_right,
+        left_on: vec![],
+        right_on: vec![],
+        predicates: vec![],
+        options,
+    };
+    let filtered = DslPlan::Filter {
+        input: Arc::new(cross_join),
+        predicate: predicates.into_iter().reduce(|acc, e| acc.and(e)).unwrap(),
+    };
+    let mut node = to_alp_impl(filtered, expr_arena, lp_arena, convert)?;
+
+    // the filter may be split into several filter nodes
+    let mut predicates = vec![];
+    while let IR::Filter { input, predicate } = lp_arena.get(node) {
+        predicates.push(predicate.clone());
+        node = *input;
+    }
+    let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        mut left_on,
+        mut right_on,
+        mut options,
+    } = lp_arena.take(node)
+    else {
+        unreachable!()
+    };
+    let schema_left = lp_arena.get(input_left).schema(lp_arena).into_owned();
+    let schema_right = lp_arena.get(input_right).schema(lp_arena).into_owned();
+    let (keys, predicates) = resolve_join_predicates(
+        &mut options,
+        predicates,
+        expr_arena,
+        &schema_left,
+        &schema_right,
+    )?;
+    if let Some((keys_left, keys_right)) = keys {
+        left_on = keys_left;
+        right_on = keys_right;
+    }
+    lp_arena.replace(
+        node,
+        IR::Join {
+            input_left,
+            input_right,
+            schema,
+            left_on,
+            right_on,
+            options,
+        },
+    );
+
+    for predicate in predicates {
+        node = lp_arena.add(IR::Filter {
+            input: node,
+            predicate,
+        });
+    }
+    Ok(node)
+}
+
 fn resolve_with_columns(
     exprs: Vec<Expr>,
     input: Node,
@@ -3,7 +3,14 @@ mod dsl_to_ir;
 mod expr_expansion;
 mod expr_to_ir;
 mod ir_to_dsl;
-#[cfg(any(feature = "ipc", feature = "parquet", feature = "csv"))]
+#[cfg(any(
+    feature = "ipc",
+    feature = "parquet",
+    feature = "csv",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf"
+))]
 mod scans;
 mod stack_opt;
 
@@ -211,6 +218,7 @@ impl IR {
                     input_right: Arc::new(i_r),
                     left_on,
                     right_on,
+                    predicates: vec![],
                     options,
                 }
             },
@@ -16,7 +16,13 @@ fn get_path(paths: &[PathBuf]) -> PolarsResult<&PathBuf> {
         .ok_or_else(|| polars_err!(ComputeError: "expected at least 1 path"))
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc",
+    feature = "fwf"
+))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.as_ref().into(), IDX_DTYPE);
@@ -40,11 +46,12 @@ fn prepare_schemas(mut schema: Schema, row_index: Option<&RowIndex>) -> (SchemaR
 pub(super) fn parquet_file_info(
     paths: &[PathBuf],
     file_options: &FileScanOptions,
+    options: &ParquetOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<(FileInfo, Option<FileMetaDataRef>)> {
     let path = get_path(paths)?;
 
-    let (schema, reader_schema, num_rows, metadata) = if is_cloud_url(path) {
+    let (mut reader_schema, num_rows, metadata) = if is_cloud_url(path) {
         #[cfg(not(feature = "cloud"))]
         panic!("One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled.");
 
@@ -58,25 +65,45 @@ pub(super) fn parquet_file_info(
                 let num_rows = reader.num_rows().await?;
                 let metadata = reader.get_metadata().await?.clone();
 
-                let schema =
-                    prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref());
-                PolarsResult::Ok((schema, reader_schema, Some(num_rows), Some(metadata)))
+                PolarsResult::Ok((reader_schema, Some(num_rows), Some(metadata)))
             })?
         }
     } else {
         let file = polars_utils::open_file(path)?;
         let mut reader = ParquetReader::new(file);
-        let reader_schema = reader.schema()?;
-        let schema =
-            prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref());
         (
-            schema,
-            reader_schema,
+            reader.schema()?,
             Some(reader.num_rows()?),
             Some(reader.get_metadata()?.clone()),
         )
     };
 
+    // The schema of the scan is the union of the schemas of all files.
+    if options.allow_missing_columns {
+        let schema = Arc::make_mut(&mut reader_schema);
+        for path in &paths[1..] {
+            let file_schema = if is_cloud_url(path) {
+                #[cfg(not(feature = "cloud"))]
+                panic!("One or more of the cloud storage features ('aws', 'gcp', ...) must be enabled.");
+
+                #[cfg(feature = "cloud")]
+                {
+                    let uri = path.to_string_lossy();
+                    get_runtime().block_on(async {
+                        ParquetAsyncReader::from_uri(&uri, cloud_options, None, None)
+                            .await?
+                            .schema()
+                            .await
+                    })?
+                }
+            } else {
+                ParquetReader::new(polars_utils::open_file(path)?).schema()?
+            };
+            merge_evolved_schema(schema, &file_schema)?;
+        }
+    }
+
+    let schema = prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref());
     let file_info = FileInfo::new(
         schema,
         Some(Either::Left(reader_schema)),
@@ -126,6 +153,28 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    paths: &[PathBuf],
+    file_options: &FileScanOptions,
+) -> PolarsResult<FileInfo> {
+    let path = get_path(paths)?;
+    polars_ensure!(
+        !is_cloud_url(path),
+        ComputeError: "cannot scan avro files from cloud storage"
+    );
+
+    let reader_schema =
+        polars_io::avro::AvroReader::new(polars_utils::open_file(path)?).arrow_schema()?;
+    let file_info = FileInfo::new(
+        prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref()),
+        Some(Either::Left(Arc::new(reader_schema))),
+        (None, 0),
+    );
+
+    Ok(file_info)
+}
+
 #[cfg(feature = "csv")]
 pub(super) fn csv_file_info(
     paths: &[PathBuf],
@@ -133,7 +182,7 @@ pub(super) fn csv_file_info(
     csv_options: &mut CsvReadOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<FileInfo> {
-    use std::io::{Read, Seek};
+    use std::io::{BufReader, Read, Seek};
 
     use polars_core::{config, POOL};
     use polars_io::csv::read::is_compressed;
@@ -194,10 +243,15 @@ pub(super) fn csv_file_info(
             if csv_options.raise_if_empty {
                 polars_bail!(NoData: "empty CSV")
             }
-        } else {
-            polars_ensure!(
-            !is_compressed(&magic_nr),
-            ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
+        } else if is_compressed(&magic_nr) {
+            // Compressed files are decompressed in batches when they are read.
+            file.rewind()?;
+            return Ok(
+                SchemaInferenceResult::try_from_compressed_reader_and_options(
+                    BufReader::new(file),
+                    csv_options,
+                )?
+                .unwrap(),
             );
         }
 
@@ -284,7 +338,24 @@ pub(super) fn ndjson_file_info(
 
     let f = polars_utils::open_file(path)?;
     let mut reader = std::io::BufReader::new(f);
+    // Compressed files are decompressed in batches when they are read.
+    if polars_io::compression::is_compressed_reader(&mut reader)? {
+        let decoder = polars_io::compression::maybe_decoder(reader)?.unwrap();
+        return ndjson_file_info_impl(
+            std::io::BufReader::new(decoder),
+            file_options,
+            ndjson_options,
+        );
+    }
+    ndjson_file_info_impl(reader, file_options, ndjson_options)
+}
 
+#[cfg(feature = "json")]
+fn ndjson_file_info_impl<R: std::io::BufRead>(
+    mut reader: R,
+    file_options: &FileScanOptions,
+    ndjson_options: &mut NDJsonReadOptions,
+) -> PolarsResult<FileInfo> {
     let (reader_schema, schema) = if let Some(schema) = ndjson_options.schema.take() {
         if file_options.row_index.is_none() {
             (schema.clone(), schema.clone())
@@ -306,3 +377,56 @@ pub(super) fn ndjson_file_info(
         (None, usize::MAX),
     ))
 }
+
+#[cfg(feature = "orc")]
+pub(super) fn orc_file_info(
+    paths: &[PathBuf],
+    file_options: &FileScanOptions,
+) -> PolarsResult<FileInfo> {
+    let path = get_path(paths)?;
+    polars_ensure!(
+        !is_cloud_url(path),
+        ComputeError: "cannot scan orc files from cloud storage"
+    );
+
+    let mut reader = polars_io::orc::OrcReader::new(polars_utils::open_file(path)?);
+    let reader_schema = reader.schema()?;
+    let num_rows = reader.num_rows()?;
+    let file_info = FileInfo::new(
+        prepare_output_schema((&reader_schema).into(), file_options.row_index.as_ref()),
+        Some(Either::Left(reader_schema)),
+        (Some(num_rows), num_rows),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "fwf")]
+pub(super) fn fwf_file_info(
+    paths: &[PathBuf],
+    file_options: &FileScanOptions,
+    fwf_options: &mut polars_io::fwf::FixedWidthReadOptions,
+) -> PolarsResult<FileInfo> {
+    let path = get_path(paths)?;
+    polars_ensure!(
+        !is_cloud_url(path),
+        ComputeError: "cannot scan fixed-width files from cloud storage"
+    );
+
+    let mut reader = fwf_options
+        .clone()
+        .into_reader_with_file_handle(polars_utils::open_file(path)?);
+    let (reader_schema, column_spans) = reader.schema_with_column_spans()?;
+    // All files are read with the column spans of the first file.
+    fwf_options.column_spans = Some(column_spans);
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            reader_schema.as_ref().clone(),
+            file_options.row_index.as_ref(),
+        ),
+        Some(Either::Right(reader_schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
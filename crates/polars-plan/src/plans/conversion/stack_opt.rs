@@ -7,6 +7,8 @@ pub(super) struct ConversionOptimizer {
     scratch: Vec<Node>,
     simplify: Option<SimplifyExprRule>,
     coerce: Option<TypeCoercionRule>,
+    /// Already converted IR nodes that are referenced by the plan.
+    used_ir_nodes: PlHashSet<Node>,
 }
 
 impl ConversionOptimizer {
@@ -27,9 +29,29 @@ impl ConversionOptimizer {
             scratch: Vec::with_capacity(8),
             simplify,
             coerce,
+            used_ir_nodes: Default::default(),
         }
     }
 
+    /// Claim an already converted IR (sub)plan for the plan being converted. Returns
+    /// `false` if any of its nodes is referenced already, as every node may only have
+    /// a single parent.
+    pub(super) fn claim_ir_node(&mut self, node: Node, lp_arena: &Arena<IR>) -> bool {
+        // Subplans are claimed as a whole, so a subplan that is used more than once is
+        // rejected on its root, and the walk stops at the first node that is referenced.
+        let mut stack = vec![node];
+        let mut nodes = vec![];
+        while let Some(node) = stack.pop() {
+            if self.used_ir_nodes.contains(&node) {
+                return false;
+            }
+            nodes.push(node);
+            lp_arena.get(node).copy_inputs(&mut stack);
+        }
+        self.used_ir_nodes.extend(nodes);
+        true
+    }
+
     pub(super) fn push_scratch(&mut self, expr: Node, expr_arena: &Arena<AExpr>) {
         self.scratch.push(expr);
         // traverse all subexpressions and add to the stack
@@ -2,8 +2,12 @@ use std::hash::{Hash, Hasher};
 
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FixedWidthReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetaDataRef;
 #[cfg(feature = "parquet")]
@@ -35,6 +39,12 @@ pub enum FileScan {
     },
     #[cfg(feature = "json")]
     NDJson { options: NDJsonReadOptions },
+    #[cfg(feature = "avro")]
+    Avro,
+    #[cfg(feature = "orc")]
+    Orc { options: OrcOptions },
+    #[cfg(feature = "fwf")]
+    FixedWidth { options: FixedWidthReadOptions },
     #[cfg_attr(feature = "serde", serde(skip))]
     Anonymous {
         options: Arc<AnonymousScanOptions>,
@@ -84,6 +94,12 @@ impl PartialEq for FileScan {
             ) => l == r && c_l == c_r,
             #[cfg(feature = "json")]
             (FileScan::NDJson { options: l }, FileScan::NDJson { options: r }) => l == r,
+            #[cfg(feature = "avro")]
+            (FileScan::Avro, FileScan::Avro) => true,
+            #[cfg(feature = "orc")]
+            (FileScan::Orc { options: l }, FileScan::Orc { options: r }) => l == r,
+            #[cfg(feature = "fwf")]
+            (FileScan::FixedWidth { options: l }, FileScan::FixedWidth { options: r }) => l == r,
             _ => false,
         }
     }
@@ -123,6 +139,12 @@ impl Hash for FileScan {
             },
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => options.hash(state),
+            #[cfg(feature = "avro")]
+            FileScan::Avro => {},
+            #[cfg(feature = "orc")]
+            FileScan::Orc { options } => options.hash(state),
+            #[cfg(feature = "fwf")]
+            FileScan::FixedWidth { options } => options.hash(state),
             FileScan::Anonymous { options, .. } => options.hash(state),
         }
     }
@@ -151,6 +173,12 @@ impl FileScan {
             Self::Ipc { .. } => _file_options.row_index.is_some(),
             #[cfg(feature = "parquet")]
             Self::Parquet { .. } => _file_options.row_index.is_some(),
+            #[cfg(feature = "avro")]
+            Self::Avro => true,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => true,
+            #[cfg(feature = "fwf")]
+            Self::FixedWidth { .. } => true,
             #[allow(unreachable_patterns)]
             _ => false,
         }
@@ -162,10 +190,17 @@ impl FileScan {
             Self::Csv { .. } => true,
             #[cfg(feature = "ipc")]
             Self::Ipc { .. } => false,
+            // The streaming parquet source requires all files to have the same schema.
             #[cfg(feature = "parquet")]
-            Self::Parquet { .. } => true,
+            Self::Parquet { options, .. } => !options.allow_missing_columns,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            #[cfg(feature = "avro")]
+            Self::Avro => false,
+            #[cfg(feature = "orc")]
+            Self::Orc { .. } => false,
+            #[cfg(feature = "fwf")]
+            Self::FixedWidth { .. } => false,
             #[allow(unreachable_patterns)]
             _ => false,
         }
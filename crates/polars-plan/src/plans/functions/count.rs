@@ -1,9 +1,15 @@
 #[cfg(feature = "ipc")]
 use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroReader;
 #[cfg(feature = "parquet")]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::count_rows as count_rows_csv;
+#[cfg(feature = "fwf")]
+use polars_io::fwf::FixedWidthReadOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcReader;
 #[cfg(all(feature = "parquet", feature = "cloud"))]
 use polars_io::parquet::read::ParquetAsyncReader;
 #[cfg(feature = "parquet")]
@@ -11,7 +17,14 @@ use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
 #[cfg(any(feature = "parquet", feature = "ipc"))]
-use polars_io::{utils::is_cloud_url, SerReader};
+use polars_io::utils::is_cloud_url;
+#[cfg(any(
+    feature = "parquet",
+    feature = "ipc",
+    feature = "avro",
+    feature = "orc"
+))]
+use polars_io::SerReader;
 
 use super::*;
 
@@ -21,7 +34,10 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc",
+        feature = "fwf"
     )))]
     {
         unreachable!()
@@ -31,7 +47,10 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro",
+        feature = "orc",
+        feature = "fwf"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -73,6 +92,12 @@ pub fn count_rows(paths: &Arc<[PathBuf]>, scan_type: &FileScan) -> PolarsResult<
             ),
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => count_rows_ndjson(paths),
+            #[cfg(feature = "avro")]
+            FileScan::Avro => count_rows_avro(paths),
+            #[cfg(feature = "orc")]
+            FileScan::Orc { .. } => count_rows_orc(paths),
+            #[cfg(feature = "fwf")]
+            FileScan::FixedWidth { options } => count_rows_fwf(paths, options),
             FileScan::Anonymous { .. } => {
                 unreachable!()
             },
@@ -191,3 +216,42 @@ pub(super) fn count_rows_ndjson(paths: &Arc<[PathBuf]>) -> PolarsResult<usize> {
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+pub(super) fn count_rows_avro(paths: &Arc<[PathBuf]>) -> PolarsResult<usize> {
+    paths
+        .iter()
+        .map(|path| {
+            let file = polars_utils::open_file(path)?;
+            AvroReader::new(file).num_rows()
+        })
+        .sum()
+}
+
+#[cfg(feature = "orc")]
+pub(super) fn count_rows_orc(paths: &Arc<[PathBuf]>) -> PolarsResult<usize> {
+    paths
+        .iter()
+        .map(|path| {
+            let file = polars_utils::open_file(path)?;
+            OrcReader::new(file).num_rows()
+        })
+        .sum()
+}
+
+#[cfg(feature = "fwf")]
+pub(super) fn count_rows_fwf(
+    paths: &Arc<[PathBuf]>,
+    options: &FixedWidthReadOptions,
+) -> PolarsResult<usize> {
+    paths
+        .iter()
+        .map(|path| {
+            let file = polars_utils::open_file(path)?;
+            options
+                .clone()
+                .into_reader_with_file_handle(file)
+                .count_rows()
+        })
+        .sum()
+}
@@ -12,6 +12,10 @@ pub enum DslFunction {
     Unpivot {
         args: UnpivotArgs,
     },
+    #[cfg(feature = "pivot")]
+    Pivot {
+        args: PivotArgs,
+    },
     RowIndex {
         name: Arc<str>,
         offset: Option<IdxSize>,
@@ -83,6 +87,20 @@ impl DslFunction {
                 args: Arc::new(args),
                 schema: Default::default(),
             },
+            #[cfg(feature = "pivot")]
+            DslFunction::Pivot { args } => {
+                polars_ensure!(
+                    !args.index.is_empty() && !args.values.is_empty(),
+                    InvalidOperation: "`index` and `values` cannot be empty in a lazy `pivot`"
+                );
+                for name in args.index.iter().chain(&args.values).chain([&args.on]) {
+                    polars_ensure!(input_schema.contains(name), ColumnNotFound: "{name}");
+                }
+                FunctionNode::Pivot {
+                    args: Arc::new(args),
+                    schema: Default::default(),
+                }
+            },
             DslFunction::FunctionNode(func) => func,
             DslFunction::RowIndex { name, offset } => FunctionNode::RowIndex {
                 name,
@@ -126,6 +144,8 @@ impl Display for DslFunction {
             FunctionNode(inner) => write!(f, "{inner}"),
             Explode { .. } => write!(f, "EXPLODE"),
             Unpivot { .. } => write!(f, "UNPIVOT"),
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => write!(f, "PIVOT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
             Stats(_) => write!(f, "STATS"),
             FillNan(_) => write!(f, "FILL NAN"),
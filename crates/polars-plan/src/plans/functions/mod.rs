@@ -2,6 +2,8 @@ mod count;
 mod dsl;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "pivot")]
+mod pivot;
 #[cfg(feature = "python")]
 mod python_udf;
 mod rename;
@@ -14,6 +16,8 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 pub use dsl::*;
+#[cfg(feature = "pivot")]
+pub use pivot::{PivotAggregation, PivotArgs};
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -95,6 +99,12 @@ pub enum FunctionNode {
         #[cfg_attr(feature = "serde", serde(skip))]
         schema: CachedSchema,
     },
+    #[cfg(feature = "pivot")]
+    Pivot {
+        args: Arc<PivotArgs>,
+        #[cfg_attr(feature = "serde", serde(skip))]
+        schema: CachedSchema,
+    },
     RowIndex {
         name: Arc<str>,
         // Might be cached.
@@ -126,6 +136,8 @@ impl PartialEq for FunctionNode {
             ) => existing_l == existing_r && new_l == new_r,
             (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
+            #[cfg(feature = "pivot")]
+            (Pivot { args: l, .. }, Pivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
             #[cfg(feature = "merge_sorted")]
             (MergeSorted { column: l }, MergeSorted { column: r }) => l == r,
@@ -166,6 +178,8 @@ impl Hash for FunctionNode {
             },
             FunctionNode::Explode { columns, schema: _ } => columns.hash(state),
             FunctionNode::Unpivot { args, schema: _ } => args.hash(state),
+            #[cfg(feature = "pivot")]
+            FunctionNode::Pivot { args, schema: _ } => args.hash(state),
             FunctionNode::RowIndex {
                 name,
                 schema: _,
@@ -188,6 +202,9 @@ impl FunctionNode {
             MergeSorted { .. } => false,
             Count { .. } | Unnest { .. } | Rename { .. } | Explode { .. } => true,
             Unpivot { args, .. } => args.streamable,
+            // The new columns need all rows of a group.
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => false,
             Opaque { streamable, .. } => *streamable,
             #[cfg(feature = "python")]
             OpaquePython { streamable, .. } => *streamable,
@@ -215,6 +232,9 @@ impl FunctionNode {
             Rechunk | Unnest { .. } | Rename { .. } | Explode { .. } | Unpivot { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            // Only predicates on the index columns are pushed down.
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => true,
             RowIndex { .. } | Count { .. } => false,
             Pipeline { .. } => unimplemented!(),
         }
@@ -234,6 +254,8 @@ impl FunctionNode {
             | Unpivot { .. } => true,
             #[cfg(feature = "merge_sorted")]
             MergeSorted { .. } => true,
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => true,
             RowIndex { .. } => true,
             Pipeline { .. } => unimplemented!(),
         }
@@ -299,6 +321,8 @@ impl FunctionNode {
                 let args = (**args).clone();
                 df.unpivot2(args)
             },
+            #[cfg(feature = "pivot")]
+            Pivot { args, schema } => pivot::pivot(&df, args, schema),
             RowIndex { name, offset, .. } => df.with_row_index(name.as_ref(), *offset),
         }
     }
@@ -354,6 +378,8 @@ impl Display for FunctionNode {
             Rename { .. } => write!(f, "RENAME"),
             Explode { .. } => write!(f, "EXPLODE"),
             Unpivot { .. } => write!(f, "UNPIVOT"),
+            #[cfg(feature = "pivot")]
+            Pivot { .. } => write!(f, "PIVOT"),
             RowIndex { .. } => write!(f, "WITH ROW INDEX"),
         }
     }
@@ -0,0 +1,141 @@
+use polars_ops::pivot::{pivot_stable, PivotAgg};
+use polars_utils::format_smartstring;
+
+use super::*;
+
+/// The aggregation of the values that end up in the same cell of a pivot.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PivotAggregation {
+    First,
+    Last,
+    Sum,
+    Min,
+    Max,
+    Mean,
+    Median,
+    Count,
+}
+
+impl PivotAggregation {
+    /// The equivalent aggregation of a column, used to determine the output dtype.
+    fn to_expr(self, name: &str) -> Expr {
+        use PivotAggregation::*;
+        let e = col(name);
+        match self {
+            First => e.first(),
+            Last => e.last(),
+            Sum => e.sum(),
+            Min => e.min(),
+            Max => e.max(),
+            Mean => e.mean(),
+            Median => e.median(),
+            Count => e.len(),
+        }
+    }
+}
+
+impl From<PivotAggregation> for PivotAgg {
+    fn from(value: PivotAggregation) -> Self {
+        match value {
+            PivotAggregation::First => PivotAgg::First,
+            PivotAggregation::Last => PivotAgg::Last,
+            PivotAggregation::Sum => PivotAgg::Sum,
+            PivotAggregation::Min => PivotAgg::Min,
+            PivotAggregation::Max => PivotAgg::Max,
+            PivotAggregation::Mean => PivotAgg::Mean,
+            PivotAggregation::Median => PivotAgg::Median,
+            PivotAggregation::Count => PivotAgg::Count,
+        }
+    }
+}
+
+/// Arguments of a pivot of which the new columns are known up front, so that its output
+/// schema is known without looking at the data.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PivotArgs {
+    /// The column of which the values become the new columns.
+    pub on: SmartString,
+    /// The values of `on` (cast to strings) that become the new columns, in order. Other
+    /// values of `on` don't become columns.
+    pub on_columns: Arc<[SmartString]>,
+    /// The columns of which the unique combinations become the rows.
+    pub index: Vec<SmartString>,
+    /// The columns of which the values are aggregated into the new columns.
+    pub values: Vec<SmartString>,
+    pub agg: PivotAggregation,
+    /// Separates the value column and the value of `on` in the names of the new columns,
+    /// if there is more than one value column.
+    pub separator: SmartString,
+}
+
+impl PivotArgs {
+    fn column_name(&self, value: &str, on_column: &str) -> SmartString {
+        if self.values.len() > 1 {
+            format_smartstring!("{value}{}{on_column}", self.separator)
+        } else {
+            on_column.into()
+        }
+    }
+}
+
+pub(super) fn pivot_schema<'a>(
+    args: &PivotArgs,
+    cached_schema: &CachedSchema,
+    input_schema: &'a Schema,
+) -> PolarsResult<Cow<'a, SchemaRef>> {
+    let mut guard = cached_schema.lock().unwrap();
+    if let Some(schema) = &*guard {
+        return Ok(Cow::Owned(schema.clone()));
+    }
+
+    let mut new_schema =
+        Schema::with_capacity(args.index.len() + args.values.len() * args.on_columns.len());
+    for name in &args.index {
+        new_schema.with_column(name.clone(), input_schema.try_get(name)?.clone());
+    }
+    for value in &args.values {
+        let dtype = args
+            .agg
+            .to_expr(value)
+            .to_field(input_schema, Context::Aggregation)?
+            .dtype;
+        for on_column in args.on_columns.iter() {
+            new_schema.with_column(args.column_name(value, on_column), dtype.clone());
+        }
+    }
+    let schema = Arc::new(new_schema);
+    *guard = Some(schema.clone());
+    Ok(Cow::Owned(schema))
+}
+
+pub(super) fn pivot(
+    df: &DataFrame,
+    args: &PivotArgs,
+    cached_schema: &CachedSchema,
+) -> PolarsResult<DataFrame> {
+    let out = pivot_stable(
+        df,
+        [&args.on],
+        Some(&args.index),
+        Some(&args.values),
+        false,
+        Some(args.agg.into()),
+        Some(args.separator.as_str()),
+    )?;
+
+    // The data may lack some of the `on_columns` and have values of `on` that aren't in
+    // them, so we conform the output to the schema.
+    let input_schema = df.schema();
+    let schema = pivot_schema(args, cached_schema, &input_schema)?;
+    let height = out.height();
+    let columns = schema
+        .iter()
+        .map(|(name, dtype)| match out.column(name) {
+            Ok(s) => s.cast(dtype),
+            Err(_) => Ok(Series::full_null(name, height, dtype)),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    DataFrame::new(columns)
+}
@@ -99,6 +99,8 @@ impl FunctionNode {
             },
             Explode { schema, columns } => explode_schema(schema, input_schema, columns),
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
+            #[cfg(feature = "pivot")]
+            Pivot { schema, args } => pivot::pivot_schema(args, schema, input_schema),
         }
     }
 }
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 
 use percent_encoding::percent_decode;
@@ -68,19 +69,6 @@ pub fn hive_partitions_from_paths(
     reader_schema: &Schema,
     try_parse_dates: bool,
 ) -> PolarsResult<Option<Arc<[HivePartitions]>>> {
-    let paths = paths
-        .iter()
-        .map(|x| {
-            Ok(PathBuf::from(
-                percent_decode(x.to_str().unwrap().as_bytes())
-                    .decode_utf8()
-                    .map_err(to_compute_err)?
-                    .as_ref(),
-            ))
-        })
-        .collect::<PolarsResult<Vec<PathBuf>>>()?;
-    let paths = paths.as_slice();
-
     let Some(path) = paths.first() else {
         return Ok(None);
     };
@@ -104,7 +92,7 @@ pub fn hive_partitions_from_paths(
 
     let hive_schema = if let Some(ref schema) = schema {
         Arc::new(get_hive_parts_iter!(path_string).map(|(name, _)| {
-                let Some(dtype) = schema.get(name) else {
+                let Some(dtype) = schema.get(&name) else {
                     polars_bail!(
                         SchemaFieldNotFound:
                         "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -119,27 +107,35 @@ pub fn hive_partitions_from_paths(
                     dtype.clone()
                 };
 
-                Ok(Field::new(name, dtype))
+                Ok(Field::new(&name, dtype))
             }).collect::<PolarsResult<Schema>>()?)
     } else {
         let mut hive_schema = Schema::with_capacity(16);
-        let mut schema_inference_map: PlHashMap<&str, PlHashSet<DataType>> =
+        let mut schema_inference_map: PlHashMap<Cow<str>, PlHashSet<DataType>> =
             PlHashMap::with_capacity(16);
 
         for (name, _) in get_hive_parts_iter!(path_string) {
             // If the column is also in the file we can use the dtype stored there.
-            if let Some(dtype) = reader_schema.get(name) {
+            if let Some(dtype) = reader_schema.get(&name) {
                 let dtype = if !try_parse_dates && dtype.is_temporal() {
                     DataType::String
                 } else {
                     dtype.clone()
                 };
 
-                hive_schema.insert_at_index(hive_schema.len(), name.into(), dtype.clone())?;
+                hive_schema.insert_at_index(
+                    hive_schema.len(),
+                    name.as_ref().into(),
+                    dtype.clone(),
+                )?;
                 continue;
             }
 
-            hive_schema.insert_at_index(hive_schema.len(), name.into(), DataType::String)?;
+            hive_schema.insert_at_index(
+                hive_schema.len(),
+                name.as_ref().into(),
+                DataType::String,
+            )?;
             schema_inference_map.insert(name, PlHashSet::with_capacity(4));
         }
 
@@ -150,7 +146,7 @@ pub fn hive_partitions_from_paths(
         if !schema_inference_map.is_empty() {
             for path in paths {
                 for (name, value) in get_hive_parts_iter!(path.to_str().unwrap()) {
-                    let Some(entry) = schema_inference_map.get_mut(name) else {
+                    let Some(entry) = schema_inference_map.get_mut(&name) else {
                         continue;
                     };
 
@@ -158,13 +154,14 @@ pub fn hive_partitions_from_paths(
                         continue;
                     }
 
-                    entry.insert(infer_field_schema(value, try_parse_dates, false));
+                    let value = decode_hive_value(value)?;
+                    entry.insert(infer_field_schema(&value, try_parse_dates, false));
                 }
             }
 
             for (name, ref possibilities) in schema_inference_map.drain() {
                 let dtype = finish_infer_field_schema(possibilities);
-                *hive_schema.try_get_mut(name).unwrap() = dtype;
+                *hive_schema.try_get_mut(&name).unwrap() = dtype;
             }
         }
         Arc::new(hive_schema)
@@ -183,7 +180,7 @@ pub fn hive_partitions_from_paths(
         let path = path.to_str().unwrap();
 
         for (name, value) in get_hive_parts_iter!(path) {
-            let Some(index) = hive_schema.index_of(name) else {
+            let Some(index) = hive_schema.index_of(&name) else {
                 polars_bail!(
                     SchemaFieldNotFound:
                     "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -195,6 +192,7 @@ pub fn hive_partitions_from_paths(
             let buf = buffers.get_mut(index).unwrap();
 
             if !value.is_empty() && value != "__HIVE_DEFAULT_PARTITION__" {
+                let value = decode_hive_value(value)?;
                 buf.add(value.as_bytes(), false, false, false)?;
             } else {
                 buf.add_null(false);
@@ -248,10 +246,19 @@ fn separator(_url: &Path) -> char {
     '/'
 }
 
-/// Parse a Hive partition string (e.g. "column=1.5") into a name and value part.
+/// Percent-decodes the value of a Hive partition. Values are decoded after splitting the path,
+/// so that they may contain encoded separators.
+fn decode_hive_value(value: &str) -> PolarsResult<Cow<'_, str>> {
+    percent_decode(value.as_bytes())
+        .decode_utf8()
+        .map_err(to_compute_err)
+}
+
+/// Parse a Hive partition string (e.g. "column=1.5") into a name and value part. The name is
+/// percent-decoded here, the value is decoded by [`decode_hive_value`].
 ///
 /// Returns `None` if the string is not a Hive partition string.
-fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
+fn parse_hive_string(part: &'_ str) -> Option<(Cow<'_, str>, &'_ str)> {
     let mut it = part.split('=');
     let name = it.next()?;
     let value = it.next()?;
@@ -266,5 +273,5 @@ fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
         return None;
     }
 
-    Some((name, value))
+    Some((percent_decode(name.as_bytes()).decode_utf8_lossy(), value))
 }
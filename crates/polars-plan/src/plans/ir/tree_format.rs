@@ -360,6 +360,7 @@ impl<'a> TreeFmtNode<'a> {
                             match payload {
                                 SinkType::Memory => "SINK (memory)",
                                 SinkType::File { .. } => "SINK (file)",
+                                SinkType::Partition { .. } => "SINK (partition)",
                                 #[cfg(feature = "cloud")]
                                 SinkType::Cloud { .. } => "SINK (cloud)",
                             },
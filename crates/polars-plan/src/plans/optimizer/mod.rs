@@ -27,6 +27,7 @@ use collapse_and_project::SimpleProjectionAndCollapse;
 use delay_rechunk::DelayRechunk;
 use polars_core::config::verbose;
 use polars_io::predicates::PhysicalIoExpr;
+pub(crate) use predicate_pushdown::resolve_join_predicates;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
@@ -71,6 +71,7 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     {
         match how {
             JoinType::Left => LeftRight(false, true),
+            JoinType::Right => LeftRight(true, false),
             JoinType::Full { .. } | JoinType::Cross | JoinType::AsOf(_) => LeftRight(true, true),
             _ => LeftRight(false, false),
         }
@@ -79,6 +80,7 @@ fn join_produces_null(how: &JoinType) -> LeftRight<bool> {
     {
         match how {
             JoinType::Left => LeftRight(false, true),
+            JoinType::Right => LeftRight(true, false),
             JoinType::Full { .. } | JoinType::Cross => LeftRight(true, true),
             _ => LeftRight(false, false),
         }
@@ -119,6 +121,288 @@ fn predicate_applies_to_both_tables(
     left_used && right_used
 }
 
+// Resolves a column of the output of a cross join to the column of the left (`true`)
+// or right (`false`) input it originates from.
+fn resolve_cross_join_column<'a>(
+    name: &'a str,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<(bool, &'a str)> {
+    if schema_left.contains(name) {
+        Some((true, name))
+    } else if schema_right.contains(name) {
+        Some((false, name))
+    } else if name.ends_with(suffix) && schema_right.contains(split_suffix(name, suffix)) {
+        Some((false, split_suffix(name, suffix)))
+    } else {
+        None
+    }
+}
+
+// Whether equality of values of this dtype is the same as equality of their join keys.
+fn is_equi_join_key(dtype: &DataType) -> bool {
+    dtype.is_integer()
+        || dtype.is_temporal()
+        || matches!(
+            dtype,
+            DataType::String | DataType::Binary | DataType::Boolean
+        )
+}
+
+/// Takes the conjuncts of the predicates that compare a column of the left table to a
+/// column of the right table out of the predicates, as far as `take` accepts them. The
+/// comparisons are given as `left op right`.
+fn take_cross_join_comparisons(
+    acc_predicates: &mut PlHashMap<Arc<str>, ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+    mut take: impl FnMut(Operator, &DataType, &DataType) -> bool,
+) -> Vec<(Arc<str>, Operator, Arc<str>)> {
+    let mut comparisons = vec![];
+    let mut remaining = Vec::with_capacity(acc_predicates.len());
+
+    for (_, predicate) in acc_predicates.drain() {
+        let mut stack = vec![predicate.node()];
+        while let Some(node) = stack.pop() {
+            let comparison = match expr_arena.get(node) {
+                AExpr::BinaryExpr {
+                    left,
+                    op: Operator::And | Operator::LogicalAnd,
+                    right,
+                } => {
+                    stack.push(*left);
+                    stack.push(*right);
+                    continue;
+                },
+                AExpr::BinaryExpr { left, op, right } => {
+                    match (expr_arena.get(*left), expr_arena.get(*right)) {
+                        (AExpr::Column(a), AExpr::Column(b)) => {
+                            match (
+                                resolve_cross_join_column(a, schema_left, schema_right, suffix),
+                                resolve_cross_join_column(b, schema_left, schema_right, suffix),
+                            ) {
+                                (Some((true, l)), Some((false, r))) => Some((l, *op, r)),
+                                (Some((false, r)), Some((true, l))) => {
+                                    swap_comparison(*op).map(|op| (l, op, r))
+                                },
+                                _ => None,
+                            }
+                        },
+                        _ => None,
+                    }
+                },
+                _ => None,
+            }
+            .filter(|(l, op, r)| {
+                take(
+                    *op,
+                    schema_left.get(l).unwrap(),
+                    schema_right.get(r).unwrap(),
+                )
+            })
+            .map(|(l, op, r)| (Arc::<str>::from(l), op, Arc::<str>::from(r)));
+            match comparison {
+                Some(comparison) => comparisons.push(comparison),
+                None => remaining.push(ExprIR::from_node(node, expr_arena)),
+            }
+        }
+    }
+    for predicate in &remaining {
+        insert_and_combine_predicate(acc_predicates, predicate, expr_arena);
+    }
+    comparisons
+}
+
+// The comparison with its operands swapped, such that `a op b` is `b swapped_op a`.
+fn swap_comparison(op: Operator) -> Option<Operator> {
+    Some(match op {
+        Operator::Eq => Operator::Eq,
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        _ => return None,
+    })
+}
+
+fn join_key_columns(
+    columns: impl Iterator<Item = Arc<str>>,
+    expr_arena: &mut Arena<AExpr>,
+) -> Vec<ExprIR> {
+    columns
+        .map(|name| ExprIR::from_node(expr_arena.add(AExpr::Column(name)), expr_arena))
+        .collect()
+}
+
+/// A cross join filtered on the equality of columns of both tables (e.g. SQL's
+/// `FROM a, b WHERE a.k = b.k`) is an inner join on those columns. Takes the equalities
+/// that can be used as join keys out of the predicates and returns the keys, if any.
+fn cross_join_equi_keys(
+    acc_predicates: &mut PlHashMap<Arc<str>, ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<(Vec<ExprIR>, Vec<ExprIR>)> {
+    let keys = take_cross_join_comparisons(
+        acc_predicates,
+        expr_arena,
+        schema_left,
+        schema_right,
+        suffix,
+        |op, dtype_left, dtype_right| {
+            op == Operator::Eq && is_equi_join_key(dtype_left) && dtype_left == dtype_right
+        },
+    );
+    (!keys.is_empty()).then(|| {
+        (
+            join_key_columns(keys.iter().map(|(l, _, _)| l.clone()), expr_arena),
+            join_key_columns(keys.into_iter().map(|(_, _, r)| r), expr_arena),
+        )
+    })
+}
+
+// Whether the values of these dtypes can be compared by an inequality join.
+#[cfg(feature = "iejoin")]
+fn is_inequality_join_key(dtype_left: &DataType, dtype_right: &DataType) -> bool {
+    (dtype_left.is_numeric() && dtype_right.is_numeric())
+        || (dtype_left == dtype_right
+            && (dtype_left.is_temporal() || matches!(dtype_left, DataType::String)))
+}
+
+/// A cross join filtered on inequalities between columns of both tables (e.g. a range
+/// join on `a.start <= b.ts AND b.ts < a.end`) is an inequality join on (up to two of)
+/// those columns. Takes the inequalities that are used as join keys out of the
+/// predicates and returns the keys, if any.
+#[cfg(feature = "iejoin")]
+fn cross_join_inequality_keys(
+    acc_predicates: &mut PlHashMap<Arc<str>, ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+    suffix: &str,
+) -> Option<(Vec<ExprIR>, Vec<ExprIR>, IEJoinOptions)> {
+    let keys = take_cross_join_comparisons(
+        acc_predicates,
+        expr_arena,
+        schema_left,
+        schema_right,
+        suffix,
+        {
+            let mut n_keys = 0;
+            move |op, dtype_left, dtype_right| {
+                let is_key = n_keys < 2
+                    && matches!(
+                        op,
+                        Operator::Lt | Operator::LtEq | Operator::Gt | Operator::GtEq
+                    )
+                    && is_inequality_join_key(dtype_left, dtype_right);
+                n_keys += is_key as usize;
+                is_key
+            }
+        },
+    );
+    let mut operators = keys.iter().map(|(_, op, _)| match op {
+        Operator::Lt => InequalityOperator::Lt,
+        Operator::LtEq => InequalityOperator::LtEq,
+        Operator::Gt => InequalityOperator::Gt,
+        _ => InequalityOperator::GtEq,
+    });
+    let options = IEJoinOptions {
+        operator1: operators.next()?,
+        operator2: operators.next(),
+    };
+    Some((
+        join_key_columns(keys.iter().map(|(l, _, _)| l.clone()), expr_arena),
+        join_key_columns(keys.into_iter().map(|(_, _, r)| r), expr_arena),
+        options,
+    ))
+}
+
+/// Plans a cross join that is filtered on the predicates as an inner or inequality join
+/// on the comparisons of columns of both tables among them, which are taken out of the
+/// predicates. Returns the keys of the join, if it is planned as such.
+///
+/// This changes the row order of the cross join, so it is only done if the join allows
+/// it (SQL's implicit joins and `join_where`).
+fn cross_join_to_keyed_join(
+    options: &mut Arc<JoinOptions>,
+    acc_predicates: &mut PlHashMap<Arc<str>, ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+) -> Option<(Vec<ExprIR>, Vec<ExprIR>)> {
+    if !matches!(options.args.how, JoinType::Cross)
+        || !options.allow_keyed_cross_join
+        || options.args.slice.is_some()
+    {
+        return None;
+    }
+    if let Some(keys) = cross_join_equi_keys(
+        acc_predicates,
+        expr_arena,
+        schema_left,
+        schema_right,
+        options.args.suffix(),
+    ) {
+        // keeping the key columns of both tables gives the schema of the cross join
+        let options = Arc::make_mut(options);
+        options.args.how = JoinType::Inner;
+        options.args.coalesce = JoinCoalesce::KeepColumns;
+        return Some(keys);
+    }
+    #[cfg(feature = "iejoin")]
+    if let Some((left_on, right_on, ie_options)) = cross_join_inequality_keys(
+        acc_predicates,
+        expr_arena,
+        schema_left,
+        schema_right,
+        options.args.suffix(),
+    ) {
+        // an inequality join does not coalesce, so it has the schema of the cross join
+        Arc::make_mut(options).args.how = JoinType::IEJoin(ie_options);
+        return Some((left_on, right_on));
+    }
+    None
+}
+
+/// [`cross_join_to_keyed_join`] for the (conjunctive) predicates of a join on
+/// predicates, such that the join never materializes the Cartesian product, even if
+/// predicate pushdown is disabled. Returns the predicates that remain to be applied to
+/// the output of the join.
+///
+/// Like predicate pushdown, no join keys are taken if a predicate is not elementwise
+/// (e.g. it aggregates), as it has to be evaluated on the Cartesian product.
+pub(crate) fn resolve_join_predicates(
+    options: &mut Arc<JoinOptions>,
+    predicates: Vec<ExprIR>,
+    expr_arena: &mut Arena<AExpr>,
+    schema_left: &Schema,
+    schema_right: &Schema,
+) -> PolarsResult<(Option<(Vec<ExprIR>, Vec<ExprIR>)>, Vec<ExprIR>)> {
+    let mut acc_predicates = PlHashMap::with_capacity(predicates.len());
+    for predicate in &predicates {
+        insert_and_combine_predicate(&mut acc_predicates, predicate, expr_arena);
+    }
+    if !matches!(
+        pushdown_eligibility(&[], &acc_predicates, expr_arena)?.0,
+        PushdownEligibility::Full
+    ) {
+        return Ok((None, predicates));
+    }
+    let keys = cross_join_to_keyed_join(
+        options,
+        &mut acc_predicates,
+        expr_arena,
+        schema_left,
+        schema_right,
+    );
+    Ok((keys, acc_predicates.into_values().collect()))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(super) fn process_join(
     opt: &PredicatePushDown,
@@ -126,16 +410,27 @@ pub(super) fn process_join(
     expr_arena: &mut Arena<AExpr>,
     input_left: Node,
     input_right: Node,
-    left_on: Vec<ExprIR>,
-    right_on: Vec<ExprIR>,
+    mut left_on: Vec<ExprIR>,
+    mut right_on: Vec<ExprIR>,
     schema: SchemaRef,
-    options: Arc<JoinOptions>,
-    acc_predicates: PlHashMap<Arc<str>, ExprIR>,
+    mut options: Arc<JoinOptions>,
+    mut acc_predicates: PlHashMap<Arc<str>, ExprIR>,
 ) -> PolarsResult<IR> {
     use IR::*;
     let schema_left = lp_arena.get(input_left).schema(lp_arena);
     let schema_right = lp_arena.get(input_right).schema(lp_arena);
 
+    if let Some((keys_left, keys_right)) = cross_join_to_keyed_join(
+        &mut options,
+        &mut acc_predicates,
+        expr_arena,
+        &schema_left,
+        &schema_right,
+    ) {
+        left_on = keys_left;
+        right_on = keys_right;
+    }
+
     let on_names = left_on
         .iter()
         .flat_map(|e| aexpr_to_leaf_names_iter(e.node(), expr_arena))
@@ -150,6 +445,18 @@ pub(super) fn process_join(
     let mut pushdown_right = init_hashmap(Some(acc_predicates.len()));
     let mut local_predicates = Vec::with_capacity(acc_predicates.len());
 
+    // A right join that coalesces replaces the key columns of the left table by those of
+    // the right table.
+    let coalesced_left_keys =
+        if matches!(options.args.how, JoinType::Right) && options.args.should_coalesce() {
+            left_on
+                .iter()
+                .map(|e| e.output_name_arc().clone())
+                .collect::<PlHashSet<_>>()
+        } else {
+            PlHashSet::new()
+        };
+
     for (_, predicate) in acc_predicates {
         // Cross joins produce a cartesian product, so if a predicate combines columns from both tables, we should not push down.
         if matches!(options.args.how, JoinType::Cross)
@@ -204,7 +511,7 @@ pub(super) fn process_join(
                 filter_right = match &options.args.how {
                     // TODO! if join_on right has a different name
                     // we can set this to `true` IFF we rename the predicate
-                    JoinType::Inner | JoinType::Left => {
+                    JoinType::Inner | JoinType::Left | JoinType::Right => {
                         check_input_node(predicate.node(), &schema_right, expr_arena)
                     },
                     #[cfg(feature = "semi_anti_join")]
@@ -218,6 +525,13 @@ pub(super) fn process_join(
         // not on `x_rhs`.
         } else if !block_pushdown_right
             && check_input_node(predicate.node(), &schema_right, expr_arena)
+            // in a right join, a predicate that cannot pass to the left table should not
+            // be applied to a right column of the same name
+            && !(block_pushdown_left
+                && matches!(options.args.how, JoinType::Right)
+                && aexpr_to_leaf_names_iter(predicate.node(), expr_arena).any(|name| {
+                    schema_left.contains(name.as_ref()) && !coalesced_left_keys.contains(&name)
+                }))
         {
             filter_right = true
         }
@@ -232,7 +546,9 @@ pub(super) fn process_join(
             // 'we should not filter right, because that would lead to
             // invalid results.
             // see: #2057
-            (false, true, JoinType::Left)
+            (false, true, JoinType::Left) |
+            // likewise for a right join and a predicate only available in the left table
+            (true, false, JoinType::Right)
             => {
                 local_predicates.push(predicate);
                 continue;
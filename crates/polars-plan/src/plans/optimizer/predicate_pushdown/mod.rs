@@ -4,6 +4,7 @@ mod keys;
 mod rename;
 mod utils;
 
+pub(crate) use join::resolve_join_predicates;
 use polars_core::datatypes::PlHashMap;
 use polars_core::prelude::*;
 use recursive::recursive;
@@ -336,6 +337,12 @@ impl<'a> PredicatePushDown<'a> {
                     FileScan::Parquet { .. } => vec![],
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { .. } => vec![],
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro => vec![],
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => vec![],
+                    #[cfg(feature = "fwf")]
+                    FileScan::FixedWidth { .. } => vec![],
                     _ => {
                         // Disallow row index pushdown of other scans as they may
                         // not update the row index properly before applying the
@@ -555,6 +562,32 @@ impl<'a> PredicatePushDown<'a> {
                                 expr_arena,
                             ))
                         },
+                        #[cfg(feature = "pivot")]
+                        FunctionNode::Pivot { args, .. } => {
+                            // A predicate on the index columns filters whole rows of the
+                            // output, so it can be done before the pivot.
+                            let condition =
+                                |name: Arc<str>| !args.index.iter().any(|s| s.as_str() == &*name);
+                            let local_predicates = transfer_to_local_by_name(
+                                expr_arena,
+                                &mut acc_predicates,
+                                condition,
+                            );
+
+                            let lp = self.pushdown_and_continue(
+                                lp,
+                                acc_predicates,
+                                lp_arena,
+                                expr_arena,
+                                false,
+                            )?;
+                            Ok(self.optional_apply_predicate(
+                                lp,
+                                local_predicates,
+                                lp_arena,
+                                expr_arena,
+                            ))
+                        },
                         _ => self.pushdown_and_continue(
                             lp,
                             acc_predicates,
@@ -1,5 +1,9 @@
+#[cfg(feature = "pivot")]
+mod pivot;
 mod unpivot;
 
+#[cfg(feature = "pivot")]
+use pivot::process_pivot;
 use unpivot::process_unpivot;
 
 use super::*;
@@ -81,6 +85,24 @@ pub(super) fn process_functions(
                 expr_arena,
             )
         },
+        #[cfg(feature = "pivot")]
+        Pivot { ref args, .. } => {
+            let lp = IR::MapFunction {
+                input,
+                function: function.clone(),
+            };
+
+            process_pivot(
+                proj_pd,
+                lp,
+                args,
+                input,
+                acc_projections,
+                projections_seen,
+                lp_arena,
+                expr_arena,
+            )
+        },
         _ => {
             if function.allow_projection_pd() && !acc_projections.is_empty() {
                 let original_acc_projection_len = acc_projections.len();
@@ -0,0 +1,39 @@
+use super::*;
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn process_pivot(
+    proj_pd: &mut ProjectionPushDown,
+    lp: IR,
+    args: &Arc<PivotArgs>,
+    input: Node,
+    acc_projections: Vec<ColumnNode>,
+    projections_seen: usize,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<IR> {
+    // The output columns don't exist in the input, which only needs the columns the pivot
+    // reads. Those are all needed, as dropping a value column would rename the others.
+    let mut input_projections = vec![];
+    let mut input_names = PlHashSet::new();
+    for name in args.index.iter().chain(&args.values).chain([&args.on]) {
+        let node = expr_arena.add(AExpr::Column(ColumnName::from(name.as_str())));
+        add_expr_to_accumulated(node, &mut input_projections, &mut input_names, expr_arena)
+    }
+    proj_pd.pushdown_and_assign(
+        input,
+        input_projections,
+        input_names,
+        projections_seen,
+        lp_arena,
+        expr_arena,
+    )?;
+
+    if acc_projections.is_empty() {
+        Ok(lp)
+    } else {
+        Ok(IRBuilder::from_lp(lp, expr_arena, lp_arena)
+            .project_simple_nodes(acc_projections)
+            .unwrap()
+            .build())
+    }
+}
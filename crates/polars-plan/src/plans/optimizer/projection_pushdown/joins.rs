@@ -250,6 +250,11 @@ pub(super) fn process_join(
         // duplicates so store the names.
         let mut local_projected_names = PlHashSet::new();
 
+        // A right join that coalesces keeps the key columns of the right table instead of
+        // those of the left table.
+        let coalesce_right =
+            matches!(options.args.how, JoinType::Right) && options.args.should_coalesce();
+
         // We need the join columns so we push the projection downwards
         for e in &left_on {
             if !local_projected_names.insert(e.output_name_arc().clone()) {
@@ -262,9 +267,12 @@ pub(super) fn process_join(
                 &mut local_projection,
                 &mut names_left,
                 expr_arena,
-                true,
+                !coalesce_right,
             );
         }
+        if coalesce_right {
+            local_projected_names.clear();
+        }
 
         // For left and inner joins we can set `coalesce` to `true` if the rhs key columns are not projected.
         // This saves a materialization.
@@ -298,7 +306,7 @@ pub(super) fn process_join(
         }
 
         // In  both columns remain. So `add_local=true` also for the right table
-        let add_local = !options.args.should_coalesce();
+        let add_local = !options.args.should_coalesce() || coalesce_right;
         for e in &right_on {
             // In case of full outer joins we also add the columns.
             // But before we do that we must check if the column wasn't already added by the lhs.
@@ -413,6 +413,12 @@ impl ProjectionPushDown {
                     FileScan::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
                     FileScan::Parquet { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro => true,
+                    #[cfg(feature = "orc")]
+                    FileScan::Orc { .. } => true,
+                    #[cfg(feature = "fwf")]
+                    FileScan::FixedWidth { .. } => true,
                 };
 
                 if do_optimization {
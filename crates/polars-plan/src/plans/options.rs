@@ -4,12 +4,16 @@ use std::path::PathBuf;
 
 use polars_core::prelude::*;
 use polars_core::utils::SuperTypeOptions;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcWriterOptions;
 #[cfg(feature = "json")]
 use polars_io::json::JsonWriterOptions;
+#[cfg(feature = "orc")]
+use polars_io::orc::OrcWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
 use polars_io::{HiveOptions, RowIndex};
@@ -240,6 +244,54 @@ pub enum SinkType {
         file_type: FileType,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
     },
+    /// Write a Hive partitioned dataset, i.e. `key=value/` directories, to `path`.
+    Partition {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        partition_by: PartitionBy,
+    },
+}
+
+/// Options to partition the output of a sink into `key=value/` (Hive) directories.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartitionBy {
+    /// The columns to partition by, one directory level per column. These columns are not
+    /// written to the files.
+    pub keys: Vec<String>,
+    /// The maximum number of rows per file. A partition is split into multiple files named
+    /// `data-0000`, `data-0001`, ... once this is exceeded.
+    pub max_rows_per_file: Option<usize>,
+    /// The maximum number of files that are open for writing at once. Beyond that, the file
+    /// of the partition that was written to least recently is finished, and the partition
+    /// continues in a new file if more of its rows arrive.
+    pub max_open_files: usize,
+}
+
+impl PartitionBy {
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            keys: keys.into_iter().map(|s| s.as_ref().to_string()).collect(),
+            max_rows_per_file: None,
+            max_open_files: 64,
+        }
+    }
+
+    /// Start a new file once a file of a partition holds this many rows.
+    pub fn with_max_rows_per_file(mut self, max_rows_per_file: Option<usize>) -> Self {
+        self.max_rows_per_file = max_rows_per_file;
+        self
+    }
+
+    /// Keep at most this many files open for writing at once.
+    pub fn with_max_open_files(mut self, max_open_files: usize) -> Self {
+        self.max_open_files = max_open_files;
+        self
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -260,6 +312,10 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
+    #[cfg(feature = "orc")]
+    Orc(OrcWriterOptions),
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -164,6 +164,10 @@ pub fn set_estimated_row_counts(
                         let (known_size, estimated_size) = options.rows_left;
                         (known_size, estimated_size, filter_count_left)
                     },
+                    JoinType::Right => {
+                        let (known_size, estimated_size) = options.rows_right;
+                        (known_size, estimated_size, filter_count_right)
+                    },
                     JoinType::Cross | JoinType::Full { .. } => {
                         let (known_size_left, estimated_size_left) = options.rows_left;
                         let (known_size_right, estimated_size_right) = options.rows_right;
@@ -244,6 +248,35 @@ pub(crate) fn det_join_schema(
         // the schema will never change.
         #[cfg(feature = "semi_anti_join")]
         JoinType::Semi | JoinType::Anti => Ok(schema_left.clone()),
+        // a right join keeps the key columns of the right table if it coalesces, and
+        // otherwise has the schema of a left join
+        JoinType::Right if options.args.should_coalesce() => {
+            let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
+
+            let mut arena = Arena::with_capacity(8);
+            let mut join_on_left: PlHashSet<_> = PlHashSet::with_capacity(left_on.len());
+            for e in left_on {
+                let field = e.to_field_amortized(schema_left, Context::Default, &mut arena)?;
+                join_on_left.insert(field.name);
+                arena.clear();
+            }
+
+            for (name, dtype) in schema_left.iter() {
+                if !join_on_left.contains(name.as_str()) {
+                    new_schema.with_column(name.clone(), dtype.clone());
+                }
+            }
+            for (name, dtype) in schema_right.iter() {
+                if new_schema.contains(name.as_str()) {
+                    let new_name = format_smartstring!("{}{}", name, options.args.suffix());
+                    new_schema.with_column(new_name, dtype.clone());
+                } else {
+                    new_schema.with_column(name.clone(), dtype.clone());
+                }
+            }
+
+            Ok(Arc::new(new_schema))
+        },
         _how => {
             let mut new_schema = Schema::with_capacity(schema_left.len() + schema_right.len());
 
@@ -9,18 +9,28 @@ use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use sqlparser::ast::{
-    Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg, GroupByExpr, Ident, JoinConstraint,
-    JoinOperator, ObjectName, ObjectType, Offset, OrderByExpr, Query, RenameSelectItem, Select,
-    SelectItem, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias, TableFactor,
+    Assignment, Delete, DescribeAlias, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
+    FunctionArg, GroupByExpr, Ident, Insert, Join, JoinConstraint, JoinOperator, MergeAction,
+    MergeClauseKind, MergeInsertKind, NamedWindowDefinition, NamedWindowExpr, ObjectName,
+    ObjectType, Offset, OrderByExpr, Query, RenameSelectItem, Select, SelectItem, SetExpr,
+    SetOperator, SetQuantifier, ShowStatementFilter, Statement, TableAlias, TableFactor,
     TableWithJoins, UnaryOperator, Value as SQLValue, Values, WildcardAdditionalOptions,
+    WindowSpec, WindowType,
 };
-use sqlparser::dialect::GenericDialect;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, TokenWithLocation};
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::dialect::{is_unnest_ordinality, PolarsDialect, SQLDialect};
+use crate::function_registry::{is_udf_aggregation, FunctionRegistry, InMemoryFunctionRegistry};
+use crate::information_schema::{
+    describe_schema, information_schema_relation, table_name_filter, InformationSchemaTable,
+    INFORMATION_SCHEMA,
+};
+use crate::recursive_cte::{RecursiveCte, WorkingTable, DEFAULT_RECURSION_LIMIT};
 use crate::sql_expr::{
-    parse_sql_array, parse_sql_expr, process_join_constraint, resolve_compound_identifier,
-    to_sql_interface_err,
+    decorrelate_lateral, parse_sql_array, parse_sql_expr, process_join_constraint,
+    resolve_compound_identifier, to_sql_interface_err, SubqueryJoin,
 };
 use crate::table_functions::PolarsTableFunctions;
 
@@ -54,18 +64,32 @@ pub struct SQLContext {
     pub(crate) expr_arena: Arena<AExpr>,
 
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
+    information_schema_map: RefCell<PlHashMap<String, LazyFrame>>,
+    information_schema_filter: Option<PlHashSet<String>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    named_windows: RefCell<PlHashMap<String, WindowSpec>>,
+    pub(crate) subquery_joins: RefCell<Vec<SubqueryJoin>>,
+    pub(crate) grouping_keys: RefCell<Vec<Expr>>,
+    recursion_limit: usize,
+    pub(crate) dialect: SQLDialect,
 }
 
 impl Default for SQLContext {
     fn default() -> Self {
         Self {
-            function_registry: Arc::new(DefaultFunctionRegistry {}),
+            function_registry: Arc::new(InMemoryFunctionRegistry::new()),
             table_map: Default::default(),
             cte_map: Default::default(),
+            information_schema_map: Default::default(),
+            information_schema_filter: None,
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            named_windows: Default::default(),
+            subquery_joins: Default::default(),
+            grouping_keys: Default::default(),
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            dialect: SQLDialect::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -134,15 +158,14 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
+        let dialect = PolarsDialect(self.dialect);
+        let tokens = dialect.tokenize(query).map_err(to_sql_interface_err)?;
+        let ast = Parser::new(&dialect)
+            .with_options(ParserOptions {
+                trailing_commas: allows_trailing_commas(&tokens),
+                ..Default::default()
+            })
+            .with_tokens_with_locations(tokens)
             .parse_statements()
             .map_err(to_sql_interface_err)?;
 
@@ -157,8 +180,13 @@ impl SQLContext {
 
         // Every execution should clear the statement-level maps.
         self.cte_map.borrow_mut().clear();
+        self.information_schema_map.borrow_mut().clear();
+        self.information_schema_filter = None;
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
+        self.named_windows.borrow_mut().clear();
+        self.subquery_joins.borrow_mut().clear();
+        self.grouping_keys.borrow_mut().clear();
 
         Ok(res)
     }
@@ -170,6 +198,32 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations of a recursive CTE (`WITH RECURSIVE`); a
+    /// query whose recursion does not finish within the limit fails. Defaults to 1000.
+    pub fn with_recursion_limit(mut self, limit: usize) -> Self {
+        self.recursion_limit = limit;
+        self
+    }
+
+    /// Set the SQL dialect that queries are parsed and evaluated in; defaults to
+    /// [`SQLDialect::Generic`].
+    /// ```rust
+    /// # use polars_sql::{SQLContext, SQLDialect};
+    /// # fn main() {
+    /// let ctx = SQLContext::new().with_dialect(SQLDialect::PostgreSQL);
+    /// assert_eq!(ctx.dialect(), SQLDialect::PostgreSQL);
+    /// # }
+    /// ```
+    pub fn with_dialect(mut self, dialect: SQLDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Get the SQL dialect of the SQLContext
+    pub fn dialect(&self) -> SQLDialect {
+        self.dialect
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -187,6 +241,8 @@ impl SQLContext {
         Ok(match ast {
             Statement::Query(query) => self.execute_query(query)?,
             stmt @ Statement::ShowTables { .. } => self.execute_show_tables(stmt)?,
+            stmt @ Statement::ShowColumns { .. } => self.execute_show_columns(stmt)?,
+            stmt @ Statement::ExplainTable { .. } => self.execute_describe_table(stmt)?,
             stmt @ Statement::CreateTable { .. } => self.execute_create_table(stmt)?,
             stmt @ Statement::Drop {
                 object_type: ObjectType::Table,
@@ -194,6 +250,10 @@ impl SQLContext {
             } => self.execute_drop_table(stmt)?,
             stmt @ Statement::Explain { .. } => self.execute_explain(stmt)?,
             stmt @ Statement::Truncate { .. } => self.execute_truncate_table(stmt)?,
+            stmt @ Statement::Insert { .. } => self.execute_insert(stmt)?,
+            stmt @ Statement::Update { .. } => self.execute_update(stmt)?,
+            stmt @ Statement::Delete { .. } => self.execute_delete(stmt)?,
+            stmt @ Statement::Merge { .. } => self.execute_merge(stmt)?,
             _ => polars_bail!(
                 SQLInterface: "statement type {:?} is not supported", ast,
             ),
@@ -220,6 +280,63 @@ impl SQLContext {
                     .get(name)
                     .and_then(|alias| self.table_map.get(alias).cloned())
             })
+            .or_else(|| self.information_schema_map.borrow().get(name).cloned())
+    }
+
+    /// Resolve a window reference (`OVER w` or `OVER (w ORDER BY ...)`) against the
+    /// windows defined by the `WINDOW` clause.
+    pub(crate) fn resolve_window_spec(&self, window: &WindowType) -> PolarsResult<WindowSpec> {
+        let (name, spec) = match window {
+            WindowType::NamedWindow(name) => (Some(name), None),
+            WindowType::WindowSpec(spec) => (spec.window_name.as_ref(), Some(spec)),
+        };
+        let Some(name) = name else {
+            return Ok(spec.unwrap().clone());
+        };
+        let base = self
+            .named_windows
+            .borrow()
+            .get(&name.value)
+            .cloned()
+            .ok_or_else(|| polars_err!(SQLInterface: "window '{}' is not defined", name))?;
+
+        match spec {
+            None => Ok(base),
+            Some(spec) => {
+                // a window that refers to another may only add ORDER BY and frame clauses
+                polars_ensure!(
+                    spec.partition_by.is_empty(),
+                    SQLSyntax: "cannot override PARTITION BY clause of window '{}'", name
+                );
+                polars_ensure!(
+                    spec.order_by.is_empty() || base.order_by.is_empty(),
+                    SQLSyntax: "cannot override ORDER BY clause of window '{}'", name
+                );
+                Ok(WindowSpec {
+                    window_name: None,
+                    partition_by: base.partition_by,
+                    order_by: if spec.order_by.is_empty() {
+                        base.order_by
+                    } else {
+                        spec.order_by.clone()
+                    },
+                    window_frame: spec.window_frame.clone().or(base.window_frame),
+                })
+            },
+        }
+    }
+
+    fn register_named_windows(&self, named_windows: &[NamedWindowDefinition]) -> PolarsResult<()> {
+        for NamedWindowDefinition(name, window_expr) in named_windows {
+            let spec = self.resolve_window_spec(&match window_expr {
+                NamedWindowExpr::NamedWindow(base) => WindowType::NamedWindow(base.clone()),
+                NamedWindowExpr::WindowSpec(spec) => WindowType::WindowSpec(spec.clone()),
+            })?;
+            self.named_windows
+                .borrow_mut()
+                .insert(name.value.clone(), spec);
+        }
+        Ok(())
     }
 
     fn expr_or_ordinal(
@@ -454,10 +571,20 @@ impl SQLContext {
     }
 
     // EXPLAIN SELECT * FROM DF
+    // DESCRIBE SELECT * FROM DF
     fn execute_explain(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
-            Statement::Explain { statement, .. } => {
-                let lf = self.execute_statement(statement)?;
+            Statement::Explain {
+                describe_alias,
+                statement,
+                ..
+            } => {
+                let mut lf = self.execute_statement(statement)?;
+                if !matches!(describe_alias, DescribeAlias::Explain) {
+                    // describe the columns of the result, rather than the plan
+                    let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                    return describe_schema(&schema);
+                }
                 let plan = lf.describe_optimized_plan()?;
                 let plan = plan
                     .split('\n')
@@ -477,6 +604,92 @@ impl SQLContext {
         Ok(df.lazy())
     }
 
+    // SHOW COLUMNS FROM DF [LIKE 'pattern' | WHERE ...]
+    fn execute_show_columns(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        match stmt {
+            Statement::ShowColumns {
+                table_name, filter, ..
+            } => {
+                let mut lf = self.get_relation(table_name)?;
+                let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                let mut columns = describe_schema(&schema)?;
+                if let Some(filter) = filter {
+                    let column_name = Box::new(SQLExpr::Identifier(Ident::new("column_name")));
+                    let predicate = match filter {
+                        ShowStatementFilter::Like(pattern) => SQLExpr::Like {
+                            negated: false,
+                            expr: column_name,
+                            pattern: Box::new(SQLExpr::Value(SQLValue::SingleQuotedString(
+                                pattern.clone(),
+                            ))),
+                            escape_char: None,
+                        },
+                        ShowStatementFilter::ILike(pattern) => SQLExpr::ILike {
+                            negated: false,
+                            expr: column_name,
+                            pattern: Box::new(SQLExpr::Value(SQLValue::SingleQuotedString(
+                                pattern.clone(),
+                            ))),
+                            escape_char: None,
+                        },
+                        ShowStatementFilter::Where(expr) => expr.clone(),
+                    };
+                    let schema =
+                        columns.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                    let predicate = parse_sql_expr(&predicate, self, Some(&schema))?;
+                    columns = columns.filter(predicate);
+                }
+                Ok(columns)
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    // DESCRIBE DF
+    fn execute_describe_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        match stmt {
+            Statement::ExplainTable { table_name, .. } => {
+                let mut lf = self.get_relation(table_name)?;
+                let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                describe_schema(&schema)
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    /// Get a table (or `information_schema` relation) by name.
+    fn get_relation(&mut self, name: &ObjectName) -> PolarsResult<LazyFrame> {
+        match name.0.as_slice() {
+            [schema, tbl_name] if schema.value.eq_ignore_ascii_case(INFORMATION_SCHEMA) => {
+                self.get_information_schema_table(&tbl_name.value)
+            },
+            [tbl_name] => self
+                .get_table_from_current_scope(&tbl_name.value)
+                .ok_or_else(|| polars_err!(SQLInterface: "relation '{}' was not found", name)),
+            _ => polars_bail!(SQLInterface: "relation '{}' was not found", name),
+        }
+    }
+
+    /// Generate an `information_schema` relation from the registered tables.
+    ///
+    /// Only the schemas of the tables the WHERE clause restricts the relation to
+    /// (if any) are resolved.
+    fn get_information_schema_table(&mut self, name: &str) -> PolarsResult<LazyFrame> {
+        let table = InformationSchemaTable::try_from_name(name)?;
+        let filter = self.information_schema_filter.take();
+        let mut tables = Vec::with_capacity(self.table_map.len());
+        for tbl_name in self.get_tables().into_iter().filter(|tbl_name| {
+            filter
+                .as_ref()
+                .map_or(true, |filter| filter.contains(tbl_name))
+        }) {
+            let mut lf = self.table_map.get(&tbl_name).cloned().unwrap();
+            let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            tables.push((tbl_name, schema));
+        }
+        table.generate(&tables)
+    }
+
     fn execute_drop_table(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
         match stmt {
             Statement::Drop { names, .. } => {
@@ -520,30 +733,546 @@ impl SQLContext {
         }
     }
 
+    // INSERT INTO tbl [(col, ...)] { SELECT ... | VALUES ... }
+    fn execute_insert(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Insert(Insert {
+            table_name,
+            columns,
+            overwrite,
+            source,
+            partitioned,
+            after_columns,
+            on,
+            returning,
+            ..
+        }) = stmt
+        {
+            polars_ensure!(
+                partitioned.is_none() && after_columns.is_empty(),
+                SQLInterface: "INSERT does not support use of 'partitions'"
+            );
+            polars_ensure!(on.is_none(), SQLInterface: "INSERT does not support 'ON CONFLICT' or 'ON DUPLICATE KEY'");
+            polars_ensure!(returning.is_none(), SQLInterface: "INSERT does not support 'RETURNING'");
+
+            let tbl_name = table_name.0.first().unwrap().value.as_str();
+            let mut lf = self.table_map.get(tbl_name).cloned().ok_or_else(
+                || polars_err!(SQLInterface: "relation '{}' was not found", tbl_name),
+            )?;
+            let Some(source) = source else {
+                polars_bail!(SQLInterface: "INSERT requires a SELECT or VALUES clause");
+            };
+            let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            let mut rows = self.execute_query(source)?;
+            let rows_schema = rows.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            let values = rows_schema.iter_names().map(|name| col(name)).collect();
+            let rows = rows.select(insert_projection(&schema, columns, values)?);
+
+            lf = if *overwrite {
+                rows
+            } else {
+                polars_lazy::dsl::concat(vec![lf, rows], UnionArgs::default())?
+            };
+            self.register(tbl_name, lf);
+            Ok(dml_response("INSERT"))
+        } else {
+            unreachable!()
+        }
+    }
+
+    // UPDATE tbl SET col = expr, ... [WHERE ...]
+    fn execute_update(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Update {
+            table,
+            assignments,
+            from,
+            selection,
+            returning,
+        } = stmt
+        {
+            polars_ensure!(from.is_none(), SQLInterface: "UPDATE does not (yet) support 'FROM'");
+            polars_ensure!(returning.is_none(), SQLInterface: "UPDATE does not support 'RETURNING'");
+            polars_ensure!(table.joins.is_empty(), SQLInterface: "UPDATE does not support joined tables");
+
+            let (tbl_name, _, mut lf) = self.get_dml_table(&table.relation)?;
+            let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            let ((predicate, updates), subquery_joins) = self.parse_with_subquery_joins(|ctx| {
+                let predicate = selection
+                    .as_ref()
+                    .map(|expr| parse_sql_expr(expr, ctx, Some(&schema)))
+                    .transpose()?;
+                let updates = ctx.process_assignments(assignments, &schema, &schema)?;
+                Ok((predicate, updates))
+            })?;
+
+            // rows for which the predicate does not hold (or is null) are unchanged
+            let mut updates = updates
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = value.strict_cast(schema.get(&name).unwrap().clone());
+                    match &predicate {
+                        Some(predicate) => {
+                            when(predicate.clone()).then(value).otherwise(col(&name))
+                        },
+                        None => value,
+                    }
+                    .alias(&name)
+                })
+                .collect::<Vec<_>>();
+            lf = self.apply_dml_subqueries(lf, subquery_joins, updates.iter_mut().collect());
+            lf = lf.with_columns(updates).select(
+                schema
+                    .iter_names()
+                    .map(|name| col(name))
+                    .collect::<Vec<_>>(),
+            );
+            self.register(&tbl_name, lf);
+            Ok(dml_response("UPDATE"))
+        } else {
+            unreachable!()
+        }
+    }
+
+    // DELETE FROM tbl [WHERE ...]
+    fn execute_delete(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Delete(Delete {
+            tables,
+            from,
+            using,
+            selection,
+            returning,
+            order_by,
+            limit,
+        }) = stmt
+        {
+            polars_ensure!(
+                tables.is_empty() && using.is_none(),
+                SQLInterface: "DELETE does not support multiple tables or 'USING'"
+            );
+            polars_ensure!(returning.is_none(), SQLInterface: "DELETE does not support 'RETURNING'");
+            polars_ensure!(
+                order_by.is_empty() && limit.is_none(),
+                SQLInterface: "DELETE does not support 'ORDER BY' or 'LIMIT'"
+            );
+            let (FromTable::WithFromKeyword(from) | FromTable::WithoutKeyword(from)) = from;
+            polars_ensure!(
+                from.len() == 1 && from[0].joins.is_empty(),
+                SQLInterface: "DELETE requires exactly one table"
+            );
+
+            let (tbl_name, _, mut lf) = self.get_dml_table(&from[0].relation)?;
+            let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            lf = match selection {
+                Some(expr) => {
+                    let (mut predicate, subquery_joins) =
+                        self.parse_with_subquery_joins(|ctx| {
+                            parse_sql_expr(expr, ctx, Some(&schema))
+                        })?;
+                    lf = self.apply_dml_subqueries(lf, subquery_joins, vec![&mut predicate]);
+                    // rows for which the predicate is null are retained
+                    lf.filter(predicate.not().fill_null(lit(true))).select(
+                        schema
+                            .iter_names()
+                            .map(|name| col(name))
+                            .collect::<Vec<_>>(),
+                    )
+                },
+                None => DataFrame::empty_with_schema(&schema).lazy(),
+            };
+            self.register(&tbl_name, lf);
+            Ok(dml_response("DELETE"))
+        } else {
+            unreachable!()
+        }
+    }
+
+    // MERGE INTO tbl USING source ON ... WHEN [NOT] MATCHED [AND ...] THEN ...
+    fn execute_merge(&mut self, stmt: &Statement) -> PolarsResult<LazyFrame> {
+        if let Statement::Merge {
+            table,
+            source,
+            on,
+            clauses,
+            ..
+        } = stmt
+        {
+            let (tbl_name, tgt_name, mut lf) = self.get_dml_table(table)?;
+            let (src_name, mut src) = self.get_table(source)?;
+            let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            let src_schema = src.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+            let (left_on, right_on) = process_join_constraint(
+                &JoinConstraint::On(on.as_ref().clone()),
+                &tgt_name,
+                &src_name,
+            )?;
+            let (source_clauses, target_clauses): (Vec<_>, Vec<_>) =
+                clauses.iter().partition(|clause| {
+                    matches!(
+                        clause.clause_kind,
+                        MergeClauseKind::NotMatched | MergeClauseKind::NotMatchedByTarget
+                    )
+                });
+
+            // Source rows without a matching target row are inserted by the first
+            // 'WHEN NOT MATCHED' clause whose condition holds
+            let mut inserts = vec![];
+            if !source_clauses.is_empty() {
+                let unmatched = merge_unmatched_rows(
+                    src.clone(),
+                    lf.clone(),
+                    left_on.clone(),
+                    right_on.clone(),
+                );
+                let mut inserted = lit(false);
+                for clause in source_clauses {
+                    let MergeAction::Insert(insert) = &clause.action else {
+                        polars_bail!(SQLInterface: "'WHEN NOT MATCHED' clauses only support INSERT; found {}", clause.action);
+                    };
+                    let values = match &insert.kind {
+                        MergeInsertKind::Values(Values { rows, .. }) => {
+                            polars_ensure!(rows.len() == 1, SQLInterface: "MERGE INSERT expects a single row of VALUES; found {}", rows.len());
+                            rows[0]
+                                .iter()
+                                .map(|expr| parse_sql_expr(expr, self, Some(&src_schema)))
+                                .collect::<PolarsResult<Vec<_>>>()?
+                        },
+                        MergeInsertKind::Row => {
+                            src_schema.iter_names().map(|name| col(name)).collect()
+                        },
+                    };
+                    let condition = self.merge_clause_predicate(&clause.predicate, &src_schema)?;
+                    inserts.push(
+                        unmatched
+                            .clone()
+                            .filter(inserted.clone().not().and(condition.clone()))
+                            .select(insert_projection(&schema, &insert.columns, values)?),
+                    );
+                    inserted = inserted.or(condition);
+                }
+            }
+
+            // Target rows are updated/deleted by the first 'WHEN MATCHED' (or 'WHEN NOT
+            // MATCHED BY SOURCE') clause whose condition holds
+            if !target_clauses.is_empty() {
+                let matched = col(MERGE_MATCHED).is_not_null();
+                lf = lf
+                    .join_builder()
+                    .with(src.with_column(lit(true).alias(MERGE_MATCHED)))
+                    .left_on(left_on)
+                    .right_on(right_on)
+                    .how(JoinType::Left)
+                    // each target row may be matched by (at most) one source row
+                    .validate(JoinValidation::ManyToOne)
+                    .suffix(format!(":{}", src_name))
+                    .coalesce(JoinCoalesce::KeepColumns)
+                    .finish();
+                self.track_joined_aliases(&mut lf, &src_name, &schema, &src_schema)?;
+                let joined_schema =
+                    lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+
+                let mut actions = Vec::with_capacity(target_clauses.len());
+                for clause in target_clauses {
+                    let condition = match clause.clause_kind {
+                        MergeClauseKind::Matched => matched.clone(),
+                        _ => matched.clone().not(),
+                    }
+                    .and(self.merge_clause_predicate(&clause.predicate, &joined_schema)?);
+                    let updates = match &clause.action {
+                        MergeAction::Update { assignments } => Some(
+                            self.process_assignments(assignments, &schema, &joined_schema)?
+                                .into_iter()
+                                .collect::<PlHashMap<_, _>>(),
+                        ),
+                        MergeAction::Delete => None,
+                        action => {
+                            polars_bail!(SQLInterface: "'WHEN MATCHED' clauses only support UPDATE or DELETE; found {}", action)
+                        },
+                    };
+                    actions.push((condition, updates));
+                }
+                let mut exprs = schema
+                    .iter()
+                    .filter(|(name, _)| {
+                        actions.iter().any(|(_, updates)| {
+                            updates
+                                .as_ref()
+                                .map_or(false, |u| u.contains_key(name.as_str()))
+                        })
+                    })
+                    .map(|(name, dtype)| {
+                        actions
+                            .iter()
+                            .rev()
+                            .fold(col(name), |expr, (condition, updates)| {
+                                let value =
+                                    match updates.as_ref().and_then(|u| u.get(name.as_str())) {
+                                        Some(value) => value.clone().strict_cast(dtype.clone()),
+                                        None => col(name),
+                                    };
+                                when(condition.clone()).then(value).otherwise(expr)
+                            })
+                            .alias(name)
+                    })
+                    .collect::<Vec<_>>();
+                exprs.push(
+                    actions
+                        .iter()
+                        .rev()
+                        .fold(lit(false), |expr, (condition, updates)| {
+                            when(condition.clone())
+                                .then(lit(updates.is_none()))
+                                .otherwise(expr)
+                        })
+                        .alias(MERGE_DELETED),
+                );
+                lf = lf
+                    .with_columns(exprs)
+                    .filter(col(MERGE_DELETED).not())
+                    .select(
+                        schema
+                            .iter_names()
+                            .map(|name| col(name))
+                            .collect::<Vec<_>>(),
+                    );
+            }
+            if !inserts.is_empty() {
+                inserts.insert(0, lf);
+                lf = polars_lazy::dsl::concat(inserts, UnionArgs::default())?;
+            }
+            self.register(&tbl_name, lf);
+            Ok(dml_response("MERGE"))
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Resolve the registered table that is the target of a DML statement, returning
+    /// its name, the name it is referenced by in the statement, and the table itself.
+    fn get_dml_table(
+        &mut self,
+        relation: &TableFactor,
+    ) -> PolarsResult<(String, String, LazyFrame)> {
+        match relation {
+            TableFactor::Table {
+                name, args: None, ..
+            } => {
+                let tbl_name = name.0.first().unwrap().value.clone();
+                polars_ensure!(
+                    self.table_map.contains_key(&tbl_name),
+                    SQLInterface: "relation '{}' was not found", tbl_name
+                );
+                let (ref_name, lf) = self.get_table(relation)?;
+                Ok((tbl_name, ref_name, lf))
+            },
+            _ => {
+                polars_bail!(SQLInterface: "expected a table as the target of the statement; found {}", relation)
+            },
+        }
+    }
+
+    /// Parse the `col = expr` assignments of an UPDATE (or MERGE) statement against the
+    /// given schema, returning the updated column names of the target table and values.
+    fn process_assignments(
+        &mut self,
+        assignments: &[Assignment],
+        target_schema: &Schema,
+        schema: &Schema,
+    ) -> PolarsResult<Vec<(String, Expr)>> {
+        let mut updated = PlHashSet::with_capacity(assignments.len());
+        assignments
+            .iter()
+            .map(|Assignment { id, value }| {
+                let name = id.last().unwrap().value.as_str();
+                polars_ensure!(
+                    target_schema.contains(name),
+                    SQLInterface: "UPDATE target column '{}' does not exist", name
+                );
+                polars_ensure!(
+                    updated.insert(name),
+                    SQLInterface: "column '{}' is assigned more than once", name
+                );
+                Ok((name.to_string(), parse_sql_expr(value, self, Some(schema))?))
+            })
+            .collect()
+    }
+
+    /// The (null-safe) condition of a MERGE 'WHEN' clause.
+    fn merge_clause_predicate(
+        &mut self,
+        predicate: &Option<SQLExpr>,
+        schema: &Schema,
+    ) -> PolarsResult<Expr> {
+        Ok(match predicate {
+            Some(expr) => parse_sql_expr(expr, self, Some(schema))?.fill_null(lit(false)),
+            None => lit(true),
+        })
+    }
+
+    /// Join any correlated subqueries to the target table of a DML statement, and
+    /// resolve the uncorrelated subqueries referenced by the given expressions.
+    fn apply_dml_subqueries(
+        &self,
+        mut lf: LazyFrame,
+        subquery_joins: Vec<SubqueryJoin>,
+        exprs: Vec<&mut Expr>,
+    ) -> LazyFrame {
+        for join in subquery_joins {
+            lf = join.join_left(lf);
+        }
+        self.process_subqueries(lf, exprs)
+    }
+
     fn register_cte(&mut self, name: &str, lf: LazyFrame) {
         self.cte_map.borrow_mut().insert(name.to_owned(), lf);
     }
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let recursive_lf = if with.recursive {
+                    self.process_recursive_cte(&cte_name, &cte.query, &cte.alias)?
+                } else {
+                    None
+                };
+                let lf = match recursive_lf {
+                    Some(lf) => lf,
+                    None => {
+                        let lf = self.execute_query(&cte.query)?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    },
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Plan a CTE of a `WITH RECURSIVE` clause: `<anchor> UNION [ALL] <recursive term>`, where
+    /// only the recursive term references the CTE. Returns `None` if the CTE does not
+    /// reference itself (and can be planned like any other CTE).
+    fn process_recursive_cte(
+        &mut self,
+        cte_name: &str,
+        query: &Query,
+        alias: &TableAlias,
+    ) -> PolarsResult<Option<LazyFrame>> {
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = query.body.as_ref()
+        else {
+            return Ok(None);
+        };
+        let union_all = match set_quantifier {
+            SetQuantifier::All => true,
+            SetQuantifier::Distinct | SetQuantifier::None => false,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", set_quantifier, cte_name
+            ),
+        };
+        // CTEs defined within the CTE's own query
+        self.register_ctes(query)?;
+
+        let anchor = self.process_query(left, query)?;
+        let mut anchor = self.rename_columns_from_table_alias(anchor, alias)?;
+        let schema = anchor.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+
+        // plan the recursive term against the rows of the previous iteration
+        let working_table = WorkingTable::new(schema.clone());
+        let outer_cte = self
+            .cte_map
+            .borrow_mut()
+            .insert(cte_name.to_string(), working_table.scan()?);
+        let recursive_term = self.process_query(right, query);
+        match outer_cte {
+            Some(lf) => self.register_cte(cte_name, lf),
+            None => {
+                self.cte_map.borrow_mut().remove(cte_name);
+            },
+        }
+        let mut recursive_term = recursive_term?;
+        if !working_table.is_referenced() {
+            return Ok(None);
+        }
+        polars_ensure!(
+            query.order_by.is_empty() && query.limit.is_none() && query.offset.is_none(),
+            SQLInterface: "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE '{}'", cte_name
+        );
+
+        // the recursive term takes the column names and types of the anchor
+        let recursive_schema =
+            recursive_term.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        polars_ensure!(
+            recursive_schema.len() == schema.len(),
+            SQLSyntax: "recursive CTE '{}' has {} columns in its anchor and {} in its recursive term",
+            cte_name, schema.len(), recursive_schema.len()
+        );
+        let recursive_term = recursive_term.select(
+            recursive_schema
+                .iter_names()
+                .zip(schema.iter())
+                .map(|(name, (anchor_name, dtype))| {
+                    col(name).strict_cast(dtype.clone()).alias(anchor_name)
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        let cte = RecursiveCte::new(
+            cte_name,
+            working_table,
+            recursive_term,
+            union_all,
+            self.recursion_limit,
+        );
+        Ok(Some(cte.finish(anchor)))
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
-        let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
-        if !tbl_expr.joins.is_empty() {
-            for tbl in &tbl_expr.joins {
+        let (l_name, lf) = self.get_table(&tbl_expr.relation)?;
+        self.execute_joins(lf, &l_name, &tbl_expr.joins)
+    }
+
+    /// Execute an implicit join (`FROM a, b`) of the preceding FROM items with a table.
+    ///
+    /// This is a cross join; an equality predicate on columns of both sides in the WHERE
+    /// clause turns it into an equi-join when the query is optimized.
+    fn execute_implicit_join(
+        &mut self,
+        mut lf: LazyFrame,
+        tbl_expr: &TableWithJoins,
+    ) -> PolarsResult<LazyFrame> {
+        if let Some(name) =
+            self.process_lateral_join(&mut lf, &tbl_expr.relation, &JoinOperator::CrossJoin)?
+        {
+            return self.execute_joins(lf, &name, &tbl_expr.joins);
+        }
+        let (r_name, rf) = self.get_table(&tbl_expr.relation)?;
+        let mut rf = self.execute_joins(rf, &r_name, &tbl_expr.joins)?;
+        let left_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let right_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        lf = sql_cross_join(lf, rf, &r_name);
+        self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
+        Ok(lf)
+    }
+
+    /// Execute the explicit joins of the table `l_name` (`lf`) with the given tables.
+    fn execute_joins(
+        &mut self,
+        mut lf: LazyFrame,
+        l_name: &str,
+        joins: &[Join],
+    ) -> PolarsResult<LazyFrame> {
+        if !joins.is_empty() {
+            for tbl in joins {
+                if self
+                    .process_lateral_join(&mut lf, &tbl.relation, &tbl.join_operator)?
+                    .is_some()
+                {
+                    continue;
+                }
                 let (r_name, mut rf) = self.get_table(&tbl.relation)?;
                 let left_schema =
                     lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
@@ -552,31 +1281,34 @@ impl SQLContext {
 
                 lf = match &tbl.join_operator {
                     JoinOperator::FullOuter(constraint) => {
-                        self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Full)?
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Full)?
                     },
                     JoinOperator::Inner(constraint) => {
-                        self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Inner)?
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Inner)?
                     },
                     JoinOperator::LeftOuter(constraint) => {
-                        self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Left)?
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Left)?
+                    },
+                    JoinOperator::RightOuter(constraint) => {
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Right)?
                     },
                     #[cfg(feature = "semi_anti_join")]
                     JoinOperator::LeftAnti(constraint) => {
-                        self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Anti)?
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Anti)?
                     },
                     #[cfg(feature = "semi_anti_join")]
                     JoinOperator::LeftSemi(constraint) => {
-                        self.process_join(lf, rf, constraint, &l_name, &r_name, JoinType::Semi)?
+                        self.process_join(lf, rf, constraint, l_name, &r_name, JoinType::Semi)?
                     },
                     #[cfg(feature = "semi_anti_join")]
                     JoinOperator::RightAnti(constraint) => {
-                        self.process_join(rf, lf, constraint, &l_name, &r_name, JoinType::Anti)?
+                        self.process_join(rf, lf, constraint, l_name, &r_name, JoinType::Anti)?
                     },
                     #[cfg(feature = "semi_anti_join")]
                     JoinOperator::RightSemi(constraint) => {
-                        self.process_join(rf, lf, constraint, &l_name, &r_name, JoinType::Semi)?
+                        self.process_join(rf, lf, constraint, l_name, &r_name, JoinType::Semi)?
                     },
-                    JoinOperator::CrossJoin => lf.cross_join(rf, Some(format!(":{}", r_name))),
+                    JoinOperator::CrossJoin => sql_cross_join(lf, rf, &r_name),
                     join_type => {
                         polars_bail!(
                             SQLInterface:
@@ -586,48 +1318,244 @@ impl SQLContext {
                 };
 
                 // track join-aliased columns so we can resolve them later
-                let joined_schema =
-                    lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-                self.joined_aliases.borrow_mut().insert(
-                    r_name.to_string(),
-                    right_schema
-                        .iter_names()
-                        .filter_map(|name| {
-                            // col exists in both tables and is aliased in the joined result
-                            let aliased_name = format!("{}:{}", name, r_name);
-                            if left_schema.contains(name)
-                                && joined_schema.contains(aliased_name.as_str())
-                            {
-                                Some((name.to_string(), aliased_name))
-                            } else {
-                                None
-                            }
-                        })
-                        .collect::<PlHashMap<String, String>>(),
-                );
+                self.track_joined_aliases(&mut lf, &r_name, &left_schema, &right_schema)?;
             }
         };
         Ok(lf)
     }
 
+    /// Record the columns of the `r_name` table that were suffixed by joining it to a
+    /// table with the given left schema, so that references to them resolve.
+    fn track_joined_aliases(
+        &mut self,
+        joined: &mut LazyFrame,
+        r_name: &str,
+        left_schema: &Schema,
+        right_schema: &Schema,
+    ) -> PolarsResult<()> {
+        let joined_schema = joined.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        self.joined_aliases.borrow_mut().insert(
+            r_name.to_string(),
+            right_schema
+                .iter_names()
+                .filter_map(|name| {
+                    // col exists in both tables and is aliased in the joined result
+                    let aliased_name = format!("{}:{}", name, r_name);
+                    if left_schema.contains(name) && joined_schema.contains(aliased_name.as_str()) {
+                        Some((name.to_string(), aliased_name))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<PlHashMap<String, String>>(),
+        );
+        Ok(())
+    }
+
+    /// Join a `LATERAL` derived table or an `UNNEST` of columns that references the
+    /// preceding FROM items (`lf`) to them, returning the name of the joined relation;
+    /// returns `None` (leaving `lf` untouched) for any other relation.
+    fn process_lateral_join(
+        &mut self,
+        lf: &mut LazyFrame,
+        relation: &TableFactor,
+        join_operator: &JoinOperator,
+    ) -> PolarsResult<Option<String>> {
+        let is_lateral = match relation {
+            TableFactor::Derived { lateral, .. } => *lateral,
+            TableFactor::UNNEST { array_exprs, .. } => array_exprs
+                .iter()
+                .any(|expr| !matches!(expr, SQLExpr::Array(_))),
+            _ => false,
+        };
+        if !is_lateral {
+            return Ok(None);
+        }
+        let left_schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let (left_join, on_predicate) = match join_operator {
+            JoinOperator::CrossJoin | JoinOperator::Inner(JoinConstraint::None) => (false, None),
+            JoinOperator::Inner(JoinConstraint::On(expr)) => (false, Some(expr)),
+            JoinOperator::LeftOuter(JoinConstraint::On(SQLExpr::Value(SQLValue::Boolean(
+                true,
+            )))) => (true, None),
+            _ => polars_bail!(
+                SQLInterface: "LATERAL join type '{:?}' not currently supported (use CROSS JOIN, JOIN ... ON or LEFT JOIN ... ON TRUE)", join_operator
+            ),
+        };
+
+        let (name, right_schema, joined) = match relation {
+            TableFactor::Derived {
+                subquery, alias, ..
+            } => {
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "derived tables must have aliases");
+                };
+                let Some(lateral) = decorrelate_lateral(subquery, self, &left_schema)? else {
+                    // the subquery does not reference the preceding FROM items
+                    return Ok(None);
+                };
+                let name = alias.name.value.clone();
+                let mut rf = lateral.lf;
+                let schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                let mut columns = schema
+                    .iter_names()
+                    .take(schema.len() - lateral.keys.len())
+                    .map(|name| name.to_string())
+                    .collect::<Vec<_>>();
+                if !alias.columns.is_empty() {
+                    polars_ensure!(
+                        alias.columns.len() == columns.len(),
+                        SQLSyntax: "number of columns ({}) in alias '{}' does not match the number of columns in the table/query ({})",
+                        alias.columns.len(), name, columns.len()
+                    );
+                    let new_columns = alias.columns.iter().map(|c| c.value.clone());
+                    rf = rf.rename(&columns, new_columns.clone());
+                    columns = new_columns.collect();
+                }
+                let joined_name = |column: &str| {
+                    if left_schema.contains(column) {
+                        format!("{}:{}", column, name)
+                    } else {
+                        column.to_string()
+                    }
+                };
+                let count_columns = lateral
+                    .count_columns
+                    .iter()
+                    .map(|idx| col(&joined_name(&columns[*idx])).fill_null(typed_lit(0 as IdxSize)))
+                    .collect::<Vec<_>>();
+
+                let rf_schema = rf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+                let right_schema = Arc::new(Schema::from_iter(
+                    rf_schema.iter_fields().take(columns.len()),
+                ));
+                // an aggregating subquery has a row for every row of the preceding items
+                let how = if left_join || lateral.aggregate {
+                    JoinType::Left
+                } else {
+                    JoinType::Inner
+                };
+                let joined = lf
+                    .clone()
+                    .join_builder()
+                    .with(rf)
+                    .left_on(lateral.left_on)
+                    .right_on(lateral.keys.iter().map(|k| col(k)).collect::<Vec<_>>())
+                    .how(how)
+                    .coalesce(JoinCoalesce::KeepColumns)
+                    .suffix(format!(":{}", name))
+                    .finish()
+                    .drop_no_validate(lateral.keys)
+                    .with_columns(count_columns);
+                (name, right_schema, joined)
+            },
+            TableFactor::UNNEST {
+                alias,
+                array_exprs,
+                with_offset,
+                with_offset_alias,
+            } => {
+                polars_ensure!(!left_join, SQLInterface: "LEFT JOIN of UNNEST is not currently supported");
+                let Some(alias) = alias else {
+                    polars_bail!(SQLSyntax: "UNNEST table must have an alias");
+                };
+                let name = alias.name.value.clone();
+                let (columns, ordinal) =
+                    unnest_column_names(alias, array_exprs.len(), *with_offset, with_offset_alias)?;
+                let columns = columns
+                    .into_iter()
+                    .map(|column| {
+                        polars_ensure!(column.is_some(), SQLSyntax: "UNNEST table alias must name the columns of arrays that are not literals");
+                        Ok(column.unwrap())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let joined_name = |column: &str| {
+                    if left_schema.contains(column) {
+                        format!("{}:{}", column, name)
+                    } else {
+                        column.to_string()
+                    }
+                };
+                let mut right_schema = Schema::with_capacity(columns.len() + 1);
+                let mut unnested = Vec::with_capacity(columns.len() + 1);
+                let mut names = Vec::with_capacity(columns.len() + 1);
+                for (expr, column) in array_exprs.iter().zip(&columns) {
+                    let expr = parse_sql_expr(expr, self, Some(&left_schema))?;
+                    let dtype = expr.to_field(&left_schema, Context::Default)?.dtype;
+                    let DataType::List(inner) = dtype else {
+                        polars_bail!(SQLSyntax: "UNNEST requires an array, found {} for column '{}'", dtype, column)
+                    };
+                    right_schema.with_column(column.as_str().into(), *inner);
+                    names.push(joined_name(column));
+                    unnested.push(expr.alias(names.last().unwrap()));
+                }
+                // rows without elements have no match
+                let has_elements = unnested
+                    .iter()
+                    .map(|e| e.clone().list().len().gt(lit(0)))
+                    .reduce(|a, b| a.or(b))
+                    .unwrap();
+                let first = unnested[0].clone();
+                if let Some((column, start)) = ordinal {
+                    let ordinals = int_ranges(
+                        typed_lit(start),
+                        first.list().len().cast(DataType::Int64) + typed_lit(start),
+                        typed_lit(1i64),
+                    );
+                    right_schema.with_column(column.as_str().into(), DataType::Int64);
+                    names.push(joined_name(&column));
+                    unnested.push(ordinals.alias(names.last().unwrap()));
+                }
+                let joined = lf
+                    .clone()
+                    .filter(has_elements)
+                    .with_columns(unnested)
+                    .explode(names.iter().map(|name| col(name)).collect::<Vec<_>>());
+                (name, Arc::new(right_schema), joined)
+            },
+            _ => unreachable!(),
+        };
+
+        // the lateral relation is only in scope of this query
+        self.cte_map.borrow_mut().insert(
+            name.clone(),
+            DataFrame::empty_with_schema(&right_schema).lazy(),
+        );
+        *lf = joined;
+        self.track_joined_aliases(lf, &name, &left_schema, &right_schema)?;
+        if on_predicate.is_some() {
+            *lf = self.process_where(lf.clone(), &on_predicate.cloned())?;
+        }
+        Ok(Some(name))
+    }
+
     /// Execute the 'SELECT' part of the query.
     fn execute_select(&mut self, select_stmt: &Select, query: &Query) -> PolarsResult<LazyFrame> {
-        let mut lf = if select_stmt.from.is_empty() {
-            DataFrame::empty().lazy()
-        } else {
-            // Note: implicit joins need more work to support properly,
-            // explicit joins are preferred for now (ref: #16662)
-            let from = select_stmt.clone().from;
-            if from.len() > 1 {
-                polars_bail!(SQLInterface: "multiple tables in FROM clause are not currently supported (found {}); use explicit JOIN syntax instead", from.len())
+        // a lone `information_schema` relation is restricted to the tables the WHERE
+        // clause selects before their schemas are resolved
+        if let (Some(selection), [tbl_expr]) = (&select_stmt.selection, &select_stmt.from[..]) {
+            if tbl_expr.joins.is_empty() {
+                self.information_schema_filter = table_name_filter(selection, &tbl_expr.relation);
             }
-            self.execute_from_statement(from.first().unwrap())?
+        }
+        let mut lf = match select_stmt.from.split_first() {
+            None => DataFrame::empty().lazy(),
+            Some((first, others)) => {
+                let mut lf = self.execute_from_statement(first)?;
+                for tbl_expr in others {
+                    lf = self.execute_implicit_join(lf, tbl_expr)?;
+                }
+                lf
+            },
         };
 
         // Filter expression (WHERE clause)
         let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
         lf = self.process_where(lf, &select_stmt.selection)?;
 
+        // Named windows (WINDOW clause), referenced by the projections and QUALIFY
+        self.register_named_windows(&select_stmt.named_window)?;
+
         // 'SELECT *' modifiers
         let mut select_modifiers = SelectModifiers {
             ilike: None,
@@ -636,50 +1564,77 @@ impl SQLContext {
             replace: vec![],
         };
 
-        // Column projections (SELECT clause)
-        let projections: Vec<Expr> = select_stmt
-            .projection
-            .iter()
-            .map(|select_item| {
-                Ok(match select_item {
-                    SelectItem::UnnamedExpr(expr) => {
-                        vec![parse_sql_expr(expr, self, Some(schema.deref()))?]
-                    },
-                    SelectItem::ExprWithAlias { expr, alias } => {
-                        let expr = parse_sql_expr(expr, self, Some(schema.deref()))?;
-                        vec![expr.alias(&alias.value)]
-                    },
-                    SelectItem::QualifiedWildcard(obj_name, wildcard_options) => self
-                        .process_qualified_wildcard(
-                            obj_name,
-                            wildcard_options,
-                            &mut select_modifiers,
-                            Some(schema.deref()),
-                        )?,
-                    SelectItem::Wildcard(wildcard_options) => {
-                        let cols = schema
-                            .iter_names()
-                            .map(|name| col(name))
-                            .collect::<Vec<_>>();
+        // Grouping sets (GROUP BY ROLLUP/CUBE/GROUPING SETS), whose keys are referenced
+        // by GROUPING() in the projections, HAVING and ORDER BY clauses
+        let grouping_sets = self.process_grouping_sets(&select_stmt.group_by, &schema)?;
+        let outer_grouping_keys = self.grouping_keys.replace(
+            grouping_sets
+                .as_deref()
+                .map(distinct_grouping_keys)
+                .unwrap_or_default(),
+        );
 
-                        self.process_wildcard_additional_options(
-                            cols,
-                            wildcard_options,
-                            &mut select_modifiers,
-                            Some(schema.deref()),
-                        )?
-                    },
+        // Column projections (SELECT clause)
+        let (mut projections, subquery_joins) = self.parse_with_subquery_joins(|ctx| {
+            Ok(select_stmt
+                .projection
+                .iter()
+                .map(|select_item| {
+                    Ok(match select_item {
+                        SelectItem::UnnamedExpr(expr) => {
+                            vec![parse_sql_expr(expr, ctx, Some(schema.deref()))?]
+                        },
+                        SelectItem::ExprWithAlias { expr, alias } => {
+                            let expr = parse_sql_expr(expr, ctx, Some(schema.deref()))?;
+                            vec![expr.alias(&alias.value)]
+                        },
+                        SelectItem::QualifiedWildcard(obj_name, wildcard_options) => ctx
+                            .process_qualified_wildcard(
+                                obj_name,
+                                wildcard_options,
+                                &mut select_modifiers,
+                                Some(schema.deref()),
+                            )?,
+                        SelectItem::Wildcard(wildcard_options) => {
+                            let cols = schema
+                                .iter_names()
+                                .map(|name| col(name))
+                                .collect::<Vec<_>>();
+
+                            ctx.process_wildcard_additional_options(
+                                cols,
+                                wildcard_options,
+                                &mut select_modifiers,
+                                Some(schema.deref()),
+                            )?
+                        },
+                    })
                 })
-            })
-            .collect::<PolarsResult<Vec<Vec<_>>>>()?
-            .into_iter()
-            .flatten()
-            .collect();
+                .collect::<PolarsResult<Vec<Vec<_>>>>()?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<Expr>>())
+        })?;
+        let mut schema = schema;
+        let have_subplans = projections
+            .iter()
+            .any(|e| has_expr(e, |e| matches!(e, Expr::SubPlan(_, _))));
+        if !subquery_joins.is_empty() || have_subplans {
+            for join in subquery_joins {
+                lf = join.join_left(lf);
+            }
+            lf = self.process_subqueries(lf, projections.iter_mut().collect());
+            // (scalar) subquery results are now available as columns
+            schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        }
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
         match &select_stmt.group_by {
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            GroupByExpr::Expressions(_) if grouping_sets.is_some() => {
+                group_by_keys = distinct_grouping_keys(grouping_sets.as_deref().unwrap());
+            },
             GroupByExpr::Expressions(group_by_exprs) => {
                 // translate the group expressions, allowing ordinal values
                 group_by_keys = group_by_exprs
@@ -715,6 +1670,7 @@ impl SQLContext {
                             matches!(e, Expr::Agg(_))
                                 || matches!(e, Expr::Len)
                                 || matches!(e, Expr::Window { .. })
+                                || is_udf_aggregation(e)
                         }) {
                             group_by_keys.push(expr.clone())
                         }
@@ -723,15 +1679,16 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let have_order_by = !query.order_by.is_empty();
+            let project_first = have_order_by || select_stmt.qualify.is_some();
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' (or 'qualify') then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. If there's no
+            // 'order by', clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -740,7 +1697,7 @@ impl SQLContext {
                 if select_modifiers.matches_ilike(&name)
                     && !select_modifiers.exclude.contains(&name)
                 {
-                    retained_cols.push(if have_order_by {
+                    retained_cols.push(if project_first {
                         col(name.as_str())
                     } else {
                         p.clone()
@@ -749,7 +1706,7 @@ impl SQLContext {
             }
 
             // Apply the remaining modifiers and establish the final projection
-            if have_order_by {
+            if project_first {
                 lf = lf.with_columns(projections);
             }
             if !select_modifiers.replace.is_empty() {
@@ -758,6 +1715,8 @@ impl SQLContext {
             if !select_modifiers.rename.is_empty() {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
+            // Apply optional 'qualify' clause, after the window functions are evaluated.
+            lf = self.process_where(lf, &select_stmt.qualify)?;
             if have_order_by {
                 lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?
             }
@@ -771,16 +1730,25 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf =
+                self.process_group_by(lf, &group_by_keys, grouping_sets.as_deref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
+            };
+
+            // Apply optional 'qualify' clause, after the window functions are evaluated.
+            lf = self.process_where(lf, &select_stmt.qualify)?;
+            if grouping_sets.is_some() {
+                lf = lf.drop([GROUPING_ID]);
             }
+            lf
         };
+        self.grouping_keys.replace(outer_grouping_keys);
 
         // Apply optional DISTINCT clause.
         lf = match &select_stmt.distinct {
@@ -818,13 +1786,44 @@ impl SQLContext {
     ) -> PolarsResult<LazyFrame> {
         if let Some(expr) = expr {
             let schema = Some(lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?);
-            let mut filter_expression = parse_sql_expr(expr, self, schema.as_deref())?;
-            lf = self.process_subqueries(lf, vec![&mut filter_expression]);
-            lf = lf.filter(filter_expression);
+            let (filter_expression, subquery_joins) =
+                self.parse_with_subquery_joins(|ctx| parse_sql_expr(expr, ctx, schema.as_deref()))?;
+
+            // Correlated subqueries are joined to the frame before filtering
+            let mut predicates = vec![filter_expression];
+            let mut joined_cols = vec![];
+            if !subquery_joins.is_empty() {
+                predicates = split_conjunction(predicates.pop().unwrap());
+                for join in subquery_joins {
+                    let (joined, col) = join.join_for_filter(lf, &mut predicates);
+                    joined_cols.extend(col);
+                    lf = joined;
+                }
+            }
+            if let Some(mut filter_expression) = predicates.into_iter().reduce(Expr::and) {
+                lf = self.process_subqueries(lf, vec![&mut filter_expression]);
+                lf = lf.filter(filter_expression);
+            }
+            if !joined_cols.is_empty() {
+                lf = lf.drop(joined_cols);
+            }
         }
         Ok(lf)
     }
 
+    /// Parse SQL expression(s), returning the decorrelated subqueries that must be
+    /// joined to the frame before the resulting expression(s) can be evaluated.
+    fn parse_with_subquery_joins<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> PolarsResult<T>,
+    ) -> PolarsResult<(T, Vec<SubqueryJoin>)> {
+        // note: subqueries executed while parsing collect their own joins
+        let outer_joins = std::mem::take(&mut *self.subquery_joins.borrow_mut());
+        let parsed = parse(self);
+        let joins = self.subquery_joins.replace(outer_joins);
+        Ok((parsed?, joins))
+    }
+
     pub(super) fn process_join(
         &self,
         left_tbl: LazyFrame,
@@ -912,6 +1911,14 @@ impl SQLContext {
                 if let Some(args) = args {
                     return self.execute_table_function(name, alias, args);
                 }
+                if let Some((tbl_name, ref_name)) = information_schema_relation(name, alias) {
+                    // resolved after the registered tables and CTEs, so it shadows neither
+                    let lf = self.get_information_schema_table(&tbl_name)?;
+                    self.information_schema_map
+                        .borrow_mut()
+                        .insert(ref_name.clone(), lf.clone());
+                    return Ok((ref_name, lf));
+                }
                 let tbl_name = name.0.first().unwrap().value.as_str();
                 if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
                     match alias {
@@ -927,12 +1934,11 @@ impl SQLContext {
                     polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
                 }
             },
+            // a LATERAL subquery that references the preceding FROM items is joined to them
+            // by `process_lateral_join`; otherwise it is an ordinary derived table
             TableFactor::Derived {
-                lateral,
-                subquery,
-                alias,
+                subquery, alias, ..
             } => {
-                polars_ensure!(!(*lateral), SQLInterface: "LATERAL not supported");
                 if let Some(alias) = alias {
                     let mut lf = self.execute_query_no_ctes(subquery)?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
@@ -946,56 +1952,38 @@ impl SQLContext {
                 alias,
                 array_exprs,
                 with_offset,
-                with_offset_alias: _,
+                with_offset_alias,
             } => {
                 if let Some(alias) = alias {
                     let table_name = alias.name.value.clone();
-                    let column_names: Vec<Option<&str>> = alias
-                        .columns
-                        .iter()
-                        .map(|c| {
-                            if c.value.is_empty() {
-                                None
-                            } else {
-                                Some(c.value.as_str())
-                            }
-                        })
-                        .collect();
-
+                    let (column_names, ordinal) = unnest_column_names(
+                        alias,
+                        array_exprs.len(),
+                        *with_offset,
+                        with_offset_alias,
+                    )?;
                     let column_values: Vec<Series> = array_exprs
                         .iter()
                         .map(|arr| parse_sql_array(arr, self))
                         .collect::<Result<_, _>>()?;
 
-                    polars_ensure!(!column_names.is_empty(),
-                        SQLSyntax:
-                        "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
-                    );
-                    if column_names.len() != column_values.len() {
-                        let plural = if column_values.len() > 1 { "s" } else { "" };
-                        polars_bail!(
-                            SQLSyntax:
-                            "UNNEST table alias requires {} column name{}, found {}", column_values.len(), plural, column_names.len()
-                        );
-                    }
-                    let column_series: Vec<Series> = column_values
-                        .iter()
-                        .zip(column_names.iter())
-                        .map(|(s, name)| {
-                            if let Some(name) = name {
-                                s.clone().with_name(name)
-                            } else {
-                                s.clone()
-                            }
+                    let mut column_series: Vec<Series> = column_values
+                        .into_iter()
+                        .zip(column_names)
+                        .map(|(s, name)| match name {
+                            Some(name) => s.with_name(&name),
+                            None => s,
                         })
                         .collect();
+                    if let Some((name, start)) = ordinal {
+                        let height = column_series.iter().map(|s| s.len()).max().unwrap_or(0);
+                        column_series.push(Series::new(
+                            &name,
+                            (start..start + height as i64).collect::<Vec<_>>(),
+                        ));
+                    }
 
                     let lf = DataFrame::new(column_series)?.lazy();
-                    if *with_offset {
-                        // TODO: make a PR to `sqlparser-rs` to support 'ORDINALITY'
-                        //  (note that 'OFFSET' is BigQuery-specific syntax, not PostgreSQL)
-                        polars_bail!(SQLInterface: "UNNEST tables do not (yet) support WITH OFFSET/ORDINALITY");
-                    }
                     self.table_map.insert(table_name.clone(), lf.clone());
                     Ok((table_name.clone(), lf))
                 } else {
@@ -1033,7 +2021,10 @@ impl SQLContext {
         selected: Option<&[Expr]>,
     ) -> PolarsResult<LazyFrame> {
         let schema = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
-        let columns_iter = schema.iter_names().map(|e| col(e));
+        let columns_iter = schema
+            .iter_names()
+            .filter(|name| name.as_str() != GROUPING_ID)
+            .map(|e| col(e));
 
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
@@ -1078,13 +2069,103 @@ impl SQLContext {
         ))
     }
 
+    /// Expand the ROLLUP, CUBE and GROUPING SETS of a GROUP BY clause (if any) into the
+    /// full list of grouping sets; other keys are part of every grouping set.
+    fn process_grouping_sets(
+        &mut self,
+        group_by: &GroupByExpr,
+        schema: &Schema,
+    ) -> PolarsResult<Option<Vec<Vec<Expr>>>> {
+        let group_by_exprs = match group_by {
+            GroupByExpr::Expressions(exprs)
+                if exprs.iter().any(|e| {
+                    matches!(
+                        e,
+                        SQLExpr::Rollup(_) | SQLExpr::Cube(_) | SQLExpr::GroupingSets(_)
+                    )
+                }) =>
+            {
+                exprs
+            },
+            _ => return Ok(None),
+        };
+        let mut parse_keys = |exprs: &[SQLExpr]| -> PolarsResult<Vec<Expr>> {
+            exprs
+                .iter()
+                .map(|e| match e {
+                    SQLExpr::Value(SQLValue::Number(_, _)) => polars_bail!(
+                        SQLSyntax: "GROUP BY with ROLLUP, CUBE or GROUPING SETS does not support ordinal values; found {}", e
+                    ),
+                    _ => parse_sql_expr(e, self, Some(schema)),
+                })
+                .collect()
+        };
+
+        let mut grouping_sets = vec![vec![]];
+        for expr in group_by_exprs {
+            let element_sets: Vec<Vec<Expr>> = match expr {
+                // ROLLUP(a, b) => (a, b), (a), ()
+                SQLExpr::Rollup(elements) => {
+                    let elements = elements
+                        .iter()
+                        .map(|e| parse_keys(e))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    (0..=elements.len())
+                        .rev()
+                        .map(|n| elements[..n].concat())
+                        .collect()
+                },
+                // CUBE(a, b) => (a, b), (a), (b), ()
+                SQLExpr::Cube(elements) => {
+                    let elements = elements
+                        .iter()
+                        .map(|e| parse_keys(e))
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let n = elements.len();
+                    polars_ensure!(n <= 12, SQLInterface: "CUBE supports at most 12 elements; found {}", n);
+                    (0..1usize << n)
+                        .rev()
+                        .map(|mask| {
+                            (0..n)
+                                .filter(|i| mask & (1 << (n - 1 - i)) != 0)
+                                .flat_map(|i| elements[i].clone())
+                                .collect()
+                        })
+                        .collect()
+                },
+                SQLExpr::GroupingSets(sets) => sets
+                    .iter()
+                    .map(|e| parse_keys(e))
+                    .collect::<PolarsResult<_>>()?,
+                e => vec![parse_keys(std::slice::from_ref(e))?],
+            };
+            // multiple elements combine as the cross product of their grouping sets
+            grouping_sets = grouping_sets
+                .iter()
+                .flat_map(|set| {
+                    element_sets
+                        .iter()
+                        .map(move |element_set| [set.as_slice(), element_set].concat())
+                })
+                .collect();
+        }
+        let n_keys = distinct_grouping_keys(&grouping_sets).len();
+        polars_ensure!(n_keys <= 32, SQLInterface: "GROUP BY supports at most 32 distinct keys with grouping sets; found {}", n_keys);
+        Ok(Some(grouping_sets))
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<Expr>]>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        let mut schema_before = lf.schema_with_arenas(&mut self.lp_arena, &mut self.expr_arena)?;
+        if grouping_sets.is_some() {
+            // GROUPING() is evaluated on the grouping id, after aggregation
+            Arc::make_mut(&mut schema_before).with_column(GROUPING_ID.into(), DataType::UInt32);
+        }
         let group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
@@ -1097,7 +2178,7 @@ impl SQLContext {
         for mut e in projections {
             // `Len` represents COUNT(*) so we treat as an aggregation here.
             let is_agg_or_window = has_expr(e, |e| {
-                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
+                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. }) || is_udf_aggregation(e)
             });
 
             // Note: if simple aliased expression we defer aliasing until after the group_by.
@@ -1138,12 +2219,18 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(sets) => lf
+                .group_by_sets(sets)
+                .with_grouping_id(GROUPING_ID)
+                .agg(&aggregation_projection)?,
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
         // A final projection to get the proper order and any deferred transforms/aliases.
-        let final_projection = projection_schema
+        let mut final_projection = projection_schema
             .iter_names()
             .zip(projections)
             .map(|(name, projection_expr)| {
@@ -1159,6 +2246,10 @@ impl SQLContext {
                 }
             })
             .collect::<Vec<_>>();
+        if grouping_sets.is_some() {
+            // kept for GROUPING() in the HAVING and ORDER BY clauses; dropped by the caller
+            final_projection.push(col(GROUPING_ID));
+        }
 
         Ok(aggregated.select(&final_projection))
     }
@@ -1332,3 +2423,196 @@ impl SQLContext {
         }
     }
 }
+
+/// Name of the (hidden) column identifying the grouping set of an aggregated row.
+pub(crate) const GROUPING_ID: &str = "__POLARS_GROUPING_ID";
+
+/// The distinct keys of the given grouping sets, in order of first appearance.
+fn distinct_grouping_keys(grouping_sets: &[Vec<Expr>]) -> Vec<Expr> {
+    let mut keys: Vec<Expr> = vec![];
+    for key in grouping_sets.iter().flatten() {
+        if !keys.contains(key) {
+            keys.push(key.clone());
+        }
+    }
+    keys
+}
+
+const MERGE_MATCHED: &str = "__POLARS_MERGE_MATCHED";
+const MERGE_DELETED: &str = "__POLARS_MERGE_DELETED";
+
+/// The response frame of a DML statement.
+fn dml_response(statement: &str) -> LazyFrame {
+    df! {
+        "Response" => [statement]
+    }
+    .unwrap()
+    .lazy()
+}
+
+/// Project the given values onto the columns of a table schema, for insertion; if
+/// column names are given, the values are assigned to them (in order) and any other
+/// columns are null, otherwise a value is expected for every column.
+fn insert_projection(
+    schema: &Schema,
+    columns: &[Ident],
+    values: Vec<Expr>,
+) -> PolarsResult<Vec<Expr>> {
+    let names: Vec<&str> = if columns.is_empty() {
+        schema.iter_names().map(|name| name.as_str()).collect()
+    } else {
+        columns.iter().map(|c| c.value.as_str()).collect()
+    };
+    polars_ensure!(
+        names.len() == values.len(),
+        SQLInterface: "INSERT expects {} values; found {}", names.len(), values.len()
+    );
+    let mut inserted = PlHashMap::with_capacity(names.len());
+    for (name, value) in names.into_iter().zip(values) {
+        polars_ensure!(
+            schema.contains(name),
+            SQLInterface: "INSERT target column '{}' does not exist", name
+        );
+        polars_ensure!(
+            inserted.insert(name, value).is_none(),
+            SQLInterface: "INSERT target column '{}' is specified more than once", name
+        );
+    }
+    Ok(schema
+        .iter()
+        .map(|(name, dtype)| {
+            inserted
+                .remove(name.as_str())
+                .unwrap_or_else(|| lit(NULL))
+                .strict_cast(dtype.clone())
+                .alias(name)
+        })
+        .collect())
+}
+
+/// The rows of a MERGE source that do not match any row of the target table.
+fn merge_unmatched_rows(
+    source: LazyFrame,
+    target: LazyFrame,
+    target_on: Vec<Expr>,
+    source_on: Vec<Expr>,
+) -> LazyFrame {
+    let keys = target.select(target_on.clone());
+    #[cfg(feature = "semi_anti_join")]
+    {
+        source
+            .join_builder()
+            .with(keys)
+            .left_on(source_on)
+            .right_on(target_on)
+            .how(JoinType::Anti)
+            .finish()
+    }
+    #[cfg(not(feature = "semi_anti_join"))]
+    {
+        let keys = keys
+            .unique(None, UniqueKeepStrategy::Any)
+            .with_column(lit(true).alias(MERGE_MATCHED));
+        source
+            .join_builder()
+            .with(keys)
+            .left_on(source_on)
+            .right_on(target_on)
+            .how(JoinType::Left)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .finish()
+            .filter(col(MERGE_MATCHED).is_null())
+            .drop([MERGE_MATCHED])
+    }
+}
+
+/// Cross join two tables. The order of the rows of a SQL join is unspecified, so a filter
+/// on comparisons of columns of both tables may plan it as an inner or inequality join.
+fn sql_cross_join(lf: LazyFrame, rf: LazyFrame, r_name: &str) -> LazyFrame {
+    lf.join_builder()
+        .with(rf)
+        .how(JoinType::Cross)
+        .suffix(format!(":{}", r_name))
+        .allow_keyed_cross_join(true)
+        .finish()
+}
+
+/// Split a predicate on its top-level `AND` conjunctions.
+fn split_conjunction(expr: Expr) -> Vec<Expr> {
+    match expr {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            let mut exprs = split_conjunction(Arc::unwrap_or_clone(left));
+            exprs.extend(split_conjunction(Arc::unwrap_or_clone(right)));
+            exprs
+        },
+        e => vec![e],
+    }
+}
+
+/// Whether a query is parsed with trailing commas (e.g. `SELECT a, b, FROM t`).
+///
+/// A comma before a keyword is taken to be a trailing comma, which is wrong for the comma
+/// join of a `LATERAL` derived table (`FROM a, LATERAL (...)`), so such queries are parsed
+/// without trailing commas.
+fn allows_trailing_commas(tokens: &[TokenWithLocation]) -> bool {
+    let mut tokens = tokens
+        .iter()
+        .filter(|t| !matches!(t.token, Token::Whitespace(_)))
+        .map(|t| &t.token);
+    let mut prev = None;
+    tokens.all(|token| {
+        let lateral_after_comma = prev == Some(&Token::Comma)
+            && matches!(token, Token::Word(w) if w.keyword == Keyword::LATERAL);
+        prev = Some(token);
+        !lateral_after_comma
+    })
+}
+
+/// Determine the column names of an `UNNEST` of `n_arrays` arrays from its table alias
+/// (`None` keeps the name of a literal array), along with the name and first value of
+/// its `WITH OFFSET` (from 0) or `WITH ORDINALITY` (from 1) column, if any.
+#[allow(clippy::type_complexity)]
+fn unnest_column_names(
+    alias: &TableAlias,
+    n_arrays: usize,
+    with_offset: bool,
+    with_offset_alias: &Option<Ident>,
+) -> PolarsResult<(Vec<Option<String>>, Option<(String, i64)>)> {
+    let mut column_names: Vec<Option<String>> = alias
+        .columns
+        .iter()
+        .map(|c| (!c.value.is_empty()).then(|| c.value.clone()))
+        .collect();
+    polars_ensure!(!column_names.is_empty(),
+        SQLSyntax:
+        "UNNEST table alias must also declare column names, eg: {} (a,b,c)", alias.name.to_string()
+    );
+    let ordinal = match with_offset_alias {
+        _ if !with_offset => None,
+        // the ordinality column is named by the (last) column of the table alias
+        Some(ident) if is_unnest_ordinality(ident) => {
+            if column_names.len() != n_arrays + 1 {
+                polars_bail!(
+                    SQLSyntax:
+                    "UNNEST ... WITH ORDINALITY table alias requires {} column names, found {}", n_arrays + 1, column_names.len()
+                );
+            }
+            let name = column_names.pop().unwrap();
+            Some((name.unwrap_or_else(|| "ordinality".to_string()), 1))
+        },
+        Some(ident) => Some((ident.value.clone(), 0)),
+        None => Some(("offset".to_string(), 0)),
+    };
+    if column_names.len() != n_arrays {
+        let plural = if n_arrays > 1 { "s" } else { "" };
+        polars_bail!(
+            SQLSyntax:
+            "UNNEST table alias requires {} column name{}, found {}", n_arrays, plural, column_names.len()
+        );
+    }
+    Ok((column_names, ordinal))
+}
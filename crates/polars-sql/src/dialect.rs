@@ -0,0 +1,314 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+
+use polars_core::prelude::{polars_bail, PolarsError};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use sqlparser::ast::{CastKind, Expr as SQLExpr, Ident, Statement};
+use sqlparser::dialect::{
+    BigQueryDialect, Dialect, DuckDbDialect, GenericDialect, PostgreSqlDialect,
+};
+use sqlparser::keywords::Keyword;
+use sqlparser::parser::{Parser, ParserError};
+use sqlparser::tokenizer::{Token, TokenWithLocation, Tokenizer, TokenizerError, Whitespace, Word};
+
+/// The SQL dialect that queries are written in.
+///
+/// The dialect determines the syntax that is accepted when parsing queries, as well
+/// as the semantics of some functions (e.g. the argument order of `DATE_TRUNC`).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SQLDialect {
+    /// A permissive dialect that accepts the syntax of many other dialects.
+    #[default]
+    Generic,
+    /// The PostgreSQL dialect.
+    PostgreSQL,
+    /// The DuckDB dialect.
+    DuckDB,
+    /// The BigQuery (GoogleSQL) dialect.
+    BigQuery,
+}
+
+impl SQLDialect {
+    /// Whether the dialect supports the given kind of cast.
+    pub(crate) fn supports_cast(self, kind: &CastKind) -> bool {
+        match kind {
+            CastKind::Cast => true,
+            CastKind::DoubleColon => self != Self::BigQuery,
+            CastKind::TryCast => matches!(self, Self::Generic | Self::DuckDB),
+            CastKind::SafeCast => matches!(self, Self::Generic | Self::BigQuery),
+        }
+    }
+
+    /// Whether the dialect supports case-insensitive `ILIKE` pattern matching.
+    pub(crate) fn supports_ilike(self) -> bool {
+        self != Self::BigQuery
+    }
+}
+
+impl fmt::Display for SQLDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Generic => "generic",
+            Self::PostgreSQL => "PostgreSQL",
+            Self::DuckDB => "DuckDB",
+            Self::BigQuery => "BigQuery",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for SQLDialect {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "generic" => Self::Generic,
+            "postgres" | "postgresql" => Self::PostgreSQL,
+            "duckdb" => Self::DuckDB,
+            "bigquery" => Self::BigQuery,
+            _ => {
+                polars_bail!(SQLInterface: "unknown SQL dialect '{}' (expected one of 'generic', 'postgresql', 'duckdb' or 'bigquery')", s)
+            },
+        })
+    }
+}
+
+/// The parser dialect of a [`SQLDialect`]; this behaves as the corresponding
+/// `sqlparser` dialect, except that the generic dialect also accepts aggregate
+/// `FILTER (WHERE ...)` clauses.
+#[derive(Debug)]
+pub(crate) struct PolarsDialect(pub(crate) SQLDialect);
+
+impl PolarsDialect {
+    fn inner(&self) -> &'static dyn Dialect {
+        match self.0 {
+            SQLDialect::Generic => &GenericDialect,
+            SQLDialect::PostgreSQL => &PostgreSqlDialect {},
+            SQLDialect::DuckDB => &DuckDbDialect {},
+            SQLDialect::BigQuery => &BigQueryDialect,
+        }
+    }
+
+    /// Tokenize a query for the parser.
+    ///
+    /// The parser has no grammar for the `UNNEST(...) WITH ORDINALITY [AS] t(...)` of
+    /// PostgreSQL and DuckDB, so it is passed on as `UNNEST(...) [AS] t(...) WITH OFFSET AS
+    /// <marker>`; see [`is_unnest_ordinality`].
+    pub(crate) fn tokenize(&self, query: &str) -> Result<Vec<TokenWithLocation>, TokenizerError> {
+        let mut tokens = Tokenizer::new(self, query).tokenize_with_location()?;
+        if self.0 == SQLDialect::BigQuery {
+            return Ok(tokens);
+        }
+
+        let next_token = |tokens: &[TokenWithLocation], idx: usize| {
+            (idx..tokens.len()).find(|&i| !matches!(tokens[i].token, Token::Whitespace(_)))
+        };
+        let is_word = |tokens: &[TokenWithLocation], idx: Option<usize>, word: &str| {
+            idx.is_some_and(|i| {
+                matches!(&tokens[i].token, Token::Word(w) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case(word))
+            })
+        };
+        // the index after the parenthesized tokens starting at `idx`
+        let skip_parens = |tokens: &[TokenWithLocation], idx: usize| {
+            let mut depth = 0;
+            for (i, t) in tokens.iter().enumerate().skip(idx) {
+                match t.token {
+                    Token::LParen => depth += 1,
+                    Token::RParen if depth == 1 => return Some(i + 1),
+                    Token::RParen => depth -= 1,
+                    _ => {},
+                }
+            }
+            None
+        };
+
+        let mut idx = 0;
+        while idx < tokens.len() {
+            if !is_word(&tokens, Some(idx), "unnest") {
+                idx += 1;
+                continue;
+            }
+            let Some(args_end) = next_token(&tokens, idx + 1)
+                .filter(|&i| tokens[i].token == Token::LParen)
+                .and_then(|i| skip_parens(&tokens, i))
+            else {
+                break;
+            };
+            let with = next_token(&tokens, args_end);
+            let ordinality = with.and_then(|i| next_token(&tokens, i + 1));
+            if !(is_word(&tokens, with, "with") && is_word(&tokens, ordinality, "ordinality")) {
+                idx = args_end;
+                continue;
+            }
+            tokens.drain(args_end..=ordinality.unwrap());
+
+            // the `WITH OFFSET` clause follows the (optional) table alias and its column names
+            let mut alias_end = args_end;
+            let mut next = next_token(&tokens, alias_end);
+            if is_word(&tokens, next, "as") {
+                next = next_token(&tokens, next.unwrap() + 1);
+            }
+            if let Some(i) = next.filter(|&i| matches!(tokens[i].token, Token::Word(_))) {
+                alias_end = i + 1;
+                if let Some(i) =
+                    next_token(&tokens, alias_end).filter(|&i| tokens[i].token == Token::LParen)
+                {
+                    alias_end = skip_parens(&tokens, i).unwrap_or(tokens.len());
+                }
+            }
+            let with_offset = [
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("WITH"),
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("OFFSET"),
+                Token::Whitespace(Whitespace::Space),
+                Token::make_keyword("AS"),
+                Token::Whitespace(Whitespace::Space),
+                Token::Word(Word {
+                    value: UNNEST_ORDINALITY.to_string(),
+                    quote_style: None,
+                    keyword: Keyword::NoKeyword,
+                }),
+            ];
+            let n = with_offset.len();
+            tokens.splice(
+                alias_end..alias_end,
+                with_offset.map(TokenWithLocation::wrap),
+            );
+            idx = alias_end + n;
+        }
+        Ok(tokens)
+    }
+}
+
+/// The `WITH OFFSET` alias that [`PolarsDialect::tokenize`] gives an `UNNEST` written `WITH
+/// ORDINALITY`. It contains a space, so no unquoted identifier of a query can be equal to it.
+const UNNEST_ORDINALITY: &str = "WITH ORDINALITY";
+
+/// Whether the `WITH OFFSET` alias of an `UNNEST` marks it as written `WITH ORDINALITY`.
+pub(crate) fn is_unnest_ordinality(alias: &Ident) -> bool {
+    alias.quote_style.is_none() && alias.value == UNNEST_ORDINALITY
+}
+
+impl Dialect for PolarsDialect {
+    // behave as the inner dialect wherever the parser checks for it
+    fn dialect(&self) -> std::any::TypeId {
+        self.inner().dialect()
+    }
+
+    fn is_delimited_identifier_start(&self, ch: char) -> bool {
+        self.inner().is_delimited_identifier_start(ch)
+    }
+
+    fn identifier_quote_style(&self, identifier: &str) -> Option<char> {
+        self.inner().identifier_quote_style(identifier)
+    }
+
+    fn is_proper_identifier_inside_quotes(&self, chars: Peekable<Chars<'_>>) -> bool {
+        self.inner().is_proper_identifier_inside_quotes(chars)
+    }
+
+    fn is_identifier_start(&self, ch: char) -> bool {
+        self.inner().is_identifier_start(ch)
+    }
+
+    fn is_identifier_part(&self, ch: char) -> bool {
+        self.inner().is_identifier_part(ch)
+    }
+
+    fn supports_string_literal_backslash_escape(&self) -> bool {
+        self.inner().supports_string_literal_backslash_escape()
+    }
+
+    fn supports_filter_during_aggregation(&self) -> bool {
+        self.0 == SQLDialect::Generic || self.inner().supports_filter_during_aggregation()
+    }
+
+    fn supports_window_clause_named_window_reference(&self) -> bool {
+        self.inner().supports_window_clause_named_window_reference()
+    }
+
+    fn supports_within_after_array_aggregation(&self) -> bool {
+        self.inner().supports_within_after_array_aggregation()
+    }
+
+    fn supports_group_by_expr(&self) -> bool {
+        self.inner().supports_group_by_expr()
+    }
+
+    fn supports_connect_by(&self) -> bool {
+        self.inner().supports_connect_by()
+    }
+
+    fn supports_match_recognize(&self) -> bool {
+        self.inner().supports_match_recognize()
+    }
+
+    fn supports_in_empty_list(&self) -> bool {
+        self.inner().supports_in_empty_list()
+    }
+
+    fn supports_start_transaction_modifier(&self) -> bool {
+        self.inner().supports_start_transaction_modifier()
+    }
+
+    fn supports_named_fn_args_with_eq_operator(&self) -> bool {
+        self.inner().supports_named_fn_args_with_eq_operator()
+    }
+
+    fn supports_numeric_prefix(&self) -> bool {
+        self.inner().supports_numeric_prefix()
+    }
+
+    fn supports_window_function_null_treatment_arg(&self) -> bool {
+        self.inner().supports_window_function_null_treatment_arg()
+    }
+
+    fn supports_dictionary_syntax(&self) -> bool {
+        self.inner().supports_dictionary_syntax()
+    }
+
+    fn supports_lambda_functions(&self) -> bool {
+        self.inner().supports_lambda_functions()
+    }
+
+    fn supports_parenthesized_set_variables(&self) -> bool {
+        self.inner().supports_parenthesized_set_variables()
+    }
+
+    fn supports_select_wildcard_except(&self) -> bool {
+        self.inner().supports_select_wildcard_except()
+    }
+
+    fn convert_type_before_value(&self) -> bool {
+        self.inner().convert_type_before_value()
+    }
+
+    fn supports_triple_quoted_string(&self) -> bool {
+        self.inner().supports_triple_quoted_string()
+    }
+
+    fn parse_prefix(&self, parser: &mut Parser) -> Option<Result<SQLExpr, ParserError>> {
+        self.inner().parse_prefix(parser)
+    }
+
+    fn parse_infix(
+        &self,
+        parser: &mut Parser,
+        expr: &SQLExpr,
+        precedence: u8,
+    ) -> Option<Result<SQLExpr, ParserError>> {
+        self.inner().parse_infix(parser, expr, precedence)
+    }
+
+    fn get_next_precedence(&self, parser: &Parser) -> Option<Result<u8, ParserError>> {
+        self.inner().get_next_precedence(parser)
+    }
+
+    fn parse_statement(&self, parser: &mut Parser) -> Option<Result<Statement, ParserError>> {
+        self.inner().parse_statement(parser)
+    }
+}
@@ -1,19 +1,46 @@
 //! This module defines the function registry and user defined functions.
 
+#[cfg(feature = "ffi_plugin")]
+use std::path::Path;
+#[cfg(feature = "ffi_plugin")]
+use std::sync::Arc;
+
+use polars_core::prelude::PlHashMap;
 use polars_error::{polars_bail, PolarsResult};
 use polars_plan::prelude::udf::UserDefinedFunction;
+#[cfg(feature = "ffi_plugin")]
+use polars_plan::prelude::FunctionExpr;
+use polars_plan::prelude::{ApplyOptions, Expr};
 pub use polars_plan::prelude::{Context, FunctionOptions};
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
     fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()>;
+    /// Register an aggregate function; it is called once per group (or once for the
+    /// whole frame without a GROUP BY) and must return a single value.
+    fn register_aggregate(&mut self, name: &str, mut fun: UserDefinedFunction) -> PolarsResult<()> {
+        fun.options.collect_groups = ApplyOptions::GroupWise;
+        fun.options.returns_scalar = true;
+        self.register(name, fun)
+    }
+    /// Register a compiled expression plugin.
+    #[cfg(feature = "ffi_plugin")]
+    fn register_plugin(&mut self, _name: &str, _plugin: PluginFunction) -> PolarsResult<()> {
+        polars_bail!(ComputeError: "'register_plugin' not implemented on this registry")
+    }
     /// Call a user defined function.
     fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>>;
     /// Check if a function is registered.
     fn contains(&self, name: &str) -> bool;
+    /// Create the expression calling a registered function with the given arguments.
+    fn call(&self, name: &str, args: Vec<Expr>) -> PolarsResult<Option<Expr>> {
+        self.get_udf(name)?.map(|udf| udf.call(args)).transpose()
+    }
 }
 
 /// A default registry that does not support registering or calling functions.
+///
+/// [`SQLContext`](crate::SQLContext)s use an [`InMemoryFunctionRegistry`] by default.
 pub struct DefaultFunctionRegistry {}
 
 impl FunctionRegistry for DefaultFunctionRegistry {
@@ -28,3 +55,163 @@ impl FunctionRegistry for DefaultFunctionRegistry {
         false
     }
 }
+
+/// A function held by the [`InMemoryFunctionRegistry`].
+#[derive(Clone)]
+enum RegisteredFunction {
+    Udf(UserDefinedFunction),
+    #[cfg(feature = "ffi_plugin")]
+    Plugin(PluginFunction),
+}
+
+/// A registry that holds user defined functions and plugins in memory.
+///
+/// Function names are case-insensitive (as are SQL identifiers of functions); registering
+/// a function under an existing name replaces it. Functions shadowed by a built-in SQL
+/// function of the same name cannot be called.
+#[derive(Clone, Default)]
+pub struct InMemoryFunctionRegistry {
+    functions: PlHashMap<String, RegisteredFunction>,
+}
+
+impl InMemoryFunctionRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove a function, returning `true` if it was registered.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.functions.remove(&name.to_lowercase()).is_some()
+    }
+
+    fn insert(&mut self, name: &str, fun: RegisteredFunction) -> PolarsResult<()> {
+        if name.is_empty() {
+            polars_bail!(ComputeError: "cannot register a function without a name")
+        }
+        self.functions.insert(name.to_lowercase(), fun);
+        Ok(())
+    }
+}
+
+impl FunctionRegistry for InMemoryFunctionRegistry {
+    fn register(&mut self, name: &str, fun: UserDefinedFunction) -> PolarsResult<()> {
+        self.insert(name, RegisteredFunction::Udf(fun))
+    }
+
+    #[cfg(feature = "ffi_plugin")]
+    fn register_plugin(&mut self, name: &str, plugin: PluginFunction) -> PolarsResult<()> {
+        self.insert(name, RegisteredFunction::Plugin(plugin))
+    }
+
+    fn get_udf(&self, name: &str) -> PolarsResult<Option<UserDefinedFunction>> {
+        Ok(match self.functions.get(&name.to_lowercase()) {
+            Some(RegisteredFunction::Udf(udf)) => Some(udf.clone()),
+            _ => None,
+        })
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(&name.to_lowercase())
+    }
+
+    fn call(&self, name: &str, args: Vec<Expr>) -> PolarsResult<Option<Expr>> {
+        match self.functions.get(&name.to_lowercase()) {
+            Some(RegisteredFunction::Udf(udf)) => udf.clone().call(args).map(Some),
+            #[cfg(feature = "ffi_plugin")]
+            Some(RegisteredFunction::Plugin(plugin)) => Ok(Some(plugin.call(args))),
+            None => Ok(None),
+        }
+    }
+}
+
+/// An expression plugin: a function compiled into a shared library that implements the
+/// polars plugin interface, registered under a SQL function name.
+#[cfg(feature = "ffi_plugin")]
+#[derive(Clone, Debug)]
+pub struct PluginFunction {
+    lib: Arc<str>,
+    symbol: Arc<str>,
+    kwargs: Arc<[u8]>,
+    options: FunctionOptions,
+}
+
+#[cfg(feature = "ffi_plugin")]
+impl PluginFunction {
+    /// Create a plugin calling the function `symbol` of the shared library at `lib`.
+    ///
+    /// # Safety
+    /// The library is loaded and the function called over FFI when the query runs; the
+    /// symbol must be a polars expression plugin function.
+    pub unsafe fn new<P: AsRef<Path>>(lib: P, symbol: &str) -> Self {
+        Self {
+            lib: Arc::from(lib.as_ref().to_string_lossy().as_ref()),
+            symbol: Arc::from(symbol),
+            kwargs: Arc::from([]),
+            options: FunctionOptions::default(),
+        }
+    }
+
+    /// Serialized (pickled) keyword arguments passed to every call of the plugin.
+    pub fn with_kwargs(mut self, kwargs: Vec<u8>) -> Self {
+        self.kwargs = Arc::from(kwargs);
+        self
+    }
+
+    /// The plugin operates on single elements, so it may be applied to whole columns
+    /// instead of per group.
+    pub fn elementwise(mut self, toggle: bool) -> Self {
+        self.options.collect_groups = if toggle {
+            ApplyOptions::ElementWise
+        } else {
+            ApplyOptions::GroupWise
+        };
+        self
+    }
+
+    /// The plugin is an aggregation that returns a single value per group.
+    pub fn returns_scalar(mut self, toggle: bool) -> Self {
+        self.options.returns_scalar = toggle;
+        self
+    }
+
+    /// Cast the arguments to their supertype before calling the plugin.
+    pub fn cast_to_supertypes(mut self, toggle: bool) -> Self {
+        self.options.cast_to_supertypes = toggle.then(Default::default);
+        self
+    }
+
+    /// The plugin may return a different number of rows than its input.
+    pub fn changes_length(mut self, toggle: bool) -> Self {
+        self.options.changes_length = toggle;
+        self
+    }
+
+    fn call(&self, args: Vec<Expr>) -> Expr {
+        Expr::Function {
+            input: args,
+            function: FunctionExpr::FfiPlugin {
+                lib: self.lib.clone(),
+                symbol: self.symbol.clone(),
+                kwargs: self.kwargs.clone(),
+            },
+            options: self.options,
+        }
+    }
+}
+
+/// Aggregations over user defined functions and plugins: functions that are applied
+/// per group and return a single value.
+pub(crate) fn is_udf_aggregation(expr: &Expr) -> bool {
+    let options = match expr {
+        Expr::AnonymousFunction { options, .. } => options,
+        #[cfg(feature = "ffi_plugin")]
+        Expr::Function {
+            function: FunctionExpr::FfiPlugin { .. },
+            options,
+            ..
+        } => options,
+        _ => return false,
+    };
+    options.returns_scalar && matches!(options.collect_groups, ApplyOptions::GroupWise)
+}
@@ -1,20 +1,28 @@
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult, TimeUnit};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, IdxSize, PolarsResult,
+    QuantileInterpolOptions, RollingOptionsFixedWindow, TimeUnit, IDX_DTYPE,
+};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_ops::series::{RankMethod, RankOptions};
+use polars_plan::dsl::{
+    arg_sort_by, coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, repeat, when,
+};
 use polars_plan::plans::{typed_lit, LiteralValue};
-#[cfg(feature = "list_eval")]
-use polars_plan::prelude::col;
 use polars_plan::prelude::LiteralValue::Null;
-use polars_plan::prelude::{lit, StrptimeOptions};
+use polars_plan::prelude::{col, lit, ApplyOptions, StrptimeOptions};
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    NullTreatment, OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits,
+    WindowSpec, WindowType,
 };
 
+use crate::context::GROUPING_ID;
+use crate::dialect::SQLDialect;
+use crate::function_registry::is_udf_aggregation;
 use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
 use crate::SQLContext;
 
@@ -256,6 +264,16 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT DATE_PART('year', column_1) FROM df;
     /// SELECT DATE_PART('day', column_1) FROM df;
     DatePart,
+    /// SQL 'date_trunc' function (also 'timestamp_trunc' and 'datetime_trunc').
+    /// Truncates a date (or datetime) to the start of the given unit, such as 'month'.
+    /// The argument order depends on the dialect; PostgreSQL and DuckDB take the unit
+    /// as a string first, BigQuery takes it as a keyword last, and the generic dialect
+    /// accepts both.
+    /// ```sql
+    /// SELECT DATE_TRUNC('month', column_1) FROM df;
+    /// SELECT DATE_TRUNC(column_1, MONTH) FROM df;
+    /// ```
+    DateTrunc,
     /// SQL 'strftime' function.
     /// Converts a datetime to a string using a format string.
     /// ```sql
@@ -478,15 +496,26 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Count,
     /// SQL 'first' function
-    /// Returns the first element of the grouping.
+    /// Returns the first element of the grouping (or the first non-null element,
+    /// with IGNORE NULLS).
     /// ```sql
     /// SELECT FIRST(column_1) FROM df;
+    /// SELECT FIRST(column_1) IGNORE NULLS FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bitmask indicating which of the given keys are aggregated over (1) in the
+    /// grouping set of the row, with GROUP BY ROLLUP, CUBE or GROUPING SETS.
+    /// ```sql
+    /// SELECT GROUPING(column_1, column_2) FROM df GROUP BY ROLLUP(column_1, column_2);
+    /// ```
+    Grouping,
     /// SQL 'last' function
-    /// Returns the last element of the grouping.
+    /// Returns the last element of the grouping (or the last non-null element,
+    /// with IGNORE NULLS).
     /// ```sql
     /// SELECT LAST(column_1) FROM df;
+    /// SELECT LAST(column_1) IGNORE NULLS FROM df;
     /// ```
     Last,
     /// SQL 'max' function
@@ -507,12 +536,43 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT MIN(column_1) FROM df;
     /// ```
     Min,
+    /// SQL 'mode' function
+    /// Returns the most frequent element in the grouping; ties are resolved by taking
+    /// the first such element in the WITHIN GROUP order.
+    /// ```sql
+    /// SELECT MODE() WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// SELECT MODE(column_1) FROM df;
+    /// ```
+    Mode,
+    /// SQL 'percentile_cont' function
+    /// Returns the value at the given fraction of the ordered elements in the grouping,
+    /// interpolating linearly between adjacent elements.
+    /// ```sql
+    /// SELECT PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileCont,
+    /// SQL 'percentile_disc' function
+    /// Returns the first element of the ordered elements in the grouping whose position
+    /// is at or beyond the given fraction.
+    /// ```sql
+    /// SELECT PERCENTILE_DISC(0.25) WITHIN GROUP (ORDER BY column_1) FROM df;
+    /// ```
+    PercentileDisc,
     /// SQL 'stddev' function
     /// Returns the standard deviation of all the elements in the grouping.
     /// ```sql
     /// SELECT STDDEV(column_1) FROM df;
     /// ```
     StdDev,
+    /// SQL 'string_agg' function
+    /// Concatenates the non-null elements in the grouping into a string, separated
+    /// by the given delimiter.
+    /// ```sql
+    /// SELECT STRING_AGG(column_1, ',') FROM df;
+    /// SELECT STRING_AGG(column_1, ',' ORDER BY column_2) FROM df;
+    /// SELECT STRING_AGG(column_1, ',') WITHIN GROUP (ORDER BY column_2) FROM df;
+    /// ```
+    StringAgg,
     /// SQL 'sum' function
     /// Returns the sum of all the elements in the grouping.
     /// ```sql
@@ -526,6 +586,64 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'row_number' function
+    /// Returns the (1-indexed) number of the row within its window partition.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+    /// SQL 'rank' function
+    /// Returns the rank of the row within its window partition, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'dense_rank' function
+    /// Returns the rank of the row within its window partition, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'ntile' function
+    /// Divides the rows of the window partition into n buckets, as evenly as possible.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (ORDER BY column_1) FROM df;
+    /// ```
+    NTile,
+    /// SQL 'lag' function
+    /// Returns the value from the row n rows before the current row.
+    /// ```sql
+    /// SELECT LAG(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LAG(column_1, 2, 0) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'lead' function
+    /// Returns the value from the row n rows after the current row.
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LEAD(column_1, 2, 0) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'first_value' function
+    /// Returns the first value in the window frame (or the first non-null value,
+    /// with IGNORE NULLS).
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// SELECT FIRST_VALUE(column_1) IGNORE NULLS OVER (ORDER BY column_2) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'last_value' function
+    /// Returns the last value in the window frame (or the last non-null value,
+    /// with IGNORE NULLS).
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (PARTITION BY column_2 ORDER BY column_3) FROM df;
+    /// SELECT LAST_VALUE(column_1 IGNORE NULLS) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    LastValue,
+
     // ----
     // Array functions
     // ----
@@ -644,16 +762,24 @@ impl PolarsSQLFunctions {
             "count",
             "date",
             "date_part",
+            "date_trunc",
+            "datetime_trunc",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -668,17 +794,23 @@ impl PolarsSQLFunctions {
             "median",
             "min",
             "mod",
+            "mode",
+            "ntile",
             "nullif",
             "octet_length",
+            "percentile_cont",
+            "percentile_disc",
             "pi",
             "pow",
             "power",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -689,11 +821,13 @@ impl PolarsSQLFunctions {
             "stddev",
             "stdev_samp",
             "stddev_samp",
+            "string_agg",
             "strpos",
             "substr",
             "sum",
             "tan",
             "tand",
+            "timestamp_trunc",
             "unnest",
             "upper",
             "var",
@@ -764,6 +898,7 @@ impl PolarsSQLFunctions {
             // Date functions
             // ----
             "date_part" => Self::DatePart,
+            "date_trunc" | "timestamp_trunc" | "datetime_trunc" => Self::DateTrunc,
             "strftime" => Self::Strftime,
 
             // ----
@@ -800,14 +935,31 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
             "min" => Self::Min,
+            "mode" => Self::Mode,
+            "percentile_cont" => Self::PercentileCont,
+            "percentile_disc" => Self::PercentileDisc,
             "stdev" | "stddev" | "stdev_samp" | "stddev_samp" => Self::StdDev,
+            "string_agg" => Self::StringAgg,
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "row_number" => Self::RowNumber,
+            "rank" => Self::Rank,
+            "dense_rank" => Self::DenseRank,
+            "ntile" => Self::NTile,
+            "lag" => Self::Lag,
+            "lead" => Self::Lead,
+            "first_value" => Self::FirstValue,
+            "last_value" => Self::LastValue,
+
             // ----
             // Array functions
             // ----
@@ -835,21 +987,56 @@ impl PolarsSQLFunctions {
     }
 }
 
+/// Determine if a SQL function call is an aggregation (that is not evaluated over a
+/// window), including registered aggregate UDFs.
+pub(crate) fn is_aggregate_function(function: &SQLFunction, ctx: &SQLContext) -> bool {
+    use PolarsSQLFunctions::*;
+    if function.over.is_some() {
+        return false;
+    }
+    match PolarsSQLFunctions::try_from_sql(function, ctx) {
+        Ok(
+            Avg | Count | First | Last | Max | Median | Min | Mode | PercentileCont
+            | PercentileDisc | StdDev | StringAgg | Sum | Variance | ArrayAgg,
+        ) => true,
+        Ok(Udf(name)) => match ctx.function_registry.get_udf(&name) {
+            Ok(Some(udf)) => {
+                udf.options.returns_scalar
+                    && matches!(udf.options.collect_groups, ApplyOptions::GroupWise)
+            },
+            // plugins are only known by the expression calling them
+            _ => ctx
+                .function_registry
+                .call(&name, vec![])
+                .is_ok_and(|expr| expr.as_ref().is_some_and(is_udf_aggregation)),
+        },
+        _ => false,
+    }
+}
+
 impl SQLFunctionVisitor<'_> {
     pub(crate) fn visit_function(&mut self) -> PolarsResult<Expr> {
         use PolarsSQLFunctions::*;
         let function_name = PolarsSQLFunctions::try_from_sql(self.func, self.ctx)?;
         let function = self.func;
 
-        // TODO: implement the following functions where possible
         if !function.within_group.is_empty() {
-            polars_bail!(SQLInterface: "'WITHIN GROUP' is not currently supported")
+            polars_ensure!(
+                matches!(function_name, Mode | PercentileCont | PercentileDisc | StringAgg),
+                SQLSyntax: "'WITHIN GROUP' is not supported for '{}'", function.name
+            );
         }
         if function.filter.is_some() {
-            polars_bail!(SQLInterface: "'FILTER' is not currently supported")
+            polars_ensure!(
+                is_aggregate_function(function, self.ctx),
+                SQLInterface: "'FILTER' is only supported for aggregate functions that are not evaluated over a window (found '{}')", function
+            );
         }
         if function.null_treatment.is_some() {
-            polars_bail!(SQLInterface: "'IGNORE|RESPECT NULLS' is not currently supported")
+            polars_ensure!(
+                matches!(function_name, First | Last | FirstValue | LastValue),
+                SQLInterface: "'IGNORE|RESPECT NULLS' is not supported for '{}'", function.name
+            );
         }
 
         match function_name {
@@ -974,6 +1161,7 @@ impl SQLFunctionVisitor<'_> {
                     },
                 }
             }),
+            DateTrunc => self.visit_date_trunc(),
             Strftime => {
                 let args = extract_args(function)?;
                 match args.len() {
@@ -1217,17 +1405,47 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
-            Avg => self.visit_unary(Expr::mean),
+            Avg => self.visit_unary_with_opt_window_frame(
+                Expr::mean,
+                |e, reverse| {
+                    e.clone().cast(DataType::Float64).cum_sum(reverse)
+                        / e.cum_count(reverse).cast(DataType::Float64)
+                },
+                Expr::rolling_mean,
+            ),
             Count => self.visit_count(),
-            First => self.visit_unary(Expr::first),
-            Last => self.visit_unary(Expr::last),
-            Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
+            First => self.visit_first_last(false),
+            Grouping => self.visit_grouping(),
+            Last => self.visit_first_last(true),
+            Max => {
+                self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max, Expr::rolling_max)
+            },
             Median => self.visit_unary(Expr::median),
-            Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
+            Min => {
+                self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min, Expr::rolling_min)
+            },
+            Mode => self.visit_mode(),
+            PercentileCont => self.visit_percentile(false),
+            PercentileDisc => self.visit_percentile(true),
             StdDev => self.visit_unary(|e| e.std(1)),
-            Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
+            StringAgg => self.visit_string_agg(),
+            Sum => {
+                self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum, Expr::rolling_sum)
+            },
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            RowNumber => self.visit_row_number(),
+            Rank => self.visit_rank(RankMethod::Min),
+            DenseRank => self.visit_rank(RankMethod::Dense),
+            NTile => self.visit_ntile(),
+            Lag => self.visit_lag_lead(1),
+            Lead => self.visit_lag_lead(-1),
+            FirstValue => self.visit_first_last_value(false),
+            LastValue => self.visit_first_last_value(true),
+
             // ----
             // Array functions
             // ----
@@ -1258,18 +1476,67 @@ impl SQLFunctionVisitor<'_> {
             .into_iter()
             .map(|arg| {
                 if let FunctionArgExpr::Expr(e) = arg {
-                    parse_sql_expr(e, self.ctx, None)
+                    match parse_sql_expr(e, self.ctx, None)? {
+                        lit @ Expr::Literal(_) => Ok(lit),
+                        expr => self.apply_filter(expr),
+                    }
                 } else {
                     polars_bail!(SQLInterface: "only expressions are supported in UDFs")
                 }
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        self.ctx
+        let expr = self
+            .ctx
             .function_registry
-            .get_udf(func_name)?
-            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?
-            .call(args)
+            .call(func_name, args)?
+            .ok_or_else(|| polars_err!(SQLInterface: "UDF {} not found", func_name))?;
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
+    /// Parse a function argument, keeping only the values of the rows that pass the
+    /// aggregate's FILTER clause (if any).
+    fn parse_arg(&mut self, sql_expr: &SQLExpr) -> PolarsResult<Expr> {
+        let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+        self.apply_filter(expr)
+    }
+
+    /// The predicate of the aggregate's FILTER (WHERE ...) clause, if any.
+    fn filter_predicate(&mut self) -> PolarsResult<Option<Expr>> {
+        let func = self.func;
+        func.filter
+            .as_ref()
+            .map(|predicate| parse_sql_expr(predicate, self.ctx, None))
+            .transpose()
+    }
+
+    fn apply_filter(&mut self, expr: Expr) -> PolarsResult<Expr> {
+        Ok(match self.filter_predicate()? {
+            Some(predicate) => expr.filter(predicate),
+            None => expr,
+        })
+    }
+
+    /// Parse the single argument of a function that can skip nulls, also returning
+    /// whether it should; IGNORE NULLS can follow either the argument or the function.
+    fn visit_null_treatment_arg(&mut self) -> PolarsResult<(Expr, bool)> {
+        let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
+        polars_ensure!(!is_distinct, SQLSyntax: "unexpected use of DISTINCT found in '{}'", self.func.name);
+
+        let mut null_treatment = self.func.null_treatment;
+        for clause in clauses {
+            match clause {
+                FunctionArgumentClause::IgnoreOrRespectNulls(nt) => null_treatment = Some(nt),
+                _ => {
+                    polars_bail!(SQLSyntax: "unexpected clause found in '{}' ({})", self.func.name, clause)
+                },
+            }
+        }
+        let ignore_nulls = matches!(null_treatment, Some(NullTreatment::IgnoreNulls));
+        match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => Ok((self.parse_arg(sql_expr)?, ignore_nulls)),
+            _ => self.not_supported_error(),
+        }
     }
 
     fn visit_unary(&mut self, f: impl Fn(Expr) -> Expr) -> PolarsResult<Expr> {
@@ -1286,18 +1553,87 @@ impl SQLFunctionVisitor<'_> {
         &mut self,
         f: impl Fn(Expr) -> Expr,
         cumulative_f: impl Fn(Expr, bool) -> Expr,
+        rolling_f: impl Fn(Expr, RollingOptionsFixedWindow) -> Expr,
     ) -> PolarsResult<Expr> {
-        match self.func.over.as_ref() {
-            Some(WindowType::WindowSpec(spec)) => {
-                self.apply_cumulative_window(f, cumulative_f, spec)
+        match self.window_spec()? {
+            Some(spec) if spec.window_frame.is_none() => {
+                self.apply_cumulative_window(f, cumulative_f, &spec)
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
+            _ => self.visit_unary_with_opt_window_frame(f, cumulative_f, rolling_f),
+        }
+    }
+
+    /// Functions with cumulative and rolling equivalents can be applied to window frames
+    /// e.g. SUM(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) -> ROLLING_SUM(a, 3)
+    /// without an explicit window frame the function is applied over the window.
+    fn visit_unary_with_opt_window_frame(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        cumulative_f: impl Fn(Expr, bool) -> Expr,
+        rolling_f: impl Fn(Expr, RollingOptionsFixedWindow) -> Expr,
+    ) -> PolarsResult<Expr> {
+        match self.window_spec()? {
+            Some(
+                spec @ WindowSpec {
+                    window_frame: Some(_),
+                    ..
+                },
+            ) => self.apply_window_frame(f, cumulative_f, rolling_f, &spec),
             _ => self.visit_unary(f),
         }
     }
+
+    fn apply_window_frame(
+        &mut self,
+        f: impl Fn(Expr) -> Expr,
+        cumulative_f: impl Fn(Expr, bool) -> Expr,
+        rolling_f: impl Fn(Expr, RollingOptionsFixedWindow) -> Expr,
+        spec: &WindowSpec,
+    ) -> PolarsResult<Expr> {
+        let frame = spec.window_frame.as_ref().unwrap();
+        let (start, end) = self.window_frame_offsets(frame)?;
+        let rolling = |window_size: i64, center: bool| RollingOptionsFixedWindow {
+            window_size: window_size as usize,
+            min_periods: 1,
+            center,
+            ..Default::default()
+        };
+        let expr = self.visit_unary_no_window(|e| e)?;
+
+        // note: offsets are relative to the current row, `None` is unbounded
+        let expr = match (start, end) {
+            (None, None) => repeat(f(expr), len()),
+            _ if frame.units != WindowFrameUnits::Rows => polars_bail!(
+                SQLInterface: "only ROWS window frames are supported for bounded frames; found {}", frame.units
+            ),
+            (None, Some(0)) => {
+                self.apply_in_window_order(expr, &spec.order_by, |e| cumulative_f(e, false))?
+            },
+            (Some(0), None) => {
+                self.apply_in_window_order(expr, &spec.order_by, |e| cumulative_f(e, true))?
+            },
+            (Some(start), Some(0)) if start <= 0 => {
+                self.apply_in_window_order(expr, &spec.order_by, |e| {
+                    rolling_f(e, rolling(1 - start, false))
+                })?
+            },
+            (Some(0), Some(end)) if end >= 0 => {
+                self.apply_in_window_order(expr, &spec.order_by, |e| {
+                    rolling_f(e.reverse(), rolling(end + 1, false)).reverse()
+                })?
+            },
+            (Some(start), Some(end)) if start == -end && end > 0 => {
+                self.apply_in_window_order(expr, &spec.order_by, |e| {
+                    rolling_f(e, rolling(2 * end + 1, true))
+                })?
+            },
+            _ => polars_bail!(
+                SQLInterface: "window frame is not currently supported: {}", self.func
+            ),
+        };
+        self.apply_partition_by(expr, spec)
+    }
+
     /// Window specs without partition bys are essentially cumulative functions
     /// e.g. SUM(a) OVER (ORDER BY b DESC) -> CUMSUM(a, false)
     fn apply_cumulative_window(
@@ -1341,7 +1677,7 @@ impl SQLFunctionVisitor<'_> {
         let args = extract_args(self.func)?;
         match args.as_slice() {
             [FunctionArgExpr::Expr(sql_expr)] => {
-                let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+                let expr = self.parse_arg(sql_expr)?;
                 // apply the function on the inner expr -- e.g. SUM(a) -> SUM
                 Ok(f(expr))
             },
@@ -1363,7 +1699,7 @@ impl SQLFunctionVisitor<'_> {
         let args = extract_args(self.func)?;
         match args.as_slice() {
             [FunctionArgExpr::Expr(sql_expr1), FunctionArgExpr::Expr(sql_expr2)] => {
-                let expr1 = parse_sql_expr(sql_expr1, self.ctx, None)?;
+                let expr1 = self.parse_arg(sql_expr1)?;
                 let expr2 = Arg::from_sql_expr(sql_expr2, self.ctx)?;
                 f(expr1, expr2)
             },
@@ -1420,7 +1756,7 @@ impl SQLFunctionVisitor<'_> {
         let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
         match args.as_slice() {
             [FunctionArgExpr::Expr(sql_expr)] => {
-                let mut base = parse_sql_expr(sql_expr, self.ctx, None)?;
+                let mut base = self.parse_arg(sql_expr)?;
                 if is_distinct {
                     base = base.unique_stable();
                 }
@@ -1486,16 +1822,20 @@ impl SQLFunctionVisitor<'_> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
         match (is_distinct, args.as_slice()) {
             // count(*), count()
-            (false, [FunctionArgExpr::Wildcard] | []) => Ok(len()),
+            (false, [FunctionArgExpr::Wildcard] | []) => Ok(match self.filter_predicate()? {
+                // count the rows that pass the filter
+                Some(predicate) => predicate.clone().filter(predicate).len(),
+                None => len(),
+            }),
             // count(column_name)
             (false, [FunctionArgExpr::Expr(sql_expr)]) => {
-                let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+                let expr = self.parse_arg(sql_expr)?;
                 let expr = self.apply_window_spec(expr, &self.func.over)?;
                 Ok(expr.count())
             },
             // count(distinct column_name)
             (true, [FunctionArgExpr::Expr(sql_expr)]) => {
-                let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+                let expr = self.parse_arg(sql_expr)?;
                 let expr = self.apply_window_spec(expr, &self.func.over)?;
                 Ok(expr.n_unique())
             },
@@ -1503,7 +1843,401 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    /// FIRST/LAST(expr) [IGNORE NULLS]
+    fn visit_first_last(&mut self, last: bool) -> PolarsResult<Expr> {
+        let (expr, ignore_nulls) = self.visit_null_treatment_arg()?;
+        let expr = if ignore_nulls {
+            expr.drop_nulls()
+        } else {
+            expr
+        };
+        let expr = if last { expr.last() } else { expr.first() };
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
+    /// MODE() WITHIN GROUP (ORDER BY expr), or MODE(expr); of equally frequent values
+    /// the first in the given order is returned.
+    fn visit_mode(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let (expr, descending) = match (args.as_slice(), self.func.within_group.is_empty()) {
+            ([], false) => self.within_group_order()?,
+            ([FunctionArgExpr::Expr(sql_expr)], true) => (self.parse_arg(sql_expr)?, false),
+            _ => return self.not_supported_error(),
+        };
+        Ok(expr
+            .drop_nulls()
+            .mode()
+            .sort(SortOptions::default().with_order_descending(descending))
+            .first())
+    }
+
+    /// PERCENTILE_CONT/PERCENTILE_DISC(fraction) WITHIN GROUP (ORDER BY expr)
+    fn visit_percentile(&mut self, discrete: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let fraction = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => f64::from_sql_expr(sql_expr, self.ctx)?,
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects 1 argument (found {})", self.func.name, args.len())
+            },
+        };
+        polars_ensure!(
+            (0.0..=1.0).contains(&fraction),
+            SQLSyntax: "{} fraction must be between 0 and 1 (found {})", self.func.name, fraction
+        );
+        let (expr, descending) = self.within_group_order()?;
+        Ok(if discrete {
+            // the first value whose (1-indexed) position is at least `fraction * n`
+            let values = expr
+                .drop_nulls()
+                .sort(SortOptions::default().with_order_descending(descending));
+            let n = values.clone().len().cast(DataType::Float64);
+            let idx = ((lit(fraction) * n).ceil().cast(DataType::Int64) - lit(1)).clip_min(lit(0));
+            values.slice(idx, lit(1)).first()
+        } else {
+            let fraction = if descending { 1.0 - fraction } else { fraction };
+            expr.quantile(lit(fraction), QuantileInterpolOptions::Linear)
+        })
+    }
+
+    /// DATE_TRUNC('unit', expr) or, in BigQuery, DATE_TRUNC(expr, UNIT).
+    fn visit_date_trunc(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let (unit, sql_expr) = match args.as_slice() {
+            [FunctionArgExpr::Expr(a), FunctionArgExpr::Expr(b)] => {
+                let unit_first = match self.ctx.dialect {
+                    SQLDialect::PostgreSQL | SQLDialect::DuckDB => true,
+                    SQLDialect::BigQuery => false,
+                    SQLDialect::Generic => {
+                        matches!(a, SQLExpr::Value(SQLValue::SingleQuotedString(_)))
+                    },
+                };
+                if unit_first {
+                    (a, b)
+                } else {
+                    (b, a)
+                }
+            },
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects 2 arguments (found {})", self.func.name, args.len())
+            },
+        };
+        let unit = match (self.ctx.dialect, unit) {
+            (SQLDialect::BigQuery, SQLExpr::Identifier(ident)) => ident.value.to_lowercase(),
+            (SQLDialect::BigQuery, _) => {
+                polars_bail!(SQLSyntax: "{} expects a date part keyword such as MONTH in the BigQuery dialect (found {})", self.func.name, unit)
+            },
+            (SQLDialect::Generic, SQLExpr::Identifier(ident)) => ident.value.to_lowercase(),
+            (_, SQLExpr::Value(SQLValue::SingleQuotedString(s))) => s.to_lowercase(),
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects the unit as a string literal (found {})", self.func.name, unit)
+            },
+        };
+        let every = match unit.strip_suffix('s').unwrap_or(&unit) {
+            "microsecond" => "1us",
+            "millisecond" => "1ms",
+            "second" => "1s",
+            "minute" => "1m",
+            "hour" => "1h",
+            "day" => "1d",
+            "week" if self.ctx.dialect == SQLDialect::BigQuery => {
+                // BigQuery weeks start on Sunday
+                let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+                return Ok(expr
+                    .dt()
+                    .offset_by(lit("1d"))
+                    .dt()
+                    .truncate(lit("1w"))
+                    .dt()
+                    .offset_by(lit("-1d")));
+            },
+            "week" | "isoweek" => "1w",
+            "month" => "1mo",
+            "quarter" => "1q",
+            "year" => "1y",
+            "decade" => "10y",
+            _ => {
+                polars_bail!(SQLSyntax: "invalid unit for {} ({})", self.func.name, unit)
+            },
+        };
+        let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+        Ok(expr.dt().truncate(lit(every)))
+    }
+
+    /// STRING_AGG([DISTINCT] expr, delimiter [ORDER BY ...]), where the order can also
+    /// be given with WITHIN GROUP (ORDER BY ...)
+    fn visit_string_agg(&mut self) -> PolarsResult<Expr> {
+        let (args, is_distinct, clauses) = extract_args_and_clauses(self.func)?;
+        let (expr, delimiter) = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr), FunctionArgExpr::Expr(delimiter)] => (
+                self.parse_arg(sql_expr)?,
+                String::from_sql_expr(delimiter, self.ctx)?,
+            ),
+            _ => {
+                polars_bail!(SQLSyntax: "STRING_AGG expects 2 arguments (found {})", args.len())
+            },
+        };
+        let mut order_by = self.func.within_group.clone();
+        for clause in clauses {
+            match clause {
+                FunctionArgumentClause::OrderBy(order_exprs) if order_by.is_empty() => {
+                    order_by = order_exprs
+                },
+                _ => {
+                    polars_bail!(SQLSyntax: "unexpected clause found in '{}' ({})", self.func.name, clause)
+                },
+            }
+        }
+        let mut values = expr.cast(DataType::String);
+        if !order_by.is_empty() {
+            values = self.apply_order_by(values, &order_by)?;
+        }
+        if is_distinct {
+            // note: keeps the first occurrence of each value, in order
+            values = values.unique_stable();
+        }
+        Ok(when(values.clone().count().gt(lit(0)))
+            .then(values.str().join(&delimiter, true))
+            .otherwise(lit(Null)))
+    }
+
+    /// The expression to order by in an ordered-set aggregate, such as the 'x' in
+    /// `PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x)`, and whether it is descending.
+    fn within_group_order(&mut self) -> PolarsResult<(Expr, bool)> {
+        let func = self.func;
+        match func.within_group.as_slice() {
+            [ob] => Ok((self.parse_arg(&ob.expr)?, !ob.asc.unwrap_or(true))),
+            _ => {
+                polars_bail!(SQLSyntax: "{} requires a WITHIN GROUP (ORDER BY ...) clause with one expression", func.name)
+            },
+        }
+    }
+
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let keys = self.ctx.grouping_keys.borrow().clone();
+        polars_ensure!(
+            !keys.is_empty(),
+            SQLSyntax: "GROUPING requires GROUP BY with ROLLUP, CUBE or GROUPING SETS"
+        );
+        polars_ensure!(
+            !args.is_empty() && args.len() <= 32,
+            SQLSyntax: "GROUPING expects 1-32 arguments (found {})", args.len()
+        );
+        // bit `i` of the grouping id (over all keys) becomes bit `j` of the result
+        let n_keys = keys.len();
+        let n_args = args.len();
+        let bits = args
+            .into_iter()
+            .enumerate()
+            .map(|(j, arg)| {
+                let FunctionArgExpr::Expr(sql_expr) = arg else {
+                    return self.not_supported_error();
+                };
+                let expr = parse_sql_expr(sql_expr, self.ctx, None)?;
+                let i = keys.iter().position(|k| *k == expr).ok_or_else(|| {
+                    polars_err!(SQLSyntax: "GROUPING argument '{}' is not a GROUP BY key", sql_expr)
+                })?;
+                let bit = col(GROUPING_ID).floor_div(typed_lit(1u32 << (n_keys - 1 - i)))
+                    % typed_lit(2u32);
+                Ok(bit * typed_lit(1u32 << (n_args - 1 - j)))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(bits
+            .into_iter()
+            .reduce(|acc, bit| acc + bit)
+            .unwrap()
+            .alias("grouping"))
+    }
+
+    fn visit_row_number(&mut self) -> PolarsResult<Expr> {
+        let spec = self.window_spec_required()?;
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        let row_number = match self.window_order(&spec.order_by)? {
+            Some((_, idx)) => idx.arg_sort(SortOptions::default()) + typed_lit(1 as IdxSize),
+            None => int_range(
+                typed_lit(1 as IdxSize),
+                len() + typed_lit(1 as IdxSize),
+                1,
+                IDX_DTYPE,
+            ),
+        };
+        self.apply_partition_by(row_number.alias("row_number"), &spec)
+    }
+
+    fn visit_rank(&mut self, method: RankMethod) -> PolarsResult<Expr> {
+        let spec = self.window_spec_required()?;
+        if !extract_args(self.func)?.is_empty() {
+            return self.not_supported_error();
+        }
+        let rank = match self.window_order(&spec.order_by)? {
+            // number the groups of peers (rows with equal ORDER BY values) in window order,
+            // and rank the rows by their peer group
+            Some((by, idx)) => window_peer_starts(by, &idx)
+                .cum_sum(false)
+                .gather(idx.arg_sort(SortOptions::default()))
+                .rank(
+                    RankOptions {
+                        method,
+                        descending: false,
+                    },
+                    None,
+                ),
+            // without ORDER BY all rows are peers
+            None => repeat(typed_lit(1 as IdxSize), len()),
+        };
+        let name = match method {
+            RankMethod::Dense => "dense_rank",
+            _ => "rank",
+        };
+        self.apply_partition_by(rank.alias(name), &spec)
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let spec = self.window_spec_required()?;
+        let args = extract_args(self.func)?;
+        let n = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => match parse_sql_expr(sql_expr, self.ctx, None)? {
+                Expr::Literal(LiteralValue::Int(n)) if n > 0 => n as i64,
+                _ => {
+                    polars_bail!(SQLSyntax: "NTILE requires a positive integer; found {}", sql_expr)
+                },
+            },
+            _ => polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len()),
+        };
+        let row_idx = match self.window_order(&spec.order_by)? {
+            Some((_, idx)) => idx.arg_sort(SortOptions::default()),
+            None => int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE),
+        };
+        // the first (len % n) buckets get one row more than the others, so
+        // the rows before those buckets end are spread over (len / n + 1)
+        let row_idx = row_idx.cast(DataType::Int64);
+        let size = len().cast(DataType::Int64).floor_div(lit(n));
+        let rem = len().cast(DataType::Int64) - size.clone() * lit(n);
+        let big_rows = rem.clone() * (size.clone() + lit(1));
+        let ntile = when(row_idx.clone().lt(big_rows.clone()))
+            .then(row_idx.clone().floor_div(size.clone() + lit(1)))
+            .otherwise(rem + (row_idx - big_rows).floor_div(size))
+            + lit(1);
+        self.apply_partition_by(ntile.alias("ntile"), &spec)
+    }
+
+    /// LAG/LEAD(expr [, offset [, default]]) shift `expr` in the window order,
+    /// where `direction` is 1 for LAG and -1 for LEAD
+    fn visit_lag_lead(&mut self, direction: i64) -> PolarsResult<Expr> {
+        let spec = self.window_spec_required()?;
+        let args = extract_args(self.func)?;
+        let (expr, offset, default) = match args.as_slice() {
+            [FunctionArgExpr::Expr(e), rest @ ..] if rest.len() <= 2 => {
+                let expr = parse_sql_expr(e, self.ctx, None)?;
+                let offset = match rest.first() {
+                    Some(FunctionArgExpr::Expr(sql_expr)) => {
+                        match parse_sql_expr(sql_expr, self.ctx, None)? {
+                            Expr::Literal(LiteralValue::Int(n)) => n as i64,
+                            _ => {
+                                polars_bail!(SQLSyntax: "offset for {} must be an integer; found {}", self.func.name, sql_expr)
+                            },
+                        }
+                    },
+                    Some(_) => return self.not_supported_error(),
+                    None => 1,
+                };
+                let default = match rest.get(1) {
+                    Some(FunctionArgExpr::Expr(sql_expr)) => {
+                        Some(parse_sql_expr(sql_expr, self.ctx, None)?)
+                    },
+                    Some(_) => return self.not_supported_error(),
+                    None => None,
+                };
+                (expr, offset * direction, default)
+            },
+            _ => {
+                polars_bail!(SQLSyntax: "{} expects 1-3 arguments (found {})", self.func.name, args.len())
+            },
+        };
+        let expr = self.apply_in_window_order(expr, &spec.order_by, |e| match default {
+            Some(default) => e.shift_and_fill(lit(offset), default),
+            None => e.shift(lit(offset)),
+        })?;
+        self.apply_partition_by(expr, &spec)
+    }
+
+    /// FIRST_VALUE/LAST_VALUE(expr) take the first/last value of the window frame (that is
+    /// not null, with IGNORE NULLS); only frames that start at the first row of the
+    /// partition are supported
+    fn visit_first_last_value(&mut self, last: bool) -> PolarsResult<Expr> {
+        let spec = self.window_spec_required()?;
+        let (expr, ignore_nulls) = self.visit_null_treatment_arg()?;
+        let skip_nulls = |e: Expr| if ignore_nulls { e.drop_nulls() } else { e };
+
+        // note: without a frame clause the frame ends with the last peer of the current row
+        let frame = spec.window_frame.clone().unwrap_or_default();
+        let (start, end) = self.window_frame_offsets(&frame)?;
+        polars_ensure!(
+            start.is_none() && (end.is_none() || end == Some(0)),
+            SQLInterface: "window frame is not currently supported: {}", self.func
+        );
+        let order = self.window_order(&spec.order_by)?;
+        let expr = match (order, last) {
+            (None, false) => repeat(skip_nulls(expr).first(), len()),
+            (None, true) => repeat(skip_nulls(expr).last(), len()),
+            (Some((_, idx)), false) if !ignore_nulls || end.is_none() => {
+                repeat(skip_nulls(expr.gather(idx)).first(), len())
+            },
+            (Some((_, idx)), true) if end.is_none() => {
+                repeat(skip_nulls(expr.gather(idx)).last(), len())
+            },
+            (Some(_), true) if frame.units == WindowFrameUnits::Rows && !ignore_nulls => expr,
+            (Some((by, idx)), _) => {
+                // the value of the frame that ends with each row, in window order
+                let values = expr.gather(idx.clone());
+                let values = match (last, ignore_nulls) {
+                    (true, false) => values,
+                    (true, true) => values.forward_fill(None),
+                    // rows before the first non-null value have no such value in their frame
+                    (false, _) => when(
+                        values
+                            .clone()
+                            .is_not_null()
+                            .cast(IDX_DTYPE)
+                            .cum_sum(false)
+                            .gt(lit(0)),
+                    )
+                    .then(values.drop_nulls().first())
+                    .otherwise(lit(Null)),
+                };
+                let values = if frame.units == WindowFrameUnits::Rows {
+                    values
+                } else {
+                    // take the value of the last peer of each row
+                    let peer_ends = window_peer_starts(by, &idx).shift_and_fill(lit(-1), lit(true));
+                    let row_idx = int_range(typed_lit(0 as IdxSize), len(), 1, IDX_DTYPE);
+                    let last_peer = when(peer_ends)
+                        .then(row_idx)
+                        .otherwise(lit(Null))
+                        .backward_fill(None);
+                    values.gather(last_peer)
+                };
+                values.gather(idx.arg_sort(SortOptions::default()))
+            },
+        };
+        self.apply_partition_by(expr, &spec)
+    }
+
     fn apply_order_by(&mut self, expr: Expr, order_by: &[OrderByExpr]) -> PolarsResult<Expr> {
+        let (by, options) = self.sort_by_args(order_by)?;
+        let by = by
+            .into_iter()
+            .map(|e| self.apply_filter(e))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(expr.sort_by(by, options))
+    }
+
+    fn sort_by_args(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<(Vec<Expr>, SortMultipleOptions)> {
         let mut by = Vec::with_capacity(order_by.len());
         let mut descending = Vec::with_capacity(order_by.len());
         let mut nulls_last = Vec::with_capacity(order_by.len());
@@ -1516,7 +2250,7 @@ impl SQLFunctionVisitor<'_> {
             nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
             descending.push(desc_order);
         }
-        Ok(expr.sort_by(
+        Ok((
             by,
             SortMultipleOptions::default()
                 .with_order_descending_multi(descending)
@@ -1525,13 +2259,111 @@ impl SQLFunctionVisitor<'_> {
         ))
     }
 
+    /// Get the window spec of the function (if any), resolving named windows.
+    fn window_spec(&self) -> PolarsResult<Option<WindowSpec>> {
+        self.func
+            .over
+            .as_ref()
+            .map(|window_type| self.ctx.resolve_window_spec(window_type))
+            .transpose()
+    }
+
+    fn window_spec_required(&self) -> PolarsResult<WindowSpec> {
+        self.window_spec()?
+            .ok_or_else(|| polars_err!(SQLSyntax: "{} requires an OVER clause", self.func.name))
+    }
+
+    /// Get the ORDER BY expressions of the window, together with the index that sorts
+    /// the rows in the window order (or `None` if the window is not ordered).
+    fn window_order(
+        &mut self,
+        order_by: &[OrderByExpr],
+    ) -> PolarsResult<Option<(Vec<Expr>, Expr)>> {
+        if order_by.is_empty() {
+            return Ok(None);
+        }
+        let (by, options) = self.sort_by_args(order_by)?;
+        let idx = arg_sort_by(&by, options);
+        Ok(Some((by, idx)))
+    }
+
+    /// Apply `f` to the values of `expr` in the window order, and return the
+    /// results in the original row order.
+    fn apply_in_window_order(
+        &mut self,
+        expr: Expr,
+        order_by: &[OrderByExpr],
+        f: impl FnOnce(Expr) -> Expr,
+    ) -> PolarsResult<Expr> {
+        Ok(match self.window_order(order_by)? {
+            Some((_, idx)) => {
+                f(expr.gather(idx.clone())).gather(idx.arg_sort(SortOptions::default()))
+            },
+            None => f(expr),
+        })
+    }
+
+    fn apply_partition_by(&mut self, expr: Expr, spec: &WindowSpec) -> PolarsResult<Expr> {
+        if spec.partition_by.is_empty() {
+            return Ok(expr);
+        }
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, None))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(expr.over(partition_by))
+    }
+
+    /// Get the offsets of the window frame bounds relative to the current row,
+    /// where `None` is an unbounded start/end.
+    fn window_frame_offsets(
+        &mut self,
+        frame: &WindowFrame,
+    ) -> PolarsResult<(Option<i64>, Option<i64>)> {
+        let end_bound = frame
+            .end_bound
+            .clone()
+            .unwrap_or(WindowFrameBound::CurrentRow);
+        polars_ensure!(
+            !matches!(frame.start_bound, WindowFrameBound::Following(None))
+                && !matches!(end_bound, WindowFrameBound::Preceding(None)),
+            SQLSyntax: "invalid window frame: {}", self.func
+        );
+        let mut offset = |bound: &WindowFrameBound| -> PolarsResult<Option<i64>> {
+            Ok(match bound {
+                WindowFrameBound::CurrentRow => Some(0),
+                WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => None,
+                WindowFrameBound::Preceding(Some(n)) | WindowFrameBound::Following(Some(n)) => {
+                    let n = match parse_sql_expr(n, self.ctx, None)? {
+                        Expr::Literal(LiteralValue::Int(n)) if n >= 0 => n as i64,
+                        _ => {
+                            polars_bail!(SQLSyntax: "window frame offset must be a non-negative integer; found {}", n)
+                        },
+                    };
+                    Some(match bound {
+                        WindowFrameBound::Preceding(_) => -n,
+                        _ => n,
+                    })
+                },
+            })
+        };
+        let start = offset(&frame.start_bound)?;
+        let end = offset(&end_bound)?;
+        if let (Some(start), Some(end)) = (start, end) {
+            polars_ensure!(start <= end, SQLSyntax: "invalid window frame: {}", self.func);
+        }
+        Ok((start, end))
+    }
+
     fn apply_window_spec(
         &mut self,
         expr: Expr,
         window_type: &Option<WindowType>,
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
-            Some(WindowType::WindowSpec(window_spec)) => {
+            Some(window_type) => {
+                let window_spec = self.ctx.resolve_window_spec(window_type)?;
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1554,15 +2386,11 @@ impl SQLFunctionVisitor<'_> {
                     expr.over(partition_by)
                 }
             },
-            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
-                SQLInterface: "Named windows are not currently supported; found {:?}",
-                named_window
-            ),
             None => expr,
         })
     }
 
-    fn not_supported_error(&self) -> PolarsResult<Expr> {
+    fn not_supported_error<T>(&self) -> PolarsResult<T> {
         polars_bail!(
             SQLInterface:
             "no function matches the given name and arguments: `{}`",
@@ -1700,3 +2528,15 @@ impl FromSQLExpr for Expr {
         parse_sql_expr(expr, ctx, None)
     }
 }
+
+/// Flag the rows that start a new group of peers (rows with equal ORDER BY values),
+/// with the rows taken in the window order given by `idx`.
+fn window_peer_starts(by: Vec<Expr>, idx: &Expr) -> Expr {
+    by.into_iter()
+        .map(|e| {
+            let e = e.gather(idx.clone());
+            e.clone().neq_missing(e.shift(lit(1)))
+        })
+        .reduce(Expr::or)
+        .unwrap()
+}
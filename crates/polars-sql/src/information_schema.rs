@@ -0,0 +1,173 @@
+//! Virtual `information_schema` relations, generated from the schemas of the tables
+//! registered in a [`SQLContext`](crate::SQLContext).
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use sqlparser::ast::{
+    BinaryOperator, Expr as SQLExpr, Ident, ObjectName, TableAlias, TableFactor, Value as SQLValue,
+};
+
+/// The name of the schema that holds the virtual relations.
+pub(crate) const INFORMATION_SCHEMA: &str = "information_schema";
+
+/// The relations of the `information_schema`.
+pub(crate) enum InformationSchemaTable {
+    /// `information_schema.tables`; one row per registered table.
+    Tables,
+    /// `information_schema.columns`; one row per column of each registered table.
+    Columns,
+}
+
+impl InformationSchemaTable {
+    pub(crate) fn try_from_name(name: &str) -> PolarsResult<Self> {
+        Ok(match name.to_lowercase().as_str() {
+            "tables" => Self::Tables,
+            "columns" => Self::Columns,
+            _ => {
+                polars_bail!(SQLInterface: "relation '{}.{}' was not found", INFORMATION_SCHEMA, name)
+            },
+        })
+    }
+
+    /// Generate the relation from the given (sorted) tables and their schemas.
+    pub(crate) fn generate(&self, tables: &[(String, SchemaRef)]) -> PolarsResult<LazyFrame> {
+        let df = match self {
+            Self::Tables => {
+                let names = tables.iter().map(|(name, _)| name.as_str());
+                DataFrame::new(vec![
+                    Series::new("table_name", names.collect::<Vec<_>>()),
+                    Series::new("table_type", vec!["BASE TABLE"; tables.len()]),
+                ])?
+            },
+            Self::Columns => {
+                let mut table_names = vec![];
+                let mut column_names = vec![];
+                let mut ordinal_positions = vec![];
+                let mut data_types = vec![];
+                for (table_name, schema) in tables {
+                    for (idx, (name, dtype)) in schema.iter().enumerate() {
+                        table_names.push(table_name.as_str());
+                        column_names.push(name.as_str());
+                        ordinal_positions.push(idx as i64 + 1);
+                        data_types.push(dtype.to_string());
+                    }
+                }
+                DataFrame::new(vec![
+                    Series::new("table_name", table_names),
+                    Series::new("column_name", column_names),
+                    Series::new("ordinal_position", ordinal_positions),
+                    Series::new("data_type", data_types),
+                ])?
+            },
+        };
+        Ok(df.lazy())
+    }
+}
+
+/// Return the relation name and the name it is referenced by (its alias, if any)
+/// when the table factor is an `information_schema` relation.
+pub(crate) fn information_schema_relation(
+    name: &ObjectName,
+    alias: &Option<TableAlias>,
+) -> Option<(String, String)> {
+    match name.0.as_slice() {
+        [schema, tbl_name] if schema.value.eq_ignore_ascii_case(INFORMATION_SCHEMA) => {
+            let ref_name = alias.as_ref().map_or(&tbl_name.value, |a| &a.name.value);
+            Some((tbl_name.value.clone(), ref_name.clone()))
+        },
+        _ => None,
+    }
+}
+
+/// Return the names of the tables an `information_schema` relation (referenced as
+/// `ref_name`) is restricted to by the `table_name = '...'` and `table_name IN (...)`
+/// conjuncts of a WHERE clause, if any.
+pub(crate) fn table_name_filter(
+    selection: &SQLExpr,
+    relation: &TableFactor,
+) -> Option<PlHashSet<String>> {
+    let TableFactor::Table {
+        name,
+        alias,
+        args: None,
+        ..
+    } = relation
+    else {
+        return None;
+    };
+    let (_, ref_name) = information_schema_relation(name, alias)?;
+    let is_table_name = |expr: &SQLExpr| match expr {
+        SQLExpr::Identifier(ident) => is_table_name_ident(ident),
+        SQLExpr::CompoundIdentifier(idents) => match idents.as_slice() {
+            [qualifier, ident] => qualifier.value == ref_name && is_table_name_ident(ident),
+            _ => false,
+        },
+        _ => false,
+    };
+    let as_string = |expr: &SQLExpr| match expr {
+        SQLExpr::Value(SQLValue::SingleQuotedString(s)) => Some(s.clone()),
+        _ => None,
+    };
+
+    let mut filter: Option<PlHashSet<String>> = None;
+    let mut stack = vec![selection];
+    while let Some(expr) = stack.pop() {
+        let names = match expr {
+            SQLExpr::Nested(expr) => {
+                stack.push(expr);
+                continue;
+            },
+            SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::And,
+                right,
+            } => {
+                stack.push(left);
+                stack.push(right);
+                continue;
+            },
+            SQLExpr::BinaryOp {
+                left,
+                op: BinaryOperator::Eq,
+                right,
+            } => match (left.as_ref(), right.as_ref()) {
+                (column, value) | (value, column) if is_table_name(column) => {
+                    match as_string(value) {
+                        Some(name) => PlHashSet::from_iter([name]),
+                        None => continue,
+                    }
+                },
+                _ => continue,
+            },
+            SQLExpr::InList {
+                expr,
+                list,
+                negated: false,
+            } if is_table_name(expr) => match list.iter().map(as_string).collect() {
+                Some(names) => names,
+                None => continue,
+            },
+            _ => continue,
+        };
+        filter = Some(match filter {
+            Some(filter) => filter.intersection(&names).cloned().collect(),
+            None => names,
+        });
+    }
+    filter
+}
+
+fn is_table_name_ident(ident: &Ident) -> bool {
+    ident.value.eq_ignore_ascii_case("table_name")
+}
+
+/// Describe the columns of a schema, as returned by `DESCRIBE` and `SHOW COLUMNS`.
+pub(crate) fn describe_schema(schema: &Schema) -> PolarsResult<LazyFrame> {
+    let names = schema.iter_names().map(|name| name.as_str());
+    let dtypes = schema.iter_dtypes().map(|dtype| dtype.to_string());
+    let df = DataFrame::new(vec![
+        Series::new("column_name", names.collect::<Vec<_>>()),
+        Series::new("column_type", dtypes.collect::<Vec<_>>()),
+    ])?;
+    Ok(df.lazy())
+}
@@ -2,11 +2,15 @@
 //! This crate provides a SQL interface for Polars DataFrames
 #![deny(missing_docs)]
 mod context;
+mod dialect;
 pub mod function_registry;
 mod functions;
+mod information_schema;
 pub mod keywords;
+mod recursive_cte;
 mod sql_expr;
 mod table_functions;
 
 pub use context::SQLContext;
+pub use dialect::SQLDialect;
 pub use sql_expr::sql_expr;
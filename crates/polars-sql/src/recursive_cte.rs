@@ -0,0 +1,167 @@
+//! Evaluation of recursive common table expressions (`WITH RECURSIVE`).
+
+use std::any::Any;
+use std::sync::{Arc, Mutex, RwLock};
+
+use polars_core::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+
+/// The default maximum number of iterations of a recursive CTE.
+pub(crate) const DEFAULT_RECURSION_LIMIT: usize = 1000;
+
+/// The rows added by the previous iteration of a recursive CTE; the recursive term of
+/// the CTE scans this table wherever it references the CTE itself.
+pub(crate) struct WorkingTable {
+    schema: SchemaRef,
+    rows: RwLock<DataFrame>,
+}
+
+impl WorkingTable {
+    pub(crate) fn new(schema: SchemaRef) -> Arc<Self> {
+        Arc::new(Self {
+            rows: RwLock::new(DataFrame::empty_with_schema(&schema)),
+            schema,
+        })
+    }
+
+    /// A [`LazyFrame`] scanning the rows of the current iteration.
+    pub(crate) fn scan(self: &Arc<Self>) -> PolarsResult<LazyFrame> {
+        let args = ScanArgsAnonymous {
+            schema: Some(self.schema.clone()),
+            name: "RECURSIVE CTE",
+            ..Default::default()
+        };
+        LazyFrame::anonymous_scan(self.clone(), args)
+    }
+
+    /// Whether any plan (other than `self`) refers to the working table.
+    pub(crate) fn is_referenced(self: &Arc<Self>) -> bool {
+        Arc::strong_count(self) > 1
+    }
+
+    fn set_rows(&self, rows: DataFrame) {
+        *self.rows.write().unwrap() = rows;
+    }
+}
+
+impl AnonymousScan for WorkingTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        Ok(self.rows.read().unwrap().clone())
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+/// A recursive CTE, evaluated as a fixpoint: starting from the rows of the anchor (the
+/// non-recursive term), the recursive term is evaluated against the rows added by the
+/// previous iteration until it produces no (new) rows.
+///
+/// With `UNION` the result is deduplicated and rows that were already produced are not
+/// fed back into the recursion, so cyclic data terminates; with `UNION ALL` cycles are
+/// only stopped by the recursion limit.
+pub(crate) struct RecursiveCte {
+    name: String,
+    working_table: Arc<WorkingTable>,
+    recursive_term: LazyFrame,
+    union_all: bool,
+    recursion_limit: usize,
+    // concurrent evaluations would share the working table
+    lock: Mutex<()>,
+}
+
+impl RecursiveCte {
+    pub(crate) fn new(
+        name: &str,
+        working_table: Arc<WorkingTable>,
+        recursive_term: LazyFrame,
+        union_all: bool,
+        recursion_limit: usize,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            working_table,
+            recursive_term,
+            union_all,
+            recursion_limit,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// The CTE as a [`LazyFrame`]; the fixpoint is evaluated when the frame is collected.
+    pub(crate) fn finish(self, anchor: LazyFrame) -> LazyFrame {
+        // nothing may be pushed into the anchor, as its rows seed the recursion
+        let optimizations = AllowedOptimizations {
+            projection_pushdown: false,
+            predicate_pushdown: false,
+            slice_pushdown: false,
+            streaming: false,
+            ..Default::default()
+        };
+        anchor.map(
+            move |anchor| self.evaluate(anchor),
+            optimizations,
+            None,
+            Some("RECURSIVE CTE"),
+        )
+    }
+
+    fn evaluate(&self, anchor: DataFrame) -> PolarsResult<DataFrame> {
+        let _guard = self.lock.lock().unwrap();
+        let res = self.fixpoint(anchor);
+        self.working_table
+            .set_rows(DataFrame::empty_with_schema(&self.working_table.schema));
+        res
+    }
+
+    fn fixpoint(&self, anchor: DataFrame) -> PolarsResult<DataFrame> {
+        // the (row-encoded) rows produced so far, with UNION
+        let mut seen = PlHashSet::new();
+        let mut result = if self.union_all {
+            anchor
+        } else {
+            take_unseen_rows(&anchor, &mut seen)?
+        };
+        let mut working = result.clone();
+        let mut iterations = 0;
+        while working.height() > 0 {
+            polars_ensure!(
+                iterations < self.recursion_limit,
+                ComputeError: "recursive CTE '{}' did not finish within {} iterations{}",
+                self.name, self.recursion_limit,
+                if self.union_all { " (use UNION instead of UNION ALL to stop at cycles)" } else { "" }
+            );
+            iterations += 1;
+
+            self.working_table.set_rows(working);
+            let new_rows = self.recursive_term.clone().collect()?;
+            working = if self.union_all {
+                new_rows
+            } else {
+                take_unseen_rows(&new_rows, &mut seen)?
+            };
+            result.vstack_mut(&working)?;
+        }
+        result.align_chunks();
+        Ok(result)
+    }
+}
+
+/// Keep the rows that are not in `seen` (and the first of duplicate rows), and add them
+/// to it.
+fn take_unseen_rows(rows: &DataFrame, seen: &mut PlHashSet<Box<[u8]>>) -> PolarsResult<DataFrame> {
+    let encoded = _get_rows_encoded_ca_unordered("", rows.get_columns())?;
+    let mask = BooleanChunked::from_iter_values(
+        "",
+        encoded
+            .into_no_null_iter()
+            .map(|row| seen.insert(row.into())),
+    );
+    rows.filter(&mask)
+}
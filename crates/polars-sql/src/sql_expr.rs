@@ -4,6 +4,7 @@ use std::ops::Div;
 use polars_core::export::regex;
 use polars_core::prelude::*;
 use polars_lazy::prelude::*;
+use polars_ops::frame::JoinCoalesce;
 use polars_plan::prelude::typed_lit;
 use polars_plan::prelude::LiteralValue::Null;
 use polars_time::Duration;
@@ -16,14 +17,15 @@ use serde::{Deserialize, Serialize};
 use sqlparser::ast::ExactNumberInfo;
 use sqlparser::ast::{
     ArrayElemTypeDef, BinaryOperator as SQLBinaryOperator, BinaryOperator, CastFormat, CastKind,
-    DataType as SQLDataType, DateTimeField, Expr as SQLExpr, Function as SQLFunction, Ident,
-    Interval, JoinConstraint, ObjectName, Query as Subquery, SelectItem, Subscript, TimezoneInfo,
-    TrimWhereField, UnaryOperator, Value as SQLValue,
+    DataType as SQLDataType, DateTimeField, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
+    FunctionArgExpr, FunctionArguments, GroupByExpr, Ident, Interval, JoinConstraint, ObjectName,
+    Query as Subquery, Select, SelectItem, SetExpr, Subscript, TableFactor, TableWithJoins,
+    TimezoneInfo, TrimWhereField, UnaryOperator, Value as SQLValue,
 };
-use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
-use crate::functions::SQLFunctionVisitor;
+use crate::dialect::{PolarsDialect, SQLDialect};
+use crate::functions::{is_aggregate_function, SQLFunctionVisitor};
 use crate::SQLContext;
 
 static DATETIME_LITERAL_RE: std::sync::OnceLock<Regex> = std::sync::OnceLock::new();
@@ -108,7 +110,7 @@ pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<D
         SQLDataType::Int(_) | SQLDataType::Integer(_) => DataType::Int32,
         SQLDataType::Int2(_) | SQLDataType::SmallInt(_) => DataType::Int16,
         SQLDataType::Int4(_) | SQLDataType::MediumInt(_) => DataType::Int32,
-        SQLDataType::Int8(_) | SQLDataType::BigInt(_) => DataType::Int64,
+        SQLDataType::Int8(_) | SQLDataType::BigInt(_) | SQLDataType::Int64 => DataType::Int64,
         SQLDataType::TinyInt(_) => DataType::Int8,
 
         // ---------------------------------
@@ -124,9 +126,10 @@ pub(crate) fn map_sql_polars_datatype(data_type: &SQLDataType) -> PolarsResult<D
         // ---------------------------------
         // float
         // ---------------------------------
-        SQLDataType::Double | SQLDataType::DoublePrecision | SQLDataType::Float8 => {
-            DataType::Float64
-        },
+        SQLDataType::Double
+        | SQLDataType::DoublePrecision
+        | SQLDataType::Float8
+        | SQLDataType::Float64 => DataType::Float64,
         SQLDataType::Float(n_bytes) => match n_bytes {
             Some(n) if (1u64..=24u64).contains(n) => DataType::Float32,
             Some(n) if (25u64..=53u64).contains(n) => DataType::Float64,
@@ -280,6 +283,7 @@ impl SQLExprVisitor<'_> {
             SQLExpr::Extract { field, expr } => {
                 parse_extract_date_part(self.visit_expr(expr)?, field)
             },
+            SQLExpr::Exists { subquery, negated } => self.visit_exists(subquery, *negated),
             SQLExpr::Floor { expr, .. } => Ok(self.visit_expr(expr)?.floor()),
             SQLExpr::Function(function) => self.visit_function(function),
             SQLExpr::Identifier(ident) => self.visit_identifier(ident),
@@ -347,7 +351,7 @@ impl SQLExprVisitor<'_> {
                 Ok(if *negated { matches.not() } else { matches })
             },
             SQLExpr::Subscript { expr, subscript } => self.visit_subscript(expr, subscript),
-            SQLExpr::Subquery(_) => polars_bail!(SQLInterface: "unexpected subquery"),
+            SQLExpr::Subquery(subquery) => self.visit_scalar_subquery(subquery),
             SQLExpr::Trim {
                 expr,
                 trim_where,
@@ -420,15 +424,9 @@ impl SQLExprVisitor<'_> {
             if schema.len() != 1 {
                 polars_bail!(SQLSyntax: "SQL subquery returns more than one column");
             }
-            let rand_string: String = thread_rng()
-                .sample_iter(&Alphanumeric)
-                .take(16)
-                .map(char::from)
-                .collect();
-
             let schema_entry = schema.get_at_index(0);
             if let Some((old_name, _)) = schema_entry {
-                let new_name = String::from(old_name.as_str()) + rand_string.as_str();
+                let new_name = subquery_column_name(old_name);
                 lf = lf.rename([old_name.to_string()], [new_name.clone()]);
                 return Ok(Expr::SubPlan(
                     SpecialEq::new(Arc::new(lf.logical_plan)),
@@ -485,6 +483,9 @@ impl SQLExprVisitor<'_> {
         escape_char: &Option<String>,
         case_insensitive: bool,
     ) -> PolarsResult<Expr> {
+        if case_insensitive && !self.ctx.dialect.supports_ilike() {
+            polars_bail!(SQLInterface: "ILIKE is not supported in the {} dialect", self.ctx.dialect);
+        }
         if escape_char.is_some() {
             polars_bail!(SQLInterface: "ESCAPE char for LIKE/ILIKE is not currently supported; found '{}'", escape_char.clone().unwrap());
         }
@@ -864,6 +865,16 @@ impl SQLExprVisitor<'_> {
                 polars_err!(SQLInterface: "use of FORMAT is not currently supported in CAST"),
             );
         }
+        let dialect = self.ctx.dialect;
+        if !dialect.supports_cast(cast_kind) {
+            let cast = match cast_kind {
+                CastKind::DoubleColon => "'::' cast",
+                CastKind::TryCast => "TRY_CAST",
+                CastKind::SafeCast => "SAFE_CAST",
+                CastKind::Cast => "CAST",
+            };
+            polars_bail!(SQLInterface: "{} is not supported in the {} dialect", cast, dialect);
+        }
         let expr = self.visit_expr(expr)?;
 
         #[cfg(feature = "json")]
@@ -1027,6 +1038,39 @@ impl SQLExprVisitor<'_> {
         subquery: &Subquery,
         negated: bool,
     ) -> PolarsResult<Expr> {
+        if let Some(mut correlated) = self.decorrelate_subquery(subquery)? {
+            // `x IN (SELECT y FROM t WHERE t.k = o.k)` is evaluated as
+            // `EXISTS (SELECT 1 FROM t WHERE t.k = o.k AND t.y = x)`
+            let value = correlated.single_projection()?;
+            let outer_value = self.visit_expr(expr)?;
+            if !negated {
+                correlated.inner_keys.push(value);
+                correlated.outer_keys.push(outer_value);
+                return self.visit_correlated_exists(correlated, false);
+            }
+            // `x NOT IN (...)` is NULL (rather than TRUE) if x is NULL or the subquery
+            // returns a NULL, unless the subquery has no rows (or returns x)
+            let mut null_values = correlated.clone();
+            null_values.and_where(SQLExpr::IsNull(Box::new(value.clone())));
+            let any_rows = self.correlated_subquery_join(correlated.clone(), None)?;
+            let has_nulls = self.correlated_subquery_join(null_values, None)?;
+            correlated.inner_keys.push(value);
+            correlated.outer_keys.push(outer_value.clone());
+            let matched = self.correlated_subquery_join(correlated, None)?;
+
+            let expr = when(any_rows.exists_predicate(true))
+                .then(lit(true))
+                .when(matched.exists_predicate(false))
+                .then(lit(false))
+                .when(outer_value.is_null().or(has_nulls.exists_predicate(false)))
+                .then(lit(Null).cast(DataType::Boolean))
+                .otherwise(lit(true));
+            self.ctx
+                .subquery_joins
+                .borrow_mut()
+                .extend([any_rows, has_nulls, matched]);
+            return Ok(expr);
+        }
         let subquery_result = self.visit_subquery(subquery, SubqueryRestriction::SingleColumn)?;
         let expr = self.visit_expr(expr)?;
         Ok(if negated {
@@ -1036,6 +1080,379 @@ impl SQLExprVisitor<'_> {
         })
     }
 
+    /// Visit an `EXISTS` subquery predicate.
+    fn visit_exists(&mut self, subquery: &Subquery, negated: bool) -> PolarsResult<Expr> {
+        if let Some(correlated) = self.decorrelate_subquery(subquery)? {
+            return self.visit_correlated_exists(correlated, negated);
+        }
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        // `EXISTS (SELECT 1 FROM ...)` only depends on the rows of the subquery; note that
+        // a projection of literals alone is not evaluated per-row
+        let mut query = subquery.clone();
+        if let SetExpr::Select(select) = query.body.as_mut() {
+            if select.projection.iter().all(|item| {
+                matches!(
+                    item,
+                    SelectItem::UnnamedExpr(SQLExpr::Value(_))
+                        | SelectItem::ExprWithAlias {
+                            expr: SQLExpr::Value(_),
+                            ..
+                        }
+                )
+            }) {
+                select.projection = vec![SelectItem::Wildcard(Default::default())];
+            }
+        }
+        let name = subquery_column_name("exists");
+        let lf = self
+            .ctx
+            .execute_query_no_ctes(&query)?
+            .limit(1)
+            .select([len().alias(&name)]);
+        let n_rows = Expr::SubPlan(SpecialEq::new(Arc::new(lf.logical_plan)), vec![name]).first();
+        Ok(if negated {
+            n_rows.eq(typed_lit(0 as IdxSize))
+        } else {
+            n_rows.gt(typed_lit(0 as IdxSize))
+        })
+    }
+
+    fn visit_correlated_exists(
+        &mut self,
+        correlated: CorrelatedSubquery,
+        negated: bool,
+    ) -> PolarsResult<Expr> {
+        let join = self.correlated_subquery_join(correlated, None)?;
+        let expr = join.exists_predicate(negated);
+        self.ctx.subquery_joins.borrow_mut().push(join);
+        Ok(expr)
+    }
+
+    /// Visit a scalar subquery, eg: `(SELECT MAX(x) FROM t)`, which must return a
+    /// single column and (at most) a single row.
+    fn visit_scalar_subquery(&mut self, subquery: &Subquery) -> PolarsResult<Expr> {
+        if let Some(correlated) = self.decorrelate_subquery(subquery)? {
+            let value = correlated.single_projection()?;
+            // unlike other aggregates, COUNT over no matching rows is zero (not NULL)
+            let is_count = is_correlated_count(&value)?;
+            let join = self.correlated_subquery_join(correlated, Some(value))?;
+            let expr = col(&join.column);
+            self.ctx.subquery_joins.borrow_mut().push(join);
+            return Ok(if is_count {
+                expr.fill_null(typed_lit(0 as IdxSize))
+            } else {
+                expr
+            });
+        }
+        Ok(self
+            .visit_subquery(subquery, SubqueryRestriction::SingleColumn)?
+            .first())
+    }
+
+    /// Split a subquery that references columns of the outer query into the
+    /// (uncorrelated) subquery and the equality predicates that correlate the two;
+    /// returns `None` if the subquery is not correlated.
+    fn decorrelate_subquery(
+        &mut self,
+        subquery: &Subquery,
+    ) -> PolarsResult<Option<CorrelatedSubquery>> {
+        let SetExpr::Select(select) = subquery.body.as_ref() else {
+            return Ok(None);
+        };
+        let Some(selection) = &select.selection else {
+            return Ok(None);
+        };
+        let scope = self.subquery_scope(&select.from)?;
+
+        let mut predicates = vec![];
+        let mut inner_keys = vec![];
+        let mut outer_keys = vec![];
+        for predicate in split_sql_conjunction(selection) {
+            let (_, has_outer) = self.sql_expr_scope(predicate, &scope);
+            if !has_outer {
+                predicates.push(predicate.clone());
+                continue;
+            }
+            let keys = match predicate {
+                SQLExpr::BinaryOp {
+                    left,
+                    op: SQLBinaryOperator::Eq,
+                    right,
+                } => {
+                    let (l_inner, l_outer) = self.sql_expr_scope(left, &scope);
+                    let (r_inner, r_outer) = self.sql_expr_scope(right, &scope);
+                    if !l_outer && !r_inner {
+                        Some((left, right))
+                    } else if !r_outer && !l_inner {
+                        Some((right, left))
+                    } else {
+                        None
+                    }
+                },
+                _ => None,
+            };
+            let Some((inner, outer)) = keys else {
+                polars_bail!(SQLInterface: "correlated subquery predicate is not currently supported: {}", predicate)
+            };
+            inner_keys.push(inner.as_ref().clone());
+            outer_keys.push(self.visit_expr(outer)?);
+        }
+        if outer_keys.is_empty() {
+            return Ok(None);
+        }
+        for item in &select.projection {
+            if let SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } = item {
+                polars_ensure!(
+                    !self.sql_expr_scope(e, &scope).1,
+                    SQLInterface: "correlated subquery can only reference outer columns in its WHERE clause"
+                );
+            }
+        }
+        if subquery.with.is_some() {
+            polars_bail!(SQLSyntax: "SQL subquery cannot be a CTE 'WITH' clause");
+        }
+        polars_ensure!(
+            subquery.limit.is_none() && subquery.offset.is_none(),
+            SQLInterface: "LIMIT/OFFSET are not currently supported in a correlated subquery"
+        );
+
+        let mut select = select.as_ref().clone();
+        select.selection = predicates
+            .into_iter()
+            .reduce(|left, right| SQLExpr::BinaryOp {
+                left: Box::new(left),
+                op: SQLBinaryOperator::And,
+                right: Box::new(right),
+            });
+        let mut query = subquery.clone();
+        query.order_by.clear();
+
+        Ok(Some(CorrelatedSubquery {
+            query,
+            select,
+            inner_keys,
+            outer_keys,
+        }))
+    }
+
+    /// Execute a decorrelated subquery as a frame that can be joined to the outer
+    /// query, either on its own (`EXISTS`) or providing a value (scalar subquery).
+    fn correlated_subquery_join(
+        &mut self,
+        correlated: CorrelatedSubquery,
+        value: Option<SQLExpr>,
+    ) -> PolarsResult<SubqueryJoin> {
+        let CorrelatedSubquery {
+            mut query,
+            mut select,
+            inner_keys,
+            outer_keys,
+        } = correlated;
+
+        let keys = inner_keys
+            .iter()
+            .map(|_| subquery_column_name("key"))
+            .collect::<Vec<_>>();
+        select.projection = inner_keys
+            .iter()
+            .zip(&keys)
+            .map(|(expr, key)| SelectItem::ExprWithAlias {
+                expr: expr.clone(),
+                alias: Ident::new(key),
+            })
+            .collect();
+
+        let (kind, column) = match value {
+            None => (SubqueryJoinType::Exists, subquery_column_name("exists")),
+            Some(value) => {
+                // the subquery is evaluated once per distinct key (eg: aggregates)
+                let column = subquery_column_name("value");
+                select.projection.push(SelectItem::ExprWithAlias {
+                    expr: value,
+                    alias: Ident::new(&column),
+                });
+                match &mut select.group_by {
+                    GroupByExpr::Expressions(exprs) if exprs.is_empty() => {
+                        select.group_by = GroupByExpr::All
+                    },
+                    GroupByExpr::Expressions(exprs) => exprs.extend(inner_keys),
+                    GroupByExpr::All => {},
+                }
+                (SubqueryJoinType::Scalar, column)
+            },
+        };
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+
+        Ok(SubqueryJoin {
+            lf: self.ctx.execute_query_no_ctes(&query)?,
+            left_on: outer_keys,
+            keys,
+            column,
+            kind,
+        })
+    }
+
+    /// Decorrelate a `LATERAL` subquery that references columns of the preceding FROM
+    /// items (the active schema); returns `None` if the subquery is not correlated.
+    ///
+    /// The subquery is evaluated once for all rows, with its correlation keys as
+    /// additional columns (and GROUP BY keys if it aggregates).
+    fn visit_lateral(&mut self, subquery: &Subquery) -> PolarsResult<Option<LateralJoin>> {
+        let Some(CorrelatedSubquery {
+            mut query,
+            mut select,
+            inner_keys,
+            outer_keys,
+        }) = self.decorrelate_subquery(subquery)?
+        else {
+            return Ok(None);
+        };
+
+        let projected_exprs = select
+            .projection
+            .iter()
+            .map(|item| match item {
+                SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. } => {
+                    Some(expr)
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let aggregate = match &mut select.group_by {
+            GroupByExpr::Expressions(exprs) if exprs.is_empty() => {
+                let aggregate = projected_exprs
+                    .iter()
+                    .flatten()
+                    .any(|expr| sql_expr_has_aggregate(expr, self.ctx));
+                if aggregate {
+                    select.group_by = GroupByExpr::All;
+                }
+                aggregate
+            },
+            GroupByExpr::Expressions(exprs) => {
+                exprs.extend(inner_keys.iter().cloned());
+                false
+            },
+            GroupByExpr::All => false,
+        };
+        // unlike other aggregates, COUNT over no matching rows is zero (not NULL)
+        let mut count_columns = vec![];
+        if aggregate {
+            for (idx, expr) in projected_exprs.iter().enumerate() {
+                if let Some(expr) = expr {
+                    if is_correlated_count(expr)? {
+                        count_columns.push(idx);
+                    }
+                }
+            }
+        }
+
+        let keys = inner_keys
+            .iter()
+            .map(|_| subquery_column_name("key"))
+            .collect::<Vec<_>>();
+        select
+            .projection
+            .extend(inner_keys.into_iter().zip(&keys).map(|(expr, key)| {
+                SelectItem::ExprWithAlias {
+                    expr,
+                    alias: Ident::new(key),
+                }
+            }));
+        query.body = Box::new(SetExpr::Select(Box::new(select)));
+
+        Ok(Some(LateralJoin {
+            lf: self.ctx.execute_query_no_ctes(&query)?,
+            left_on: outer_keys,
+            keys,
+            aggregate,
+            count_columns,
+        }))
+    }
+
+    /// Determine the relations and (if known) the columns in scope of a subquery.
+    fn subquery_scope(&mut self, from: &[TableWithJoins]) -> PolarsResult<SubqueryScope> {
+        let mut relations = PlHashSet::new();
+        let mut columns = Some(PlHashSet::new());
+        for relation in from.iter().flat_map(|tbl| {
+            std::iter::once(&tbl.relation).chain(tbl.joins.iter().map(|j| &j.relation))
+        }) {
+            match relation {
+                TableFactor::Table {
+                    name,
+                    alias,
+                    args: None,
+                    ..
+                } => {
+                    let tbl_name = name.0.first().unwrap().value.as_str();
+                    relations.insert(tbl_name.to_string());
+                    if let Some(alias) = alias {
+                        relations.insert(alias.name.value.clone());
+                    }
+                    match (
+                        self.ctx.get_table_from_current_scope(tbl_name),
+                        &mut columns,
+                    ) {
+                        (Some(mut lf), Some(columns)) => {
+                            let schema = lf.schema_with_arenas(
+                                &mut self.ctx.lp_arena,
+                                &mut self.ctx.expr_arena,
+                            )?;
+                            columns.extend(schema.iter_names().map(|name| name.to_string()));
+                        },
+                        _ => columns = None,
+                    }
+                },
+                TableFactor::Derived {
+                    alias: Some(alias), ..
+                } => {
+                    relations.insert(alias.name.value.clone());
+                    columns = None;
+                },
+                _ => columns = None,
+            }
+        }
+        Ok(SubqueryScope { relations, columns })
+    }
+
+    /// Determine if a SQL expression references columns of the subquery and/or
+    /// columns of the outer query, as `(inner, outer)`.
+    fn sql_expr_scope(&self, expr: &SQLExpr, scope: &SubqueryScope) -> (bool, bool) {
+        let mut refs = vec![];
+        collect_sql_column_refs(expr, &mut refs);
+        refs.iter().fold((false, false), |(inner, outer), idents| {
+            if self.is_outer_reference(idents, scope) {
+                (inner, true)
+            } else {
+                (true, outer)
+            }
+        })
+    }
+
+    fn is_outer_reference(&self, idents: &[Ident], scope: &SubqueryScope) -> bool {
+        let in_subquery_columns =
+            |name: &str| scope.columns.as_ref().map(|columns| columns.contains(name));
+        match idents {
+            [name] => {
+                in_subquery_columns(&name.value) == Some(false)
+                    && self
+                        .active_schema
+                        .is_some_and(|schema| schema.contains(&name.value))
+            },
+            [qualifier, ..] => {
+                !scope.relations.contains(&qualifier.value)
+                    && in_subquery_columns(&qualifier.value) != Some(true)
+                    && self
+                        .ctx
+                        .get_table_from_current_scope(&qualifier.value)
+                        .is_some()
+            },
+            [] => false,
+        }
+    }
+
     /// Visit `CASE` control flow expression.
     fn visit_case_when_then(&mut self, expr: &SQLExpr) -> PolarsResult<Expr> {
         if let SQLExpr::Case {
@@ -1229,7 +1646,7 @@ pub(super) fn process_join_constraint(
 pub fn sql_expr<S: AsRef<str>>(s: S) -> PolarsResult<Expr> {
     let mut ctx = SQLContext::new();
 
-    let mut parser = Parser::new(&GenericDialect);
+    let mut parser = Parser::new(&PolarsDialect(SQLDialect::Generic));
     parser = parser.with_options(ParserOptions {
         trailing_commas: true,
         ..Default::default()
@@ -1259,6 +1676,20 @@ pub(crate) fn parse_sql_expr(
     visitor.visit_expr(expr)
 }
 
+/// Decorrelate a `LATERAL` subquery from the preceding FROM items with the given schema;
+/// returns `None` if the subquery does not reference them.
+pub(crate) fn decorrelate_lateral(
+    subquery: &Subquery,
+    ctx: &mut SQLContext,
+    schema: &Schema,
+) -> PolarsResult<Option<LateralJoin>> {
+    let mut visitor = SQLExprVisitor {
+        ctx,
+        active_schema: Some(schema),
+    };
+    visitor.visit_lateral(subquery)
+}
+
 pub(crate) fn parse_sql_array(expr: &SQLExpr, ctx: &mut SQLContext) -> PolarsResult<Series> {
     match expr {
         SQLExpr::Array(arr) => {
@@ -1407,6 +1838,290 @@ fn bitstring_to_bytes_literal(b: &String) -> PolarsResult<Expr> {
     }))
 }
 
+/// A subquery that references columns of the outer query, with the correlating
+/// equality predicates removed from its `WHERE` clause.
+#[derive(Clone)]
+struct CorrelatedSubquery {
+    query: Subquery,
+    select: Select,
+    inner_keys: Vec<SQLExpr>,
+    outer_keys: Vec<Expr>,
+}
+
+impl CorrelatedSubquery {
+    /// Add a (non-correlated) predicate to the WHERE clause.
+    fn and_where(&mut self, predicate: SQLExpr) {
+        self.select.selection = Some(match self.select.selection.take() {
+            Some(selection) => SQLExpr::BinaryOp {
+                left: Box::new(selection),
+                op: SQLBinaryOperator::And,
+                right: Box::new(predicate),
+            },
+            None => predicate,
+        });
+    }
+
+    fn single_projection(&self) -> PolarsResult<SQLExpr> {
+        match self.select.projection.as_slice() {
+            [SelectItem::UnnamedExpr(expr) | SelectItem::ExprWithAlias { expr, .. }] => {
+                Ok(expr.clone())
+            },
+            _ => polars_bail!(SQLSyntax: "SQL subquery must return a single column"),
+        }
+    }
+}
+
+struct SubqueryScope {
+    relations: PlHashSet<String>,
+    // `None` if the subquery columns cannot be determined up-front
+    columns: Option<PlHashSet<String>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Eq)]
+enum SubqueryJoinType {
+    Exists,
+    Scalar,
+}
+
+/// A decorrelated subquery, joined to the outer query on its correlation keys; the
+/// result of the subquery is then available as `column`.
+#[derive(Clone)]
+pub(crate) struct SubqueryJoin {
+    lf: LazyFrame,
+    left_on: Vec<Expr>,
+    keys: Vec<String>,
+    column: String,
+    kind: SubqueryJoinType,
+}
+
+impl SubqueryJoin {
+    /// The predicate that an `[NOT] EXISTS` subquery evaluates to after a left join.
+    fn exists_predicate(&self, negated: bool) -> Expr {
+        if negated {
+            col(&self.column).is_null()
+        } else {
+            col(&self.column).is_not_null()
+        }
+    }
+
+    /// Left-join the subquery result onto `lf`, adding `column`.
+    pub(crate) fn join_left(self, lf: LazyFrame) -> LazyFrame {
+        let (rf, validation) = match self.kind {
+            SubqueryJoinType::Exists => (
+                self.lf
+                    .unique(None, UniqueKeepStrategy::Any)
+                    .with_column(lit(true).alias(&self.column)),
+                JoinValidation::ManyToMany,
+            ),
+            // more than one row per key is an error (as for an uncorrelated subquery)
+            SubqueryJoinType::Scalar => (self.lf, JoinValidation::ManyToOne),
+        };
+        lf.join_builder()
+            .with(rf)
+            .left_on(self.left_on)
+            .right_on(self.keys.iter().map(|k| col(k)).collect::<Vec<_>>())
+            .how(JoinType::Left)
+            .validate(validation)
+            .coalesce(JoinCoalesce::CoalesceColumns)
+            .finish()
+            .drop_no_validate(self.keys)
+    }
+
+    /// Join the subquery result onto `lf` for evaluation of the given (conjunctive)
+    /// filter predicates; returns the name of the added column, if any.
+    ///
+    /// An `[NOT] EXISTS` subquery that must hold for every row is applied as a
+    /// semi/anti join instead, and its predicate is removed.
+    pub(crate) fn join_for_filter(
+        self,
+        lf: LazyFrame,
+        predicates: &mut Vec<Expr>,
+    ) -> (LazyFrame, Option<String>) {
+        #[cfg(feature = "semi_anti_join")]
+        if self.kind == SubqueryJoinType::Exists {
+            for negated in [false, true] {
+                let predicate = self.exists_predicate(negated);
+                if let Some(idx) = predicates.iter().position(|e| *e == predicate) {
+                    predicates.remove(idx);
+                    let lf = lf
+                        .join_builder()
+                        .with(self.lf)
+                        .left_on(self.left_on)
+                        .right_on(self.keys.iter().map(|k| col(k)).collect::<Vec<_>>())
+                        .how(if negated {
+                            JoinType::Anti
+                        } else {
+                            JoinType::Semi
+                        })
+                        .finish();
+                    return (lf, None);
+                }
+            }
+        }
+        #[cfg(not(feature = "semi_anti_join"))]
+        let _ = predicates;
+        let column = self.column.clone();
+        (self.join_left(lf), Some(column))
+    }
+}
+
+/// A decorrelated `LATERAL` subquery: joining `lf` on its `keys` to the `left_on`
+/// expressions of the preceding FROM items evaluates the subquery for each of their rows.
+pub(crate) struct LateralJoin {
+    pub(crate) lf: LazyFrame,
+    pub(crate) left_on: Vec<Expr>,
+    pub(crate) keys: Vec<String>,
+    /// The subquery aggregates without GROUP BY, so it has a row for every row of the
+    /// preceding FROM items (which must be left-joined).
+    pub(crate) aggregate: bool,
+    /// The (indices of the) aggregate `COUNT` columns, which are zero for rows without
+    /// a match.
+    pub(crate) count_columns: Vec<usize>,
+}
+
+/// Generate a unique column name for an intermediate subquery result.
+fn subquery_column_name(prefix: &str) -> String {
+    let rand_string: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect();
+    format!("{}{}", prefix, rand_string)
+}
+
+/// Split a SQL predicate on its top-level `AND` conjunctions.
+fn split_sql_conjunction(expr: &SQLExpr) -> Vec<&SQLExpr> {
+    match expr {
+        SQLExpr::BinaryOp {
+            left,
+            op: SQLBinaryOperator::And,
+            right,
+        } => {
+            let mut exprs = split_sql_conjunction(left);
+            exprs.extend(split_sql_conjunction(right));
+            exprs
+        },
+        SQLExpr::Nested(e)
+            if matches!(
+                **e,
+                SQLExpr::BinaryOp {
+                    op: SQLBinaryOperator::And,
+                    ..
+                }
+            ) =>
+        {
+            split_sql_conjunction(e)
+        },
+        _ => vec![expr],
+    }
+}
+
+/// Collect the (possibly qualified) column identifiers referenced by a SQL
+/// expression; does not descend into nested subqueries.
+fn collect_sql_column_refs<'a>(expr: &'a SQLExpr, refs: &mut Vec<&'a [Ident]>) {
+    walk_sql_expr(expr, &mut |e| match e {
+        SQLExpr::Identifier(ident) => refs.push(std::slice::from_ref(ident)),
+        SQLExpr::CompoundIdentifier(idents) => refs.push(idents),
+        _ => {},
+    })
+}
+
+/// Determine if a SQL expression aggregates, ie: calls an aggregate function that is
+/// not evaluated over a window.
+pub(crate) fn sql_expr_has_aggregate(expr: &SQLExpr, ctx: &SQLContext) -> bool {
+    let mut has_aggregate = false;
+    walk_sql_expr(expr, &mut |e| {
+        if let SQLExpr::Function(function) = e {
+            has_aggregate |= is_aggregate_function(function, ctx);
+        }
+    });
+    has_aggregate
+}
+
+/// Whether the projected expression of a correlated subquery is a `COUNT`, of which
+/// the value over no matching rows is zero; errors if `COUNT` is part of a larger
+/// expression, as that value can't be determined after the join.
+fn is_correlated_count(expr: &SQLExpr) -> PolarsResult<bool> {
+    let is_count = |e: &SQLExpr| matches!(e, SQLExpr::Function(f) if f.name.to_string().eq_ignore_ascii_case("count"));
+    let mut n_counts = 0;
+    walk_sql_expr(expr, &mut |e| n_counts += is_count(e) as usize);
+    match n_counts {
+        0 => Ok(false),
+        1 if is_count(expr) => Ok(true),
+        _ => polars_bail!(
+            SQLInterface: "COUNT inside an expression is not currently supported in a correlated subquery: {}", expr
+        ),
+    }
+}
+
+/// Call `f` on a SQL expression and (recursively) on its sub-expressions; does not
+/// descend into nested subqueries.
+fn walk_sql_expr<'a>(expr: &'a SQLExpr, f: &mut dyn FnMut(&'a SQLExpr)) {
+    f(expr);
+    let mut visit = |e: &'a SQLExpr| walk_sql_expr(e, f);
+    match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right) => {
+            visit(left);
+            visit(right);
+        },
+        SQLExpr::Like { expr, pattern, .. }
+        | SQLExpr::ILike { expr, pattern, .. }
+        | SQLExpr::RLike { expr, pattern, .. } => {
+            visit(expr);
+            visit(pattern);
+        },
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => {
+            visit(expr);
+            visit(low);
+            visit(high);
+        },
+        SQLExpr::InList { expr, list, .. } => {
+            visit(expr);
+            list.iter().for_each(visit);
+        },
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => {
+            operand.iter().for_each(|e| visit(e));
+            conditions.iter().chain(results).for_each(&mut visit);
+            else_result.iter().for_each(|e| visit(e));
+        },
+        SQLExpr::Function(SQLFunction {
+            args: FunctionArguments::List(list),
+            ..
+        }) => list.args.iter().for_each(|arg| match arg {
+            FunctionArg::Named {
+                arg: FunctionArgExpr::Expr(e),
+                ..
+            }
+            | FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => visit(e),
+            _ => {},
+        }),
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::Ceil { expr, .. }
+        | SQLExpr::Floor { expr, .. }
+        | SQLExpr::Extract { expr, .. }
+        | SQLExpr::Trim { expr, .. }
+        | SQLExpr::InSubquery { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr) => visit(expr),
+        _ => {},
+    }
+}
+
 pub(crate) fn resolve_compound_identifier(
     ctx: &mut SQLContext,
     idents: &[Ident],
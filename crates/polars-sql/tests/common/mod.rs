@@ -0,0 +1,42 @@
+// Helpers shared by the SQL tests; not every test uses all of them.
+#![allow(dead_code)]
+
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+/// A context of the given dialect with the frames registered under their names.
+pub fn create_ctx(dialect: SQLDialect, tables: &[(&str, &LazyFrame)]) -> SQLContext {
+    let mut ctx = SQLContext::new().with_dialect(dialect);
+    for (name, lf) in tables {
+        ctx.register(name, (*lf).clone());
+    }
+    ctx
+}
+
+/// Asserts that the SQL query gives the same frame as the equivalent DSL query.
+pub fn assert_sql_ctx_to_polars(ctx: &mut SQLContext, sql: &str, expected: LazyFrame) {
+    let df_sql = ctx.execute(sql).unwrap().collect().unwrap();
+    let df_pl = expected.collect().unwrap();
+    assert!(
+        df_sql.equals_missing(&df_pl),
+        "{sql}\nSQL: {df_sql}\nDSL: {df_pl}"
+    );
+}
+
+/// Asserts that the SQL query on `df` (registered as "df") gives the same frame as the DSL
+/// query built by `f`.
+pub fn assert_sql_to_polars(df: &DataFrame, sql: &str, f: impl FnOnce(LazyFrame) -> LazyFrame) {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.clone().lazy());
+    let df_sql = ctx.execute(sql).unwrap().collect().unwrap();
+    let df_pl = f(df.clone().lazy()).collect().unwrap();
+    assert!(df_sql.equals(&df_pl));
+}
+
+/// Asserts that none of the SQL queries can be executed.
+pub fn assert_sql_errors(ctx: &mut SQLContext, queries: &[&str]) {
+    for sql in queries {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,128 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "name" => ["Alice", "bob", "ALBERT"],
+      "x" => ["1", "2", "x"],
+      "d" => ["2024-05-15", "2024-02-29", "2024-06-02"],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("d").cast(DataType::Date))
+}
+
+#[test]
+fn test_bigquery_syntax() {
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::BigQuery, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          SAFE_CAST(x AS INT64) AS x_int,
+          `name` LIKE 'A%' AS starts_a,
+        FROM df
+        "#,
+        df.clone().select([
+            col("x").cast(DataType::Int64).alias("x_int"),
+            col("name")
+                .str()
+                .contains(lit("^A.*$"), true)
+                .alias("starts_a"),
+        ]),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * EXCEPT (x, d) FROM df WHERE CAST(x AS STRING) = '2'",
+        df.filter(col("x").eq(lit("2"))).select([col("name")]),
+    );
+}
+
+#[test]
+fn test_bigquery_date_trunc() {
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::BigQuery, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          DATE_TRUNC(d, MONTH) AS month,
+          DATE_TRUNC(d, WEEK) AS week,
+          DATE_TRUNC(d, ISOWEEK) AS isoweek,
+          TIMESTAMP_TRUNC(d, YEAR) AS year,
+        FROM df
+        "#,
+        df.select([
+            col("d").dt().truncate(lit("1mo")).alias("month"),
+            // BigQuery weeks start on Sunday
+            col("d")
+                .dt()
+                .offset_by(lit("1d"))
+                .dt()
+                .truncate(lit("1w"))
+                .dt()
+                .offset_by(lit("-1d"))
+                .alias("week"),
+            col("d").dt().truncate(lit("1w")).alias("isoweek"),
+            col("d").dt().truncate(lit("1y")).alias("year"),
+        ]),
+    );
+}
+
+#[test]
+fn test_bigquery_rejected() {
+    let mut ctx = create_ctx(SQLDialect::BigQuery, &[("df", &create_df())]);
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT x::INT64 FROM df",
+            "SELECT name ILIKE 'a%' FROM df",
+            "SELECT TRY_CAST(x AS INT64) FROM df",
+            "SELECT COUNT(*) FILTER (WHERE x = '1') FROM df",
+            "SELECT * EXCLUDE (x) FROM df",
+            "SELECT DATE_TRUNC('month', d) FROM df",
+        ],
+    );
+}
+
+#[test]
+fn test_generic_dialect() {
+    // the generic dialect accepts the syntax of each of the other dialects
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::Generic, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          DATE_TRUNC('month', d) AS pg_month,
+          DATE_TRUNC(d, MONTH) AS bq_month,
+          DATE_TRUNC(d, WEEK) AS week,
+          TRY_CAST(x AS INT) AS try_x,
+          SAFE_CAST(x AS INT) AS safe_x,
+          name ILIKE 'a%' AS is_a,
+        FROM df
+        "#,
+        df.select([
+            col("d").dt().truncate(lit("1mo")).alias("pg_month"),
+            col("d").dt().truncate(lit("1mo")).alias("bq_month"),
+            col("d").dt().truncate(lit("1w")).alias("week"),
+            col("x").cast(DataType::Int32).alias("try_x"),
+            col("x").cast(DataType::Int32).alias("safe_x"),
+            col("name")
+                .str()
+                .contains(lit("^(?i)a.*$"), true)
+                .alias("is_a"),
+        ]),
+    );
+
+    assert!("snowflake".parse::<SQLDialect>().is_err());
+    assert_eq!(
+        "BigQuery".parse::<SQLDialect>().unwrap(),
+        SQLDialect::BigQuery
+    );
+}
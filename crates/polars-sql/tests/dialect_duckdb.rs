@@ -0,0 +1,59 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "name" => ["Alice", "bob", "ALBERT"],
+      "x" => ["1", "2", "x"],
+      "d" => ["2024-05-15", "2024-02-29", "2024-06-02"],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("d").cast(DataType::Date))
+}
+
+#[test]
+fn test_duckdb_syntax() {
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::DuckDB, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          TRY_CAST(x AS INTEGER) AS x_int,
+          name ILIKE '%b%' AS has_b,
+          DATE_TRUNC('year', d) AS year,
+        FROM df
+        "#,
+        df.clone().select([
+            col("x").cast(DataType::Int32).alias("x_int"),
+            col("name")
+                .str()
+                .contains(lit("^(?i).*b.*$"), true)
+                .alias("has_b"),
+            col("d").dt().truncate(lit("1y")).alias("year"),
+        ]),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * EXCLUDE (x, d) FROM df WHERE x::varchar = '2'",
+        df.filter(col("x").eq(lit("2"))).select([col("name")]),
+    );
+}
+
+#[test]
+fn test_duckdb_rejected() {
+    let mut ctx = create_ctx(SQLDialect::DuckDB, &[("df", &create_df())]);
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT * EXCEPT (x) FROM df",
+            "SELECT SAFE_CAST(x AS INTEGER) FROM df",
+            "SELECT DATE_TRUNC(d, YEAR) FROM df",
+        ],
+    );
+}
@@ -0,0 +1,97 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "name" => ["Alice", "bob", "ALBERT"],
+      "n" => [1, 2, 3],
+      "d" => ["2024-05-15", "2024-02-29", "2024-06-02"],
+    }
+    .unwrap()
+    .lazy()
+    .with_column(col("d").cast(DataType::Date))
+}
+
+#[test]
+fn test_postgresql_dialect() {
+    let ctx = create_ctx(SQLDialect::PostgreSQL, &[("df", &create_df())]);
+    assert_eq!(ctx.dialect(), SQLDialect::PostgreSQL);
+    assert_eq!(
+        "postgres".parse::<SQLDialect>().unwrap(),
+        SQLDialect::PostgreSQL
+    );
+}
+
+#[test]
+fn test_postgresql_syntax() {
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::PostgreSQL, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          n::text AS n_str,
+          name ILIKE 'al%' AS is_al,
+          name !~~* '%B%' AS no_b,
+        FROM df
+        "#,
+        df.clone().select([
+            col("n").cast(DataType::String).alias("n_str"),
+            col("name")
+                .str()
+                .contains(lit("^(?i)al.*$"), true)
+                .alias("is_al"),
+            col("name")
+                .str()
+                .contains(lit("^(?i).*B.*$"), true)
+                .not()
+                .alias("no_b"),
+        ]),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT COUNT(*) FILTER (WHERE n > 1) AS n_gt1 FROM df",
+        df.select([col("n").filter(col("n").gt(lit(1))).len().alias("n_gt1")]),
+    );
+}
+
+#[test]
+fn test_postgresql_date_trunc() {
+    let df = create_df();
+    let mut ctx = create_ctx(SQLDialect::PostgreSQL, &[("df", &df)]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          DATE_TRUNC('month', d) AS month,
+          DATE_TRUNC('week', d) AS week,
+          DATE_TRUNC('QUARTER', d) AS quarter,
+        FROM df
+        "#,
+        df.select([
+            col("d").dt().truncate(lit("1mo")).alias("month"),
+            col("d").dt().truncate(lit("1w")).alias("week"),
+            col("d").dt().truncate(lit("1q")).alias("quarter"),
+        ]),
+    );
+}
+
+#[test]
+fn test_postgresql_rejected() {
+    let mut ctx = create_ctx(SQLDialect::PostgreSQL, &[("df", &create_df())]);
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT SAFE_CAST(n AS TEXT) FROM df",
+            "SELECT TRY_CAST(n AS TEXT) FROM df",
+            "SELECT * EXCEPT (n) FROM df",
+            "SELECT * EXCLUDE (n) FROM df",
+            "SELECT DATE_TRUNC(d, MONTH) FROM df",
+            "SELECT DATE_TRUNC('fortnight', d) FROM df",
+        ],
+    );
+}
@@ -0,0 +1,208 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "grp" => ["a", "a", "a", "a", "b", "b", "c"],
+      "x" => [Some(1), Some(2), Some(2), Some(7), Some(5), None, None],
+      "s" => [Some("p"), Some("q"), None, Some("r"), Some("t"), Some("u"), Some("v")],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_agg_ctx() -> SQLContext {
+    create_ctx(SQLDialect::Generic, &[("df", &create_df())])
+}
+
+/// The non-null values joined with the delimiter, or null if there are none (as with
+/// SQL `STRING_AGG`).
+fn string_agg(values: Expr, delimiter: &str) -> Expr {
+    when(values.clone().count().gt(lit(0)))
+        .then(values.str().join(delimiter, true))
+        .otherwise(lit(NULL))
+}
+
+#[test]
+fn test_aggregate_filter() {
+    let mut ctx = create_agg_ctx();
+    let gt1 = col("x").gt(lit(1));
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          grp,
+          COUNT(*) FILTER (WHERE x > 1) AS n,
+          COUNT(DISTINCT x) FILTER (WHERE x > 1) AS n_unique,
+          MAX(x) FILTER (WHERE x < 5) AS max_lt5,
+          AVG(x) FILTER (WHERE s <> 'q') AS avg_not_q,
+          STRING_AGG(s, ',' ORDER BY x DESC) FILTER (WHERE x > 1) AS s_gt1,
+        FROM df
+        GROUP BY grp
+        ORDER BY grp
+        "#,
+        create_df()
+            .group_by([col("grp")])
+            .agg([
+                col("grp").filter(gt1.clone()).len().alias("n"),
+                col("x").filter(gt1.clone()).n_unique().alias("n_unique"),
+                col("x").filter(col("x").lt(lit(5))).max().alias("max_lt5"),
+                col("x")
+                    .filter(col("s").neq(lit("q")))
+                    .mean()
+                    .alias("avg_not_q"),
+                string_agg(
+                    col("s").filter(gt1.clone()).sort_by(
+                        [col("x").filter(gt1)],
+                        SortMultipleOptions::default()
+                            .with_order_descending(true)
+                            .with_maintain_order(true),
+                    ),
+                    ",",
+                )
+                .alias("s_gt1"),
+            ])
+            .sort(["grp"], Default::default()),
+    );
+
+    // without GROUP BY
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT SUM(x) FILTER (WHERE grp = 'a') AS total FROM df",
+        create_df().select([col("x")
+            .filter(col("grp").eq(lit("a")))
+            .sum()
+            .alias("total")]),
+    );
+}
+
+#[test]
+fn test_first_last_ignore_nulls() {
+    let mut ctx = create_agg_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          grp,
+          LAST(x) AS last_x,
+          LAST(x) IGNORE NULLS AS last_non_null,
+          FIRST(s) RESPECT NULLS AS first_s,
+        FROM df
+        GROUP BY grp
+        ORDER BY grp
+        "#,
+        create_df()
+            .group_by([col("grp")])
+            .agg([
+                col("x").last().alias("last_x"),
+                col("x").drop_nulls().last().alias("last_non_null"),
+                col("s").first().alias("first_s"),
+            ])
+            .sort(["grp"], Default::default()),
+    );
+}
+
+#[test]
+fn test_ordered_set_aggregates() {
+    let mut ctx = create_agg_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          grp,
+          PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x) AS p50,
+          PERCENTILE_CONT(0.25) WITHIN GROUP (ORDER BY x DESC) AS p75,
+          STRING_AGG(s, '|') WITHIN GROUP (ORDER BY s DESC) AS s_desc,
+        FROM df
+        GROUP BY grp
+        ORDER BY grp
+        "#,
+        create_df()
+            .group_by([col("grp")])
+            .agg([
+                col("x")
+                    .quantile(lit(0.5), QuantileInterpolOptions::Linear)
+                    .alias("p50"),
+                col("x")
+                    .quantile(lit(0.75), QuantileInterpolOptions::Linear)
+                    .alias("p75"),
+                string_agg(
+                    col("s").sort(SortOptions::default().with_order_descending(true)),
+                    "|",
+                )
+                .alias("s_desc"),
+            ])
+            .sort(["grp"], Default::default()),
+    );
+
+    // the discrete percentile and the mode are values of the group (nulls ignored)
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          grp,
+          PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY x) AS d50,
+          PERCENTILE_DISC(0.8) WITHIN GROUP (ORDER BY x) AS d80,
+          MODE() WITHIN GROUP (ORDER BY x) AS mode,
+        FROM df
+        GROUP BY grp
+        ORDER BY grp
+        "#,
+        df! {
+          "grp" => ["a", "b", "c"],
+          "d50" => [Some(2), Some(5), None],
+          "d80" => [Some(7), Some(5), None],
+          "mode" => [Some(2), Some(5), None],
+        }
+        .unwrap()
+        .lazy(),
+    );
+
+    // equally frequent values are resolved by the WITHIN GROUP order
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          MODE() WITHIN GROUP (ORDER BY x) AS lo,
+          MODE() WITHIN GROUP (ORDER BY x DESC) AS hi,
+          MODE(x) AS mode_x,
+          PERCENTILE_DISC(0) WITHIN GROUP (ORDER BY x) AS d0,
+          PERCENTILE_DISC(1) WITHIN GROUP (ORDER BY x) AS d100,
+          STRING_AGG(DISTINCT s, ', ' ORDER BY s) AS s_distinct,
+        FROM df
+        WHERE x IN (1, 5)
+        "#,
+        df! {
+          "lo" => [1],
+          "hi" => [5],
+          "mode_x" => [1],
+          "d0" => [1],
+          "d100" => [5],
+          "s_distinct" => ["p, t"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+}
+
+#[test]
+fn test_aggregate_clause_errors() {
+    let mut ctx = create_agg_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT ABS(x) FILTER (WHERE x > 1) FROM df",
+            "SELECT SUM(x) FILTER (WHERE x > 1) OVER (PARTITION BY grp) FROM df",
+            "SELECT SUM(x) WITHIN GROUP (ORDER BY x) FROM df",
+            "SELECT SUM(x) IGNORE NULLS FROM df",
+            "SELECT PERCENTILE_CONT(0.5) FROM df",
+            "SELECT PERCENTILE_CONT(1.5) WITHIN GROUP (ORDER BY x) FROM df",
+            "SELECT PERCENTILE_DISC(0.5) WITHIN GROUP (ORDER BY x, s) FROM df",
+            "SELECT STRING_AGG(s) FROM df",
+        ],
+    );
+}
@@ -2,6 +2,9 @@ use polars_core::prelude::*;
 use polars_lazy::prelude::*;
 use polars_sql::*;
 
+mod common;
+use common::*;
+
 #[test]
 fn test_describe() {
     let lf = df! {
@@ -30,3 +33,168 @@ fn test_describe() {
 
     assert_eq!(actual, expected);
 }
+
+fn create_meta_ctx() -> SQLContext {
+    let sales = df! {
+      "year" => [2018],
+      "country" => ["US"],
+      "sales" => [1000.0],
+    }
+    .unwrap()
+    .lazy();
+    let regions = df! {
+      "country" => ["US"],
+      "region" => ["Americas"],
+    }
+    .unwrap()
+    .lazy();
+    create_ctx(
+        SQLDialect::Generic,
+        &[("sales", &sales), ("regions", &regions)],
+    )
+}
+
+#[test]
+fn test_describe_table() {
+    let mut ctx = create_meta_ctx();
+    for sql in [
+        "DESCRIBE sales",
+        "DESC sales",
+        "DESCRIBE SELECT * FROM sales",
+        "SHOW COLUMNS FROM sales",
+    ] {
+        assert_sql_ctx_to_polars(
+            &mut ctx,
+            sql,
+            df! {
+              "column_name" => ["year", "country", "sales"],
+              "column_type" => ["i32", "str", "f64"],
+            }
+            .unwrap()
+            .lazy(),
+        );
+    }
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "DESCRIBE SELECT country, SUM(sales) / 2 AS half FROM sales GROUP BY country",
+        df! {
+          "column_name" => ["country", "half"],
+          "column_type" => ["str", "f64"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_errors(&mut ctx, &["DESCRIBE missing", "SHOW COLUMNS FROM missing"]);
+}
+
+#[test]
+fn test_show_columns_filter() {
+    let mut ctx = create_meta_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SHOW COLUMNS FROM sales LIKE '%r%'",
+        df! {
+          "column_name" => ["year", "country"],
+          "column_type" => ["i32", "str"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SHOW COLUMNS FROM sales WHERE column_type = 'f64'",
+        df! {
+          "column_name" => ["sales"],
+          "column_type" => ["f64"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+}
+
+#[test]
+fn test_information_schema() {
+    let mut ctx = create_meta_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * FROM information_schema.tables",
+        df! {
+          "table_name" => ["regions", "sales"],
+          "table_type" => ["BASE TABLE", "BASE TABLE"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.table_name, column_name, ordinal_position, data_type
+        FROM information_schema.columns AS c
+        JOIN information_schema.tables AS t ON c.table_name = t.table_name
+        WHERE column_name <> 'year'
+        ORDER BY c.table_name, ordinal_position
+        "#,
+        df! {
+          "table_name" => ["regions", "regions", "sales", "sales"],
+          "column_name" => ["country", "region", "country", "sales"],
+          "ordinal_position" => [1i64, 2, 2, 3],
+          "data_type" => ["str", "str", "str", "f64"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+
+    // newly registered tables are reflected
+    ctx.register("empty", DataFrame::empty().lazy());
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT table_name FROM INFORMATION_SCHEMA.TABLES",
+        df! { "table_name" => ["empty", "regions", "sales"] }
+            .unwrap()
+            .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SHOW COLUMNS FROM information_schema.tables",
+        df! {
+          "column_name" => ["table_name", "table_type"],
+          "column_type" => ["str", "str"],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_errors(&mut ctx, &["SELECT * FROM information_schema.views"]);
+}
+
+#[test]
+fn test_information_schema_scope() {
+    let mut ctx = create_meta_ctx();
+
+    // an information_schema relation does not shadow a CTE of the same name
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        WITH tables AS (SELECT 'regions' AS table_name)
+        SELECT table_name FROM information_schema.tables
+        WHERE table_name IN (SELECT table_name FROM tables)
+        "#,
+        df! { "table_name" => ["regions"] }.unwrap().lazy(),
+    );
+
+    // only the schemas of the filtered tables are resolved
+    ctx.register(
+        "invalid",
+        DataFrame::empty().lazy().select([col("missing")]),
+    );
+    assert_sql_errors(&mut ctx, &["SELECT * FROM information_schema.columns"]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT column_name FROM information_schema.columns AS c
+        WHERE c.table_name IN ('regions', 'sales') AND table_name <> 'sales'
+        "#,
+        df! { "column_name" => ["country", "region"] }
+            .unwrap()
+            .lazy(),
+    );
+}
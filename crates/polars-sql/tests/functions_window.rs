@@ -0,0 +1,344 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_ops::series::{RankMethod, RankOptions};
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3, 4, 5, 6, 7],
+      "grp" => ["a", "b", "a", "a", "b", "a", "b"],
+      "ts" => [3, 1, 1, 2, 3, 2, 2],
+      "value" => [Some(10), Some(20), None, Some(40), Some(50), Some(60), Some(70)],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_window_ctx() -> SQLContext {
+    create_ctx(SQLDialect::Generic, &[("df", &create_df())])
+}
+
+fn rank(method: RankMethod, descending: bool) -> RankOptions {
+    RankOptions { method, descending }
+}
+
+fn rolling(window_size: usize) -> RollingOptionsFixedWindow {
+    RollingOptionsFixedWindow {
+        window_size,
+        min_periods: 1,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_row_number_rank() {
+    let mut ctx = create_window_ctx();
+    // the ids ascend with the row order, so ordinal ranks break ties by id
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          ROW_NUMBER() OVER (PARTITION BY grp ORDER BY ts, id) AS rn,
+          RANK() OVER (PARTITION BY grp ORDER BY ts) AS rnk,
+          DENSE_RANK() OVER (PARTITION BY grp ORDER BY ts) AS drnk,
+          ROW_NUMBER() OVER (ORDER BY ts DESC, id) AS rn_all,
+        FROM df
+        "#,
+        create_df().select([
+            col("id"),
+            col("ts")
+                .rank(rank(RankMethod::Ordinal, false), None)
+                .over([col("grp")])
+                .alias("rn"),
+            col("ts")
+                .rank(rank(RankMethod::Min, false), None)
+                .over([col("grp")])
+                .alias("rnk"),
+            col("ts")
+                .rank(rank(RankMethod::Dense, false), None)
+                .over([col("grp")])
+                .alias("drnk"),
+            (lit(0) - col("ts"))
+                .rank(rank(RankMethod::Ordinal, false), None)
+                .alias("rn_all"),
+        ]),
+    );
+    // without ORDER BY all rows of a partition are peers
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT id, RANK() OVER (PARTITION BY grp) AS rnk_unordered FROM df",
+        create_df().select([col("id"), lit(1 as IdxSize).alias("rnk_unordered")]),
+    );
+}
+
+#[test]
+fn test_ntile() {
+    let mut ctx = create_window_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          NTILE(3) OVER (ORDER BY id) AS bucket3,
+          NTILE(5) OVER (ORDER BY id) AS bucket5,
+          NTILE(10) OVER (ORDER BY id) AS bucket10,
+        FROM df
+        "#,
+        // the first `len % n` buckets have one more row
+        create_df().select([
+            col("id"),
+            lit(Series::new("bucket3", [1i64, 1, 1, 2, 2, 3, 3])),
+            lit(Series::new("bucket5", [1i64, 1, 2, 2, 3, 4, 5])),
+            col("id").cast(DataType::Int64).alias("bucket10"),
+        ]),
+    );
+}
+
+#[test]
+fn test_lag_lead() {
+    let mut ctx = create_window_ctx();
+    // the rows of each group are in (ts, id) order after sorting by ts
+    let by_ts = create_df()
+        .with_row_index("row", None)
+        .sort(["ts", "id"], Default::default());
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          LAG(value) OVER (PARTITION BY grp ORDER BY ts, id) AS prev,
+          LEAD(value, 1, -1) OVER (PARTITION BY grp ORDER BY ts, id) AS next,
+          LAG(id, 2) OVER (ORDER BY id) AS prev2,
+        FROM df
+        "#,
+        by_ts
+            .with_columns([
+                col("value").shift(lit(1)).over([col("grp")]).alias("prev"),
+                col("value")
+                    .shift_and_fill(lit(-1), lit(-1))
+                    .over([col("grp")])
+                    .alias("next"),
+            ])
+            .sort(["row"], Default::default())
+            .select([
+                col("id"),
+                col("prev"),
+                col("next"),
+                col("id").shift(lit(2)).alias("prev2"),
+            ]),
+    );
+}
+
+#[test]
+fn test_first_last_value() {
+    let mut ctx = create_window_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          FIRST_VALUE(id) OVER (PARTITION BY grp ORDER BY ts DESC) AS first_id,
+          LAST_VALUE(id) OVER (
+            PARTITION BY grp ORDER BY ts ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+          ) AS last_id,
+          FIRST_VALUE(id) OVER (ORDER BY ts, id) AS first_all,
+        FROM df
+        "#,
+        create_df().select([
+            col("id"),
+            col("id")
+                .sort_by(
+                    [col("ts")],
+                    SortMultipleOptions::default().with_order_descending(true),
+                )
+                .first()
+                .over([col("grp")])
+                .alias("first_id"),
+            col("id")
+                .sort_by(
+                    [col("ts")],
+                    SortMultipleOptions::default().with_maintain_order(true),
+                )
+                .last()
+                .over([col("grp")])
+                .alias("last_id"),
+            col("id")
+                .sort_by([col("ts"), col("id")], SortMultipleOptions::default())
+                .first()
+                .alias("first_all"),
+        ]),
+    );
+    // the default frame ends with the last peer of the current row
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT id, LAST_VALUE(id) OVER (PARTITION BY grp ORDER BY ts) AS last_peer_id FROM df",
+        create_df().select([
+            col("id"),
+            col("id")
+                .max()
+                .over([col("grp"), col("ts")])
+                .alias("last_peer_id"),
+        ]),
+    );
+}
+
+#[test]
+fn test_first_last_value_ignore_nulls() {
+    let mut ctx = create_window_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          FIRST_VALUE(value) IGNORE NULLS OVER (PARTITION BY grp ORDER BY ts, id) AS first_value,
+          LAST_VALUE(value IGNORE NULLS) OVER (
+            PARTITION BY grp ORDER BY ts DESC, id ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+          ) AS last_value,
+          LAST_VALUE(value) IGNORE NULLS OVER (PARTITION BY grp ORDER BY ts) AS last_peer_value,
+          FIRST_VALUE(value) IGNORE NULLS OVER (PARTITION BY grp) AS first_unordered,
+          FIRST_VALUE(value) IGNORE NULLS OVER (
+            ORDER BY ts, grp ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+          ) AS first_all,
+          FIRST_VALUE(value) RESPECT NULLS OVER (ORDER BY ts, grp) AS first_all_nulls,
+        FROM df
+        "#,
+        df! {
+          "id" => [1, 2, 3, 4, 5, 6, 7],
+          "first_value" => [Some(40), Some(20), None, Some(40), Some(20), Some(40), Some(20)],
+          "last_value" => [10, 20, 60, 40, 50, 60, 70],
+          "last_peer_value" => [Some(10), Some(20), None, Some(60), Some(50), Some(60), Some(70)],
+          "first_unordered" => [10, 20, 10, 10, 20, 10, 20],
+          "first_all" => [20, 20, 20, 20, 20, 20, 20],
+          "first_all_nulls" => [None::<i32>, None, None, None, None, None, None],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_errors(
+        &mut ctx,
+        &["SELECT LAG(value) IGNORE NULLS OVER (ORDER BY id) FROM df"],
+    );
+}
+
+#[test]
+fn test_window_frames() {
+    let mut ctx = create_window_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          SUM(id) OVER (ORDER BY id ROWS BETWEEN 2 PRECEDING AND CURRENT ROW) AS sum3,
+          MAX(id) OVER (PARTITION BY grp ORDER BY id ROWS BETWEEN CURRENT ROW AND 1 FOLLOWING) AS max_next,
+          MIN(id) OVER (ORDER BY id DESC ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS min_center,
+          SUM(id) OVER (PARTITION BY grp ORDER BY id ROWS UNBOUNDED PRECEDING) AS running,
+          AVG(id) OVER (ORDER BY id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS avg2,
+          SUM(id) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS total,
+        FROM df
+        "#,
+        // the rows are in id order
+        create_df().select([
+            col("id"),
+            col("id").rolling_sum(rolling(3)).alias("sum3"),
+            max_horizontal([col("id"), col("id").shift(lit(-1)).over([col("grp")])])
+                .unwrap()
+                .alias("max_next"),
+            min_horizontal([col("id").shift(lit(1)), col("id"), col("id").shift(lit(-1))])
+                .unwrap()
+                .alias("min_center"),
+            col("id").cum_sum(false).over([col("grp")]).alias("running"),
+            col("id").rolling_mean(rolling(2)).alias("avg2"),
+            col("id").sum().alias("total"),
+        ]),
+    );
+    assert_sql_errors(
+        &mut ctx,
+        &["SELECT SUM(id) OVER (ORDER BY id RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) FROM df"],
+    );
+}
+
+#[test]
+fn test_named_windows() {
+    let mut ctx = create_window_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          ROW_NUMBER() OVER w AS rn,
+          LAG(id) OVER (w2 ORDER BY id DESC) AS next_id,
+        FROM df
+        WINDOW w2 AS (PARTITION BY grp), w AS (w2 ORDER BY ts, id)
+        "#,
+        create_df().select([
+            col("id"),
+            col("ts")
+                .rank(rank(RankMethod::Ordinal, false), None)
+                .over([col("grp")])
+                .alias("rn"),
+            col("id").shift(lit(-1)).over([col("grp")]).alias("next_id"),
+        ]),
+    );
+    assert_sql_errors(&mut ctx, &["SELECT ROW_NUMBER() OVER w FROM df"]);
+}
+
+#[test]
+fn test_qualify() {
+    let mut ctx = create_window_ctx();
+    // keep the latest row per group
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id, grp, ts FROM df
+        WHERE id > 1
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY grp ORDER BY ts DESC, id) = 1
+        ORDER BY grp
+        "#,
+        create_df()
+            .filter(col("id").gt(lit(1)))
+            .sort_by_exprs(
+                [col("ts"), col("id")],
+                SortMultipleOptions::default().with_order_descending_multi([true, false]),
+            )
+            .group_by([col("grp")])
+            .agg([col("id").first(), col("ts").first()])
+            .sort(["grp"], Default::default())
+            .select([col("id"), col("grp"), col("ts")]),
+    );
+
+    // filter on a projected window column
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id, RANK() OVER (ORDER BY ts) AS rnk FROM df
+        QUALIFY rnk > 1 AND COUNT(*) OVER () = 7
+        ORDER BY id
+        "#,
+        create_df()
+            .select([
+                col("id"),
+                col("ts")
+                    .rank(rank(RankMethod::Min, false), None)
+                    .alias("rnk"),
+            ])
+            .filter(col("rnk").gt(lit(1)))
+            .sort(["id"], Default::default()),
+    );
+
+    // after aggregation
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT grp, MAX(id) AS top FROM df GROUP BY grp
+        QUALIFY RANK() OVER (ORDER BY top DESC) = 1
+        "#,
+        create_df()
+            .group_by([col("grp")])
+            .agg([col("id").max().alias("top")])
+            .filter(col("top").eq(col("top").max())),
+    );
+}
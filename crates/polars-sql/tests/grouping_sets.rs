@@ -0,0 +1,166 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "region" => ["north", "north", "south", "south", "south"],
+      "city" => ["a", "b", "c", "c", "d"],
+      "year" => [2023, 2024, 2023, 2024, 2024],
+      "amount" => [10, 20, 30, 40, 50],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_sales_ctx() -> SQLContext {
+    create_ctx(SQLDialect::Generic, &[("sales", &create_df())])
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let mut ctx = create_sales_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT region, city, SUM(amount) AS total, GROUPING(region, city) AS grp
+        FROM sales
+        GROUP BY ROLLUP(region, city)
+        ORDER BY grp, region, city
+        "#,
+        create_df()
+            .rollup([col("region"), col("city")])
+            .with_grouping_id("grp")
+            .agg([col("amount").sum().alias("total")])
+            .unwrap()
+            .sort(["grp", "region", "city"], Default::default())
+            .select([col("region"), col("city"), col("total"), col("grp")]),
+    );
+
+    // plain keys are part of every grouping set
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT year, region, COUNT(*) AS n, GROUPING(region)
+        FROM sales
+        GROUP BY year, ROLLUP(region)
+        ORDER BY year, region NULLS LAST
+        "#,
+        create_df()
+            .group_by_sets([vec![col("year"), col("region")], vec![col("year")]])
+            .with_grouping_id("grouping")
+            .agg([len().alias("n")])
+            .unwrap()
+            .sort(
+                ["year", "region"],
+                SortMultipleOptions::default().with_nulls_last(true),
+            )
+            .select([col("year"), col("region"), col("n"), col("grouping")]),
+    );
+    let df = ctx
+        .execute("SELECT GROUPING(region) FROM sales GROUP BY ROLLUP(region)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(df.column("grouping").unwrap().dtype(), &DataType::UInt32);
+}
+
+#[test]
+fn test_group_by_cube() {
+    let mut ctx = create_sales_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT region, year, SUM(amount) AS total, GROUPING(year, region) AS grp
+        FROM sales
+        GROUP BY CUBE(region, year)
+        ORDER BY grp, region, year
+        "#,
+        create_df()
+            .cube([col("year"), col("region")])
+            .unwrap()
+            .with_grouping_id("grp")
+            .agg([col("amount").sum().alias("total")])
+            .unwrap()
+            .sort(["grp", "region", "year"], Default::default())
+            .select([col("region"), col("year"), col("total"), col("grp")]),
+    );
+}
+
+#[test]
+fn test_grouping_sets() {
+    let mut ctx = create_sales_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT region, city, MAX(amount) AS max_amount, GROUPING(region, city) AS grp
+        FROM sales
+        GROUP BY GROUPING SETS ((region), (city), ())
+        ORDER BY grp, region, city
+        "#,
+        create_df()
+            .group_by_sets([vec![col("region")], vec![col("city")], vec![]])
+            .with_grouping_id("grp")
+            .agg([col("amount").max().alias("max_amount")])
+            .unwrap()
+            .sort(["grp", "region", "city"], Default::default())
+            .select([col("region"), col("city"), col("max_amount"), col("grp")]),
+    );
+}
+
+#[test]
+fn test_grouping_in_having_and_order_by() {
+    let mut ctx = create_sales_ctx();
+    let rollup = create_df()
+        .rollup([col("region"), col("city")])
+        .with_grouping_id("grp")
+        .agg([col("amount").sum().alias("total")])
+        .unwrap();
+    // GROUPING(region) is 0 in every row that is kept
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT region, city, SUM(amount) AS total
+        FROM sales
+        GROUP BY ROLLUP(region, city)
+        HAVING GROUPING(region, city) = 1
+        ORDER BY GROUPING(region), region
+        "#,
+        rollup
+            .clone()
+            .filter(col("grp").eq(lit(1u32)))
+            .sort(["region"], Default::default())
+            .select([col("region"), col("city"), col("total")]),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT region, city, SUM(amount) AS total
+        FROM sales
+        GROUP BY ROLLUP(region, city)
+        ORDER BY GROUPING(region, city) DESC, region, city
+        "#,
+        rollup
+            .sort(
+                ["grp", "region", "city"],
+                SortMultipleOptions::default().with_order_descending_multi([true, false, false]),
+            )
+            .select([col("region"), col("city"), col("total")]),
+    );
+}
+
+#[test]
+fn test_grouping_errors() {
+    let mut ctx = create_sales_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT region, GROUPING(region) FROM sales GROUP BY region",
+            "SELECT region, GROUPING(city) FROM sales GROUP BY ROLLUP(region)",
+            "SELECT region, SUM(amount) FROM sales GROUP BY ROLLUP(1)",
+        ],
+    );
+}
@@ -0,0 +1,352 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn customers() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3],
+      "name" => ["ann", "bob", "cat"],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn orders() -> LazyFrame {
+    df! {
+      "id" => [10, 11, 12, 13],
+      "customer_id" => [1, 1, 2, 4],
+      "amount" => [5.0, 15.0, 25.0, 35.0],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn tags() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3],
+      "tags" => [
+        Series::new("", ["x", "y"]),
+        Series::new("", ["z"]),
+        Series::new("", Vec::<&str>::new()),
+      ],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_join_ctx() -> SQLContext {
+    create_ctx(
+        SQLDialect::Generic,
+        &[
+            ("customers", &customers()),
+            ("orders", &orders()),
+            ("tags", &tags()),
+        ],
+    )
+}
+
+/// The customers with the number and total amount of their orders.
+fn order_totals() -> LazyFrame {
+    let totals = orders()
+        .group_by([col("customer_id")])
+        .agg([len().alias("n"), col("amount").sum().alias("total")]);
+    customers()
+        .left_join(totals, col("id"), col("customer_id"))
+        .with_column(col("n").fill_null(lit(0 as IdxSize)))
+}
+
+#[test]
+fn test_implicit_join() {
+    let mut ctx = create_join_ctx();
+    let sql = r#"
+        SELECT c.name, o.id AS order_id, o.amount
+        FROM customers c, orders o
+        WHERE c.id = o.customer_id AND o.amount > 10
+        ORDER BY order_id
+    "#;
+
+    // the cross join is planned as an equi-join
+    let plan = ctx.execute(sql).unwrap().explain(true).unwrap();
+    assert!(!plan.contains("CROSS JOIN"), "{plan}");
+
+    let orders = orders().select([
+        col("id").alias("order_id"),
+        col("customer_id"),
+        col("amount"),
+    ]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        sql,
+        customers()
+            .inner_join(orders, col("id"), col("customer_id"))
+            .filter(col("amount").gt(lit(10)))
+            .select([col("name"), col("order_id"), col("amount")])
+            .sort(["order_id"], Default::default()),
+    );
+
+    // self-join
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c1.name, c2.name AS next_name
+        FROM customers AS c1, customers AS c2
+        WHERE c1.id + 1 = c2.id
+        ORDER BY c1.name
+        "#,
+        customers()
+            .with_column((col("id") + lit(1)).alias("next_id"))
+            .inner_join(
+                customers().select([col("id"), col("name").alias("next_name")]),
+                col("next_id"),
+                col("id"),
+            )
+            .select([col("name"), col("next_name")])
+            .sort(["name"], Default::default()),
+    );
+}
+
+#[test]
+fn test_implicit_join_three_tables() {
+    let mut ctx = create_join_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.name, o.amount, t.tags
+        FROM customers c, orders o, tags t
+        WHERE o.customer_id = c.id AND t.id = c.id AND o.amount < 20
+        ORDER BY o.amount
+        "#,
+        customers()
+            .inner_join(
+                orders().select([col("customer_id"), col("amount")]),
+                col("id"),
+                col("customer_id"),
+            )
+            .inner_join(tags(), col("id"), col("id"))
+            .filter(col("amount").lt(lit(20)))
+            .select([col("name"), col("amount"), col("tags")])
+            .sort(["amount"], Default::default()),
+    );
+
+    // without a join predicate this is a cross join
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * FROM customers c, orders o",
+        customers().cross_join(orders(), Some(":o".to_string())),
+    );
+}
+
+#[test]
+fn test_implicit_range_join() {
+    let bands = df! {
+      "band" => ["low", "mid", "high"],
+      "lo" => [0.0, 10.0, 20.0],
+      "hi" => [10.0, 30.0, 40.0],
+    }
+    .unwrap()
+    .lazy();
+    let mut ctx = create_ctx(
+        SQLDialect::Generic,
+        &[("orders", &orders()), ("bands", &bands)],
+    );
+    let sql = r#"
+        SELECT o.id, b.band
+        FROM orders o, bands b
+        WHERE b.lo <= o.amount AND o.amount < b.hi
+        ORDER BY o.id, b.band
+    "#;
+
+    // the cross join is planned as an inequality join
+    let plan = ctx.execute(sql).unwrap().explain(true).unwrap();
+    assert!(plan.contains("IEJOIN"), "{plan}");
+
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        sql,
+        orders()
+            .cross_join(bands, None)
+            .filter(
+                col("lo")
+                    .lt_eq(col("amount"))
+                    .and(col("amount").lt(col("hi"))),
+            )
+            .select([col("id"), col("band")])
+            .sort(["id", "band"], Default::default()),
+    );
+}
+
+#[test]
+fn test_lateral_subquery() {
+    let mut ctx = create_join_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.name, big.id AS order_id
+        FROM customers c,
+             LATERAL (SELECT id FROM orders o WHERE o.customer_id = c.id AND o.amount > 10) big
+        ORDER BY order_id
+        "#,
+        customers()
+            .inner_join(
+                orders()
+                    .filter(col("amount").gt(lit(10)))
+                    .select([col("id").alias("order_id"), col("customer_id")]),
+                col("id"),
+                col("customer_id"),
+            )
+            .select([col("name"), col("order_id")])
+            .sort(["order_id"], Default::default()),
+    );
+
+    // an aggregating subquery has a row for every row of the preceding table
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.name, s.n, s.total
+        FROM customers c
+        CROSS JOIN LATERAL (
+            SELECT COUNT(*) AS n, SUM(amount) AS total FROM orders WHERE customer_id = c.id
+        ) s
+        ORDER BY c.name
+        "#,
+        order_totals()
+            .select([col("name"), col("n"), col("total")])
+            .sort(["name"], Default::default()),
+    );
+
+    // LEFT JOIN LATERAL keeps the rows without a match
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.name, o.amount
+        FROM customers c
+        LEFT JOIN LATERAL (SELECT amount FROM orders WHERE customer_id = c.id) o ON TRUE
+        ORDER BY c.name, o.amount
+        "#,
+        customers()
+            .left_join(
+                orders().select([col("customer_id"), col("amount")]),
+                col("id"),
+                col("customer_id"),
+            )
+            .select([col("name"), col("amount")])
+            .sort(["name", "amount"], Default::default()),
+    );
+
+    // an uncorrelated LATERAL subquery is an ordinary derived table
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT COUNT(*) AS n FROM customers, LATERAL (SELECT 1 AS one) t",
+        customers().select([len().alias("n")]),
+    );
+}
+
+#[test]
+fn test_unnest_with_ordinality() {
+    let mut ctx = create_join_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * FROM UNNEST(['a', 'b', 'c']) WITH ORDINALITY AS t(letter, n)",
+        df! {
+          "letter" => ["a", "b", "c"],
+          "n" => [1i64, 2, 3],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * FROM UNNEST([10, 20]) AS t(v) WITH OFFSET",
+        df! {
+          "v" => [10, 20],
+          "offset" => [0i64, 1],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT * FROM UNNEST([10, 20]) AS t(v) WITH OFFSET AS pos",
+        df! {
+          "v" => [10, 20],
+          "pos" => [0i64, 1],
+        }
+        .unwrap()
+        .lazy(),
+    );
+
+    // a quoted offset alias is not mistaken for `WITH ORDINALITY`
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"SELECT * FROM UNNEST([10, 20]) AS t(v) WITH OFFSET AS "WITH ORDINALITY""#,
+        df! {
+          "v" => [10, 20],
+          "WITH ORDINALITY" => [0i64, 1],
+        }
+        .unwrap()
+        .lazy(),
+    );
+
+    // unnesting a column of the preceding table
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.name, u.tag, u.n
+        FROM customers c
+        JOIN tags t ON t.id = c.id
+        CROSS JOIN UNNEST(t.tags) WITH ORDINALITY AS u(tag, n)
+        ORDER BY c.name, u.n
+        "#,
+        df! {
+          "name" => ["ann", "ann", "bob"],
+          "tag" => ["x", "y", "z"],
+          "n" => [1i64, 2, 1],
+        }
+        .unwrap()
+        .lazy(),
+    );
+}
+
+#[test]
+fn test_trailing_commas() {
+    let mut ctx = create_join_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT id, name, FROM customers ORDER BY id",
+        customers()
+            .select([col("id"), col("name")])
+            .sort(["id"], Default::default()),
+    );
+
+    // the comma before LATERAL separates the FROM items
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT c.id, s.n
+        FROM customers c, LATERAL (SELECT COUNT(*) AS n FROM orders o WHERE o.customer_id = c.id) s
+        ORDER BY c.id
+        "#,
+        order_totals()
+            .select([col("id"), col("n")])
+            .sort(["id"], Default::default()),
+    );
+}
+
+#[test]
+fn test_lateral_errors() {
+    let mut ctx = create_join_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            // only equality predicates can correlate the subquery
+            "SELECT * FROM customers c, LATERAL (SELECT id FROM orders o WHERE o.customer_id > c.id) s",
+            "SELECT * FROM customers c FULL JOIN LATERAL (SELECT id FROM orders o WHERE o.customer_id = c.id) s ON TRUE",
+            "SELECT * FROM tags t, UNNEST(t.tags) WITH ORDINALITY AS u(tag)",
+            "SELECT * FROM UNNEST([1, 2, 3]) WITH ORDINALITY AS t(x)",
+            "SELECT * FROM customers c, UNNEST(c.name) AS u(x)",
+        ],
+    );
+}
@@ -0,0 +1,147 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn employees() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "name" => ["ann", "bob", "cat", "dan", "eve", "fay"],
+      "manager_id" => [None, Some(1), Some(1), Some(2), Some(4), Some(3)],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_cte_ctx() -> SQLContext {
+    // a cyclic graph: 1 -> 2 -> 3 -> 1, 3 -> 4
+    let edges = df! {
+      "src" => [1, 2, 3, 3],
+      "dst" => [2, 3, 1, 4],
+    }
+    .unwrap()
+    .lazy();
+    create_ctx(
+        SQLDialect::Generic,
+        &[("employees", &employees()), ("edges", &edges)],
+    )
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let mut ctx = create_cte_ctx();
+    // the direct reports of the given employees, one level at a time
+    let reports = |managers: LazyFrame, depth: i32| {
+        employees()
+            .inner_join(
+                managers.select([col("id").alias("manager")]),
+                col("manager_id"),
+                col("manager"),
+            )
+            .select([col("id"), col("name"), lit(depth).alias("depth")])
+    };
+    let root = employees().filter(col("manager_id").is_null());
+    let level1 = reports(root, 1);
+    let level2 = reports(level1.clone(), 2);
+    let level3 = reports(level2.clone(), 3);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        WITH RECURSIVE reports(id, name, depth) AS (
+            SELECT id, name, 0 FROM employees WHERE manager_id IS NULL
+            UNION ALL
+            SELECT e.id, e.name, r.depth + 1
+            FROM employees e JOIN reports r ON e.manager_id = r.id
+        )
+        SELECT name, depth FROM reports WHERE depth > 0 ORDER BY depth, name
+        "#,
+        concat([level1, level2, level3], UnionArgs::default())
+            .unwrap()
+            .sort(["depth", "name"], Default::default())
+            .select([col("name"), col("depth")]),
+    );
+}
+
+#[test]
+fn test_recursive_cte_series() {
+    let mut ctx = create_cte_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        WITH RECURSIVE seq AS (
+            SELECT 1 AS n
+            UNION ALL
+            SELECT n + 1 FROM seq WHERE n < 5
+        ),
+        squares AS (SELECT n, n * n AS sq FROM seq)
+        SELECT SUM(n) AS n, SUM(sq) AS sq FROM squares
+        "#,
+        DataFrame::empty()
+            .lazy()
+            .select([int_range(lit(1), lit(6), 1, DataType::Int32).alias("n")])
+            .select([col("n").sum(), (col("n") * col("n")).sum().alias("sq")]),
+    );
+}
+
+#[test]
+fn test_recursive_cte_cycles() {
+    // UNION does not revisit rows, so the cycle terminates
+    let mut ctx = create_cte_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        WITH RECURSIVE reachable(node) AS (
+            SELECT 1
+            UNION
+            SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+        )
+        SELECT node FROM reachable ORDER BY node
+        "#,
+        df! { "node" => [1, 2, 3, 4] }.unwrap().lazy(),
+    );
+
+    // UNION ALL keeps following the cycle until the recursion limit
+    let mut ctx = create_cte_ctx().with_recursion_limit(50);
+    let res = ctx
+        .execute(
+            r#"
+            WITH RECURSIVE walk(node) AS (
+                SELECT 1
+                UNION ALL
+                SELECT e.dst FROM edges e JOIN walk w ON e.src = w.node
+            )
+            SELECT * FROM walk
+            "#,
+        )
+        .and_then(|lf| lf.collect());
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("did not finish within 50 iterations"), "{err}");
+}
+
+#[test]
+fn test_recursive_without_self_reference() {
+    let mut ctx = create_cte_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT 2 AS n)
+        SELECT * FROM t ORDER BY n
+        "#,
+        df! { "n" => [1, 2] }.unwrap().lazy(),
+    );
+}
+
+#[test]
+fn test_recursive_cte_errors() {
+    let mut ctx = create_cte_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            // column count mismatch between the anchor and the recursive term
+            "WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT n, n + 1 AS m FROM t) SELECT * FROM t",
+            "WITH RECURSIVE t AS (SELECT 1 AS n UNION ALL SELECT n + 1 FROM t LIMIT 3) SELECT * FROM t",
+        ],
+    );
+}
@@ -3,6 +3,9 @@ use polars_lazy::prelude::*;
 use polars_sql::*;
 use polars_time::Duration;
 
+mod common;
+use common::assert_sql_to_polars;
+
 fn create_sample_df() -> DataFrame {
     let a = Series::new("a", (1..10000i64).map(|i| i / 100).collect::<Vec<_>>());
     let b = Series::new("b", 1..10000i64);
@@ -28,14 +31,6 @@ fn create_struct_df() -> (DataFrame, DataFrame) {
     )
 }
 
-fn assert_sql_to_polars(df: &DataFrame, sql: &str, f: impl FnOnce(LazyFrame) -> LazyFrame) {
-    let mut context = SQLContext::new();
-    context.register("df", df.clone().lazy());
-    let df_sql = context.execute(sql).unwrap().collect().unwrap();
-    let df_pl = f(df.clone().lazy()).collect().unwrap();
-    assert!(df_sql.equals(&df_pl));
-}
-
 #[test]
 fn test_simple_select() -> PolarsResult<()> {
     let df = create_sample_df();
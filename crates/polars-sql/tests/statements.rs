@@ -388,7 +388,7 @@ fn test_resolve_join_column_select_13618() {
     ctx.register("tbl", df1.lazy());
     ctx.register("other", df2.lazy());
 
-    let join_types = vec!["LEFT", "INNER", "FULL OUTER", ""];
+    let join_types = vec!["LEFT", "RIGHT", "INNER", "FULL OUTER", ""];
     for join_type in join_types {
         let sql = format!(
             r#"
@@ -525,6 +525,36 @@ fn test_join_on_different_keys() {
     );
 }
 
+#[test]
+fn test_right_join() {
+    let df1 = df! {"id" => [1, 2, 4], "x" => ["a", "b", "c"]}.unwrap();
+    let df2 = df! {"id" => [2, 3, 4, 2], "y" => [1, 2, 3, 4]}.unwrap();
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df1", df1.lazy());
+    ctx.register("df2", df2.lazy());
+
+    // the rows of df2 keep their order
+    let sql = r#"
+        SELECT df1.x, df2.id AS rid, df2.y
+        FROM df1
+        RIGHT JOIN df2 ON df1.id = df2.id
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "x" => [Some("b"), None, Some("c"), Some("b")],
+        "rid" => [2, 3, 4, 2],
+        "y" => [1, 2, 3, 4],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected = {:?}\nactual={:?}",
+        expected,
+        actual
+    );
+}
+
 #[test]
 fn test_join_utf8() {
     // (色) color and (野菜) vegetable
@@ -0,0 +1,251 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn inventory() -> LazyFrame {
+    df! {
+      "item" => ["apple", "banana", "cherry", "date"],
+      "qty" => [Some(10), Some(3), None, Some(8)],
+      "price" => [0.5, 0.25, 3.0, 2.0],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn deliveries() -> LazyFrame {
+    df! {
+      "item" => ["banana", "date", "elderberry", "fig"],
+      "qty" => [5, 0, 12, 4],
+      "price" => [0.3, 2.5, 4.0, 1.0],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_dml_ctx() -> SQLContext {
+    create_ctx(
+        SQLDialect::Generic,
+        &[("inventory", &inventory()), ("deliveries", &deliveries())],
+    )
+}
+
+/// The items of the (filtered) deliveries, for use with `is_in`.
+fn delivered_items(deliveries: LazyFrame) -> Expr {
+    let df = deliveries.select([col("item")]).collect().unwrap();
+    lit(df.column("item").unwrap().clone())
+}
+
+/// Executes the DML statement and asserts that the inventory then equals `expected`.
+fn assert_dml(ctx: &mut SQLContext, sql: &str, expected: LazyFrame) {
+    ctx.execute(sql).unwrap().collect().unwrap();
+    let df = ctx
+        .execute("SELECT * FROM inventory")
+        .unwrap()
+        .collect()
+        .unwrap();
+    // the registered table keeps its schema
+    assert_eq!(
+        df.dtypes(),
+        &[DataType::String, DataType::Int32, DataType::Float64],
+        "{sql}"
+    );
+    assert_sql_ctx_to_polars(ctx, "SELECT * FROM inventory", expected);
+}
+
+#[test]
+fn test_insert() {
+    let mut ctx = create_dml_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "INSERT INTO inventory VALUES ('fig', 7, 1.5), ('grape', NULL, 0.75)",
+        df! { "Response" => ["INSERT"] }.unwrap().lazy(),
+    );
+    let values = df! {
+      "item" => ["fig", "grape"],
+      "qty" => [Some(7), None],
+      "price" => [1.5, 0.75],
+    }
+    .unwrap()
+    .lazy();
+    assert_dml(
+        &mut ctx,
+        "INSERT INTO inventory (qty, item) SELECT qty * 2, item FROM deliveries WHERE qty > 10",
+        concat(
+            [
+                inventory(),
+                values,
+                deliveries().filter(col("qty").gt(lit(10))).select([
+                    col("item"),
+                    col("qty") * lit(2),
+                    lit(NULL).cast(DataType::Float64).alias("price"),
+                ]),
+            ],
+            UnionArgs::default(),
+        )
+        .unwrap(),
+    );
+    assert_dml(
+        &mut ctx,
+        "INSERT OVERWRITE TABLE inventory SELECT * FROM deliveries WHERE qty = 0",
+        deliveries().filter(col("qty").eq(lit(0))),
+    );
+}
+
+#[test]
+fn test_update() {
+    let mut ctx = create_dml_ctx();
+    let updated = inventory().with_columns([
+        when(col("qty").lt(lit(9)))
+            .then(col("qty") + lit(1))
+            .otherwise(col("qty"))
+            .alias("qty"),
+        when(col("qty").lt(lit(9)))
+            .then(col("price") * lit(2))
+            .otherwise(col("price"))
+            .alias("price"),
+    ]);
+    assert_dml(
+        &mut ctx,
+        "UPDATE inventory SET qty = qty + 1, price = price * 2 WHERE qty < 9",
+        updated.clone(),
+    );
+
+    // assignments are evaluated against the original row
+    assert_dml(
+        &mut ctx,
+        "UPDATE inventory SET qty = 0, price = qty",
+        updated.with_columns([
+            lit(0).alias("qty"),
+            col("qty").cast(DataType::Float64).alias("price"),
+        ]),
+    );
+}
+
+#[test]
+fn test_update_with_subqueries() {
+    let mut ctx = create_dml_ctx();
+    let updated = inventory()
+        .left_join(
+            deliveries().select([col("item"), col("price").alias("new_price")]),
+            col("item"),
+            col("item"),
+        )
+        .select([
+            col("item"),
+            col("qty"),
+            col("new_price").fill_null(col("price")).alias("price"),
+        ]);
+    assert_dml(
+        &mut ctx,
+        r#"
+        UPDATE inventory
+        SET price = (SELECT d.price FROM deliveries d WHERE d.item = inventory.item)
+        WHERE EXISTS (SELECT 1 FROM deliveries d WHERE d.item = inventory.item)
+        "#,
+        updated.clone(),
+    );
+    assert_dml(
+        &mut ctx,
+        "UPDATE inventory SET qty = -1 WHERE price > (SELECT AVG(price) FROM inventory)",
+        updated.with_column(
+            when(col("price").gt(col("price").mean()))
+                .then(lit(-1))
+                .otherwise(col("qty"))
+                .alias("qty"),
+        ),
+    );
+}
+
+#[test]
+fn test_delete() {
+    let mut ctx = create_dml_ctx();
+    // rows with a null predicate are not deleted
+    let kept = inventory().filter(col("qty").lt(lit(5)).fill_null(lit(false)).not());
+    assert_dml(
+        &mut ctx,
+        "DELETE FROM inventory WHERE qty < 5",
+        kept.clone(),
+    );
+    let kept = kept.filter(col("item").is_in(delivered_items(deliveries())).not());
+    assert_dml(
+        &mut ctx,
+        "DELETE FROM inventory WHERE item IN (SELECT item FROM deliveries)",
+        kept.clone(),
+    );
+    assert_dml(&mut ctx, "DELETE FROM inventory", kept.limit(0));
+}
+
+#[test]
+fn test_merge() {
+    let mut ctx = create_dml_ctx();
+    assert_dml(
+        &mut ctx,
+        r#"
+        MERGE INTO inventory t
+        USING deliveries d ON t.item = d.item
+        WHEN MATCHED AND d.qty = 0 THEN DELETE
+        WHEN MATCHED THEN UPDATE SET qty = t.qty + d.qty, price = d.price
+        WHEN NOT MATCHED AND d.qty > 10 THEN INSERT (item, qty) VALUES (d.item, d.qty)
+        WHEN NOT MATCHED THEN INSERT VALUES (d.item, 0, d.price)
+        "#,
+        df! {
+          "item" => ["apple", "banana", "cherry", "elderberry", "fig"],
+          "qty" => [Some(10), Some(8), None, Some(12), Some(0)],
+          "price" => [Some(0.5), Some(0.3), Some(3.0), None, Some(1.0)],
+        }
+        .unwrap()
+        .lazy(),
+    );
+
+    let mut ctx = create_dml_ctx();
+    let matched = col("item").is_in(delivered_items(deliveries().filter(col("qty").gt(lit(0)))));
+    assert_dml(
+        &mut ctx,
+        r#"
+        MERGE INTO inventory
+        USING (SELECT item FROM deliveries WHERE qty > 0) AS d ON inventory.item = d.item
+        WHEN NOT MATCHED BY SOURCE AND qty IS NOT NULL THEN UPDATE SET qty = 0
+        "#,
+        inventory().with_column(
+            when(matched.not().and(col("qty").is_not_null()))
+                .then(lit(0))
+                .otherwise(col("qty"))
+                .alias("qty"),
+        ),
+    );
+}
+
+#[test]
+fn test_dml_errors() {
+    let mut ctx = create_dml_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "INSERT INTO missing VALUES (1)",
+            "INSERT INTO inventory VALUES ('fig', 1)",
+            "INSERT INTO inventory (item, item) VALUES ('fig', 'fig')",
+            "UPDATE inventory SET missing = 1",
+            "UPDATE inventory SET qty = 1, qty = 2",
+            "DELETE FROM inventory, deliveries",
+            "MERGE INTO inventory t USING deliveries d ON t.item = d.item WHEN NOT MATCHED THEN DELETE",
+        ],
+    );
+
+    // a target row may not be matched by more than one source row
+    ctx.execute("INSERT INTO deliveries VALUES ('banana', 1, 0.1)")
+        .and_then(|lf| lf.collect())
+        .unwrap();
+    let res = ctx
+        .execute(
+            r#"
+            MERGE INTO inventory t USING deliveries d ON t.item = d.item
+            WHEN MATCHED THEN UPDATE SET qty = d.qty
+            "#,
+        )
+        .and_then(|_| ctx.execute("SELECT * FROM inventory"))
+        .and_then(|lf| lf.collect());
+    assert!(res.is_err());
+}
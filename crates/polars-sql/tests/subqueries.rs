@@ -0,0 +1,273 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+mod common;
+use common::*;
+
+fn customers() -> LazyFrame {
+    df! {
+      "id" => [1, 2, 3, 4],
+      "name" => ["alice", "bob", "carol", "dave"],
+      "region" => ["north", "south", "north", "south"],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn orders() -> LazyFrame {
+    df! {
+      "oid" => [10, 11, 12, 13, 14],
+      "cust_id" => [1, 1, 2, 4, 4],
+      "amount" => [100, 50, 200, 75, 25],
+      "region" => ["north", "south", "north", "south", "north"],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn create_subquery_ctx() -> SQLContext {
+    create_ctx(
+        SQLDialect::Generic,
+        &[("customers", &customers()), ("orders", &orders())],
+    )
+}
+
+/// Left joins the customers to the distinct keys of `orders`, marking the customers with a
+/// matching order in a "matched" column (null if there is none).
+fn match_orders(orders: LazyFrame, left_on: &[&str], right_on: &[&str]) -> LazyFrame {
+    let right_on: Vec<Expr> = right_on.iter().map(|c| col(c)).collect();
+    let matches = orders
+        .select(right_on.clone())
+        .unique(None, UniqueKeepStrategy::Any)
+        .with_column(lit(true).alias("matched"));
+    customers().join(
+        matches,
+        left_on.iter().map(|c| col(c)).collect::<Vec<_>>(),
+        right_on,
+        JoinArgs::new(JoinType::Left),
+    )
+}
+
+#[test]
+fn test_correlated_exists() {
+    let mut ctx = create_subquery_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id FROM customers c
+        WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id)
+        ORDER BY id
+        "#,
+        match_orders(orders(), &["id"], &["cust_id"])
+            .filter(col("matched").is_not_null())
+            .select([col("id")])
+            .sort(["id"], Default::default()),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id, name FROM customers c
+        WHERE NOT EXISTS (SELECT * FROM orders o WHERE o.cust_id = c.id AND o.amount > 60)
+        ORDER BY id
+        "#,
+        match_orders(
+            orders().filter(col("amount").gt(lit(60))),
+            &["id"],
+            &["cust_id"],
+        )
+        .filter(col("matched").is_null())
+        .select([col("id"), col("name")])
+        .sort(["id"], Default::default()),
+    );
+
+    // not a top-level conjunct (evaluated from a left join)
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT * FROM customers c
+        WHERE id = 3 OR EXISTS (SELECT 1 FROM orders o WHERE c.id = o.cust_id AND amount > 150)
+        ORDER BY id
+        "#,
+        match_orders(
+            orders().filter(col("amount").gt(lit(150))),
+            &["id"],
+            &["cust_id"],
+        )
+        .filter(col("id").eq(lit(3)).or(col("matched").is_not_null()))
+        .select([col("id"), col("name"), col("region")])
+        .sort(["id"], Default::default()),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id, EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id) AS has_orders
+        FROM customers c
+        ORDER BY id
+        "#,
+        match_orders(orders(), &["id"], &["cust_id"])
+            .select([col("id"), col("matched").is_not_null().alias("has_orders")])
+            .sort(["id"], Default::default()),
+    );
+}
+
+#[test]
+fn test_correlated_in() {
+    let mut ctx = create_subquery_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id FROM customers c
+        WHERE region IN (SELECT o.region FROM orders o WHERE o.cust_id = c.id)
+        ORDER BY id
+        "#,
+        match_orders(orders(), &["id", "region"], &["cust_id", "region"])
+            .filter(col("matched").is_not_null())
+            .select([col("id")])
+            .sort(["id"], Default::default()),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id FROM customers c
+        WHERE region NOT IN (SELECT o.region FROM orders o WHERE o.cust_id = c.id)
+        ORDER BY id
+        "#,
+        match_orders(orders(), &["id", "region"], &["cust_id", "region"])
+            .filter(col("matched").is_null())
+            .select([col("id")])
+            .sort(["id"], Default::default()),
+    );
+
+    // NOT IN is NULL if the value is NULL or the subquery returns a NULL (that the
+    // value doesn't equal), unless the subquery returns no rows
+    let tags = df! {
+      "cust_id" => [1, 1, 2, 4],
+      "region" => [Some("south"), None, Some("north"), Some("south")],
+    }
+    .unwrap();
+    ctx.register("tags", tags.lazy());
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          region NOT IN (SELECT t.region FROM tags t WHERE t.cust_id = c.id) AS not_in,
+          NULLIF(region, 'south') NOT IN (SELECT t.region FROM tags t WHERE t.cust_id = c.id) AS null_not_in,
+        FROM customers c
+        ORDER BY id
+        "#,
+        df! {
+          "id" => [1, 2, 3, 4],
+          "not_in" => [None, Some(true), Some(true), Some(false)],
+          "null_not_in" => [None, None, Some(true), None],
+        }
+        .unwrap()
+        .lazy(),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT id FROM customers c
+        WHERE region NOT IN (SELECT t.region FROM tags t WHERE t.cust_id = c.id)
+        ORDER BY id
+        "#,
+        customers()
+            .filter(col("id").is_in(lit(Series::new("", [2, 3]))))
+            .select([col("id")]),
+    );
+}
+
+#[test]
+fn test_correlated_scalar_subquery() {
+    let mut ctx = create_subquery_ctx();
+    let totals = orders()
+        .group_by([col("cust_id")])
+        .agg([col("amount").sum().alias("total"), len().alias("n_orders")]);
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT
+          id,
+          (SELECT SUM(amount) FROM orders o WHERE o.cust_id = c.id) AS total,
+          (SELECT COUNT(*) FROM orders o WHERE o.cust_id = c.id) AS n_orders,
+        FROM customers c
+        ORDER BY id
+        "#,
+        customers()
+            .left_join(totals, col("id"), col("cust_id"))
+            .select([
+                col("id"),
+                col("total"),
+                col("n_orders").fill_null(lit(0 as IdxSize)),
+            ])
+            .sort(["id"], Default::default()),
+    );
+
+    // orders above their customer's average order amount
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT oid FROM orders o
+        WHERE amount > (SELECT AVG(amount) FROM orders o2 WHERE o2.cust_id = o.cust_id)
+        ORDER BY oid
+        "#,
+        orders()
+            .filter(col("amount").gt(col("amount").mean().over([col("cust_id")])))
+            .select([col("oid")])
+            .sort(["oid"], Default::default()),
+    );
+
+    // more than one row per outer row
+    let res = ctx
+        .execute("SELECT id, (SELECT oid FROM orders o WHERE o.cust_id = c.id) FROM customers c")
+        .and_then(|lf| lf.collect());
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_scalar_subquery() {
+    let mut ctx = create_subquery_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        r#"
+        SELECT oid, amount - (SELECT MIN(amount) FROM orders) AS diff FROM orders
+        WHERE amount > (SELECT AVG(amount) FROM orders)
+        ORDER BY oid
+        "#,
+        orders()
+            .with_column((col("amount") - col("amount").min()).alias("diff"))
+            .filter(col("amount").gt(col("amount").mean()))
+            .select([col("oid"), col("diff")])
+            .sort(["oid"], Default::default()),
+    );
+}
+
+#[test]
+fn test_uncorrelated_exists() {
+    let mut ctx = create_subquery_ctx();
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT id FROM customers WHERE EXISTS (SELECT 1 FROM orders WHERE amount > 1000)",
+        customers().select([col("id")]).limit(0),
+    );
+    assert_sql_ctx_to_polars(
+        &mut ctx,
+        "SELECT id FROM customers WHERE NOT EXISTS (SELECT 1 FROM orders WHERE amount > 1000)",
+        customers().select([col("id")]),
+    );
+}
+
+#[test]
+fn test_unsupported_correlated_subquery() {
+    let mut ctx = create_subquery_ctx();
+    assert_sql_errors(
+        &mut ctx,
+        &[
+            "SELECT id FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.amount > c.id)",
+            "SELECT id FROM customers c WHERE EXISTS (SELECT c.name FROM orders o WHERE o.cust_id = c.id)",
+            "SELECT id FROM customers c WHERE EXISTS (SELECT 1 FROM orders o WHERE o.cust_id = c.id LIMIT 1)",
+            "SELECT id, (SELECT COUNT(*) + 1 FROM orders o WHERE o.cust_id = c.id) FROM customers c",
+        ],
+    );
+}
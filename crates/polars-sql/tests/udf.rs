@@ -97,3 +97,99 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_udf_registry() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register(
+        "sales",
+        df! {
+          "region" => ["north", "north", "south", "south", "south"],
+          "amount" => [10, 20, 30, 40, 50],
+        }?
+        .lazy(),
+    );
+
+    // scalar UDF with a different output type
+    let halve = UserDefinedFunction::new(
+        "halve",
+        vec![Field::new("amount", DataType::Int32)],
+        GetOutput::from_type(DataType::Float64),
+        |s: &mut [Series]| Ok(Some(s[0].cast(&DataType::Float64)? / 2.0)),
+    );
+    // aggregate UDF returning a single value per group
+    let spread = UserDefinedFunction::new(
+        "spread",
+        vec![Field::new("amount", DataType::Int32)],
+        GetOutput::same_type(),
+        |s: &mut [Series]| {
+            let s = &s[0];
+            let spread =
+                s.max_reduce()?.into_series(s.name()) - s.min_reduce()?.into_series(s.name());
+            spread.map(Some)
+        },
+    );
+    ctx.registry_mut().register("Halve", halve)?;
+    ctx.registry_mut().register_aggregate("spread", spread)?;
+
+    let df = ctx
+        .execute("SELECT region, HALVE(amount) AS half FROM sales ORDER BY half DESC LIMIT 2")?
+        .collect()?;
+    let expected = df! {
+      "region" => ["south", "south"],
+      "half" => [25.0, 20.0],
+    }?;
+    assert!(df.equals(&expected), "{df}");
+
+    let df = ctx
+        .execute(
+            r#"
+            SELECT region, spread(amount) AS spread, SUM(amount) AS total
+            FROM sales GROUP BY region ORDER BY region
+            "#,
+        )?
+        .collect()?;
+    let expected = df! {
+      "region" => ["north", "south"],
+      "spread" => [10, 20],
+      "total" => [30, 120],
+    }?;
+    assert!(df.equals(&expected), "{df}");
+
+    let df = ctx
+        .execute("SELECT spread(amount) AS spread FROM sales")?
+        .collect()?;
+    assert!(df.equals(&df! { "spread" => [40] }?), "{df}");
+
+    let df = ctx
+        .execute("SELECT amount, spread(amount) OVER (PARTITION BY region) AS s FROM sales")?
+        .collect()?;
+    let expected = df! {
+      "amount" => [10, 20, 30, 40, 50],
+      "s" => [10, 10, 20, 20, 20],
+    }?;
+    assert!(df.equals(&expected), "{df}");
+
+    assert!(ctx.execute("SELECT unknown_fn(amount) FROM sales").is_err());
+    assert!(ctx.execute("SELECT halve(amount, 1) FROM sales").is_err());
+    Ok(())
+}
+
+#[cfg(feature = "ffi_plugin")]
+#[test]
+fn test_plugin_registry() -> PolarsResult<()> {
+    use polars_sql::function_registry::PluginFunction;
+
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df! { "a" => [1, 2, 3] }?.lazy());
+    let plugin = unsafe { PluginFunction::new("libmy_plugin.so", "pig_latin") }.elementwise(true);
+    ctx.registry_mut().register_plugin("pig_latin", plugin)?;
+
+    // the name resolves to the plugin, whose library is loaded to resolve the output type
+    let Err(err) = ctx.execute("SELECT PIG_LATIN(a) AS b FROM df") else {
+        panic!("expected the plugin library to be loaded")
+    };
+    let err = err.to_string();
+    assert!(err.contains("libmy_plugin.so"), "{err}");
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
+#[cfg(feature = "rolling_window_by")]
 use polars_ops::series::SeriesMethods;
 
 use super::*;
+#[cfg(feature = "rolling_window_by")]
 use crate::prelude::*;
 use crate::series::AsSeries;
 
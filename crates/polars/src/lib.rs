@@ -206,6 +206,10 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `orc` - Read and write Apache ORC format
+//!     - `flight` - Exchange DataFrames with Arrow Flight services
+//!     - `xlsx` - Read and write Excel (xlsx) files
+//!     - `fwf` - Read fixed-width text files
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!                      Supported compressions:
 //!                         * zip
@@ -224,6 +228,8 @@
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
+//!     - `iejoin` - Inequality joins, to join on inequalities (such as ranges) instead of equality.
+//!     - `interval_ops` - Join, cluster, merge, intersect and subtract `[start, end)` intervals.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
 //!     - `row_hash` - Utility to hash [`DataFrame`] rows to [`UInt64Chunked`]
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
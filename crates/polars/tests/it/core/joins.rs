@@ -112,6 +112,62 @@ fn test_left_join() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_right_join() -> PolarsResult<()> {
+    let (temp, rain) = create_frames();
+
+    // the rows of the right table keep their order, the columns of the left table come
+    // first and the keys of the right table are kept
+    let out = temp.right_join(&rain, ["days"], ["days"])?;
+    let expected = df![
+        "temp" => [Some(19.9), Some(7.), None, Some(19.9)],
+        "rain" => [Some(0.1), Some(0.3), None, Some(0.1)],
+        "days" => [1, 2, 3, 1],
+        "rain_right" => [0.1, 0.2, 0.3, 0.4],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+
+    let out = temp.join(
+        &rain,
+        ["days"],
+        ["days"],
+        JoinArgs::new(JoinType::Right).with_coalesce(JoinCoalesce::KeepColumns),
+    )?;
+    assert_eq!(
+        out.get_column_names(),
+        &["days", "temp", "rain", "days_right", "rain_right"]
+    );
+    assert_eq!(out.column("days")?.null_count(), 1);
+
+    // multiple keys
+    let left = df![
+        "a" => [1, 1, 2],
+        "b" => ["x", "y", "x"],
+        "l" => [10, 20, 30],
+    ]?;
+    let right = df![
+        "r" => [0, 1, 2],
+        "b" => ["x", "x", "z"],
+        "a" => [2, 1, 1],
+    ]?;
+    let out = left.right_join(&right, ["a", "b"], ["a", "b"])?;
+    let expected = df![
+        "l" => [Some(30), Some(10), None],
+        "r" => [0, 1, 2],
+        "b" => ["x", "x", "z"],
+        "a" => [2, 1, 1],
+    ]?;
+    assert!(out.equals_missing(&expected), "{out}");
+
+    // a right join is a left join with the tables swapped
+    let swapped = right.left_join(&left, ["a", "b"], ["a", "b"])?;
+    assert!(out
+        .select(swapped.get_column_names())?
+        .equals_missing(&swapped));
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_full_outer_join() -> PolarsResult<()> {
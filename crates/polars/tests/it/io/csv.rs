@@ -1391,3 +1391,121 @@ fn test_read_io_reader() {
     let expected = CsvReader::new(file).finish().unwrap();
     assert!(df.equals(&expected))
 }
+
+#[test]
+#[cfg(feature = "decompress")]
+fn test_write_and_read_compressed_csv() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("x\n{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for compression in [
+        CompressionCodec::Gzip(Some(9)),
+        CompressionCodec::Zstd(None),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        CsvWriter::new(&mut buf)
+            .with_compression(Some(compression))
+            .finish(&mut df)?;
+        assert!(is_compressed(&buf));
+
+        let out = CsvReader::new(Cursor::new(&buf)).finish()?;
+        assert!(out.equals(&df));
+
+        // Parse in small batches; the quoted newlines must not split a record.
+        let out = CsvReadOptions::default()
+            .try_into_compressed_batched_reader(buf.as_slice())?
+            .unwrap()
+            .with_decompressed_chunk_size(100)
+            .finish()?;
+        assert!(out.equals(&df));
+    }
+
+    let mut buf: Vec<u8> = Vec::new();
+    let err = CsvWriter::new(&mut buf)
+        .with_compression(Some(CompressionCodec::Gzip(Some(10))))
+        .finish(&mut df);
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "lazy"))]
+fn test_scan_compressed_csv() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_scan_compressed_csv");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let mut df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("x{i}")).collect::<Vec<_>>(),
+    ]?;
+    for compression in [CompressionCodec::Gzip(None), CompressionCodec::Zstd(None)] {
+        let path = dir.join(format!("data.csv.{}", compression.extension()));
+        CsvWriter::new(std::fs::File::create(&path)?)
+            .with_compression(Some(compression))
+            .finish(&mut df)?;
+
+        let out = LazyCsvReader::new(&path).finish()?.collect()?;
+        assert!(out.equals(&df));
+
+        let out = LazyCsvReader::new(&path)
+            .with_n_rows(Some(600))
+            .with_row_index(Some(RowIndex {
+                name: Arc::from("idx"),
+                offset: 5,
+            }))
+            .finish()?
+            .filter(col("a").gt_eq(lit(598i64)))
+            .collect()?;
+        let expected = df![
+            "idx" => [603 as IdxSize, 604],
+            "a" => [598i64, 599],
+            "b" => ["x598", "x599"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = LazyCsvReader::new(&path)
+            .finish()?
+            .select([len()])
+            .collect()?;
+        assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+
+        #[cfg(feature = "streaming")]
+        {
+            let out = LazyCsvReader::new(&path)
+                .finish()?
+                .filter(col("a").lt(lit(10i64)))
+                .with_streaming(true)
+                .collect()?;
+            assert!(out.equals(&df.head(Some(10))));
+        }
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "streaming"))]
+fn test_sink_compressed_csv() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_sink_compressed_csv.csv.zst");
+
+    let df = df![
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("x{i}")).collect::<Vec<_>>(),
+    ]?;
+    let options = CsvWriterOptions {
+        maintain_order: true,
+        compression: Some(CompressionCodec::Zstd(Some(3))),
+        ..Default::default()
+    };
+    df.clone().lazy().sink_csv(&path, options)?;
+
+    let out = LazyCsvReader::new(&path).finish()?.collect()?;
+    assert!(out.equals(&df));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
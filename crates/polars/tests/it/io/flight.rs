@@ -0,0 +1,81 @@
+use polars::io::flight::{FlightClient, FlightServer};
+use polars::prelude::*;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+async fn serve(server: FlightServer) -> (String, oneshot::Sender<()>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let uri = format!("http://{}", listener.local_addr().unwrap());
+    let (tx, rx) = oneshot::channel();
+    tokio::spawn(server.serve_with_listener(listener, async {
+        rx.await.ok();
+    }));
+    (uri, tx)
+}
+
+fn example_df() -> DataFrame {
+    let mut df = df![
+        "int" => [Some(1i64), None, Some(3)],
+        "float" => [1.5f64, 2.5, f64::NAN],
+        "str" => [Some("a"), Some("bb"), None],
+        "list" => [Series::new("", [1i32, 2]), Series::new("", [3i32]), Series::new("", [0i32; 0])],
+    ]
+    .unwrap();
+    df.vstack_mut(&df.clone()).unwrap();
+    df
+}
+
+#[tokio::test]
+async fn test_flight_do_get() {
+    let df = example_df();
+    let server = FlightServer::new();
+    server.register("eager", df.clone());
+    #[cfg(feature = "lazy")]
+    server.register("lazy", df.clone().lazy().filter(col("int").is_not_null()));
+    let (uri, shutdown) = serve(server).await;
+
+    let mut client = FlightClient::connect(uri).await.unwrap();
+    let out = client.do_get("eager").await.unwrap();
+    assert!(out.equals_missing(&df));
+    assert_eq!(client.get_schema("eager").await.unwrap(), df.schema());
+
+    #[cfg(feature = "lazy")]
+    {
+        let out = client.do_get("lazy").await.unwrap();
+        assert_eq!(out.shape(), (4, 4));
+        let mut names = client.list_flights().await.unwrap();
+        names.sort();
+        assert_eq!(names, ["eager", "lazy"]);
+    }
+
+    let err = client.do_get("missing").await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("no flight registered under 'missing'"));
+    shutdown.send(()).unwrap();
+}
+
+#[tokio::test]
+async fn test_flight_do_put() {
+    let df = example_df();
+    let server = FlightServer::new();
+    let (uri, shutdown) = serve(server.clone()).await;
+
+    let mut client = FlightClient::connect(uri)
+        .await
+        .unwrap()
+        .with_pl_flavor(true);
+    client.do_put("uploaded", &df).await.unwrap();
+    assert!(server.collect("uploaded").unwrap().equals_missing(&df));
+    assert!(client.do_get("uploaded").await.unwrap().equals_missing(&df));
+
+    let empty = df.clear();
+    client.do_put("empty", &empty).await.unwrap();
+    let out = client.do_get("empty").await.unwrap();
+    assert_eq!(out.schema(), df.schema());
+    assert_eq!(out.height(), 0);
+
+    assert!(server.deregister("uploaded"));
+    assert!(client.do_get("uploaded").await.is_err());
+    shutdown.send(()).unwrap();
+}
@@ -0,0 +1,197 @@
+use std::fmt::Write;
+use std::io::Cursor;
+
+use polars::io::fwf::*;
+use polars::io::RowIndex;
+use polars::prelude::*;
+
+const ACCOUNTS: &str = "\
+ID    NAME          BALANCE  OPENED
+1     Alice          100.50  2021-01-04
+2     Bob                NA  2022-11-30
+3                     -7.25
+4     Dave Jones    1000     2020-02-29
+";
+
+#[test]
+fn test_read_fwf_header_spans() -> PolarsResult<()> {
+    let file = Cursor::new(ACCOUNTS);
+    let df = FixedWidthReadOptions::default()
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())))
+        .with_try_parse_dates(true)
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    let mut expected = df![
+        "ID" => [1i64, 2, 3, 4],
+        "NAME" => [Some("Alice"), Some("Bob"), None, Some("Dave Jones")],
+        "BALANCE" => [Some(100.5f64), None, Some(-7.25), Some(1000.0)],
+        "OPENED" => [Some(18631i32), Some(19326), None, Some(18321)],
+    ]?;
+    expected.apply("OPENED", |s| s.cast(&DataType::Date).unwrap())?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_options() -> PolarsResult<()> {
+    let data = "skipped line\r\n0012Alpha  X\r\n\r\n0345Beta   Y\r\n9999       Z\r\n";
+    let options = FixedWidthReadOptions::default()
+        .with_has_header(false)
+        .with_skip_rows(1)
+        .with_column_widths(&[4, 7, 1])
+        .with_null_values(Some(NullValues::Named(vec![(
+            "column_1".into(),
+            "9999".into(),
+        )])));
+
+    let df = options
+        .clone()
+        .into_reader_with_file_handle(Cursor::new(data))
+        .finish()?;
+    let expected = df![
+        "column_1" => [Some(12i64), Some(345), None],
+        "column_2" => [Some("Alpha"), Some("Beta"), None],
+        "column_3" => ["X", "Y", "Z"],
+    ]?;
+    assert!(df.equals_missing(&expected));
+
+    // Projection, n_rows, row index and a dtype overwrite.
+    let df = options
+        .clone()
+        .with_columns(Some(Arc::from([
+            "column_3".to_string(),
+            "column_1".to_string(),
+        ])))
+        .with_n_rows(Some(2))
+        .with_row_index(Some(RowIndex {
+            name: Arc::from("idx"),
+            offset: 10,
+        }))
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "column_1",
+            DataType::Float32,
+        )]))))
+        .into_reader_with_file_handle(Cursor::new(data))
+        .finish()?;
+    let expected = df![
+        "idx" => [10 as IdxSize, 11],
+        "column_3" => ["X", "Y"],
+        "column_1" => [12.0f32, 345.0],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // Without trimming, the padding is kept.
+    let df = options
+        .with_trim(false)
+        .with_column_spans(Some(Arc::from([(0, 4), (4, 11)])))
+        .into_reader_with_file_handle(Cursor::new(data))
+        .finish()?;
+    assert_eq!(df.column("column_2")?.str()?.get(1), Some("Beta   "));
+
+    // Spans must be given without a header.
+    let err = FixedWidthReadOptions::default()
+        .with_has_header(false)
+        .into_reader_with_file_handle(Cursor::new(data))
+        .finish();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_char_spans() -> PolarsResult<()> {
+    let data = "Stadt    Größe\nMünchen  1.5\nKöln     2\n";
+    let df = FixedWidthReadOptions::default()
+        .with_span_unit(SpanUnit::Chars)
+        .into_reader_with_file_handle(Cursor::new(data))
+        .finish()?;
+    let expected = df![
+        "Stadt" => ["München", "Köln"],
+        "Größe" => [1.5f64, 2.0],
+    ]?;
+    assert!(df.equals(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_read_fwf_parallel() -> PolarsResult<()> {
+    let n = 50_000;
+    let mut data = String::new();
+    for i in 0..n {
+        let name = format!("name{}", i % 7);
+        writeln!(data, "{i:>8}{name:<10}{:>6}", i % 3).unwrap();
+    }
+    let options = FixedWidthReadOptions::default()
+        .with_has_header(false)
+        .with_column_widths(&[8, 10, 6]);
+
+    let single = options
+        .clone()
+        .with_n_threads(Some(1))
+        .into_reader_with_file_handle(Cursor::new(data.as_bytes()))
+        .finish()?;
+    let parallel = options
+        .clone()
+        .with_n_threads(Some(4))
+        .into_reader_with_file_handle(Cursor::new(data.as_bytes()))
+        .finish()?;
+    assert_eq!(parallel.height(), n);
+    assert!(single.equals(&parallel));
+    assert_eq!(
+        parallel.column("column_1")?.i64()?.get(n - 1),
+        Some(n as i64 - 1)
+    );
+    assert_eq!(parallel.column("column_2")?.str()?.get(9), Some("name2"));
+
+    let df = options
+        .with_n_threads(Some(4))
+        .with_n_rows(Some(12_345))
+        .into_reader_with_file_handle(Cursor::new(data.as_bytes()))
+        .finish()?;
+    assert_eq!(df.height(), 12_345);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_fwf() -> PolarsResult<()> {
+    let dir = std::env::temp_dir().join("polars_test_scan_fwf");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("0.txt"), ACCOUNTS)?;
+    std::fs::write(
+        dir.join("1.txt"),
+        "ID    NAME          BALANCE  OPENED\n5     Erin                1  2023-05-05\n",
+    )?;
+
+    let read_options = FixedWidthReadOptions::default()
+        .with_null_values(Some(NullValues::AllColumnsSingle("NA".into())));
+    let lf = LazyFrame::scan_fwf(dir.join("*.txt"), read_options.clone())?;
+    assert_eq!(lf.clone().collect()?.height(), 5);
+
+    let q = lf
+        .clone()
+        .with_row_index("idx", None)
+        .filter(col("BALANCE").gt(lit(0.0)))
+        .select([col("idx"), col("NAME")]);
+    let plan = q.clone().explain(true)?;
+    assert!(plan.contains("PROJECT 2/4 COLUMNS"), "{plan}");
+    let out = q.collect()?;
+    let expected = df![
+        "idx" => [0 as IdxSize, 3, 4],
+        "NAME" => ["Alice", "Dave Jones", "Erin"],
+    ]?;
+    assert!(out.equals(&expected));
+
+    let out = LazyFixedWidthReader::new(dir.join("*.txt"))
+        .with_read_options(read_options)
+        .with_n_rows(Some(2))
+        .finish()?
+        .select([col("ID")])
+        .collect()?;
+    assert!(out.equals(&df!["ID" => [1i64, 2]]?));
+
+    let count = lf.select([len()]).collect()?;
+    assert_eq!(count.column("len")?.idx()?.get(0), Some(5));
+    Ok(())
+}
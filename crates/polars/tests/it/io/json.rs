@@ -179,3 +179,112 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+#[cfg(feature = "decompress")]
+fn test_write_and_read_compressed_ndjson() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+
+    for compression in [
+        CompressionCodec::Gzip(None),
+        CompressionCodec::Zstd(Some(5)),
+    ] {
+        let mut buf: Vec<u8> = Vec::new();
+        JsonWriter::new(&mut buf)
+            .with_json_format(JsonFormat::JsonLines)
+            .with_compression(Some(compression))
+            .finish(&mut df)?;
+        assert!(is_compressed(&buf));
+
+        let out = JsonLineReader::new(Cursor::new(&buf)).finish()?;
+        assert!(out.equals(&df));
+
+        let out = JsonLineReader::new(Cursor::new(&buf))
+            .with_n_rows(Some(10))
+            .finish()?;
+        assert!(out.equals(&df.head(Some(10))));
+        assert_eq!(JsonLineReader::new(Cursor::new(&buf)).count()?, 1000);
+
+        let overwrite = Schema::from_iter([Field::new("a", DataType::Float64)]);
+        let out = JsonLineReader::new(Cursor::new(&buf))
+            .with_schema_overwrite(&overwrite)
+            .finish()?;
+        assert_eq!(out.column("a")?.dtype(), &DataType::Float64);
+        assert!(out
+            .column("a")?
+            .equals(&df.column("a")?.cast(&DataType::Float64)?));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "decompress", feature = "lazy"))]
+fn test_scan_compressed_ndjson() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_scan_compressed_ndjson.ndjson.gz");
+
+    let mut df = df![
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    ]?;
+    JsonWriter::new(std::fs::File::create(&path)?)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_compression(Some(CompressionCodec::Gzip(None)))
+        .finish(&mut df)?;
+
+    let out = LazyJsonLineReader::new(&path).finish()?.collect()?;
+    assert!(out.equals(&df));
+
+    let out = LazyJsonLineReader::new(&path)
+        .finish()?
+        .filter(col("a").gt_eq(lit(998i64)))
+        .select([col("b")])
+        .collect()?;
+    let expected = df!["b" => ["998", "999"]]?;
+    assert!(out.equals(&expected));
+
+    let out = LazyJsonLineReader::new(&path)
+        .finish()?
+        .select([len()])
+        .collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+
+    // `n_rows` limits the rows read before the predicate, also across decompressed batches
+    let path_large =
+        std::env::temp_dir().join("polars_test_scan_compressed_ndjson_large.ndjson.gz");
+    let mut df_large = df![
+        "a" => (0..200i64).collect::<Vec<_>>(),
+        "b" => vec!["x".repeat(100_000); 200],
+    ]?;
+    JsonWriter::new(std::fs::File::create(&path_large)?)
+        .with_json_format(JsonFormat::JsonLines)
+        .with_compression(Some(CompressionCodec::Gzip(None)))
+        .finish(&mut df_large)?;
+    let out = LazyJsonLineReader::new(&path_large)
+        .with_n_rows(Some(180))
+        .finish()?
+        .filter(col("a").gt_eq(lit(100i64)))
+        .select([col("a")])
+        .collect()?;
+    let expected = df!["a" => (100..180i64).collect::<Vec<_>>()]?;
+    assert!(out.equals(&expected), "{out}");
+    std::fs::remove_file(&path_large)?;
+
+    #[cfg(feature = "streaming")]
+    {
+        let path = std::env::temp_dir().join("polars_test_sink_compressed_ndjson.ndjson.zst");
+        let options = JsonWriterOptions {
+            maintain_order: true,
+            compression: Some(CompressionCodec::Zstd(None)),
+        };
+        df.clone().lazy().sink_json(&path, options)?;
+        let out = LazyJsonLineReader::new(&path).finish()?.collect()?;
+        assert!(out.equals(&df));
+        std::fs::remove_file(&path)?;
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
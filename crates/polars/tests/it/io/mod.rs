@@ -9,10 +9,19 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(feature = "flight")]
+mod flight;
+
+#[cfg(feature = "fwf")]
+mod fwf;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(feature = "orc")]
+mod orc;
+#[cfg(feature = "xlsx")]
+mod xlsx;
 
 use polars::prelude::*;
 
@@ -0,0 +1,198 @@
+use std::io::Cursor;
+
+use polars::io::orc::*;
+use polars::prelude::*;
+
+fn write_orc(df: &mut DataFrame, compression: OrcCompression) -> Cursor<Vec<u8>> {
+    let mut buf = Cursor::new(Vec::new());
+    OrcWriter::new(&mut buf)
+        .with_compression(compression)
+        .finish(df)
+        .unwrap();
+    buf.set_position(0);
+    buf
+}
+
+#[test]
+fn test_orc_round_trip() {
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true)],
+        "i8" => [Some(1i8), Some(-2), None, Some(4)],
+        "i32" => [1i32, -2, 3, i32::MAX],
+        "i64" => [Some(i64::MIN), None, Some(0), Some(i64::MAX)],
+        "f32" => [1.5f32, f32::NAN, -3.0, 0.0],
+        "f64" => [Some(1.5f64), Some(2.5), None, Some(-0.5)],
+        "str" => [Some("a"), Some("bb"), None, Some("")],
+        "bin" => [b"a".as_ref(), b"", b"\x00\xff", b"bin"],
+    ]
+    .unwrap();
+
+    for compression in [
+        OrcCompression::Uncompressed,
+        OrcCompression::Zlib,
+        OrcCompression::Snappy,
+        OrcCompression::Lz4,
+        OrcCompression::Zstd,
+    ] {
+        let buf = write_orc(&mut df, compression);
+        let out = OrcReader::new(buf).finish().unwrap();
+        assert!(out.equals_missing(&df), "{compression:?}");
+    }
+}
+
+#[test]
+fn test_orc_temporal_round_trip() {
+    let mut df = df![
+        "date" => [Some(0i32), Some(-365), None, Some(19_000)],
+        "datetime" => [Some(0i64), Some(-1_500_000), Some(1_700_000_000_123_456), None],
+    ]
+    .unwrap();
+    df.apply("date", |s| s.cast(&DataType::Date).unwrap())
+        .unwrap();
+    df.apply("datetime", |s| {
+        s.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap()
+    })
+    .unwrap();
+
+    let buf = write_orc(&mut df, OrcCompression::Zstd);
+    let out = OrcReader::new(buf).finish().unwrap();
+    assert_eq!(out.column("date").unwrap().dtype(), &DataType::Date);
+    // ORC stores timestamps with nanosecond precision.
+    let expected = df
+        .column("datetime")
+        .unwrap()
+        .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+        .unwrap();
+    assert!(out.column("datetime").unwrap().equals_missing(&expected));
+    assert!(out
+        .column("date")
+        .unwrap()
+        .equals_missing(df.column("date").unwrap()));
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_orc_read_writer_timezone() {
+    // Written in America/Los_Angeles, see examples/datasets/make_writer_timezone_orc.py.
+    let file = std::fs::File::open("../../examples/datasets/writer_timezone.orc").unwrap();
+    let out = OrcReader::new(file).finish().unwrap();
+
+    // 2015-01-01 00:00:00, 2015-07-01 12:00:00.5 (PDT), 2020-03-08 03:30:00 (the first
+    // hour of PDT) and 2023-11-05 23:59:59.123456 (PST).
+    let values = [
+        Some(1_420_070_400_000_000_000i64),
+        Some(1_435_752_000_500_000_000),
+        Some(1_583_638_200_000_000_000),
+        Some(1_699_228_799_123_456_000),
+    ];
+    let mut wall_clock = values;
+    wall_clock[2] = None;
+    let expected = DataFrame::new(vec![
+        Series::new("ts", wall_clock)
+            .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+            .unwrap(),
+        Series::new("ts_instant", values)
+            .cast(&DataType::Datetime(
+                TimeUnit::Nanoseconds,
+                Some("UTC".into()),
+            ))
+            .unwrap(),
+    ])
+    .unwrap();
+    assert!(out.equals_missing(&expected), "{out}");
+}
+
+#[test]
+fn test_orc_nested_round_trip() {
+    let list = Series::new(
+        "list",
+        [
+            Some(Series::new("", [1i64, 2, 3])),
+            None,
+            Some(Series::new("", [] as [i64; 0])),
+            Some(Series::new("", [4i64])),
+        ],
+    );
+    let fields = [
+        Series::new("x", [Some(1i32), None, Some(3), Some(4)]),
+        Series::new("y", ["a", "b", "c", "d"]),
+    ];
+    let strct = StructChunked::new("struct", &fields).unwrap().into_series();
+    let mut df = DataFrame::new(vec![list, strct]).unwrap();
+
+    let buf = write_orc(&mut df, OrcCompression::Snappy);
+    let mut reader = OrcReader::new(buf);
+    let schema = reader.schema().unwrap();
+    assert!(matches!(
+        schema.fields[0].data_type(),
+        ArrowDataType::LargeList(_)
+    ));
+    let out = reader.finish().unwrap();
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+fn test_orc_projection_and_slice() {
+    let mut df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000).map(|i| format!("{}", i % 7)).collect::<Vec<_>>(),
+        "c" => (0..10_000).map(|i| i as f64).collect::<Vec<_>>(),
+    ]
+    .unwrap();
+
+    let mut buf = Cursor::new(Vec::new());
+    OrcWriter::new(&mut buf)
+        .with_stripe_size(Some(1_000))
+        .finish(&mut df)
+        .unwrap();
+    buf.set_position(0);
+
+    let mut reader = OrcReader::new(buf);
+    assert_eq!(reader.num_rows().unwrap(), 10_000);
+    let out = reader
+        .with_columns(Some(vec!["c".to_string(), "b".to_string()]))
+        .with_n_rows(Some(2_500))
+        .finish()
+        .unwrap();
+    let expected = df.select(["b", "c"]).unwrap().slice(0, 2_500);
+    assert!(out.equals(&expected));
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_orc_stripe_statistics_pruning() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => (0..10_000i64).collect::<Vec<_>>(),
+        "b" => (0..10_000).map(|i| format!("{i:05}")).collect::<Vec<_>>(),
+    ]?;
+
+    let path = std::env::temp_dir().join("polars_test_orc_stripe_statistics.orc");
+    OrcWriter::new(std::fs::File::create(&path)?)
+        .with_stripe_size(Some(1_000))
+        .finish(&mut df)?;
+
+    for use_statistics in [true, false] {
+        let args = ScanArgsOrc {
+            use_statistics,
+            ..Default::default()
+        };
+        let out = LazyFrame::scan_orc(&path, args.clone())?
+            .filter(
+                col("a")
+                    .gt_eq(lit(4_990i64))
+                    .and(col("a").lt(lit(5_010i64))),
+            )
+            .collect()?;
+        assert!(out.equals(&df.slice(4_990, 20)));
+
+        let out = LazyFrame::scan_orc(&path, args)?
+            .filter(col("b").eq(lit("09999")))
+            .select([col("a")])
+            .collect()?;
+        assert!(out.equals(&df!["a" => [9_999i64]]?));
+    }
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use std::io::Cursor;
+
+use polars::io::xlsx::*;
+use polars::prelude::*;
+
+fn write_xlsx(df: &mut DataFrame, sheet_name: &str) -> Cursor<Vec<u8>> {
+    let mut buf = Cursor::new(Vec::new());
+    XlsxWriter::new(&mut buf)
+        .with_sheet_name(sheet_name.into())
+        .finish(df)
+        .unwrap();
+    buf.set_position(0);
+    buf
+}
+
+#[test]
+fn test_xlsx_round_trip() {
+    let mut df = df![
+        "bool" => [Some(true), None, Some(false), Some(true)],
+        "i64" => [Some(-1i64), None, Some(0), Some(1 << 40)],
+        "f64" => [Some(1.5f64), Some(-2.25), None, Some(1e-3)],
+        "str" => [Some(" a"), Some("<b> & \"c\""), None, Some("_x0041_\u{1}")],
+        "date" => [Some(0i32), Some(-365), None, Some(19_000)],
+        "datetime" => [Some(0i64), Some(1_700_000_000_123_000), Some(-86_400_000_000), None],
+        "time" => [Some(0i64), Some(3_600_000_000_000), None, Some(86_399_999_000_000)],
+        "null" => [None::<i32>, None, None, None],
+    ]
+    .unwrap();
+    df.apply("date", |s| s.cast(&DataType::Date).unwrap())
+        .unwrap();
+    df.apply("datetime", |s| {
+        s.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap()
+    })
+    .unwrap();
+    df.apply("time", |s| s.cast(&DataType::Time).unwrap())
+        .unwrap();
+    df.apply("null", |s| s.cast(&DataType::String).unwrap())
+        .unwrap();
+
+    let mut buf = write_xlsx(&mut df, "data");
+    let mut reader = XlsxReader::new(&mut buf);
+    assert_eq!(reader.sheet_names().unwrap(), ["data"]);
+    let out = reader.finish().unwrap();
+    assert_eq!(out.schema(), df.schema());
+    assert!(out.equals_missing(&df));
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_xlsx_write_time_zone() {
+    // 1970-01-01 00:00 and 2024-07-01 00:00 UTC
+    let mut df = df!["datetime" => [0i64, 1_719_792_000_000_000]].unwrap();
+    df.apply("datetime", |s| {
+        s.cast(&DataType::Datetime(
+            TimeUnit::Microseconds,
+            Some("Europe/Amsterdam".into()),
+        ))
+        .unwrap()
+    })
+    .unwrap();
+
+    let mut buf = write_xlsx(&mut df, "data");
+    let out = XlsxReader::new(&mut buf).finish().unwrap();
+    // written as the wall time in Amsterdam (CET and CEST)
+    let mut expected = df!["datetime" => [3_600_000_000i64, 1_719_799_200_000_000]].unwrap();
+    expected
+        .apply("datetime", |s| {
+            s.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+                .unwrap()
+        })
+        .unwrap();
+    assert!(out.equals(&expected), "{out}");
+}
+
+#[test]
+fn test_xlsx_read_options() {
+    let mut df = df![
+        "a" => [1i64, 2, 3, 4],
+        "b" => ["x", "y", "z", "w"],
+        "c" => ["2024-01-01", "2024-01-02", "2024-01-03", "2024-01-04"],
+    ]
+    .unwrap();
+    let mut buf = write_xlsx(&mut df, "Sheet1");
+
+    let out = XlsxReader::new(&mut buf)
+        .with_sheet_name(Some("Sheet1".into()))
+        .with_range(Some("A2:B3".into()))
+        .has_header(false)
+        .finish()
+        .unwrap();
+    let expected = df![
+        "column_1" => [1i64, 2],
+        "column_2" => ["x", "y"],
+    ]
+    .unwrap();
+    assert!(out.equals(&expected));
+
+    let out = XlsxReader::new(&mut buf)
+        .with_n_rows(Some(2))
+        .with_columns(Some(vec!["c".into(), "a".into()]))
+        .with_try_parse_dates(true)
+        .with_schema_overwrite(Some(Arc::new(Schema::from_iter([Field::new(
+            "a",
+            DataType::Float32,
+        )]))))
+        .finish()
+        .unwrap();
+    let expected = DataFrame::new(vec![
+        Series::new("c", [19723i32, 19724])
+            .cast(&DataType::Date)
+            .unwrap(),
+        Series::new("a", [1.0f32, 2.0]),
+    ])
+    .unwrap();
+    assert!(out.equals(&expected));
+
+    let out = XlsxReader::new(&mut buf)
+        .infer_schema_length(Some(0))
+        .finish()
+        .unwrap();
+    assert!(out.dtypes().iter().all(|dt| dt == &DataType::String));
+    assert_eq!(out.column("a").unwrap().str().unwrap().get(3), Some("4"));
+
+    let err = XlsxReader::new(&mut buf)
+        .with_sheet_name(Some("missing".into()))
+        .finish();
+    assert!(err.is_err());
+}
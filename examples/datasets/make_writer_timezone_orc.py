@@ -0,0 +1,176 @@
+"""
+Generate `writer_timezone.orc`, an ORC file with timestamps written in the
+America/Los_Angeles time zone.
+
+The file is encoded directly from the ORC v1 specification (without using the
+Polars ORC writer) the way the Java and C++ writers lay it out: uncompressed,
+one stripe, RLE v2 streams and the writer time zone in the stripe footer.
+
+Columns:
+- ts: TIMESTAMP, the wall clock times below (with a null as the third value)
+- ts_instant: TIMESTAMP WITH LOCAL TIME ZONE, the same times as instants in UTC
+
+Usage: python make_writer_timezone_orc.py
+"""
+
+from __future__ import annotations
+
+from datetime import datetime, timezone
+from pathlib import Path
+from zoneinfo import ZoneInfo
+
+WRITER_TZ = "America/Los_Angeles"
+ORC_EPOCH = datetime(2015, 1, 1)
+
+# wall clock times; the third row of `ts` is null
+TIMES = [
+    datetime(2015, 1, 1, 0, 0, 0),
+    datetime(2015, 7, 1, 12, 0, 0, 500_000),
+    datetime(2020, 3, 8, 3, 30, 0),
+    datetime(2023, 11, 5, 23, 59, 59, 123_456),
+]
+NULL_ROW = 2
+
+
+def varint(value: int) -> bytes:
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def zigzag(value: int) -> int:
+    return (value << 1) ^ (value >> 63)
+
+
+# protobuf fields
+def uint_field(field: int, value: int) -> bytes:
+    return varint(field << 3) + varint(value)
+
+
+def bytes_field(field: int, value: bytes) -> bytes:
+    return varint((field << 3) | 2) + varint(len(value)) + value
+
+
+def rle_v2_direct(values: list[int], *, signed: bool) -> bytes:
+    """Encode the values as a single RLE v2 run with the DIRECT sub-encoding."""
+    values = [zigzag(v) if signed else v for v in values]
+    width = max(1, max(v.bit_length() for v in values))
+    widths = [*range(1, 25), 26, 28, 30, 32, 40, 48, 56, 64]
+    width = next(w for w in widths if w >= width)
+    code = widths.index(width)
+    n = len(values) - 1
+    header = bytes([0x40 | (code << 1) | (n >> 8), n & 0xFF])
+    bits = "".join(format(v, f"0{width}b") for v in values)
+    bits += "0" * (-len(bits) % 8)
+    return header + int(bits, 2).to_bytes(len(bits) // 8, "big")
+
+
+def present(validity: list[bool]) -> bytes:
+    """Encode the validity as a boolean (byte RLE) stream of literals."""
+    bits = "".join("1" if v else "0" for v in validity)
+    bits += "0" * (-len(bits) % 8)
+    data = int(bits, 2).to_bytes(len(bits) // 8, "big")
+    return bytes([256 - len(data)]) + data
+
+
+def encode_nanos(nanos: int) -> int:
+    """The nanoseconds with their trailing decimal zeros in the lowest 3 bits."""
+    if nanos == 0 or nanos % 100 != 0:
+        return nanos << 3
+    nanos //= 100
+    zeros = 1
+    while nanos % 10 == 0 and zeros < 7:
+        nanos //= 10
+        zeros += 1
+    return (nanos << 3) | zeros
+
+
+def timestamp_streams(times: list[datetime], tz: ZoneInfo) -> tuple[bytes, bytes]:
+    """The seconds relative to the ORC epoch in `tz` and the encoded nanos."""
+    epoch = ORC_EPOCH.replace(tzinfo=tz).timestamp()
+    seconds = [
+        int(t.replace(microsecond=0, tzinfo=tz).timestamp() - epoch)
+        for t in times
+    ]
+    nanos = [encode_nanos(t.microsecond * 1000) for t in times]
+    return rle_v2_direct(seconds, signed=True), rle_v2_direct(nanos, signed=False)
+
+
+def main() -> None:
+    la = ZoneInfo(WRITER_TZ)
+    ts = [t for i, t in enumerate(TIMES) if i != NULL_ROW]
+    ts_data, ts_nanos = timestamp_streams(ts, la)
+    instant_data, instant_nanos = timestamp_streams(TIMES, timezone.utc)
+
+    # (kind, column, data): PRESENT = 0, DATA = 1, SECONDARY = 5
+    streams = [
+        (0, 1, present([i != NULL_ROW for i in range(len(TIMES))])),
+        (1, 1, ts_data),
+        (5, 1, ts_nanos),
+        (1, 2, instant_data),
+        (5, 2, instant_nanos),
+    ]
+    data = b"".join(s for _, _, s in streams)
+
+    stripe_footer = b"".join(
+        bytes_field(1, uint_field(1, kind) + uint_field(2, col) + uint_field(3, len(s)))
+        for kind, col, s in streams
+    )
+    # DIRECT for the root struct, DIRECT_V2 for the timestamps
+    for encoding in [0, 2, 2]:
+        stripe_footer += bytes_field(2, uint_field(1, encoding))
+    stripe_footer += bytes_field(3, WRITER_TZ.encode())
+
+    header = b"ORC"
+    stripe_info = (
+        uint_field(1, len(header))
+        + uint_field(2, 0)
+        + uint_field(3, len(data))
+        + uint_field(4, len(stripe_footer))
+        + uint_field(5, len(TIMES))
+    )
+    # STRUCT = 12, TIMESTAMP = 9, TIMESTAMP_INSTANT = 18
+    types = [
+        uint_field(1, 12)
+        + bytes_field(2, varint(1) + varint(2))
+        + bytes_field(3, b"ts")
+        + bytes_field(3, b"ts_instant"),
+        uint_field(1, 9),
+        uint_field(1, 18),
+    ]
+    statistics = [
+        uint_field(1, len(TIMES)) + uint_field(10, 1),
+        uint_field(1, len(TIMES) - 1) + uint_field(10, 1),
+        uint_field(1, len(TIMES)) + uint_field(10, 0),
+    ]
+    footer = (
+        uint_field(1, len(header))
+        + uint_field(2, len(data) + len(stripe_footer))
+        + bytes_field(3, stripe_info)
+        + b"".join(bytes_field(4, t) for t in types)
+        + uint_field(6, len(TIMES))
+        + b"".join(bytes_field(7, s) for s in statistics)
+        + uint_field(8, 10_000)
+    )
+    # version 0.12, writer version 6 (ORC-135), no compression
+    postscript = (
+        uint_field(1, len(footer))
+        + uint_field(2, 0)
+        + bytes_field(4, varint(0) + varint(12))
+        + uint_field(5, 0)
+        + uint_field(6, 6)
+        + bytes_field(8000, b"ORC")
+    )
+
+    out = header + data + stripe_footer + footer + postscript + bytes([len(postscript)])
+    Path(__file__).with_name("writer_timezone.orc").write_bytes(out)
+
+
+if __name__ == "__main__":
+    main()
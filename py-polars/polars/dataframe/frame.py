@@ -3457,6 +3457,7 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filter_columns: Sequence[str] | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
     ) -> None:
@@ -3500,6 +3501,10 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filter_columns
+            Write a bloom filter per row group for these columns, which lets scans
+            of local files skip row groups on equality and `is_in` predicates. Cannot be combined
+            with `use_pyarrow`.
         use_pyarrow
             Use C++ parquet implementation vs Rust parquet implementation.
             At the moment C++ supports more features.
@@ -3550,6 +3555,9 @@ class DataFrame:
             if statistics == "full" or isinstance(statistics, dict):
                 msg = "write_parquet with `use_pyarrow=True` allows only boolean values for `statistics`"
                 raise ValueError(msg)
+            if bloom_filter_columns:
+                msg = "write_parquet with `use_pyarrow=True` does not support `bloom_filter_columns`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -3614,6 +3622,7 @@ class DataFrame:
                 statistics,
                 row_group_size,
                 data_page_size,
+                list(bloom_filter_columns or []),
             )
 
     def write_database(
@@ -6310,6 +6319,7 @@ class DataFrame:
         allow_parallel: bool = True,
         force_parallel: bool = False,
         coalesce: bool | None = None,
+        allow_exact_matches: bool = True,
     ) -> DataFrame:
         """
         Perform an asof join.
@@ -6395,6 +6405,13 @@ class DataFrame:
 
             Note that joining on any other expressions than `col`
             will turn off coalescing.
+        allow_exact_matches
+            Whether exact matches are valid join predicates.
+
+            - If True, allow matching with the same ``on`` value
+              (i.e. less-than-or-equal-to / greater-than-or-equal-to)
+            - If False, don't match the same ``on`` value
+              (i.e., strictly less-than / strictly greater-than).
 
         Examples
         --------
@@ -6613,6 +6630,7 @@ class DataFrame:
                 allow_parallel=allow_parallel,
                 force_parallel=force_parallel,
                 coalesce=coalesce,
+                allow_exact_matches=allow_exact_matches,
             )
             .collect(_eager=True)
         )
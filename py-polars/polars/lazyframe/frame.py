@@ -2153,6 +2153,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_pagesize_limit: int | None = None,
+        bloom_filter_columns: Sequence[str] | None = None,
         maintain_order: bool = True,
         type_coercion: bool = True,
         predicate_pushdown: bool = True,
@@ -2210,6 +2211,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_pagesize_limit
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        bloom_filter_columns
+            Write a bloom filter per row group for these columns, which lets scans
+            of local files skip row groups on equality and `is_in` predicates.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will  be slightly faster.
@@ -2268,6 +2272,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             statistics=statistics,
             row_group_size=row_group_size,
             data_pagesize_limit=data_pagesize_limit,
+            bloom_filter_columns=list(bloom_filter_columns or []),
             maintain_order=maintain_order,
         )
 
@@ -3789,6 +3794,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         allow_parallel: bool = True,
         force_parallel: bool = False,
         coalesce: bool | None = None,
+        allow_exact_matches: bool = True,
     ) -> LazyFrame:
         """
         Perform an asof join.
@@ -3812,6 +3818,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         The default is "backward".
 
+        In the streaming engine only the left DataFrame is streamed; the right
+        DataFrame is collected in memory.
+
         Parameters
         ----------
         other
@@ -3874,6 +3883,13 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
             Note that joining on any other expressions than `col`
             will turn off coalescing.
+        allow_exact_matches
+            Whether exact matches are valid join predicates.
+
+            - If True, allow matching with the same ``on`` value
+              (i.e. less-than-or-equal-to / greater-than-or-equal-to)
+            - If False, don't match the same ``on`` value
+              (i.e., strictly less-than / strictly greater-than).
 
 
         Examples
@@ -3965,6 +3981,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 tolerance_num,
                 tolerance_str,
                 coalesce=coalesce,
+                allow_exact_matches=allow_exact_matches,
             )
         )
 
@@ -400,7 +400,7 @@ impl PyDataFrame {
     }
 
     #[cfg(feature = "parquet")]
-    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size))]
+    #[pyo3(signature = (py_f, compression, compression_level, statistics, row_group_size, data_page_size, bloom_filter_columns))]
     pub fn write_parquet(
         &mut self,
         py: Python,
@@ -410,8 +410,13 @@ impl PyDataFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_page_size: Option<usize>,
+        bloom_filter_columns: Vec<String>,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let bloom_filter_columns = bloom_filter_columns
+            .into_iter()
+            .map(|name| (name, Default::default()))
+            .collect::<Vec<_>>();
 
         if let Ok(s) = py_f.extract::<PyBackedStr>(py) {
             let f = std::fs::File::create(&*s)?;
@@ -421,6 +426,7 @@ impl PyDataFrame {
                     .with_statistics(statistics.0)
                     .with_row_group_size(row_group_size)
                     .with_data_page_size(data_page_size)
+                    .with_bloom_filter_columns(bloom_filter_columns)
                     .finish(&mut self.df)
                     .map_err(PyPolarsErr::from)
             })?;
@@ -431,6 +437,7 @@ impl PyDataFrame {
                 .with_statistics(statistics.0)
                 .with_row_group_size(row_group_size)
                 .with_data_page_size(data_page_size)
+                .with_bloom_filter_columns(bloom_filter_columns)
                 .finish(&mut self.df)
                 .map_err(PyPolarsErr::from)?;
         }
@@ -295,6 +295,7 @@ impl PyLazyFrame {
             low_memory,
             cloud_options,
             use_statistics,
+            allow_missing_columns: false,
             hive_options,
             glob,
         };
@@ -614,7 +615,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(all(feature = "streaming", feature = "parquet"))]
-    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, maintain_order))]
+    #[pyo3(signature = (path, compression, compression_level, statistics, row_group_size, data_pagesize_limit, bloom_filter_columns, maintain_order))]
     fn sink_parquet(
         &self,
         py: Python,
@@ -624,6 +625,7 @@ impl PyLazyFrame {
         statistics: Wrap<StatisticsOptions>,
         row_group_size: Option<usize>,
         data_pagesize_limit: Option<usize>,
+        bloom_filter_columns: Vec<String>,
         maintain_order: bool,
     ) -> PyResult<()> {
         let compression = parse_parquet_compression(compression, compression_level)?;
@@ -631,6 +633,10 @@ impl PyLazyFrame {
         let options = ParquetWriteOptions {
             compression,
             statistics: statistics.0,
+            bloom_filter_columns: bloom_filter_columns
+                .into_iter()
+                .map(|name| (name, Default::default()))
+                .collect(),
             row_group_size,
             data_pagesize_limit,
             maintain_order,
@@ -711,6 +717,7 @@ impl PyLazyFrame {
             maintain_order,
             batch_size,
             serialize_options,
+            compression: None,
         };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
@@ -726,7 +733,10 @@ impl PyLazyFrame {
     #[cfg(all(feature = "streaming", feature = "json"))]
     #[pyo3(signature = (path, maintain_order))]
     fn sink_json(&self, py: Python, path: PathBuf, maintain_order: bool) -> PyResult<()> {
-        let options = JsonWriterOptions { maintain_order };
+        let options = JsonWriterOptions {
+            maintain_order,
+            compression: None,
+        };
 
         // if we don't allow threads and we have udfs trying to acquire the gil from different
         // threads we deadlock.
@@ -842,7 +852,7 @@ impl PyLazyFrame {
     }
 
     #[cfg(feature = "asof_join")]
-    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce))]
+    #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_exact_matches))]
     fn join_asof(
         &self,
         other: Self,
@@ -857,6 +867,7 @@ impl PyLazyFrame {
         tolerance: Option<Wrap<AnyValue<'_>>>,
         tolerance_str: Option<String>,
         coalesce: Option<bool>,
+        allow_exact_matches: bool,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -881,6 +892,7 @@ impl PyLazyFrame {
                 right_by: right_by.map(strings_to_smartstrings),
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                allow_eq: allow_exact_matches,
             }))
             .suffix(suffix)
             .finish()
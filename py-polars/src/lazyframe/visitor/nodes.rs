@@ -340,6 +340,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                     ("parquet", options, cloud_options).into_py(py)
                 },
                 FileScan::Ipc { .. } => return Err(PyNotImplementedError::new_err("ipc scan")),
+                FileScan::Avro => return Err(PyNotImplementedError::new_err("avro scan")),
                 FileScan::NDJson { options } => {
                     let options = serde_json::to_string(options)
                         .map_err(|err| PyValueError::new_err(format!("{err:?}")))?;
@@ -448,10 +449,12 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
             options: (
                 match options.args.how {
                     JoinType::Left => "left",
+                    JoinType::Right => "right",
                     JoinType::Inner => "inner",
                     JoinType::Full => "full",
                     JoinType::AsOf(_) => return Err(PyNotImplementedError::new_err("asof join")),
                     JoinType::Cross => "cross",
+                    JoinType::IEJoin(_) => return Err(PyNotImplementedError::new_err("iejoin")),
                     JoinType::Semi => "leftsemi",
                     JoinType::Anti => "leftanti",
                 },
@@ -564,6 +567,10 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                         .map_or_else(|| py.None(), |s| s.as_str().to_object(py)),
                 )
                     .to_object(py),
+                #[cfg(feature = "pivot")]
+                FunctionNode::Pivot { args: _, schema: _ } => {
+                    return Err(PyNotImplementedError::new_err("pivot mapfunction"))
+                },
                 FunctionNode::RowIndex {
                     name,
                     schema: _,
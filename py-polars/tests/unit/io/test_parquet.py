@@ -1230,3 +1230,23 @@ def test_parquet_record_batches_pyarrow_fixed_size_list_16614(tmp_path: Path) ->
 
     assert b["x"].shape[0] == n
     assert_frame_equal(b, x)
+
+
+def test_parquet_bloom_filter_columns(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame({"a": ["x", "y", "z"] * 100, "b": range(300)})
+
+    path = tmp_path / "bloom.parquet"
+    df.write_parquet(path, bloom_filter_columns=["a"], row_group_size=100)
+    assert_frame_equal(pl.read_parquet(path), df)
+    out = pl.scan_parquet(path).filter(pl.col("a") == "w").collect()
+    assert out.height == 0
+
+    path = tmp_path / "bloom_sink.parquet"
+    df.lazy().sink_parquet(path, bloom_filter_columns=["a"])
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    with pytest.raises(ValueError, match="bloom_filter_columns"):
+        df.write_parquet(
+            tmp_path / "bloom_pa.parquet", bloom_filter_columns=["a"], use_pyarrow=True
+        )
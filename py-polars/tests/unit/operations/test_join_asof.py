@@ -1180,3 +1180,41 @@ def test_join_as_of_by_schema() -> None:
     b = pl.DataFrame({"a": [1], "b": [2], "d": [4]}).lazy()
     q = a.join_asof(b, on=pl.col("a").set_sorted(), by="b")
     assert q.collect_schema().names() == q.collect().columns
+
+
+def test_join_asof_allow_exact_matches() -> None:
+    df1 = pl.DataFrame({"a": [1, 2, 3, 4, 5, 10]}).set_sorted("a")
+    df2 = pl.DataFrame({"a": [1, 3, 7], "b": [1, 2, 3]}).set_sorted("a")
+
+    out = df1.join_asof(df2, on="a", allow_exact_matches=False)
+    assert_frame_equal(
+        out,
+        pl.DataFrame({"a": [1, 2, 3, 4, 5, 10], "b": [None, 1, 1, 2, 2, 3]}),
+    )
+
+    out = df1.join_asof(df2, on="a", strategy="forward", allow_exact_matches=False)
+    assert_frame_equal(
+        out,
+        pl.DataFrame({"a": [1, 2, 3, 4, 5, 10], "b": [2, 2, 3, 3, 3, None]}),
+    )
+
+
+def test_join_asof_by_mixed_dtypes() -> None:
+    trades = pl.DataFrame(
+        {
+            "time": [1, 2, 3],
+            "ticker": ["a", "a", "b"],
+            "venue": pl.Series([1, 2, 1], dtype=pl.Int32),
+        }
+    ).set_sorted("time")
+    quotes = pl.DataFrame(
+        {
+            "time": [0, 1, 2],
+            "ticker": ["a", "a", "b"],
+            "venue": pl.Series([1, 2, 1], dtype=pl.Int64),
+            "bid": [10, 11, 12],
+        }
+    ).set_sorted("time")
+
+    out = trades.join_asof(quotes, on="time", by=["ticker", "venue"])
+    assert out["bid"].to_list() == [10, 11, 12]
@@ -201,6 +201,22 @@ def test_unnest_table_function(array_keyword: str) -> None:
         )
 
 
+def test_unnest_table_function_offset() -> None:
+    with pl.SQLContext(df=None, eager=True) as ctx:
+        res = ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
+        assert res.to_dict(as_series=False) == {
+            "colx": [1, 2, 3],
+            "offset": [0, 1, 2],
+        }
+        res = ctx.execute(
+            "SELECT * FROM UNNEST(['a', 'b']) WITH ORDINALITY AS tbl (colx, idx)"
+        )
+        assert res.to_dict(as_series=False) == {
+            "colx": ["a", "b"],
+            "idx": [1, 2],
+        }
+
+
 def test_unnest_table_function_errors() -> None:
     with pl.SQLContext(df=None, eager=True) as ctx:
         with pytest.raises(
@@ -228,10 +244,10 @@ def test_unnest_table_function_errors() -> None:
             ctx.execute("SELECT * FROM UNNEST([1, 2, 3])")
 
         with pytest.raises(
-            SQLInterfaceError,
-            match=r"UNNEST tables do not \(yet\) support WITH OFFSET/ORDINALITY",
+            SQLSyntaxError,
+            match="UNNEST ... WITH ORDINALITY table alias requires 2 column names, found 1",
         ):
-            ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) tbl (colx) WITH OFFSET")
+            ctx.execute("SELECT * FROM UNNEST([1, 2, 3]) WITH ORDINALITY AS tbl (colx)")
 
         with pytest.raises(
             SQLInterfaceError,